@@ -6,14 +6,14 @@ import { fromHexToAscii } from "./common.ts";
 
 export namespace DataAvailability {
 	export class DataSubmittedEvent {
-		constructor(public who: string, public dataHash: string) {}
+		constructor(public who: string, public dataHash: string, public blobHash: string) {}
 		static New(events: EventRecord[]): DataSubmittedEvent | undefined {
 			const ed: any = events.find((e) => e.event.method == "DataSubmitted" && e.event.section == "dataAvailability")?.event.data;
 			if (ed == undefined) {
 				return undefined;
 			}
 
-			return new DataSubmittedEvent(ed["who"].toString(), ed["dataHash"].toString());
+			return new DataSubmittedEvent(ed["who"].toString(), ed["dataHash"].toString(), ed["blobHash"].toString());
 		}
 	}
 
@@ -115,6 +115,7 @@ export namespace DataAvailability {
     constructor(
       public who: string,
       public dataHash: string,
+      public blobHash: string,
     ) {}
 
     static decode(event: EventRecord): DataSubmitted | null {
@@ -123,7 +124,7 @@ export namespace DataAvailability {
       }
       const ed: any = event.event.data
 
-      return new DataSubmitted(ed["who"].toString(), ed["dataHash"].toString())
+      return new DataSubmitted(ed["who"].toString(), ed["dataHash"].toString(), ed["blobHash"].toString())
     }
   }
 
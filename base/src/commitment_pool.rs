@@ -0,0 +1,50 @@
+//! Thread pool the commitments of the header extension are built in.
+//!
+//! The commitments of the rows of the grid are built in parallel, and dominate the time taken
+//! to propose and import blocks with large grids. The node sizes this pool at startup, so the
+//! commitments do not compete with the other rayon users of the node for the global pool.
+use once_cell::sync::OnceCell;
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+
+static COMMITMENT_POOL: OnceCell<ThreadPool> = OnceCell::new();
+
+/// Builds the commitment thread pool with `threads` threads, or one per CPU if `threads` is
+/// zero. The pool is only built once, later calls keep it as is.
+pub fn init(threads: usize) -> Result<(), ThreadPoolBuildError> {
+	COMMITMENT_POOL.get_or_try_init(|| {
+		ThreadPoolBuilder::new()
+			.num_threads(threads)
+			.thread_name(|idx| format!("kate-commitment-{idx}"))
+			.build()
+	})?;
+	Ok(())
+}
+
+/// Runs `op` in the commitment thread pool, or in the global rayon pool if it was not built.
+pub fn install<R, OP>(op: OP) -> R
+where
+	R: Send,
+	OP: FnOnce() -> R + Send,
+{
+	match COMMITMENT_POOL.get() {
+		Some(pool) => pool.install(op),
+		None => op(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ops_run_in_the_commitment_pool_once_built() {
+		assert_eq!(install(|| 1 + 1), 2);
+
+		init(2).unwrap();
+		assert_eq!(install(rayon::current_num_threads), 2);
+
+		// The pool is only built once.
+		init(4).unwrap();
+		assert_eq!(install(rayon::current_num_threads), 2);
+	}
+}
@@ -35,9 +35,9 @@ impl GetAppId for SubmittedData {
 
 #[derive(Debug, Default)]
 pub struct ExtractedTxData {
-	pub app_extrinsic: Option<AppExtrinsic>,
-	pub submitted_data: Option<SubmittedData>,
-	pub bridge_data: Option<BridgedData>,
+	pub app_extrinsics: Vec<AppExtrinsic>,
+	pub submitted_data: Vec<SubmittedData>,
+	pub bridge_data: Vec<BridgedData>,
 }
 
 #[derive(Debug, Default, PassByCodec, Encode, Decode)]
@@ -191,17 +191,9 @@ impl From<Vec<ExtractedTxData>> for HeaderExtensionBuilderData {
 		let mut app_extrinsics = Vec::new();
 
 		for val in value {
-			if let Some(data_submission) = val.submitted_data {
-				data_submissions.push(data_submission);
-			}
-
-			if let Some(bridge_message) = val.bridge_data {
-				bridge_messages.push(bridge_message);
-			}
-
-			if let Some(app_extrinsic) = val.app_extrinsic {
-				app_extrinsics.push(app_extrinsic);
-			}
+			data_submissions.extend(val.submitted_data);
+			bridge_messages.extend(val.bridge_data);
+			app_extrinsics.extend(val.app_extrinsics);
 		}
 
 		Self {
@@ -1,6 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(unused_extern_crates)]
 
+#[cfg(feature = "std")]
+pub mod commitment_pool;
 #[cfg(feature = "std")]
 pub mod metrics;
 
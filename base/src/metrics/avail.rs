@@ -38,6 +38,8 @@ pub struct HeaderExtensionBuilderMetrics {
 	pub total_execution_time: Histogram,
 	pub evaluation_grid_build_time: Histogram,
 	pub commitment_build_time: Histogram,
+	pub polynomial_grid_build_time: Histogram,
+	pub extended_commitments_build_time: Histogram,
 	pub grid_rows: Histogram,
 	pub grid_cols: Histogram,
 }
@@ -105,8 +107,22 @@ impl HeaderExtensionBuilderMetrics {
 			"Header Extension Builder - Commitment Build Time in microseconds",
 			buckets.to_vec(),
 		)?;
+		let polynomial_grid_build_time = custom_histogram(
+			registry,
+			"avail_header_extension_builder_polynomial_grid_build_time",
+			"Header Extension Builder - Polynomial Grid Build Time in microseconds",
+			buckets.to_vec(),
+		)?;
+		let extended_commitments_build_time = custom_histogram(
+			registry,
+			"avail_header_extension_builder_extended_commitments_build_time",
+			"Header Extension Builder - Extended Commitments Build Time in microseconds",
+			buckets.to_vec(),
+		)?;
 
-		let buckets = [4.0, 6.0, 8.0, 12.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0];
+		let buckets = [
+			4.0, 6.0, 8.0, 12.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0, 1024.0, 2048.0, 4096.0,
+		];
 		let grid_rows = custom_histogram(
 			registry,
 			"avail_header_extension_builder_grid_rows",
@@ -124,6 +140,8 @@ impl HeaderExtensionBuilderMetrics {
 			total_execution_time,
 			evaluation_grid_build_time,
 			commitment_build_time,
+			polynomial_grid_build_time,
+			extended_commitments_build_time,
 			grid_rows,
 			grid_cols,
 		})
@@ -156,6 +174,24 @@ impl HeaderExtensionBuilderMetrics {
 		}
 	}
 
+	pub(crate) fn observe_polynomial_grid_build_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.header_extension
+				.polynomial_grid_build_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_extended_commitments_build_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.header_extension
+				.extended_commitments_build_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
 	pub fn observe_grid_rows(value: f64) {
 		if let Some(metrics) = AVAIL_METRICS.get() {
 			metrics.header_extension.grid_rows.observe(value);
@@ -172,8 +208,13 @@ impl HeaderExtensionBuilderMetrics {
 pub struct KateRpcMetrics {
 	pub query_rows_execution_time: Histogram,
 	pub query_proof_execution_time: Histogram,
+	pub query_multiproof_execution_time: Histogram,
 	pub query_block_length_execution_time: Histogram,
 	pub query_data_proof_execution_time: Histogram,
+	pub query_leaf_proof_execution_time: Histogram,
+	pub query_data_location_execution_time: Histogram,
+	pub query_app_data_ranges_execution_time: Histogram,
+	pub query_app_subtree_execution_time: Histogram,
 }
 
 impl KateRpcMetrics {
@@ -210,6 +251,13 @@ impl KateRpcMetrics {
 			buckets.to_vec(),
 		)?;
 
+		let query_multiproof_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_multiproof_execution_time",
+			"Kate RPC - Query Multiproof Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
 		let buckets = [
 			100.0, 200.0, 300.0, 400.0, 500.0, // 0.10ms, 0.20ms, 0.30ms, 0.40ms, 0.50ms,
 			750.0, 1000.0, 1250.0, 2500.0, // 0.75ms, 1.0ms, 1.25ms, 2.5ms
@@ -234,11 +282,49 @@ impl KateRpcMetrics {
 			buckets.to_vec(),
 		)?;
 
+		let query_leaf_proof_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_leaf_proof_execution_time",
+			"Kate RPC - Query Leaf Proof Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
+		let buckets = [
+			1000.0, 5000.0, 10000.0, 25000.0, //  1ms, 5ms, 10ms, 25ms
+			50000.0, 75000.0, 100_000.0, 150_000.0, // 50ms, 75ms, 100ms, 150ms
+			200_000.0, 300_000.0, 400_000.0, 500_000.0, // 200ms, 300ms, 400ms, 500ms
+		];
+		let query_data_location_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_data_location_execution_time",
+			"Kate RPC - Query Data Location Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
+		let query_app_data_ranges_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_app_data_ranges_execution_time",
+			"Kate RPC - Query App Data Ranges Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
+		let query_app_subtree_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_app_subtree_execution_time",
+			"Kate RPC - Query App Subtree Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
 		Ok(Self {
 			query_rows_execution_time,
 			query_proof_execution_time,
+			query_multiproof_execution_time,
 			query_block_length_execution_time,
 			query_data_proof_execution_time,
+			query_leaf_proof_execution_time,
+			query_data_location_execution_time,
+			query_app_data_ranges_execution_time,
+			query_app_subtree_execution_time,
 		})
 	}
 
@@ -260,6 +346,15 @@ impl KateRpcMetrics {
 		}
 	}
 
+	pub(crate) fn observe_query_multiproof_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_multiproof_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
 	pub(crate) fn observe_query_block_length_execution_time(duration: Duration) {
 		if let Some(metrics) = AVAIL_METRICS.get() {
 			metrics
@@ -277,6 +372,42 @@ impl KateRpcMetrics {
 				.observe(duration.as_micros() as f64);
 		}
 	}
+
+	pub(crate) fn observe_query_leaf_proof_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_leaf_proof_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_data_location_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_data_location_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_app_data_ranges_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_app_data_ranges_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_app_subtree_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_app_subtree_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
 }
 
 pub struct ImportBlockMetrics {
@@ -325,12 +456,19 @@ impl ImportBlockMetrics {
 pub enum ObserveKind {
 	ImportBlockTotalExecutionTime,
 	KateQueryDataProof,
+	KateQueryLeafProof,
+	KateQueryDataLocation,
+	KateQueryAppDataRanges,
+	KateQueryAppSubtree,
 	KateQueryBlockLength,
 	KateQueryProof,
+	KateQueryMultiProof,
 	KateQueryRows,
 	HETotalExecutionTime,
 	HEGrid,
 	HECommitment,
+	HEPolynomialGrid,
+	HEExtendedCommitments,
 }
 
 pub struct MetricObserver {
@@ -357,12 +495,27 @@ impl Drop for MetricObserver {
 			ObserveKind::KateQueryDataProof => {
 				KateRpcMetrics::observe_query_data_proof_execution_time(duration)
 			},
+			ObserveKind::KateQueryLeafProof => {
+				KateRpcMetrics::observe_query_leaf_proof_execution_time(duration)
+			},
+			ObserveKind::KateQueryDataLocation => {
+				KateRpcMetrics::observe_query_data_location_execution_time(duration)
+			},
+			ObserveKind::KateQueryAppDataRanges => {
+				KateRpcMetrics::observe_query_app_data_ranges_execution_time(duration)
+			},
+			ObserveKind::KateQueryAppSubtree => {
+				KateRpcMetrics::observe_query_app_subtree_execution_time(duration)
+			},
 			ObserveKind::KateQueryBlockLength => {
 				KateRpcMetrics::observe_query_block_length_execution_time(duration)
 			},
 			ObserveKind::KateQueryProof => {
 				KateRpcMetrics::observe_query_proof_execution_time(duration)
 			},
+			ObserveKind::KateQueryMultiProof => {
+				KateRpcMetrics::observe_query_multiproof_execution_time(duration)
+			},
 			ObserveKind::KateQueryRows => {
 				KateRpcMetrics::observe_query_rows_execution_time(duration)
 			},
@@ -375,6 +528,12 @@ impl Drop for MetricObserver {
 			ObserveKind::HECommitment => {
 				HeaderExtensionBuilderMetrics::observe_commitment_build_time(duration)
 			},
+			ObserveKind::HEPolynomialGrid => {
+				HeaderExtensionBuilderMetrics::observe_polynomial_grid_build_time(duration)
+			},
+			ObserveKind::HEExtendedCommitments => {
+				HeaderExtensionBuilderMetrics::observe_extended_commitments_build_time(duration)
+			},
 		}
 	}
 }
@@ -58,6 +58,12 @@ pub struct Cli {
 	/// Max size cannot exceed 10_000
 	#[arg(long, default_value_t = 64, value_parser=kate_max_cells_size_upper_bound)]
 	pub kate_max_cells_size: usize,
+
+	/// Number of threads building the commitments of the blocks.
+	///
+	/// Defaults to one thread per CPU.
+	#[arg(long, default_value_t = 0)]
+	pub kate_commitment_threads: usize,
 }
 
 fn kate_max_cells_size_upper_bound(s: &str) -> Result<usize, String> {
@@ -26,7 +26,6 @@ use da_runtime::{apis::RuntimeApi, NodeBlock as Block, Runtime};
 use codec::Encode;
 use frame_system_rpc_runtime_api::AccountNonceApi;
 use futures::prelude::*;
-use pallet_transaction_payment::ChargeTransactionPayment;
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_babe::{self, SlotProportion};
 pub use sc_executor::NativeElseWasmExecutor;
@@ -135,7 +134,7 @@ pub fn create_extrinsic(
 		frame_system::CheckEra::<Runtime>::from(Era::mortal(period, best_block.saturated_into())),
 		frame_system::CheckNonce::<Runtime>::from(nonce),
 		frame_system::CheckWeight::<Runtime>::new(),
-		ChargeTransactionPayment::<Runtime>::from(tip),
+		da_control::PrioritizedTransactionPayment::<Runtime>::from(tip),
 		da_control::CheckAppId::<Runtime>::from(app_id),
 	);
 
@@ -655,6 +654,8 @@ pub fn new_full_base(
 /// Builds a new service for a full client.
 pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
 	let database_path = config.database.path().map(Path::to_path_buf);
+	avail_base::commitment_pool::init(cli.kate_commitment_threads)
+		.map_err(|e| ServiceError::Application(e.into()))?;
 	let task_manager = new_full_base(
 		config,
 		cli.no_hardware_benchmarks,
@@ -9,16 +9,16 @@ use avail_core::{
 };
 use codec::{Decode, Encode};
 use frame_benchmarking::{
-	impl_benchmark_test_suite, v1::BenchmarkError, v2::*, whitelisted_caller,
+	account, impl_benchmark_test_suite, v1::BenchmarkError, v2::*, whitelisted_caller,
 };
-use frame_support::traits::Get;
+use frame_support::traits::{Currency, Get, Hooks, ReservableCurrency};
 use frame_system::{
 	limits::BlockLength, native::hosted_header_builder::hosted_header_builder, RawOrigin,
 };
 use scale_info::{StaticTypeInfo, TypeInfo};
 use sp_core::H256;
 use sp_runtime::{
-	traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+	traits::{Bounded, DispatchInfoOf, Dispatchable, SignedExtension},
 	transaction_validity::{TransactionValidity, TransactionValidityError},
 };
 use sp_std::{fmt::Debug, iter::repeat, vec, vec::Vec};
@@ -155,6 +155,17 @@ where
 	(txs, root, block_length, block_number, seed)
 }
 
+fn block_length_bounds<T: Config>() -> BlockLengthBounds {
+	BlockLengthBounds {
+		min_rows: T::MinBlockRows::get(),
+		max_rows: T::MaxBlockRows::get(),
+		min_cols: T::MinBlockCols::get(),
+		max_cols: T::MaxBlockCols::get(),
+		upper_target: Perbill::from_percent(80),
+		lower_target: Perbill::from_percent(20),
+	}
+}
+
 #[benchmarks(
 	where <T as frame_system::Config>::RuntimeCall: From<DACall<T>>, T: Send + Sync + Debug + StaticTypeInfo
 )]
@@ -164,6 +175,7 @@ mod benchmarks {
 	#[benchmark]
 	fn create_application_key() -> Result<(), BenchmarkError> {
 		let caller = whitelisted_caller::<T::AccountId>();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		let origin = RawOrigin::Signed(caller.clone());
 		let max_key_len = T::MaxAppKeyLength::get();
 		let key = generate_bounded::<AppKeyFor<T>>(max_key_len);
@@ -173,35 +185,33 @@ mod benchmarks {
 		_(origin, key);
 
 		let info = Pallet::<T>::application_key(key_verify);
-		assert_eq!(
-			info,
-			Some(AppKeyInfoFor::<T> {
-				owner: caller,
-				id: AppId(10)
-			})
-		);
+		let expected =
+			AppKeyInfoFor::<T>::new(caller, AppId(10)).with_deposit(T::AppKeyDeposit::get());
+		assert_eq!(info, Some(expected));
 
 		Ok(())
 	}
 
 	#[benchmark]
 	fn submit_block_length_proposal() -> Result<(), BenchmarkError> {
-		let origin = RawOrigin::Root;
-		let rows = T::MaxBlockRows::get().0;
-		let cols = T::MaxBlockCols::get().0;
+		let rows = T::MinBlockRows::get();
+		let cols = T::MinBlockCols::get();
 
 		#[extrinsic_call]
-		_(origin, rows, cols);
+		_(RawOrigin::Root, rows.0, cols.0);
 
+		assert_eq!(PendingBlockLengthProposal::<T>::get(), Some((rows, cols)));
 		Ok(())
 	}
 
 	#[benchmark]
 	fn submit_data(i: Linear<1, { T::MaxAppDataLength::get() }>) -> Result<(), BenchmarkError> {
 		let caller = whitelisted_caller::<T::AccountId>();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		let origin = RawOrigin::Signed(caller.clone());
 		let data = generate_bounded::<AppDataFor<T>>(i);
 		let data_hash = H256(blake2_256(&data));
+		let blob_hash = H256(keccak_256(&data));
 
 		#[extrinsic_call]
 		_(origin, data);
@@ -210,6 +220,7 @@ mod benchmarks {
 			Event::DataSubmitted {
 				who: caller,
 				data_hash,
+				blob_hash,
 			}
 			.into(),
 		);
@@ -297,6 +308,367 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_application_key_metadata() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+		let metadata = AppKeyMetadata {
+			display_name_hash: H256::repeat_byte(1),
+			contact: generate_bounded(MAX_APP_CONTACT_LENGTH),
+		};
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			key.clone(),
+			Some(metadata.clone()),
+		);
+
+		let info = Pallet::<T>::application_key(key).unwrap();
+		assert_eq!(info.metadata, Some(metadata));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_app_key_ownership() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let new_owner = account::<T::AccountId>("new_owner", 0, 0);
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key.clone(), new_owner.clone());
+
+		assert_eq!(PendingAppKeyTransfers::<T>::get(key), Some(new_owner));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_app_key_ownership() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let owner = account::<T::AccountId>("owner", 0, 0);
+		let deposit = T::AppKeyDeposit::get();
+		T::Currency::make_free_balance_be(&owner, deposit * 2u32.into());
+		T::Currency::reserve(&owner, deposit)?;
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		let info = AppKeyInfoFor::<T>::new(owner, AppId(10)).with_deposit(deposit);
+		AppKeys::<T>::insert(&key, info);
+		PendingAppKeyTransfers::<T>::insert(&key, &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), key.clone());
+
+		let info = Pallet::<T>::application_key(key).unwrap();
+		assert_eq!(info.owner, caller);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_submitter_allowlist(
+		s: Linear<0, { T::MaxAppSubmitters::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+		let submitters: Vec<T::AccountId> = (0..s).map(|i| account("submitter", i, 0)).collect();
+		let submitters = SubmittersFor::<T>::try_from(submitters).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, Some(submitters.clone()));
+
+		assert_eq!(SubmitterAllowlists::<T>::get(AppId(10)), Some(submitters));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_data_quota() -> Result<(), BenchmarkError> {
+		let quota = AppDataQuota {
+			per_block: Some(1024),
+			per_era: Some(1024 * 1024),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, AppId(1), Some(quota));
+
+		assert_eq!(AppDataQuotas::<T>::get(AppId(1)), Some(quota));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_block_length_governor() -> Result<(), BenchmarkError> {
+		let bounds = block_length_bounds::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(bounds));
+
+		assert_eq!(BlockLengthGovernor::<T>::get(), Some(bounds));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn govern_block_length() -> Result<(), BenchmarkError> {
+		let bounds = block_length_bounds::<T>();
+		BlockLengthGovernor::<T>::put(bounds);
+		GridUtilization::<T>::put(Perbill::one());
+		let block_length = BlockLength::with_normal_ratio(
+			bounds.min_rows,
+			bounds.min_cols,
+			BLOCK_CHUNK_SIZE,
+			DA_DISPATCH_RATIO,
+		)
+		.unwrap();
+		frame_system::DynamicBlockLength::<T>::put(block_length);
+		let block = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			Pallet::<T>::on_finalize(block);
+			Pallet::<T>::on_initialize(block);
+		}
+
+		let block_length = frame_system::DynamicBlockLength::<T>::get();
+		assert_eq!(block_length.rows.0, bounds.min_rows.0 * 2);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_data_byte_price() -> Result<(), BenchmarkError> {
+		let price = Pallet::<T>::data_byte_price();
+
+		#[block]
+		{
+			Pallet::<T>::update_data_byte_price(Perbill::one());
+		}
+
+		assert!(DataBytePrice::<T>::get() > price);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fund_sponsorship_pool() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, amount);
+
+		let pool = Pallet::<T>::sponsorship_pool_account(AppId(10));
+		assert_eq!(T::Currency::free_balance(&pool), amount);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_sponsorship_pool() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+		let pool = Pallet::<T>::sponsorship_pool_account(AppId(10));
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		T::Currency::make_free_balance_be(&pool, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), key, amount);
+
+		assert_eq!(T::Currency::free_balance(&caller), amount);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_application_key() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let deposit = T::AppKeyDeposit::get();
+		T::Currency::make_free_balance_be(&caller, deposit * 2u32.into());
+		T::Currency::reserve(&caller, deposit)?;
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		let info = AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)).with_deposit(deposit);
+		AppKeys::<T>::insert(&key, info);
+		let pool = Pallet::<T>::sponsorship_pool_account(AppId(10));
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		T::Currency::make_free_balance_be(&pool, amount);
+		AppReservedSpaces::<T>::insert(AppId(10), 1024);
+		TotalReservedSpace::<T>::put(1024);
+		let children = (0..T::MaxAppChildren::get())
+			.map(|i| AppId(10 + i))
+			.collect::<Vec<_>>();
+		AppChildren::<T>::insert(AppId(9), AppChildrenFor::<T>::truncate_from(children));
+		AppParents::<T>::insert(AppId(10), AppId(9));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), key.clone());
+
+		assert_eq!(Pallet::<T>::application_key(key), None);
+		assert_eq!(T::Currency::reserved_balance(&caller), Zero::zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_reserved_space() -> Result<(), BenchmarkError> {
+		let bytes = Pallet::<T>::max_reserved_space();
+		AppReservedSpaces::<T>::insert(AppId(1), bytes);
+		TotalReservedSpace::<T>::put(bytes);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, AppId(1), Some(bytes));
+
+		assert_eq!(AppReservedSpaces::<T>::get(AppId(1)), Some(bytes));
+		assert_eq!(TotalReservedSpace::<T>::get(), bytes);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_max_data_length() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+		let max_length = T::MaxAppDataLength::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, Some(max_length));
+
+		assert_eq!(AppMaxDataLengths::<T>::get(AppId(10)), Some(max_length));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_sequencing() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, true);
+
+		assert_eq!(
+			AppSequences::<T>::get(AppId(10)).map(|s| s.enabled),
+			Some(true)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn retire_application_key() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key);
+
+		assert!(AppRetirements::<T>::contains_key(AppId(10)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn complete_app_key_retirement() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let deposit = T::AppKeyDeposit::get();
+		T::Currency::make_free_balance_be(&caller, deposit * 2u32.into());
+		T::Currency::reserve(&caller, deposit)?;
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		let info = AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)).with_deposit(deposit);
+		AppKeys::<T>::insert(&key, info);
+		AppRetirements::<T>::insert(AppId(10), 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), key);
+
+		assert_eq!(T::Currency::reserved_balance(&caller), Zero::zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reserve_block_space() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let block = T::MaxIntentLookahead::get();
+		let bytes = T::MaxAppDataLength::get().min(Pallet::<T>::max_reserved_space());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), block, bytes);
+
+		assert_eq!(BlockSpaceIntentOf::<T>::get(block, &caller), Some(bytes));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_child_application_key() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let parent_key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(
+			&parent_key,
+			AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)),
+		);
+		let children = (1..T::MaxAppChildren::get())
+			.map(|i| AppId(10 + i))
+			.collect::<Vec<_>>();
+		AppChildren::<T>::insert(AppId(10), AppChildrenFor::<T>::truncate_from(children));
+		let key = AppKeyFor::<T>::truncate_from(repeat_bytes(b'Y', T::MaxAppKeyLength::get()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), parent_key, key.clone());
+
+		let id = Pallet::<T>::application_key(key).map(|info| info.id);
+		assert_eq!(id.and_then(AppParents::<T>::get), Some(AppId(10)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn pause_application() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key);
+
+		assert!(Pallet::<T>::is_paused(AppId(10)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unpause_application() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+		AppKeys::<T>::insert(&key, AppKeyInfoFor::<T>::new(caller.clone(), AppId(10)));
+		PausedApps::<T>::insert(AppId(10), 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key);
+
+		assert!(!Pallet::<T>::is_paused(AppId(10)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_block_length_proposal() -> Result<(), BenchmarkError> {
+		// Reducing the block length also checks the weight of the block.
+		let block_length = BlockLength::with_normal_ratio(
+			T::MaxBlockRows::get(),
+			T::MaxBlockCols::get(),
+			BLOCK_CHUNK_SIZE,
+			DA_DISPATCH_RATIO,
+		)
+		.unwrap();
+		frame_system::DynamicBlockLength::<T>::put(block_length);
+		let rows = T::MinBlockRows::get();
+		let cols = T::MinBlockCols::get();
+		PendingBlockLengthProposal::<T>::put((rows, cols));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		let block: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+		let at = block + T::BlockLengthChangeDelay::get();
+		assert_last_event::<T>(Event::BlockLengthChangeAnnounced { rows, cols, at }.into());
+		Ok(())
+	}
+
 	#[benchmark(extra)]
 	fn commitment_builder_64(
 		i: Linear<32, { T::MaxBlockRows::get().0 }>,
@@ -339,5 +711,19 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark(extra)]
+	fn commitment_builder_512(
+		i: Linear<32, { T::MaxBlockRows::get().0 }>,
+	) -> Result<(), BenchmarkError> {
+		let (txs, root, block_length, block_number, seed) = commitment_parameters::<T>(i, 512);
+
+		#[block]
+		{
+			hosted_header_builder::build(txs, root, block_length, block_number, seed);
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_benchmark_ext(), crate::mock::Test);
 }
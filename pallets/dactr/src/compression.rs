@@ -0,0 +1,81 @@
+//! Zstandard frame header parsing for compressed data submissions.
+//!
+//! The runtime does not decompress submitted data. It only reads the content size declared by
+//! the frame header, which the decompressor of a light client enforces.
+
+/// Magic number starting a zstd frame, in little endian.
+pub const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// Returns the content size declared by the header of the zstd frame at the start of `data`, if
+/// it is a well formed header declaring it.
+pub fn zstd_frame_content_size(data: &[u8]) -> Option<u64> {
+	let data = data.strip_prefix(&ZSTD_MAGIC)?;
+	let (&descriptor, mut data) = data.split_first()?;
+
+	// The reserved bit must be zero.
+	if descriptor & 0b0000_1000 != 0 {
+		return None;
+	}
+	let single_segment = descriptor & 0b0010_0000 != 0;
+	let dictionary_id_len = [0, 1, 2, 4][usize::from(descriptor & 0b11)];
+	let content_size_len = match descriptor >> 6 {
+		0 if single_segment => 1,
+		0 => return None,
+		1 => 2,
+		2 => 4,
+		_ => 8,
+	};
+
+	if !single_segment {
+		data = data.get(1..)?;
+	}
+	let content_size = data.get(dictionary_id_len..dictionary_id_len + content_size_len)?;
+
+	let mut bytes = [0u8; 8];
+	bytes[..content_size_len].copy_from_slice(content_size);
+	let content_size = u64::from_le_bytes(bytes);
+
+	// The two bytes field is offset by 256.
+	if content_size_len == 2 {
+		return content_size.checked_add(256);
+	}
+	Some(content_size)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Single segment frame holding `hello` in a raw block.
+	const HELLO: [u8; 14] = [
+		0x28, 0xB5, 0x2F, 0xFD, 0x20, 0x05, 0x29, 0x00, 0x00, b'h', b'e', b'l', b'l', b'o',
+	];
+
+	#[test]
+	fn reads_declared_content_size() {
+		assert_eq!(zstd_frame_content_size(&HELLO), Some(5));
+
+		// Windowed frame with a one byte dictionary id and a two bytes content size.
+		let frame = [0x28, 0xB5, 0x2F, 0xFD, 0x41, 0x50, 0x07, 0x10, 0x00];
+		assert_eq!(zstd_frame_content_size(&frame), Some(272));
+
+		// Single segment frame with a four bytes content size.
+		let frame = [0x28, 0xB5, 0x2F, 0xFD, 0xA0, 0x00, 0x00, 0x01, 0x00];
+		assert_eq!(zstd_frame_content_size(&frame), Some(65_536));
+	}
+
+	#[test]
+	fn rejects_malformed_headers() {
+		// Not a zstd frame.
+		assert_eq!(zstd_frame_content_size(b"hello"), None);
+		// Content size not declared.
+		let frame = [0x28, 0xB5, 0x2F, 0xFD, 0x00, 0x50];
+		assert_eq!(zstd_frame_content_size(&frame), None);
+		// Reserved bit set.
+		let frame = [0x28, 0xB5, 0x2F, 0xFD, 0x28, 0x05];
+		assert_eq!(zstd_frame_content_size(&frame), None);
+		// Truncated content size.
+		let frame = [0x28, 0xB5, 0x2F, 0xFD, 0xA0, 0x00, 0x00];
+		assert_eq!(zstd_frame_content_size(&frame), None);
+	}
+}
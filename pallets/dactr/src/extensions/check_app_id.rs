@@ -1,9 +1,14 @@
 use super::MAX_ITERATIONS;
-use crate::{Call as DACall, CheckBatchTransactions, Config as DAConfig, Pallet, LOG_TARGET};
+use crate::{
+	pool, AppDataQuotas, AppDataUsages, AppOriginFilter, BlockSpaceIntentOf, Call as DACall,
+	CheckBatchTransactions, Config as DAConfig, Pallet, ReservedSpaceUsages, SubmittedDataLeaves,
+	TotalReservedSpaceUsage, LOG_TARGET,
+};
 use avail_core::{traits::GetAppId, AppId, InvalidTransactionCustomId};
 
 use codec::{Decode, Encode};
 use frame_support::{
+	dispatch::DispatchClass,
 	ensure,
 	traits::{IsSubType, IsType},
 };
@@ -11,24 +16,34 @@ use frame_system::{AllExtrinsicsLen, Config as SystemConfig, DynamicBlockLength,
 use pallet_utility::{Call as UtilityCall, Config as UtilityConfig};
 use pallet_vector::{Call as VectorCall, Config as VectorConfig};
 use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{DispatchInfoOf, SignedExtension},
+	traits::{DispatchInfoOf, SaturatedConversion, SignedExtension},
 	transaction_validity::{
 		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
 };
 use sp_std::{
+	collections::btree_map::BTreeMap,
 	default::Default,
 	fmt::{self, Debug, Formatter},
 	marker::PhantomData,
 	vec::Vec,
 };
 
+/// Custom validity error of the data submitted to an application paused by its owner. It is
+/// kept clear of the codes of `InvalidTransactionCustomId`.
+pub const PAUSED_APP_ERROR: u8 = 100;
+
 /// Check for Application Id.
 ///
 /// # Transaction Validity
 ///
-/// Only registered application can be used by transactions.
+/// Only registered application can be used by transactions. The calls wrapped by proxy and
+/// multisig calls are validated for the account they are dispatched for.
+/// The price of submitted data is charged before its dispatch, as the header extension commits
+/// it even if the dispatch fails.
 ///
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -51,20 +66,58 @@ where
 
 	/// It validates that `AppId` is correct and already registered for the call and potential nested calls.
 	/// Transaction validation:
-	///  - `DataAvailability::submit_data(..)` extrinsic can use `AppId != 0`.
+	///  - `DataAvailability::submit_data(..)`, `DataAvailability::submit_compressed_data(..)`,
+	///  `DataAvailability::submit_data_with_retention(..)`,
+	///  `DataAvailability::submit_reserved_data(..)` and
+	///  `DataAvailability::submit_sponsored_data(..)` extrinsics can use `AppId != 0`. The latter
+	///  must use the `AppId` it is sponsored for.
+	///  - `DataAvailability::submit_data_batch(..)` must use `AppId == 0`, its entries carry their
+	///  own application.
 	///  - `Utility::batch/batch_all/force_batch(..)` extrinsic can use `AppId != 0` If the wrapped calls are ALL `DataAvailability::submit_data(..)`.
 	///  - Any other call must use `AppId == 0`.
 	///  - It also ensures that Kate's evaluation grid can be generated during the header
 	///  production.
+	///  - Compressed data must be a zstd frame declaring its decompressed length.
+	///  - Submitted data must not exceed the maximum data length of its application.
+	///  - Submitted data must not target a retired application.
 	pub fn do_validate(
 		&self,
 		call: &<T as SystemConfig>::RuntimeCall,
 		len: usize,
 	) -> TransactionValidity {
 		self.ensure_valid_app_id(call)?;
-		if let Some(DACall::<T>::submit_data { .. }) = call.is_sub_type() {
+		if let Some(DACall::<T>::submit_compressed_data {
+			data,
+			decompressed_len,
+		}) = call.is_sub_type()
+		{
+			ensure!(
+				<Pallet<T>>::is_valid_compression(data, *decompressed_len),
+				InvalidTransaction::Call
+			);
+		}
+		if let Some(DACall::<T>::submit_sponsored_data { app_id, .. }) = call.is_sub_type() {
+			ensure!(
+				*app_id == self.app_id(),
+				InvalidTransaction::Custom(InvalidTransactionCustomId::InvalidAppId as u8)
+			);
+		}
+		if let Some(batch @ DACall::<T>::submit_data_batch { .. }) = call.is_sub_type() {
+			self.ensure_max_data_length(batch)?;
+			self.ensure_not_retired(batch)?;
+			self.ensure_not_paused(batch)?;
+		}
+		if let Some(
+			DACall::<T>::submit_data { .. }
+			| DACall::<T>::submit_compressed_data { .. }
+			| DACall::<T>::submit_sponsored_data { .. }
+			| DACall::<T>::submit_data_with_retention { .. }
+			| DACall::<T>::submit_reserved_data { .. }
+			| DACall::<T>::submit_data_batch { .. },
+		) = call.is_sub_type()
+		{
 			let all_extrinsics_len = self
-				.next_all_extrinsics_len(len)
+				.next_all_extrinsics_len(call, len)
 				.ok_or(InvalidTransaction::ExhaustsResources)?;
 			AllExtrinsicsLen::<T>::put(all_extrinsics_len);
 		}
@@ -73,17 +126,27 @@ where
 		Ok(ValidTransaction::default())
 	}
 
-	fn next_all_extrinsics_len(&self, len: usize) -> Option<ExtrinsicLenOf<T>> {
-		let app_id = self.app_id();
-		let len = u32::try_from(len).ok()?;
-
+	fn next_all_extrinsics_len(
+		&self,
+		call: &<T as SystemConfig>::RuntimeCall,
+		len: usize,
+	) -> Option<ExtrinsicLenOf<T>> {
 		// Get maximum padded length of current block length.
 		let curr_len = DynamicBlockLength::<T>::get();
 		let max_scalars = curr_len.rows.0.checked_mul(curr_len.cols.0)?;
 
-		// Update extrinsics length info.
+		// Update extrinsics length info. The entries of a data batch are laid out in the grid
+		// by their own application, instead of the extrinsic.
 		let mut all_extrinsics_len = AllExtrinsicsLen::<T>::get().unwrap_or_default();
-		let _ = all_extrinsics_len.add_padded(app_id, len)?;
+		if let Some(DACall::<T>::submit_data_batch { batch }) = call.is_sub_type() {
+			for (app_id, data) in batch.iter() {
+				let len = u32::try_from(data.len()).ok()?;
+				let _ = all_extrinsics_len.add_padded(*app_id, len)?;
+			}
+		} else {
+			let len = u32::try_from(len).ok()?;
+			let _ = all_extrinsics_len.add_padded(self.app_id(), len)?;
+		}
 
 		// Calculate total padded length
 		let total_scalars = all_extrinsics_len.total_num_scalars()?;
@@ -99,6 +162,314 @@ where
 		}
 	}
 
+	/// It resolves the account and the call the extrinsic signed by `who` dispatches, through
+	/// the proxy and multisig calls wrapping it. Only the accounts allowed to dispatch calls on
+	/// behalf of another one can use these calls.
+	pub fn resolve_origin<'a>(
+		&self,
+		who: &T::AccountId,
+		call: &'a <T as SystemConfig>::RuntimeCall,
+	) -> Result<(T::AccountId, &'a <T as SystemConfig>::RuntimeCall), TransactionValidityError> {
+		let (mut who, mut call) = (who.clone(), call);
+		let mut iterations = 0;
+		while let Some(inner) = T::AppOriginFilter::inner_call(&who, call) {
+			iterations += 1;
+			ensure!(
+				iterations <= MAX_ITERATIONS,
+				InvalidTransaction::Custom(InvalidTransactionCustomId::MaxRecursionExceeded as u8)
+			);
+			who = T::AppOriginFilter::inner_origin(&who, call)
+				.ok_or(InvalidTransaction::BadSigner)?;
+			call = inner;
+		}
+		Ok((who, call))
+	}
+
+	/// It validates that `who` is allowed to submit data for the `AppId`, if the application
+	/// restricts its submitters.
+	pub fn ensure_allowed_submitter(
+		&self,
+		who: &T::AccountId,
+	) -> Result<(), TransactionValidityError> {
+		let app_id = self.app_id();
+		ensure!(
+			app_id == AppId(0) || <Pallet<T>>::is_allowed_submitter(app_id, who),
+			InvalidTransaction::BadSigner
+		);
+		Ok(())
+	}
+
+	/// It validates that the `AppId` does not exceed its data quota with `len` more bytes, and
+	/// records them if `note` is set.
+	pub fn ensure_within_quota(
+		&self,
+		len: usize,
+		note: bool,
+	) -> Result<(), TransactionValidityError> {
+		let app_id = self.app_id();
+		if app_id == AppId(0) {
+			return Ok(());
+		}
+
+		let Some(quota) = AppDataQuotas::<T>::get(app_id) else {
+			return Ok(());
+		};
+
+		let len = u32::try_from(len).map_err(|_| InvalidTransaction::ExhaustsResources)?;
+		let usage = <Pallet<T>>::next_app_data_usage(app_id, &quota, len)
+			.ok_or(InvalidTransaction::ExhaustsResources)?;
+		if note {
+			AppDataUsages::<T>::insert(app_id, usage);
+		}
+		Ok(())
+	}
+
+	/// It validates that the entries of a data batch submitted by `who` target registered
+	/// applications which allow `who` to submit data, within their data quotas, and records
+	/// their quota usage if `note` is set.
+	pub fn ensure_valid_batch_entries(
+		&self,
+		who: &T::AccountId,
+		call: &<T as SystemConfig>::RuntimeCall,
+		note: bool,
+	) -> Result<(), TransactionValidityError> {
+		let Some(DACall::<T>::submit_data_batch { batch }) = call.is_sub_type() else {
+			return Ok(());
+		};
+
+		let next_app_id = <Pallet<T>>::peek_next_application_id();
+		let mut app_lens = BTreeMap::<AppId, u32>::new();
+		for (id, data) in batch.iter() {
+			ensure!(
+				*id < next_app_id,
+				InvalidTransaction::Custom(InvalidTransactionCustomId::InvalidAppId as u8)
+			);
+			ensure!(
+				<Pallet<T>>::is_allowed_submitter(*id, who),
+				InvalidTransaction::BadSigner
+			);
+			let len = app_lens.entry(*id).or_default();
+			*len = len.saturating_add(data.len() as u32);
+		}
+
+		// The entries of an application share its quota.
+		for (id, len) in app_lens {
+			let Some(quota) = AppDataQuotas::<T>::get(id) else {
+				continue;
+			};
+			let usage = <Pallet<T>>::next_app_data_usage(id, &quota, len)
+				.ok_or(InvalidTransaction::ExhaustsResources)?;
+			if note {
+				AppDataUsages::<T>::insert(id, usage);
+			}
+		}
+		Ok(())
+	}
+
+	/// It validates that the data fee of `call` submitted by `who` can be paid, and charges it
+	/// if `note` is set. The header extension commits the submitted data even if its dispatch
+	/// fails, so the fee is charged before it.
+	pub fn ensure_data_fee(
+		&self,
+		who: &T::AccountId,
+		call: &<T as SystemConfig>::RuntimeCall,
+		note: bool,
+	) -> Result<(), TransactionValidityError> {
+		let Some(call) = call.is_sub_type() else {
+			return Ok(());
+		};
+
+		<Pallet<T>>::charge_submitted_data_fee(who, call, note)
+			.map_err(|_| InvalidTransaction::Payment.into())
+	}
+
+	/// It validates that the extrinsic does not eat into the unused block space reserved for
+	/// the other applications, and records the reserved space it uses if `note` is set. The
+	/// length of the block includes the extrinsic once `CheckWeight` pre-dispatched it.
+	pub fn ensure_reserved_space(
+		&self,
+		info: &DispatchInfoOf<<T as SystemConfig>::RuntimeCall>,
+		len: usize,
+		note: bool,
+	) -> Result<(), TransactionValidityError> {
+		if info.class != DispatchClass::Normal {
+			return Ok(());
+		}
+
+		let len = u32::try_from(len).map_err(|_| InvalidTransaction::ExhaustsResources)?;
+		let mut block_len = <frame_system::Pallet<T>>::all_extrinsics_len();
+		if !note {
+			block_len = block_len.saturating_add(len);
+		}
+
+		let app_id = self.app_id();
+		let (app_usage, total_usage) =
+			<Pallet<T>>::next_reserved_space_usage(app_id, len, block_len)
+				.ok_or(InvalidTransaction::ExhaustsResources)?;
+		if note && app_usage.bytes > 0 {
+			ReservedSpaceUsages::<T>::insert(app_id, app_usage);
+			TotalReservedSpaceUsage::<T>::put(total_usage);
+		}
+		Ok(())
+	}
+
+	/// It validates that the data submission fits the current grid alongside the data
+	/// submissions queued in the transaction pool, and notes it as queued. Only the pool
+	/// validation queues submissions, so it is not checked at block building.
+	pub fn ensure_fits_queued_grid(
+		&self,
+		who: &T::AccountId,
+		call: &<T as SystemConfig>::RuntimeCall,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		if !matches!(
+			call.is_sub_type(),
+			Some(
+				DACall::<T>::submit_data { .. }
+					| DACall::<T>::submit_compressed_data { .. }
+					| DACall::<T>::submit_sponsored_data { .. }
+					| DACall::<T>::submit_data_with_retention { .. }
+					| DACall::<T>::submit_reserved_data { .. }
+					| DACall::<T>::submit_data_batch { .. }
+			)
+		) {
+			return Ok(());
+		}
+
+		let len = u32::try_from(len).map_err(|_| InvalidTransaction::ExhaustsResources)?;
+		let mut extrinsic_len = ExtrinsicLenOf::<T>::default();
+		let scalars = extrinsic_len
+			.add_padded(self.app_id(), len)
+			.and_then(|_| extrinsic_len.total_num_scalars())
+			.ok_or(InvalidTransaction::ExhaustsResources)?;
+		let block_len = DynamicBlockLength::<T>::get();
+		let max_scalars = block_len.rows.0.saturating_mul(block_len.cols.0);
+
+		let key = H256(blake2_256(&(who, call).encode()));
+		ensure!(
+			pool::queue_submission(key, scalars, max_scalars),
+			InvalidTransaction::ExhaustsResources
+		);
+		Ok(())
+	}
+
+	/// Returns whether `call` fills the block space intent of `who` in the current block, whose
+	/// space is already reserved. It validates that the intent exists and fits the data, as the
+	/// data is committed even if its dispatch fails.
+	pub fn fills_block_space_intent(
+		&self,
+		who: &T::AccountId,
+		call: &<T as SystemConfig>::RuntimeCall,
+	) -> Result<bool, TransactionValidityError> {
+		let Some(DACall::<T>::submit_reserved_data { data }) = call.is_sub_type() else {
+			return Ok(false);
+		};
+
+		let block: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+		let bytes = BlockSpaceIntentOf::<T>::get(block, who);
+		ensure!(
+			bytes.is_some_and(|bytes| data.len() <= bytes as usize),
+			InvalidTransaction::Call
+		);
+		Ok(true)
+	}
+
+	/// It counts the leaves `call` adds to the submitted data sub trie of the data root, even if
+	/// its dispatch fails, as the header extension includes them.
+	pub fn note_data_leaves(&self, call: &<T as SystemConfig>::RuntimeCall) {
+		let Some(call) = call.is_sub_type() else {
+			return;
+		};
+
+		let leaves = <Pallet<T>>::data_leaves(call);
+		if leaves > 0 {
+			SubmittedDataLeaves::<T>::mutate(|total| *total = total.saturating_add(leaves));
+		}
+	}
+
+	/// It records the data submitted by `call` in the usage statistics of the applications,
+	/// and assigns it the next sequence number of the applications numbering their submissions.
+	pub fn note_submissions(&self, call: &<T as SystemConfig>::RuntimeCall) {
+		let note = |app_id: AppId, len: usize| {
+			<Pallet<T>>::note_app_usage(app_id, len as u32);
+			<Pallet<T>>::note_app_sequence(app_id);
+			<Pallet<T>>::note_submission_receipt(app_id);
+		};
+		match call.is_sub_type() {
+			Some(
+				DACall::<T>::submit_data { data }
+				| DACall::<T>::submit_compressed_data { data, .. }
+				| DACall::<T>::submit_sponsored_data { data, .. }
+				| DACall::<T>::submit_data_with_retention { data, .. }
+				| DACall::<T>::submit_reserved_data { data },
+			) => note(self.app_id(), data.len()),
+			Some(DACall::<T>::submit_data_batch { batch }) => {
+				for (app_id, data) in batch.iter() {
+					note(*app_id, data.len());
+				}
+			},
+			_ => {},
+		}
+	}
+
+	/// It validates that the data submitted by `call` does not exceed the maximum data length
+	/// of its application, using the larger of the compressed and declared decompressed
+	/// lengths of compressed data.
+	pub fn ensure_max_data_length(&self, call: &DACall<T>) -> Result<(), TransactionValidityError> {
+		let within_max_data_length = match call {
+			DACall::<T>::submit_data { data }
+			| DACall::<T>::submit_sponsored_data { data, .. }
+			| DACall::<T>::submit_data_with_retention { data, .. }
+			| DACall::<T>::submit_reserved_data { data } => {
+				<Pallet<T>>::is_within_max_data_length(self.app_id(), data.len())
+			},
+			DACall::<T>::submit_compressed_data {
+				data,
+				decompressed_len,
+			} => <Pallet<T>>::is_within_max_data_length(
+				self.app_id(),
+				<Pallet<T>>::compressed_data_len(data, *decompressed_len),
+			),
+			DACall::<T>::submit_data_batch { batch } => batch
+				.iter()
+				.all(|(id, data)| <Pallet<T>>::is_within_max_data_length(*id, data.len())),
+			_ => true,
+		};
+		ensure!(
+			within_max_data_length,
+			InvalidTransaction::ExhaustsResources
+		);
+		Ok(())
+	}
+
+	/// It validates that the data submitted by `call` does not target a retired application.
+	pub fn ensure_not_retired(&self, call: &DACall<T>) -> Result<(), TransactionValidityError> {
+		let retired = match call {
+			DACall::<T>::submit_data_batch { batch } => {
+				batch.iter().any(|(id, _)| <Pallet<T>>::is_retired(*id))
+			},
+			_ => <Pallet<T>>::is_retired(self.app_id()),
+		};
+		ensure!(
+			!retired,
+			InvalidTransaction::Custom(InvalidTransactionCustomId::InvalidAppId as u8)
+		);
+		Ok(())
+	}
+
+	/// It validates that the data submitted by `call` does not target an application paused by
+	/// its owner.
+	pub fn ensure_not_paused(&self, call: &DACall<T>) -> Result<(), TransactionValidityError> {
+		let paused = match call {
+			DACall::<T>::submit_data_batch { batch } => {
+				batch.iter().any(|(id, _)| <Pallet<T>>::is_paused(*id))
+			},
+			_ => <Pallet<T>>::is_paused(self.app_id()),
+		};
+		ensure!(!paused, InvalidTransaction::Custom(PAUSED_APP_ERROR));
+		Ok(())
+	}
+
 	fn ensure_valid_app_id(
 		&self,
 		call: &<T as SystemConfig>::RuntimeCall,
@@ -114,13 +485,23 @@ where
 		let mut iterations = 0;
 
 		while let Some(call) = stack.pop() {
-			if let Some(DACall::<T>::submit_data { .. }) = call.is_sub_type() {
+			if let Some(
+				da_call @ (DACall::<T>::submit_data { .. }
+				| DACall::<T>::submit_compressed_data { .. }
+				| DACall::<T>::submit_sponsored_data { .. }
+				| DACall::<T>::submit_data_with_retention { .. }
+				| DACall::<T>::submit_reserved_data { .. }),
+			) = call.is_sub_type()
+			{
 				let next_app_id =
 					maybe_next_app_id.get_or_insert_with(<Pallet<T>>::peek_next_application_id);
 				ensure!(
 					self.app_id() < *next_app_id,
 					InvalidTransaction::Custom(InvalidTransactionCustomId::InvalidAppId as u8)
 				);
+				self.ensure_max_data_length(da_call)?;
+				self.ensure_not_retired(da_call)?;
+				self.ensure_not_paused(da_call)?;
 			} else {
 				match call.is_sub_type() {
 					Some(UtilityCall::<T>::batch { calls })
@@ -188,22 +569,42 @@ where
 
 	fn validate(
 		&self,
-		_who: &Self::AccountId,
+		who: &Self::AccountId,
 		call: &Self::Call,
-		_info: &DispatchInfoOf<Self::Call>,
+		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		self.do_validate(call, len)
+		let (who, call) = self.resolve_origin(who, call)?;
+		self.ensure_allowed_submitter(&who)?;
+		self.ensure_within_quota(len, false)?;
+		self.ensure_valid_batch_entries(&who, call, false)?;
+		if !self.fills_block_space_intent(&who, call)? {
+			self.ensure_reserved_space(info, len, false)?;
+		}
+		let valid = self.do_validate(call, len)?;
+		self.ensure_data_fee(&who, call, false)?;
+		self.ensure_fits_queued_grid(&who, call, len)?;
+		Ok(valid)
 	}
 
 	fn pre_dispatch(
 		self,
-		_who: &Self::AccountId,
+		who: &Self::AccountId,
 		call: &Self::Call,
-		_info: &DispatchInfoOf<Self::Call>,
+		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
+		let (who, call) = self.resolve_origin(who, call)?;
+		self.ensure_allowed_submitter(&who)?;
+		self.ensure_within_quota(len, true)?;
+		self.ensure_valid_batch_entries(&who, call, true)?;
+		if !self.fills_block_space_intent(&who, call)? {
+			self.ensure_reserved_space(info, len, true)?;
+		}
 		self.do_validate(call, len)?;
+		self.ensure_data_fee(&who, call, true)?;
+		self.note_data_leaves(call);
+		self.note_submissions(call);
 		Ok(())
 	}
 
@@ -226,15 +627,27 @@ where
 mod tests {
 	use avail_core::{
 		asdr::AppUncheckedExtrinsic,
-		InvalidTransactionCustomId::{ForbiddenAppId, InvalidAppId},
+		InvalidTransactionCustomId::{ForbiddenAppId, InvalidAppId, MaxRecursionExceeded},
 	};
 	use frame_system::pallet::Call as SysCall;
 	use sp_runtime::transaction_validity::InvalidTransaction;
 	use test_case::test_case;
 
 	use super::*;
-	use crate::extensions::extensions_mock::{new_test_ext, RuntimeCall, Test};
+	use crate::extensions::extensions_mock::{
+		new_test_ext, Balances, RuntimeCall, System, Test, Utility,
+	};
 	use crate::pallet::Call as DACall;
+	use crate::{
+		AppDataQuota, AppMaxDataLengths, AppReservedSpaces, AppRetirements, AppSequence,
+		AppSequences, AppUsageHistory, AppUsageStats, BlockSpaceIntents, IntentSpaces, PausedApps,
+		SubmittedDataLeaves, SubmitterAllowlists, TotalReservedSpace,
+	};
+	use frame_support::{
+		dispatch::DispatchInfo,
+		traits::{Currency, Get},
+	};
+	use sp_runtime::AccountId32;
 
 	fn remark_call() -> RuntimeCall {
 		RuntimeCall::System(SysCall::remark { remark: vec![] })
@@ -246,6 +659,24 @@ mod tests {
 		})
 	}
 
+	fn submit_compressed_data_call(decompressed_len: u32) -> RuntimeCall {
+		// Single segment zstd frame holding `hello` in a raw block.
+		let data = vec![
+			0x28, 0xB5, 0x2F, 0xFD, 0x20, 0x05, 0x29, 0x00, 0x00, b'h', b'e', b'l', b'l', b'o',
+		];
+		RuntimeCall::DataAvailability(DACall::submit_compressed_data {
+			data: data.try_into().unwrap(),
+			decompressed_len,
+		})
+	}
+
+	fn submit_data_batch_call() -> RuntimeCall {
+		let data = vec![b'X'].try_into().unwrap();
+		RuntimeCall::DataAvailability(DACall::submit_data_batch {
+			batch: vec![(AppId(1), data)].try_into().unwrap(),
+		})
+	}
+
 	fn to_invalid_tx(custom_id: InvalidTransactionCustomId) -> TransactionValidity {
 		Err(TransactionValidityError::Invalid(
 			InvalidTransaction::Custom(custom_id as u8),
@@ -254,6 +685,10 @@ mod tests {
 
 	#[test_case(1, submit_data_call() => Ok(ValidTransaction::default()); "Submit Data call should be allowed to use any valid AppId" )]
 	#[test_case(100, submit_data_call() => to_invalid_tx(InvalidAppId); "Submit Data call with invalid AppId should be blocked" )]
+	#[test_case(1, submit_compressed_data_call(5) => Ok(ValidTransaction::default()); "Submit Compressed Data call declaring its content size should be allowed" )]
+	#[test_case(1, submit_compressed_data_call(6) => Err(InvalidTransaction::Call.into()); "Submit Compressed Data call with a wrong decompressed length should be blocked" )]
+	#[test_case(0, submit_data_batch_call() => Ok(ValidTransaction::default()); "Submit Data Batch call should be allowed with AppId == 0" )]
+	#[test_case(1, submit_data_batch_call() => to_invalid_tx(ForbiddenAppId); "Submit Data Batch call with AppId != 0 should be blocked" )]
 	#[test_case(0, remark_call() => Ok(ValidTransaction::default()); "Any Non-Submit-Data call with AppId == 0 should be allowed" )]
 	#[test_case(1, remark_call() => to_invalid_tx(ForbiddenAppId); "Any Non-Submit-Data call with valid AppId != 0 should be blocked" )]
 	fn do_validate_test(id: u32, call: RuntimeCall) -> TransactionValidity {
@@ -262,4 +697,394 @@ mod tests {
 		let len = extrinsic.encoded_size();
 		new_test_ext().execute_with(|| CheckAppId::<Test>::from(AppId(id)).do_validate(&call, len))
 	}
+
+	#[test]
+	fn allowlisted_submitters_only() {
+		new_test_ext().execute_with(|| {
+			let allowed = AccountId32::new([1u8; 32]);
+			let other = AccountId32::new([2u8; 32]);
+			let submitters = vec![allowed.clone()].try_into().unwrap();
+			SubmitterAllowlists::<Test>::insert(AppId(1), submitters);
+
+			let check = CheckAppId::<Test>::from(AppId(1));
+			assert_eq!(check.ensure_allowed_submitter(&allowed), Ok(()));
+			assert_eq!(
+				check.ensure_allowed_submitter(&other),
+				Err(InvalidTransaction::BadSigner.into())
+			);
+			// Applications without an allowlist stay open.
+			let check = CheckAppId::<Test>::from(AppId(0));
+			assert_eq!(check.ensure_allowed_submitter(&other), Ok(()));
+		})
+	}
+
+	#[test]
+	fn wrapped_submissions_are_validated_for_their_origin() {
+		new_test_ext().execute_with(|| {
+			let signer = AccountId32::new([2u8; 32]);
+			let derivative = Utility::derivative_account_id(signer.clone(), 0);
+			Balances::make_free_balance_be(&derivative, 1_000);
+			let submitters = vec![derivative.clone()].try_into().unwrap();
+			SubmitterAllowlists::<Test>::insert(AppId(1), submitters);
+			let as_derivative = |call: RuntimeCall| {
+				RuntimeCall::Utility(UtilityCall::as_derivative {
+					index: 0,
+					call: Box::new(call),
+				})
+			};
+
+			let check = CheckAppId::<Test>::from(AppId(1));
+			let info = DispatchInfo::default();
+			let call = as_derivative(submit_data_call());
+			assert_eq!(
+				check.resolve_origin(&signer, &call),
+				Ok((derivative, &submit_data_call()))
+			);
+			assert!(check.validate(&signer, &call, &info, 100).is_ok());
+
+			// The signer itself is not allowed to submit data for the application.
+			assert_eq!(
+				check.validate(&signer, &submit_data_call(), &info, 100),
+				Err(InvalidTransaction::BadSigner.into())
+			);
+
+			let call = as_derivative(as_derivative(as_derivative(submit_data_call())));
+			assert_eq!(
+				check.validate(&signer, &call, &info, 100),
+				to_invalid_tx(MaxRecursionExceeded)
+			);
+		})
+	}
+
+	#[test]
+	fn batch_entries_are_validated_for_their_origin() {
+		new_test_ext().execute_with(|| {
+			let alice = AccountId32::new([1u8; 32]);
+			let bob = AccountId32::new([2u8; 32]);
+			Balances::make_free_balance_be(&bob, 1_000);
+			let submitters = vec![bob.clone()].try_into().unwrap();
+			SubmitterAllowlists::<Test>::insert(AppId(1), submitters);
+			let quota = AppDataQuota {
+				per_block: Some(3),
+				per_era: None,
+			};
+			AppDataQuotas::<Test>::insert(AppId(2), quota);
+			let batch = |entries: Vec<(u32, &[u8])>| {
+				let batch = entries
+					.into_iter()
+					.map(|(id, data)| (AppId(id), data.to_vec().try_into().unwrap()))
+					.collect::<Vec<_>>();
+				RuntimeCall::DataAvailability(DACall::submit_data_batch {
+					batch: batch.try_into().unwrap(),
+				})
+			};
+			let check = CheckAppId::<Test>::from(AppId(0));
+			let info = DispatchInfo::default();
+
+			assert_eq!(
+				check.validate(&alice, &batch(vec![(2, b"a"), (100, b"b")]), &info, 100),
+				to_invalid_tx(InvalidAppId)
+			);
+			assert_eq!(
+				check.validate(&alice, &batch(vec![(2, b"a"), (1, b"b")]), &info, 100),
+				Err(InvalidTransaction::BadSigner.into())
+			);
+			assert!(check
+				.validate(&bob, &batch(vec![(2, b"a"), (1, b"b")]), &info, 100)
+				.is_ok());
+
+			// The entries of an application share its quota, which pre-dispatch records.
+			assert_eq!(
+				check.validate(&bob, &batch(vec![(2, b"ab"), (2, b"cd")]), &info, 100),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+			assert_eq!(
+				check.ensure_valid_batch_entries(&bob, &batch(vec![(2, b"ab")]), true),
+				Ok(())
+			);
+			assert_eq!(
+				check.ensure_valid_batch_entries(&bob, &batch(vec![(2, b"cd")]), false),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+		})
+	}
+
+	#[test]
+	fn batch_entries_are_padded_by_their_application() {
+		new_test_ext().execute_with(|| {
+			let batch = vec![
+				(AppId(1), b"abc".to_vec().try_into().unwrap()),
+				(AppId(2), b"de".to_vec().try_into().unwrap()),
+			];
+			let call = RuntimeCall::DataAvailability(DACall::submit_data_batch {
+				batch: batch.try_into().unwrap(),
+			});
+			let check = CheckAppId::<Test>::from(AppId(0));
+			assert!(check.do_validate(&call, 100).is_ok());
+
+			// Each entry takes a scalar of its application, the extrinsic none.
+			let len = AllExtrinsicsLen::<Test>::get().unwrap();
+			assert_eq!(len.padded(), 5);
+			assert_eq!(len.total_num_scalars(), Some(2));
+		})
+	}
+
+	#[test]
+	fn data_fee_is_charged_before_dispatch() {
+		new_test_ext().execute_with(|| {
+			let alice = AccountId32::new([1u8; 32]);
+			let bob = AccountId32::new([2u8; 32]);
+			let call = RuntimeCall::DataAvailability(DACall::submit_data {
+				data: vec![b'X'; 40].try_into().unwrap(),
+			});
+			let check = CheckAppId::<Test>::from(AppId(1));
+			let info = DispatchInfo::default();
+			let balance = Balances::free_balance(&alice);
+
+			// The data is committed even if its dispatch fails, so its fee is charged anyway.
+			assert!(check.validate(&alice, &call, &info, 100).is_ok());
+			assert_eq!(Balances::free_balance(&alice), balance);
+			assert!(check
+				.clone()
+				.pre_dispatch(&alice, &call, &info, 100)
+				.is_ok());
+			assert_eq!(Balances::free_balance(&alice), balance - 40);
+
+			assert_eq!(
+				check.validate(&bob, &call, &info, 100),
+				Err(InvalidTransaction::Payment.into())
+			);
+		})
+	}
+
+	#[test]
+	fn app_data_quota_is_enforced() {
+		new_test_ext().execute_with(|| {
+			let quota = AppDataQuota {
+				per_block: Some(100),
+				per_era: None,
+			};
+			AppDataQuotas::<Test>::insert(AppId(1), quota);
+			let check = CheckAppId::<Test>::from(AppId(1));
+
+			assert_eq!(check.ensure_within_quota(100, false), Ok(()));
+			assert_eq!(check.ensure_within_quota(60, true), Ok(()));
+			assert_eq!(
+				check.ensure_within_quota(41, false),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+			assert_eq!(check.ensure_within_quota(40, true), Ok(()));
+			// Applications without a quota are only limited by the block length.
+			assert_eq!(
+				CheckAppId::<Test>::from(AppId(0)).ensure_within_quota(1000, true),
+				Ok(())
+			);
+		})
+	}
+
+	#[test]
+	fn reserved_space_is_honored() {
+		new_test_ext().execute_with(|| {
+			let block_length = <Test as SystemConfig>::BlockLength::get();
+			let max_len = *block_length.max.get(DispatchClass::Normal);
+			AppReservedSpaces::<Test>::insert(AppId(1), 100);
+			TotalReservedSpace::<Test>::put(100);
+			let info = DispatchInfo::default();
+			let open = CheckAppId::<Test>::from(AppId(0));
+			let reserved = CheckAppId::<Test>::from(AppId(1));
+
+			// Other extrinsics cannot use the space reserved for the application.
+			let mut len = ExtrinsicLenOf::<Test>::default();
+			len.add_raw(max_len - 150).unwrap();
+			AllExtrinsicsLen::<Test>::put(len);
+			assert_eq!(open.ensure_reserved_space(&info, 50, false), Ok(()));
+			assert_eq!(
+				open.ensure_reserved_space(&info, 51, false),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+			// The application first fills its reservation, then the rest of the block.
+			assert_eq!(reserved.ensure_reserved_space(&info, 150, false), Ok(()));
+			assert_eq!(
+				reserved.ensure_reserved_space(&info, 151, false),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+
+			// Once the reservation is used, the application shares the rest of the block.
+			let mut len = ExtrinsicLenOf::<Test>::default();
+			len.add_raw(max_len - 50).unwrap();
+			AllExtrinsicsLen::<Test>::put(len);
+			assert_eq!(reserved.ensure_reserved_space(&info, 100, true), Ok(()));
+			assert_eq!(open.ensure_reserved_space(&info, 50, false), Ok(()));
+			assert_eq!(reserved.ensure_reserved_space(&info, 50, false), Ok(()));
+		})
+	}
+
+	#[test]
+	fn block_space_intents_are_honored() {
+		new_test_ext().execute_with(|| {
+			let block_length = <Test as SystemConfig>::BlockLength::get();
+			let max_len = *block_length.max.get(DispatchClass::Normal);
+			IntentSpaces::<Test>::insert(
+				1,
+				BlockSpaceIntents {
+					count: 1,
+					bytes: 100,
+				},
+			);
+			let info = DispatchInfo::default();
+			let check = CheckAppId::<Test>::from(AppId(1));
+
+			// Other extrinsics cannot use the space reserved by the intent.
+			let mut len = ExtrinsicLenOf::<Test>::default();
+			len.add_raw(max_len - 150).unwrap();
+			AllExtrinsicsLen::<Test>::put(len);
+			assert_eq!(check.ensure_reserved_space(&info, 50, false), Ok(()));
+			assert_eq!(
+				check.ensure_reserved_space(&info, 51, false),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+
+			// The data filling an intent uses its reserved space.
+			let alice = AccountId32::new([1u8; 32]);
+			let fill = |len: usize| {
+				RuntimeCall::DataAvailability(DACall::submit_reserved_data {
+					data: vec![b'X'; len].try_into().unwrap(),
+				})
+			};
+			BlockSpaceIntentOf::<Test>::insert(1, &alice, 100);
+			assert_eq!(check.fills_block_space_intent(&alice, &fill(100)), Ok(true));
+			assert_eq!(
+				check.fills_block_space_intent(&alice, &submit_data_call()),
+				Ok(false)
+			);
+
+			// Only the data fitting an intent of its submitter in the current block is accepted.
+			let bob = AccountId32::new([2u8; 32]);
+			let no_intent = Err(InvalidTransaction::Call.into());
+			assert_eq!(
+				check.fills_block_space_intent(&alice, &fill(101)),
+				no_intent
+			);
+			assert_eq!(check.fills_block_space_intent(&bob, &fill(10)), no_intent);
+			assert_eq!(check.validate(&bob, &fill(10), &info, 100), no_intent);
+			System::set_block_number(2);
+			assert_eq!(check.fills_block_space_intent(&alice, &fill(10)), no_intent);
+		})
+	}
+
+	#[test]
+	fn data_leaves_are_counted() {
+		new_test_ext().execute_with(|| {
+			let check = CheckAppId::<Test>::from(AppId(0));
+			check.note_data_leaves(&remark_call());
+			check.note_data_leaves(&submit_data_batch_call());
+			check.note_data_leaves(&submit_compressed_data_call(5));
+			assert_eq!(SubmittedDataLeaves::<Test>::get(), 2);
+			// Empty data is not included in the data root.
+			check.note_data_leaves(&submit_data_call());
+			assert_eq!(SubmittedDataLeaves::<Test>::get(), 2);
+		})
+	}
+
+	#[test]
+	fn app_usage_is_recorded() {
+		new_test_ext().execute_with(|| {
+			let check = CheckAppId::<Test>::from(AppId(1));
+			check.note_submissions(&remark_call());
+			check.note_submissions(&submit_data_batch_call());
+			check.note_submissions(&submit_compressed_data_call(5));
+
+			let history = AppUsageHistory::<Test>::get(AppId(1));
+			let stats = AppUsageStats {
+				era: 0,
+				bytes: 15,
+				submissions: 2,
+			};
+			assert_eq!(history.into_inner(), vec![stats]);
+		})
+	}
+
+	#[test]
+	fn max_data_length_is_enforced() {
+		new_test_ext().execute_with(|| {
+			AppMaxDataLengths::<Test>::insert(AppId(1), 4);
+			let validate = |id: u32, call: RuntimeCall| {
+				CheckAppId::<Test>::from(AppId(id)).do_validate(&call, 100)
+			};
+
+			// The batch entry fits, the decompressed `hello` does not.
+			assert!(validate(0, submit_data_batch_call()).is_ok());
+			assert_eq!(
+				validate(1, submit_compressed_data_call(5)),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+			// Nor does its 14 bytes frame, which is longer than its content.
+			AppMaxDataLengths::<Test>::insert(AppId(1), 5);
+			assert_eq!(
+				validate(1, submit_compressed_data_call(5)),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+			AppMaxDataLengths::<Test>::insert(AppId(1), 14);
+			assert!(validate(1, submit_compressed_data_call(5)).is_ok());
+		})
+	}
+
+	#[test]
+	fn submissions_are_sequenced() {
+		new_test_ext().execute_with(|| {
+			let sequence = AppSequence {
+				enabled: true,
+				next: 7,
+			};
+			AppSequences::<Test>::insert(AppId(1), sequence);
+			let check = CheckAppId::<Test>::from(AppId(1));
+			check.note_submissions(&submit_data_batch_call());
+			check.note_submissions(&submit_compressed_data_call(5));
+			assert_eq!(AppSequences::<Test>::get(AppId(1)).map(|s| s.next), Some(9));
+
+			// Applications without sequencing are not numbered.
+			CheckAppId::<Test>::from(AppId(0)).note_submissions(&submit_data_call());
+			assert_eq!(AppSequences::<Test>::get(AppId(0)), None);
+		})
+	}
+
+	#[test]
+	fn retired_apps_are_rejected() {
+		new_test_ext().execute_with(|| {
+			AppRetirements::<Test>::insert(AppId(1), 2);
+			let validate = |id: u32, call: RuntimeCall| {
+				CheckAppId::<Test>::from(AppId(id)).do_validate(&call, 100)
+			};
+
+			// Submissions are accepted during the grace period.
+			assert!(validate(1, submit_data_call()).is_ok());
+			assert!(validate(0, submit_data_batch_call()).is_ok());
+
+			System::set_block_number(2);
+			assert_eq!(validate(1, submit_data_call()), to_invalid_tx(InvalidAppId));
+			assert_eq!(
+				validate(0, submit_data_batch_call()),
+				to_invalid_tx(InvalidAppId)
+			);
+		})
+	}
+
+	#[test]
+	fn paused_apps_are_rejected() {
+		new_test_ext().execute_with(|| {
+			PausedApps::<Test>::insert(AppId(1), 1);
+			let validate = |id: u32, call: RuntimeCall| {
+				CheckAppId::<Test>::from(AppId(id)).do_validate(&call, 100)
+			};
+			let paused = Err(InvalidTransaction::Custom(PAUSED_APP_ERROR).into());
+
+			assert_eq!(validate(1, submit_data_call()), paused);
+			assert_eq!(validate(0, submit_data_batch_call()), paused);
+			assert!(validate(0, submit_data_call()).is_ok());
+
+			PausedApps::<Test>::remove(AppId(1));
+			assert!(validate(1, submit_data_call()).is_ok());
+			assert!(validate(0, submit_data_batch_call()).is_ok());
+		})
+	}
 }
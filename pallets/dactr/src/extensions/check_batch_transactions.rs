@@ -29,7 +29,17 @@ where
 	[u8; 32]: From<<T as frame_system::Config>::AccountId>,
 {
 	pub fn is_submit_data_call(&self) -> bool {
-		matches!(self.0.is_sub_type(), Some(DACall::<T>::submit_data { .. }))
+		matches!(
+			self.0.is_sub_type(),
+			Some(
+				DACall::<T>::submit_data { .. }
+					| DACall::<T>::submit_compressed_data { .. }
+					| DACall::<T>::submit_sponsored_data { .. }
+					| DACall::<T>::submit_data_with_retention { .. }
+					| DACall::<T>::submit_reserved_data { .. }
+					| DACall::<T>::submit_data_batch { .. }
+			)
+		)
 	}
 
 	pub fn is_send_message_call(&self) -> bool {
@@ -1,7 +1,11 @@
 #![cfg(test)]
 
 use frame_support::weights::WeightToFee;
-use frame_support::{derive_impl, weights::IdentityFee};
+use frame_support::{
+	derive_impl,
+	traits::{ConstU32, ConstU64},
+	weights::IdentityFee,
+};
 use frame_system::{
 	mocking::MockUncheckedExtrinsic, native::hosted_header_builder::da::HeaderExtensionBuilder,
 	test_utils::TestRandomness,
@@ -71,12 +75,52 @@ impl pallet_utility::Config for Test {
 }
 
 #[derive_impl(da_control::config_preludes::TestDefaultConfig)]
-impl da_control::Config for Test {}
+impl da_control::Config for Test {
+	type Currency = Balances;
+	type AppOriginFilter = DerivativeOrigin;
+	type MinDataBytePrice = ConstU64<1>;
+	type AppKeyDeposit = ConstU64<10>;
+}
+
+/// Dispatches the calls wrapped by `Utility::as_derivative` on behalf of the derivative account
+/// of their signer, as proxies dispatch them on behalf of the proxied account.
+pub struct DerivativeOrigin;
+
+impl AppOriginFilter<AccountId32, RuntimeCall> for DerivativeOrigin {
+	fn inner_call<'a>(_: &AccountId32, call: &'a RuntimeCall) -> Option<&'a RuntimeCall> {
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. }) => {
+				Some(call.as_ref())
+			},
+			_ => None,
+		}
+	}
+
+	fn inner_origin(who: &AccountId32, call: &RuntimeCall) -> Option<AccountId32> {
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { index, .. }) => {
+				Some(Utility::derivative_account_id(who.clone(), *index))
+			},
+			_ => None,
+		}
+	}
+}
 
 #[derive_impl(pallet_vector::config_preludes::TestDefaultConfig as pallet_vector::DefaultConfig)]
 impl pallet_vector::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type AddressBindingOrigin = pallet_vector::EnsureBridgedOrigin;
+	type RuntimeBridgedOrigin = RuntimeOrigin;
+	type DomainPauseOrigin = frame_system::EnsureRoot<AccountId32>;
+	type WhitelistOrigin = frame_system::EnsureRoot<AccountId32>;
+	type EmergencyPauseOrigin = frame_system::EnsureRoot<AccountId32>;
+	type MaxEmergencyPauseDuration = ConstU32<100>;
+	type StateRootRollbackOrigin = frame_system::EnsureRoot<AccountId32>;
+	type ProverUnbondingPeriod = ConstU32<10>;
+	type MessagePreimageRetention = ConstU32<10>;
+	type VerificationKeyActivationDelay = ConstU32<5>;
+	type ArbitraryMessageByteFee = ConstU64<0>;
 }
 
 #[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig as pallet_timestamp::DefaultConfig)]
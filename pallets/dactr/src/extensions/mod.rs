@@ -1,5 +1,6 @@
 pub mod check_app_id;
 pub mod check_batch_transactions;
 pub mod extensions_mock;
+pub mod prioritized_payment;
 
 const MAX_ITERATIONS: usize = 2;
@@ -0,0 +1,215 @@
+use crate::{Call as DACall, Config as DAConfig};
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	traits::{Get, IsSubType},
+};
+use frame_system::Config as SystemConfig;
+use pallet_transaction_payment::{
+	ChargeTransactionPayment, Config as PaymentConfig, OnChargeTransaction,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Saturating, SignedExtension},
+	transaction_validity::{TransactionPriority, TransactionValidity, TransactionValidityError},
+	DispatchResult, SaturatedConversion,
+};
+use sp_std::fmt::{self, Debug, Formatter};
+
+type PaymentBalanceOf<T> =
+	<<T as PaymentConfig>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// Charges the transaction fees like `ChargeTransactionPayment`, with the same encoding, and
+/// orders the data submissions by their tip.
+///
+/// # Transaction Priority
+///
+/// The tip of a data submission is its priority fee: the submission is prioritized by its tip
+/// per byte, scaled by the bytes fitting in a block, regardless of its weight. As
+/// `ChargeTransactionPayment` scales the tip of other transactions by the number of them fitting
+/// in a block, both compete in the same priority range. Submissions with the same priority fee
+/// are included in their order of arrival.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PrioritizedTransactionPayment<T: PaymentConfig>(#[codec(compact)] PaymentBalanceOf<T>);
+
+impl<T> PrioritizedTransactionPayment<T>
+where
+	T: PaymentConfig + DAConfig + Send + Sync,
+	PaymentBalanceOf<T>: Send + Sync + From<u64>,
+	<T as SystemConfig>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<DACall<T>>,
+{
+	/// utility constructor. Used only in client/factory code.
+	pub fn from(tip: PaymentBalanceOf<T>) -> Self {
+		Self(tip)
+	}
+
+	fn charge(&self) -> ChargeTransactionPayment<T> {
+		ChargeTransactionPayment::<T>::from(self.0)
+	}
+
+	/// Returns whether `call` submits data, which is prioritized by its priority fee.
+	pub fn is_data_submission(call: &<T as SystemConfig>::RuntimeCall) -> bool {
+		matches!(
+			call.is_sub_type(),
+			Some(
+				DACall::<T>::submit_data { .. }
+					| DACall::<T>::submit_compressed_data { .. }
+					| DACall::<T>::submit_sponsored_data { .. }
+					| DACall::<T>::submit_data_with_retention { .. }
+					| DACall::<T>::submit_reserved_data { .. }
+					| DACall::<T>::submit_data_batch { .. }
+			)
+		)
+	}
+
+	/// Returns the priority of a data submission of `len` bytes paying `tip`: its tip per byte
+	/// times the bytes of its class fitting in a block.
+	pub fn data_priority(
+		tip: PaymentBalanceOf<T>,
+		info: &DispatchInfoOf<<T as SystemConfig>::RuntimeCall>,
+		len: usize,
+	) -> TransactionPriority {
+		let max_block_length = *<T as SystemConfig>::BlockLength::get().max.get(info.class);
+		let len = len.max(1).saturated_into::<u64>();
+		let max_submissions_per_block = (u64::from(max_block_length) / len).max(1);
+
+		tip.saturating_add(PaymentBalanceOf::<T>::from(1))
+			.saturating_mul(PaymentBalanceOf::<T>::from(max_submissions_per_block))
+			.saturated_into()
+	}
+}
+
+impl<T: PaymentConfig> Debug for PrioritizedTransactionPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "PrioritizedTransactionPayment<{:?}>", self.0)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T> SignedExtension for PrioritizedTransactionPayment<T>
+where
+	T: PaymentConfig + DAConfig + Send + Sync,
+	PaymentBalanceOf<T>: Send + Sync + From<u64>,
+	<T as SystemConfig>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<DACall<T>>,
+{
+	type AccountId = T::AccountId;
+	type AdditionalSigned = ();
+	type Call = <T as SystemConfig>::RuntimeCall;
+	type Pre = <ChargeTransactionPayment<T> as SignedExtension>::Pre;
+
+	const IDENTIFIER: &'static str = <ChargeTransactionPayment<T> as SignedExtension>::IDENTIFIER;
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let mut valid = self.charge().validate(who, call, info, len)?;
+		if Self::is_data_submission(call) {
+			valid.priority = Self::data_priority(self.0, info, len);
+		}
+		Ok(valid)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.charge().pre_dispatch(who, call, info, len)
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		ChargeTransactionPayment::<T>::post_dispatch(pre, info, post_info, len, result)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::extensions::extensions_mock::{new_test_ext, RuntimeCall, Test};
+	use frame_system::pallet::Call as SysCall;
+	use pallet_balances::Call as BalancesCall;
+	use sp_runtime::AccountId32;
+
+	fn submit_data_call() -> RuntimeCall {
+		RuntimeCall::DataAvailability(DACall::submit_data {
+			data: vec![].try_into().unwrap(),
+		})
+	}
+
+	fn alice() -> AccountId32 {
+		let mut alice = [0u8; 32];
+		alice[0] = 1;
+		AccountId32::new(alice)
+	}
+
+	fn priority(call: &RuntimeCall, tip: u64, len: usize) -> TransactionPriority {
+		let info = DispatchInfo::default();
+		PrioritizedTransactionPayment::<Test>::from(tip)
+			.validate(&alice(), call, &info, len)
+			.unwrap()
+			.priority
+	}
+
+	#[test]
+	fn data_submissions_are_prioritized_by_tip_per_byte() {
+		new_test_ext().execute_with(|| {
+			let call = submit_data_call();
+			assert!(priority(&call, 1_000, 100) > priority(&call, 1_000, 200));
+			assert!(priority(&call, 1_000, 200) > priority(&call, 0, 100));
+		})
+	}
+
+	#[test]
+	fn tipped_submissions_compete_with_transfers() {
+		new_test_ext().execute_with(|| {
+			let submission = submit_data_call();
+			let transfer = RuntimeCall::Balances(BalancesCall::transfer_keep_alive {
+				dest: alice(),
+				value: 1,
+			});
+
+			// Tipping one unit per byte ranks a submission above an untipped transfer.
+			assert!(priority(&submission, 10_000, 10_000) > priority(&transfer, 0, 100));
+			// An untipped submission of a larger share of the block ranks below it.
+			assert!(priority(&submission, 0, 10_000) < priority(&transfer, 0, 100));
+		})
+	}
+
+	#[test]
+	fn other_calls_keep_their_priority() {
+		new_test_ext().execute_with(|| {
+			let call = RuntimeCall::System(SysCall::remark { remark: vec![] });
+			let info = DispatchInfo::default();
+			let expected = ChargeTransactionPayment::<Test>::from(1_000)
+				.validate(&alice(), &call, &info, 100)
+				.unwrap()
+				.priority;
+			assert_eq!(priority(&call, 1_000, 100), expected);
+		})
+	}
+}
@@ -0,0 +1,140 @@
+//! Dimensions of the grid holding a set of blobs.
+//!
+//! The data of each application is SCALE encoded as the list of its blobs and split into chunks
+//! of `DATA_CHUNK_SIZE` bytes, padded with a tail chunk. The grid has the width of the block
+//! while the chunks do not fit a single row, and as many rows as needed, rounded up to a power of
+//! two. Its remaining cells are filled with padding.
+use avail_core::{kate::DATA_CHUNK_SIZE, AppId};
+use codec::{Compact, CompactLen as _, Decode, Encode};
+use frame_system::{limits::BlockLength, native::hosted_header_builder::MIN_WIDTH};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::collections::btree_map::BTreeMap;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Dimensions of the grid, before its extension, holding a set of blobs and its chunks.
+#[derive(Clone, Copy, Default, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GridEstimate {
+	/// Number of rows of the grid.
+	pub rows: u32,
+	/// Number of columns of the grid.
+	pub cols: u32,
+	/// Number of chunks holding the blobs.
+	pub chunks: u32,
+	/// Number of chunks filled with padding.
+	pub padding_chunks: u32,
+	/// Whether the grid fits in the block length.
+	pub fits: bool,
+}
+
+/// Returns the dimensions of the grid holding `blobs`, the application and length of each blob,
+/// in a block of `block_length`.
+pub fn grid_estimate(blobs: &[(AppId, u32)], block_length: &BlockLength) -> GridEstimate {
+	let mut apps = BTreeMap::<AppId, (u32, u32)>::new();
+	for &(app_id, len) in blobs {
+		let (count, data_len) = apps.entry(app_id).or_default();
+		*count = count.saturating_add(1);
+		*data_len = data_len.saturating_add(encoded_len(len));
+	}
+
+	// The data of each application ends with a tail chunk.
+	let chunk_size = DATA_CHUNK_SIZE as u32;
+	let chunks = apps
+		.values()
+		.map(|&(count, data_len)| {
+			let len = compact_len(count).saturating_add(data_len);
+			len.saturating_add(chunk_size) / chunk_size
+		})
+		.fold(0u32, |chunks, app_chunks| chunks.saturating_add(app_chunks));
+	if chunks == 0 {
+		return GridEstimate {
+			fits: true,
+			..Default::default()
+		};
+	}
+
+	let max_cols = block_length.cols.0.max(1);
+	let (rows, cols) = if chunks < max_cols {
+		(1, chunks.next_power_of_two().max(MIN_WIDTH as u32))
+	} else {
+		(chunks.div_ceil(max_cols).next_power_of_two(), max_cols)
+	};
+
+	GridEstimate {
+		rows,
+		cols,
+		chunks,
+		padding_chunks: rows.saturating_mul(cols).saturating_sub(chunks),
+		fits: rows <= block_length.rows.0,
+	}
+}
+
+fn encoded_len(len: u32) -> u32 {
+	compact_len(len).saturating_add(len)
+}
+
+fn compact_len(value: u32) -> u32 {
+	Compact::<u32>::compact_len(&value) as u32
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use avail_core::{BlockLengthColumns, BlockLengthRows, BLOCK_CHUNK_SIZE};
+	use sp_runtime::Perbill;
+
+	fn block_length(rows: u32, cols: u32) -> BlockLength {
+		BlockLength::with_normal_ratio(
+			BlockLengthRows(rows),
+			BlockLengthColumns(cols),
+			BLOCK_CHUNK_SIZE,
+			Perbill::from_percent(90),
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn small_blobs_fit_a_single_row() {
+		let block_length = block_length(32, 256);
+		assert_eq!(
+			grid_estimate(&[], &block_length),
+			GridEstimate {
+				fits: true,
+				..Default::default()
+			}
+		);
+
+		// 98 bytes and their prefixes take 4 chunks, the tail included.
+		let estimate = grid_estimate(&[(AppId(1), 98)], &block_length);
+		assert_eq!(estimate.chunks, 4);
+		assert_eq!((estimate.rows, estimate.cols), (1, MIN_WIDTH as u32));
+		assert_eq!(estimate.padding_chunks, MIN_WIDTH as u32 - 4);
+		assert!(estimate.fits);
+
+		// Each application is padded on its own.
+		let estimate = grid_estimate(&[(AppId(1), 98), (AppId(2), 98)], &block_length);
+		assert_eq!(estimate.chunks, 8);
+	}
+
+	#[test]
+	fn large_blobs_span_rows_of_the_block_width() {
+		let block_length = block_length(32, 256);
+		let chunk_size = DATA_CHUNK_SIZE as u32;
+
+		// 300 chunks span two rows.
+		let estimate = grid_estimate(&[(AppId(1), 300 * chunk_size - 6)], &block_length);
+		assert_eq!(estimate.chunks, 300);
+		assert_eq!((estimate.rows, estimate.cols), (2, 256));
+		assert_eq!(estimate.padding_chunks, 512 - 300);
+		assert!(estimate.fits);
+
+		// 33 rows are rounded up to 64.
+		let estimate = grid_estimate(&[(AppId(1), 33 * 256 * chunk_size)], &block_length);
+		assert_eq!((estimate.rows, estimate.cols), (64, 256));
+		assert!(!estimate.fits);
+	}
+}
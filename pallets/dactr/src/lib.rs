@@ -8,32 +8,67 @@ use avail_core::{
 };
 use codec::{Compact, CompactLen as _};
 use frame_support::weights::constants::ExtrinsicBaseWeight;
-use frame_support::{dispatch::DispatchClass, traits::Get, weights::Weight};
-use frame_system::{limits::BlockLength, pallet::DynamicBlockLength};
+use frame_support::{
+	dispatch::{DispatchClass, DispatchResult},
+	traits::{Currency, ExistenceRequirement, Get, ReservableCurrency, WithdrawReasons},
+	weights::Weight,
+	PalletId,
+};
+use frame_system::{
+	limits::BlockLength,
+	pallet::{AllExtrinsicsLen, DynamicBlockLength},
+};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_arithmetic::traits::{CheckedAdd, One, SaturatedConversion};
+use sp_arithmetic::traits::{CheckedAdd, CheckedSub, One, SaturatedConversion, Saturating, Zero};
 use sp_core::H256;
-use sp_io::hashing::blake2_256;
-use sp_runtime::Perbill;
+use sp_io::hashing::{blake2_256, keccak_256};
+use sp_runtime::{traits::AccountIdConversion, DispatchError, Perbill};
 use sp_std::{mem::replace, vec, vec::Vec};
 
 pub use crate::{pallet::*, weights::WeightInfo};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod compression;
 mod extensions;
+pub mod grid;
+pub mod migrations;
 #[cfg(feature = "std")]
 pub mod mock;
+pub mod origin;
+pub mod pool;
+pub mod retention;
+pub mod sponsorship;
 #[cfg(test)]
 mod tests;
-pub use extensions::check_app_id::CheckAppId;
+pub use extensions::check_app_id::{CheckAppId, PAUSED_APP_ERROR};
 pub use extensions::check_batch_transactions::CheckBatchTransactions;
+pub use extensions::prioritized_payment::PrioritizedTransactionPayment;
 use frame_support::dispatch::DispatchFeeModifier;
+pub use grid::GridEstimate;
+pub use origin::AppOriginFilter;
+pub use retention::RetentionHint;
+pub use sponsorship::SponsoredFeeAdapter;
 pub mod weights;
 
 pub const LOG_TARGET: &str = "runtime::da_control";
 
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+/// Maximum length of the contact in the metadata of an application key.
+pub const MAX_APP_CONTACT_LENGTH: u32 = 64;
+/// Number of blocks the grid utilization average is smoothed over.
+pub const GRID_UTILIZATION_SMOOTHING: u32 = 8;
+/// Maximum number of entries of a data batch.
+pub const MAX_DATA_BATCH_LEN: u32 = 16;
+/// Largest number of rows `MaxBlockRows` can be raised to, bounding the commitments of the
+/// header extension.
+pub const MAX_GRID_ROWS: u32 = 4096;
+/// Largest number of columns `MaxBlockCols` can be raised to, the degree supported by the public
+/// parameters of the commitments.
+pub const MAX_GRID_COLS: u32 = 1024;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{pallet_prelude::*, DefaultNoBound};
@@ -43,17 +78,133 @@ pub mod pallet {
 
 	pub type AppKeyFor<T> = BoundedVec<u8, <T as Config>::MaxAppKeyLength>;
 	pub type AppDataFor<T> = BoundedVec<u8, <T as Config>::MaxAppDataLength>;
+	pub type SubmittersFor<T> =
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxAppSubmitters>;
+	pub type DataBatchFor<T> = BoundedVec<(AppId, AppDataFor<T>), ConstU32<MAX_DATA_BATCH_LEN>>;
+	pub type AppUsageHistoryFor<T> = BoundedVec<AppUsageStats, <T as Config>::MaxAppUsageHistory>;
+	pub type AppChildrenFor<T> = BoundedVec<AppId, <T as Config>::MaxAppChildren>;
 
 	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, RuntimeDebug, MaxEncodedLen)]
-	pub struct AppKeyInfo<Acc: PartialEq> {
+	pub struct AppKeyInfo<Acc: PartialEq, Balance: PartialEq> {
 		/// Owner of the key
 		pub owner: Acc,
 		/// Application ID associated.
 		pub id: AppId,
+		/// Metadata set by the owner.
+		pub metadata: Option<AppKeyMetadata>,
+		/// Deposit reserved from the owner, returned when the key is removed.
+		pub deposit: Balance,
+	}
+
+	/// Metadata describing the application of a key.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	pub struct AppKeyMetadata {
+		/// Hash of the display name of the application.
+		pub display_name_hash: H256,
+		/// Contact of the team running the application, such as an email address or URL.
+		pub contact: BoundedVec<u8, ConstU32<MAX_APP_CONTACT_LENGTH>>,
+	}
+
+	pub type AppKeyInfoFor<T> = AppKeyInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+	/// Maximum number of bytes an application can submit.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(
+		Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, Default,
+	)]
+	pub struct AppDataQuota {
+		/// Bytes per block, unlimited if `None`.
+		pub per_block: Option<u32>,
+		/// Bytes per era, unlimited if `None`.
+		pub per_era: Option<u64>,
+	}
+
+	/// Bounds within which the block length follows the average grid utilization.
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub struct BlockLengthBounds {
+		pub min_rows: BlockLengthRows,
+		pub max_rows: BlockLengthRows,
+		pub min_cols: BlockLengthColumns,
+		pub max_cols: BlockLengthColumns,
+		/// Average utilization above which the block length is doubled.
+		pub upper_target: Perbill,
+		/// Average utilization below which the block length is halved.
+		pub lower_target: Perbill,
+	}
+
+	/// Block length change announced to the applications ahead of its enactment.
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub struct BlockLengthChange {
+		pub rows: BlockLengthRows,
+		pub cols: BlockLengthColumns,
+		/// Block the new block length is enacted at.
+		pub at: u32,
+	}
+
+	/// Bytes submitted by an application in its last block and era with submissions.
+	#[derive(
+		Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, Default,
+	)]
+	pub struct AppDataUsage {
+		/// Last block with submissions.
+		pub block: u32,
+		/// Bytes submitted in `block`.
+		pub block_bytes: u32,
+		/// Last era with submissions.
+		pub era: u32,
+		/// Bytes submitted in `era`.
+		pub era_bytes: u64,
+	}
+
+	/// Data submitted for an application in an era.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(
+		Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, Default,
+	)]
+	pub struct AppUsageStats {
+		pub era: u32,
+		/// Bytes submitted in `era`.
+		pub bytes: u64,
+		/// Number of submissions in `era`.
+		pub submissions: u32,
+	}
+
+	/// Sequence numbers of the data submitted for an application.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(
+		Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, Default,
+	)]
+	pub struct AppSequence {
+		/// Whether the submissions are numbered.
+		pub enabled: bool,
+		/// Sequence number of the next submission. It is kept while disabled, so the numbers
+		/// never go back.
+		pub next: u64,
+	}
+
+	/// Block space reserved in an upcoming block by the intents of the submitters.
+	#[derive(
+		Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, Default,
+	)]
+	pub struct BlockSpaceIntents {
+		/// Number of intents not filled yet.
+		pub count: u32,
+		/// Bytes reserved by the intents not filled yet.
+		pub bytes: u32,
 	}
 
-	pub type AppKeyInfoFor<T> = AppKeyInfo<<T as frame_system::Config>::AccountId>;
+	/// Bytes of reserved block space used in the last block with submissions.
+	#[derive(
+		Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, Default,
+	)]
+	pub struct ReservedSpaceUsage {
+		/// Last block with submissions.
+		pub block: u32,
+		/// Bytes of reserved space used in `block`.
+		pub bytes: u32,
+	}
 
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
@@ -76,6 +227,21 @@ pub mod pallet {
 			pub const MaxBlockCols: BlockLengthColumns = BlockLengthColumns(256);
 			pub const MaxAppKeyLength: u32 = 32;
 			pub const MaxAppDataLength: u32 = 524_288; // 512 Kb
+			pub const MaxAppSubmitters: u32 = 64;
+			pub const AppQuotaEraLength: u32 = 100;
+			pub const TargetGridFill: Perbill = Perbill::from_percent(50);
+			pub const MaxReservedBlockSpace: Perbill = Perbill::from_percent(50);
+			pub const MaxRetentionPeriod: u32 = 1_000;
+			pub const MaxAppUsageHistory: u32 = 8;
+			pub const AppKeyRetirementPeriod: u32 = 100;
+			pub const MaxIntentLookahead: u32 = 100;
+			pub const MaxBlockSpaceIntents: u32 = 16;
+			pub const MaxAppChildren: u32 = 8;
+			pub const BlockLengthChangeDelay: u32 = 10;
+			pub const MinChargedDataLength: u32 = 32;
+			pub const SubmissionReceiptPeriod: u32 = 100;
+			pub const DataBytePriceChangeDenominator: u32 = 8;
+			pub const DaControlPalletId: PalletId = PalletId(*b"avl/dact");
 		}
 
 		#[frame_support::register_default_impl(TestDefaultConfig)]
@@ -83,6 +249,21 @@ pub mod pallet {
 			type BlockLenProposalId = u32;
 			type MaxAppDataLength = MaxAppDataLength;
 			type MaxAppKeyLength = MaxAppKeyLength;
+			type MaxAppSubmitters = MaxAppSubmitters;
+			type AppQuotaEraLength = AppQuotaEraLength;
+			type TargetGridFill = TargetGridFill;
+			type MaxReservedBlockSpace = MaxReservedBlockSpace;
+			type MaxRetentionPeriod = MaxRetentionPeriod;
+			type MaxAppUsageHistory = MaxAppUsageHistory;
+			type AppKeyRetirementPeriod = AppKeyRetirementPeriod;
+			type MaxIntentLookahead = MaxIntentLookahead;
+			type MaxBlockSpaceIntents = MaxBlockSpaceIntents;
+			type MaxAppChildren = MaxAppChildren;
+			type BlockLengthChangeDelay = BlockLengthChangeDelay;
+			type MinChargedDataLength = MinChargedDataLength;
+			type SubmissionReceiptPeriod = SubmissionReceiptPeriod;
+			type DataBytePriceChangeDenominator = DataBytePriceChangeDenominator;
+			type PalletId = DaControlPalletId;
 			type MaxBlockCols = MaxBlockCols;
 			type MaxBlockRows = MaxBlockRows;
 			type MinBlockCols = MinBlockCols;
@@ -110,6 +291,93 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAppDataLength: Get<u32>;
 
+		/// The max number of accounts in the submitter allowlist of an application.
+		#[pallet::constant]
+		type MaxAppSubmitters: Get<u32>;
+
+		/// Number of blocks in an era of the per era data quotas.
+		#[pallet::constant]
+		type AppQuotaEraLength: Get<u32>;
+
+		/// Currency the per byte price of submitted data is burned from, and the deposits of
+		/// application keys are reserved in.
+		#[pallet::no_default]
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Unwraps the proxy and multisig calls, so the data submissions they wrap are validated
+		/// against the account they are dispatched for.
+		#[pallet::no_default]
+		type AppOriginFilter: AppOriginFilter<
+			Self::AccountId,
+			<Self as frame_system::Config>::RuntimeCall,
+		>;
+
+		/// Deposit reserved from the owner of an application key while the key exists.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type AppKeyDeposit: Get<BalanceOf<Self>>;
+
+		/// Minimum price per byte of submitted data.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type MinDataBytePrice: Get<BalanceOf<Self>>;
+
+		/// Minimum number of bytes the price of a submitted blob is charged for, so dust
+		/// submissions pay for the grid cells and commitments they use.
+		#[pallet::constant]
+		type MinChargedDataLength: Get<u32>;
+
+		/// Share of the grid filled by a block the per byte price targets. The price rises after
+		/// fuller blocks and decays after emptier ones.
+		#[pallet::constant]
+		type TargetGridFill: Get<Perbill>;
+
+		/// Bounds the change of the per byte price after a block to `1 / denominator` of it.
+		#[pallet::constant]
+		type DataBytePriceChangeDenominator: Get<u32>;
+
+		/// Share of the normal block length that can be reserved for the applications.
+		#[pallet::constant]
+		type MaxReservedBlockSpace: Get<Perbill>;
+
+		/// Maximum number of blocks submitters can suggest their data to be retained for.
+		#[pallet::constant]
+		type MaxRetentionPeriod: Get<u32>;
+
+		/// Number of eras the usage statistics of an application are kept for.
+		#[pallet::constant]
+		type MaxAppUsageHistory: Get<u32>;
+
+		/// Number of blocks an application key accepts submissions for once its retirement is
+		/// announced.
+		#[pallet::constant]
+		type AppKeyRetirementPeriod: Get<u32>;
+
+		/// Number of blocks ahead of the current one block space can be reserved in.
+		#[pallet::constant]
+		type MaxIntentLookahead: Get<u32>;
+
+		/// Maximum number of block space intents of a block.
+		#[pallet::constant]
+		type MaxBlockSpaceIntents: Get<u32>;
+
+		/// Maximum number of child applications of an application.
+		#[pallet::constant]
+		type MaxAppChildren: Get<u32>;
+
+		/// Number of blocks between the approval of a block length proposal and its enactment,
+		/// giving the applications advance notice of the new grid dimensions.
+		#[pallet::constant]
+		type BlockLengthChangeDelay: Get<u32>;
+
+		/// Number of blocks the submission receipts of a block are kept for.
+		#[pallet::constant]
+		type SubmissionReceiptPeriod: Get<u32>;
+
+		/// Pallet id, used for deriving the accounts of the sponsorship pools.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
 		/// Minimum number of rows in a block.
 		#[pallet::constant]
 		type MinBlockRows: Get<BlockLengthRows>;
@@ -130,7 +398,10 @@ pub mod pallet {
 		type WeightInfo: weights::WeightInfo;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Last application ID
@@ -147,9 +418,195 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type SubmitDataFeeModifier<T: Config> = StorageValue<_, DispatchFeeModifier, ValueQuery>;
 
+	/// Accounts proposed as the new owners of application keys, pending their acceptance.
+	#[pallet::storage]
+	pub type PendingAppKeyTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, AppKeyFor<T>, T::AccountId, OptionQuery>;
+
+	/// Accounts allowed to submit data for an application. Anyone can submit data for the
+	/// applications without an allowlist.
+	#[pallet::storage]
+	pub type SubmitterAllowlists<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, SubmittersFor<T>, OptionQuery>;
+
+	/// Data quotas of the applications. Applications without a quota are only limited by the
+	/// block length.
+	#[pallet::storage]
+	pub type AppDataQuotas<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, AppDataQuota, OptionQuery>;
+
+	/// Block length proposed by `submit_block_length_proposal`, awaiting approval.
+	#[pallet::storage]
+	pub type PendingBlockLengthProposal<T: Config> =
+		StorageValue<_, (BlockLengthRows, BlockLengthColumns), OptionQuery>;
+
+	/// Approved block length change, enacted once its block is reached.
+	#[pallet::storage]
+	pub type ScheduledBlockLengthChange<T: Config> =
+		StorageValue<_, BlockLengthChange, OptionQuery>;
+
+	/// Bounds of the automatic block length governor, disabled if unset.
+	#[pallet::storage]
+	pub type BlockLengthGovernor<T: Config> = StorageValue<_, BlockLengthBounds, OptionQuery>;
+
+	/// Average share of the grid filled by the blocks, tracked while the governor is enabled.
+	#[pallet::storage]
+	pub type GridUtilization<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	/// Price per byte of submitted data, following the congestion of the grid.
+	#[pallet::storage]
+	pub type DataBytePrice<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Data submitted by the applications with a quota.
+	#[pallet::storage]
+	pub type AppDataUsages<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, AppDataUsage, ValueQuery>;
+
+	/// Maximum length of the data submitted for the applications restricting it.
+	#[pallet::storage]
+	pub type AppMaxDataLengths<T: Config> = StorageMap<_, Twox64Concat, AppId, u32, OptionQuery>;
+
+	/// Block from which the retired applications reject new submissions.
+	#[pallet::storage]
+	pub type AppRetirements<T: Config> = StorageMap<_, Twox64Concat, AppId, u32, OptionQuery>;
+
+	/// Block from which the paused applications reject new submissions, until their owner
+	/// unpauses them.
+	#[pallet::storage]
+	pub type PausedApps<T: Config> = StorageMap<_, Twox64Concat, AppId, u32, OptionQuery>;
+
+	/// Parent of the child applications.
+	#[pallet::storage]
+	pub type AppParents<T: Config> = StorageMap<_, Twox64Concat, AppId, AppId, OptionQuery>;
+
+	/// Child applications of each parent application, in their order of creation.
+	#[pallet::storage]
+	pub type AppChildren<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, AppChildrenFor<T>, ValueQuery>;
+
+	/// Sequence numbers of the applications which ever numbered their submissions.
+	#[pallet::storage]
+	pub type AppSequences<T: Config> = StorageMap<_, Twox64Concat, AppId, AppSequence, OptionQuery>;
+
+	/// Data submitted for each application in its last `MaxAppUsageHistory` eras with
+	/// submissions, oldest first. It is kept once the application key is removed.
+	#[pallet::storage]
+	pub type AppUsageHistory<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, AppUsageHistoryFor<T>, ValueQuery>;
+
+	/// Bytes of every block reserved for the submissions of the applications. The rest of the
+	/// block is filled first come, first served.
+	#[pallet::storage]
+	pub type AppReservedSpaces<T: Config> = StorageMap<_, Twox64Concat, AppId, u32, OptionQuery>;
+
+	/// Sum of the block space reserved for the applications.
+	#[pallet::storage]
+	pub type TotalReservedSpace<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Reserved block space used by the applications with a reservation.
+	#[pallet::storage]
+	pub type ReservedSpaceUsages<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, ReservedSpaceUsage, ValueQuery>;
+
+	/// Reserved block space used by all the applications.
+	#[pallet::storage]
+	pub type TotalReservedSpaceUsage<T: Config> = StorageValue<_, ReservedSpaceUsage, ValueQuery>;
+
+	/// Bytes of an upcoming block reserved by a submitter, until they fill them.
+	#[pallet::storage]
+	pub type BlockSpaceIntentOf<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
+	/// Block space reserved by the intents of each upcoming block.
+	#[pallet::storage]
+	pub type IntentSpaces<T: Config> =
+		StorageMap<_, Twox64Concat, u32, BlockSpaceIntents, ValueQuery>;
+
+	/// Number of leaves of the submitted data sub trie of the data root in the current block.
+	#[pallet::storage]
+	pub type SubmittedDataLeaves<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Longest retention period suggested for the data submitted in the current block, or
+	/// `u32::MAX` if some data was submitted without a retention hint.
+	#[pallet::storage]
+	pub type BlockRetentionPeriod<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// Number of data submissions of each application in the recent blocks, so indexers and
+	/// light clients can find the blocks holding data of their application without downloading
+	/// their bodies. Pruned after `SubmissionReceiptPeriod` blocks.
+	#[pallet::storage]
+	pub type SubmissionReceipts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, AppId, u32, ValueQuery>;
+
+	/// Number of applications with submission receipts in each recent block.
+	#[pallet::storage]
+	pub type SubmissionReceiptApps<T: Config> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			pool::clear_queued_submissions();
+
+			// `on_finalize` clears the submitted data leaves, the retention period and the
+			// unfilled intents.
+			let intents: u64 = T::MaxBlockSpaceIntents::get().into();
+			let weight = T::WeightInfo::update_data_byte_price()
+				.saturating_add(T::DbWeight::get().writes(3 + intents))
+				.saturating_add(Self::prune_submission_receipts(n.saturated_into()))
+				.saturating_add(Self::enact_block_length_change(n.saturated_into()));
+			let Some(bounds) = BlockLengthGovernor::<T>::get() else {
+				return weight.saturating_add(T::DbWeight::get().reads(1));
+			};
+
+			Self::govern_block_length(bounds);
+			weight.saturating_add(T::WeightInfo::govern_block_length())
+		}
+
+		fn integrity_test() {
+			let (min_rows, max_rows) = (T::MinBlockRows::get().0, T::MaxBlockRows::get().0);
+			let (min_cols, max_cols) = (T::MinBlockCols::get().0, T::MaxBlockCols::get().0);
+			assert!(
+				[min_rows, max_rows, min_cols, max_cols]
+					.iter()
+					.all(|n| n.is_power_of_two()),
+				"Block dimensions must be powers of two"
+			);
+			assert!(
+				min_rows <= max_rows && min_cols <= max_cols,
+				"Minimum block dimensions exceed the maximum ones"
+			);
+			assert!(
+				max_rows <= MAX_GRID_ROWS && max_cols <= MAX_GRID_COLS,
+				"Maximum block dimensions exceed the largest supported grid"
+			);
+			assert!(
+				BlockLength::with_normal_ratio(
+					T::MaxBlockRows::get(),
+					T::MaxBlockCols::get(),
+					BLOCK_CHUNK_SIZE,
+					DA_DISPATCH_RATIO
+				)
+				.is_ok(),
+				"Length of the largest block overflows"
+			);
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			let utilization = Self::block_grid_utilization();
+			Self::update_data_byte_price(utilization);
+			if BlockLengthGovernor::<T>::exists() {
+				Self::note_grid_utilization(utilization);
+			}
+			SubmittedDataLeaves::<T>::kill();
+			Self::log_retention_hint(n.saturated_into());
+			Self::release_block_space_intents(n.saturated_into());
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Creates an application key if `key` does not exist yet.
+		/// Creates an application key if `key` does not exist yet, reserving `AppKeyDeposit`
+		/// from the owner until the key is removed.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::create_application_key())]
 		pub fn create_application_key(
@@ -157,18 +614,7 @@ pub mod pallet {
 			key: AppKeyFor<T>,
 		) -> DispatchResultWithPostInfo {
 			let owner = ensure_signed(origin)?;
-			ensure!(!key.is_empty(), Error::<T>::AppKeyCannotBeEmpty);
-			let id = AppKeys::<T>::try_mutate(&key, |key_info| -> Result<AppId, Error<T>> {
-				ensure!(key_info.is_none(), Error::<T>::AppKeyAlreadyExists);
-
-				let id = Self::next_application_id()?;
-				*key_info = Some(AppKeyInfo {
-					id,
-					owner: owner.clone(),
-				});
-
-				Ok(id)
-			})?;
+			let id = Self::insert_application_key(&owner, &key)?;
 
 			Self::deposit_event(Event::ApplicationKeyCreated { key, owner, id });
 			Ok(().into())
@@ -187,15 +633,20 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			ensure!(!data.is_empty(), Error::<T>::DataCannotBeEmpty);
 
+			Self::note_retention_period(None);
+			Self::deposit_data_locations(1);
 			let data_hash = blake2_256(&data);
 			Self::deposit_event(Event::DataSubmitted {
 				who,
 				data_hash: H256(data_hash),
+				blob_hash: H256(keccak_256(&data)),
 			});
 
 			Ok(().into())
 		}
 
+		/// Proposes a new block length, replacing the pending proposal if any. The proposal
+		/// only changes the block length once approved by `approve_block_length_proposal`.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::submit_block_length_proposal())]
 		pub fn submit_block_length_proposal(
@@ -227,19 +678,10 @@ pub mod pallet {
 				Error::<T>::NotPowerOfTwo
 			);
 
-			let current_block_dimension = DynamicBlockLength::<T>::get();
-			let is_increase =
-				rows >= current_block_dimension.rows && cols >= current_block_dimension.cols;
-			ensure!(
-				is_increase || Self::is_block_weight_acceptable(),
-				Error::<T>::InvalidBlockWeightReduction
-			);
-
-			let block_length =
-				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
-					.map_err(|_| Error::<T>::BlockDimensionsOutOfBounds)?;
+			BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+				.map_err(|_| Error::<T>::BlockDimensionsOutOfBounds)?;
 
-			DynamicBlockLength::<T>::put(block_length);
+			PendingBlockLengthProposal::<T>::put((rows, cols));
 
 			Self::deposit_event(Event::BlockLengthProposalSubmitted { rows, cols });
 
@@ -274,6 +716,11 @@ pub mod pallet {
 			// Insert the app info under the new key
 			AppKeys::<T>::insert(&new_key, app_key_info);
 
+			// Move the pending ownership transfer along with the key
+			if let Some(new_owner) = PendingAppKeyTransfers::<T>::take(&old_key) {
+				PendingAppKeyTransfers::<T>::insert(&new_key, new_owner);
+			}
+
 			Self::deposit_event(Event::ApplicationKeySet { old_key, new_key });
 
 			Ok(().into())
@@ -293,125 +740,1597 @@ pub mod pallet {
 
 			Ok(().into())
 		}
-	}
 
-	/// Event for the pallet.
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// A new application key was created.
-		ApplicationKeyCreated {
+		/// Sets or clears the metadata of an application key. Only the owner of the key can set
+		/// its metadata.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_application_key_metadata())]
+		pub fn set_application_key_metadata(
+			origin: OriginFor<T>,
 			key: AppKeyFor<T>,
-			owner: T::AccountId,
-			id: AppId,
-		},
-		DataSubmitted {
-			who: T::AccountId,
-			data_hash: H256,
-		},
-		BlockLengthProposalSubmitted {
-			rows: BlockLengthRows,
-			cols: BlockLengthColumns,
-		},
-		ApplicationKeySet {
-			old_key: AppKeyFor<T>,
-			new_key: AppKeyFor<T>,
-		},
-		SubmitDataFeeModifierSet {
-			value: DispatchFeeModifier,
-		},
-	}
+			metadata: Option<AppKeyMetadata>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-	/// Error for the System pallet
-	#[pallet::error]
-	pub enum Error<T> {
-		/// The application key already exists.
-		AppKeyAlreadyExists,
-		/// The application key is an empty string.
-		AppKeyCannotBeEmpty,
-		/// The last application ID overflowed.
-		LastAppIdOverflowed,
-		/// The submitted data is empty.
-		DataCannotBeEmpty,
-		/// The last block length proposal Id overflowed.
-		LastBlockLenProposalIdOverflowed,
-		/// The proposed block dimensions are out of bounds.
-		BlockDimensionsOutOfBounds,
-		/// The proposed block dimensions are too small.
-		BlockDimensionsTooSmall,
-		/// The request to reduce block dimensions was made in a non-empty block
-		InvalidBlockWeightReduction,
-		/// Submit data call outside of block execution context.
-		BadContext,
-		/// App info was not found for the given App key
-		UnknownAppKey,
-		/// Submit block length proposal was made with values not power of 2
-		NotPowerOfTwo,
-	}
+			AppKeys::<T>::try_mutate(&key, |key_info| -> Result<(), Error<T>> {
+				let key_info = key_info.as_mut().ok_or(Error::<T>::UnknownAppKey)?;
+				ensure!(key_info.owner == who, Error::<T>::NotAppKeyOwner);
+				key_info.metadata = metadata.clone();
+				Ok(())
+			})?;
 
-	#[pallet::genesis_config]
-	#[derive(DefaultNoBound)]
-	pub struct GenesisConfig<T: Config> {
-		#[allow(clippy::type_complexity)]
-		pub app_keys: Vec<(Vec<u8>, (T::AccountId, u32))>,
-	}
+			Self::deposit_event(Event::ApplicationKeyMetadataSet { key, metadata });
 
-	#[pallet::genesis_build]
-	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
-		fn build(&self) {
-			// Ensure app ids are unique.
-			let mut app_keys = self.app_keys.clone();
-			app_keys.sort_by(|a, b| a.1 .1.cmp(&b.1 .1));
+			Ok(().into())
+		}
 
-			// With a sorted array, we can just use the id of the last element :)
-			let last_key = app_keys.last();
-			let last_id = last_key.map(|x| x.1 .1).unwrap_or(0);
-			let next_app_id = AppId(last_id.saturating_add(1));
-			NextAppId::<T>::put::<AppId>(next_app_id);
+		/// Proposes `new_owner` as the owner of an application key. The ownership is only
+		/// transferred once `new_owner` accepts it, and a new proposal replaces the pending one.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::transfer_app_key_ownership())]
+		pub fn transfer_app_key_ownership(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-			// Insert app keys. It verifies the length limitation of each key.
-			for (key, (owner, id)) in app_keys {
-				let key = AppKeyFor::<T>::try_from(key)
-					.expect("DA Control Genesis contains invalid keys");
-				let value = AppKeyInfo {
-					id: AppId(id),
-					owner,
-				};
-				AppKeys::<T>::insert(key, value);
-			}
-		}
-	}
-}
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
 
-impl<T: Config> Pallet<T> {
-	/// Returns the latest available application ID and increases it.
-	pub fn next_application_id() -> Result<AppId, Error<T>> {
-		NextAppId::<T>::try_mutate(|id| {
-			let new_id = AppId(id.0.checked_add(1).ok_or(Error::<T>::LastAppIdOverflowed)?);
-			Ok(replace(id, new_id))
-		})
-	}
+			PendingAppKeyTransfers::<T>::insert(&key, &new_owner);
 
-	/// Check if the block weight is acceptable to execute the extrinsic
-	/// We check the current normal ratio weight, if it's too high, it means we won't reduce the block size
-	pub fn is_block_weight_acceptable() -> bool {
-		let current_weight = <frame_system::Pallet<T>>::block_weight();
-		let current_normal_weight: &Weight = current_weight.get(DispatchClass::Normal);
-		// Offsetting the base_weight multiplication done for all txs
-		let base_weight = ExtrinsicBaseWeight::get().saturating_mul(100);
-		let acceptable_limit: Weight = T::WeightInfo::submit_block_length_proposal()
-			.saturating_mul(5)
-			.saturating_add(base_weight);
-		current_normal_weight.all_lte(acceptable_limit)
-	}
-}
+			Self::deposit_event(Event::ApplicationKeyTransferProposed {
+				key,
+				owner: who,
+				new_owner,
+			});
 
-pub mod weight_helper {
+			Ok(().into())
+		}
 
-	use super::*;
+		/// Accepts the ownership of an application key proposed to the caller.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::accept_app_key_ownership())]
+		pub fn accept_app_key_ownership(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-	/// Weight for `dataAvailability::submit_data`.
-	pub fn submit_data<T: Config>(data_len: usize) -> Weight {
+			let new_owner = PendingAppKeyTransfers::<T>::get(&key)
+				.ok_or(Error::<T>::NoPendingAppKeyTransfer)?;
+			ensure!(new_owner == who, Error::<T>::NotPendingAppKeyOwner);
+
+			let (old_owner, deposit) =
+				AppKeys::<T>::try_mutate(&key, |key_info| -> Result<_, Error<T>> {
+					let key_info = key_info.as_mut().ok_or(Error::<T>::UnknownAppKey)?;
+					Ok((replace(&mut key_info.owner, who.clone()), key_info.deposit))
+				})?;
+			PendingAppKeyTransfers::<T>::remove(&key);
+
+			// The deposit follows the ownership of the key.
+			T::Currency::reserve(&who, deposit)?;
+			T::Currency::unreserve(&old_owner, deposit);
+
+			Self::deposit_event(Event::ApplicationKeyOwnershipTransferred {
+				key,
+				old_owner,
+				new_owner: who,
+			});
+
+			Ok(().into())
+		}
+
+		/// Restricts the submission of data for the application of `key` to the `submitters`,
+		/// or lifts the restriction if `None`. Only the owner of the key can set the allowlist,
+		/// and the owner is not allowed to submit unless listed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_submitter_allowlist(
+			submitters.as_ref().map_or(0, |submitters| submitters.len() as u32)
+		))]
+		pub fn set_submitter_allowlist(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			submitters: Option<SubmittersFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+			ensure!(
+				app_key_info.id != AppId(0),
+				Error::<T>::AppIdCannotBeRestricted
+			);
+
+			SubmitterAllowlists::<T>::set(app_key_info.id, submitters.clone());
+
+			Self::deposit_event(Event::SubmitterAllowlistSet {
+				id: app_key_info.id,
+				submitters,
+			});
+
+			Ok(().into())
+		}
+
+		/// Restricts the length of the data submitted for the application of `key` to
+		/// `max_length` bytes, at most `MaxAppDataLength`, or lifts the restriction if `None`.
+		/// Only the owner of the key can set it. Larger submissions are rejected by the
+		/// transaction pool, decompressed for compressed data.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::set_app_max_data_length())]
+		pub fn set_app_max_data_length(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			max_length: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+			ensure!(
+				app_key_info.id != AppId(0),
+				Error::<T>::AppIdCannotBeRestricted
+			);
+			ensure!(
+				max_length.map_or(true, |max| max <= T::MaxAppDataLength::get()),
+				Error::<T>::MaxDataLengthTooLarge
+			);
+
+			AppMaxDataLengths::<T>::set(app_key_info.id, max_length);
+
+			Self::deposit_event(Event::AppMaxDataLengthSet {
+				id: app_key_info.id,
+				max_length,
+			});
+
+			Ok(().into())
+		}
+
+		/// Enables or disables the sequence numbers of the data submitted for the application
+		/// of `key`. Only the owner of the key can set it. Each submission of an application
+		/// numbering them gets the next sequence number, emitted by `DataSequenced`, so its
+		/// readers detect missing submissions.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_app_sequencing())]
+		pub fn set_app_sequencing(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			enabled: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+			ensure!(
+				app_key_info.id != AppId(0),
+				Error::<T>::AppIdCannotBeRestricted
+			);
+
+			let id = app_key_info.id;
+			let mut sequence = AppSequences::<T>::get(id).unwrap_or_default();
+			sequence.enabled = enabled;
+			AppSequences::<T>::insert(id, sequence);
+
+			Self::deposit_event(Event::AppSequencingSet { id, enabled });
+
+			Ok(().into())
+		}
+
+		/// Announces the retirement of the application key `key`. Once `AppKeyRetirementPeriod`
+		/// blocks passed, new submissions for its application are rejected and the deposit of
+		/// the key can be refunded with `complete_app_key_retirement`. Its ID is never
+		/// reassigned.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::retire_application_key())]
+		pub fn retire_application_key(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+			let id = app_key_info.id;
+			ensure!(id != AppId(0), Error::<T>::AppIdCannotBeRestricted);
+			ensure!(
+				!AppRetirements::<T>::contains_key(id),
+				Error::<T>::AppKeyAlreadyRetiring
+			);
+
+			let now: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+			let retires_at = now.saturating_add(T::AppKeyRetirementPeriod::get());
+			AppRetirements::<T>::insert(id, retires_at);
+
+			Self::deposit_event(Event::ApplicationKeyRetiring {
+				key,
+				id,
+				retires_at,
+			});
+
+			Ok(().into())
+		}
+
+		/// Refunds the deposit of the retired application key `key` to its owner.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::complete_app_key_retirement())]
+		pub fn complete_app_key_retirement(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let (id, deposit) =
+				AppKeys::<T>::try_mutate(&key, |key_info| -> Result<_, Error<T>> {
+					let key_info = key_info.as_mut().ok_or(Error::<T>::UnknownAppKey)?;
+					ensure!(key_info.owner == who, Error::<T>::NotAppKeyOwner);
+					ensure!(Self::is_retired(key_info.id), Error::<T>::AppKeyNotRetired);
+					Ok((key_info.id, replace(&mut key_info.deposit, Zero::zero())))
+				})?;
+			T::Currency::unreserve(&who, deposit);
+
+			Self::deposit_event(Event::ApplicationKeyRetired {
+				key,
+				owner: who,
+				id,
+			});
+
+			Ok(().into())
+		}
+
+		/// Sets or removes the data quota of an application.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_app_data_quota())]
+		pub fn set_app_data_quota(
+			origin: OriginFor<T>,
+			id: AppId,
+			quota: Option<AppDataQuota>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(id != AppId(0), Error::<T>::AppIdCannotBeRestricted);
+
+			AppDataQuotas::<T>::set(id, quota);
+			if quota.is_none() {
+				AppDataUsages::<T>::remove(id);
+			}
+
+			Self::deposit_event(Event::AppDataQuotaSet { id, quota });
+
+			Ok(().into())
+		}
+
+		/// Enables the automatic adjustment of the block length within `bounds`, or disables it
+		/// if `None`. While enabled, the block length is doubled or halved at the start of a
+		/// block whenever the average grid utilization leaves the targets of `bounds`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_block_length_governor())]
+		pub fn set_block_length_governor(
+			origin: OriginFor<T>,
+			bounds: Option<BlockLengthBounds>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			if let Some(bounds) = bounds {
+				let is_power_of_two = |n: u32| n.is_power_of_two();
+				ensure!(
+					bounds.min_rows >= T::MinBlockRows::get()
+						&& bounds.max_rows <= T::MaxBlockRows::get()
+						&& bounds.min_cols >= T::MinBlockCols::get()
+						&& bounds.max_cols <= T::MaxBlockCols::get(),
+					Error::<T>::BlockDimensionsOutOfBounds
+				);
+				ensure!(
+					[
+						bounds.min_rows.0,
+						bounds.max_rows.0,
+						bounds.min_cols.0,
+						bounds.max_cols.0
+					]
+					.into_iter()
+					.all(is_power_of_two),
+					Error::<T>::NotPowerOfTwo
+				);
+				ensure!(
+					bounds.min_rows <= bounds.max_rows
+						&& bounds.min_cols <= bounds.max_cols
+						&& bounds.lower_target < bounds.upper_target,
+					Error::<T>::InvalidBlockLengthBounds
+				);
+			}
+
+			BlockLengthGovernor::<T>::set(bounds);
+			GridUtilization::<T>::kill();
+
+			Self::deposit_event(Event::BlockLengthGovernorSet { bounds });
+
+			Ok(().into())
+		}
+
+		/// Submits `data` compressed as a single zstd frame declaring its `decompressed_len`.
+		/// The fees are charged on the larger of `decompressed_len` and the compressed length,
+		/// as the declared length is not checked against the data the block commits to.
+		#[pallet::call_index(11)]
+		#[pallet::weight((
+			weight_helper::submit_data::<T>(Pallet::<T>::compressed_data_len(data, *decompressed_len)),
+			DispatchClass::Normal,
+			SubmitDataFeeModifier::<T>::get()
+		))]
+		pub fn submit_compressed_data(
+			origin: OriginFor<T>,
+			data: AppDataFor<T>,
+			decompressed_len: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!data.is_empty(), Error::<T>::DataCannotBeEmpty);
+			ensure!(
+				decompressed_len <= T::MaxAppDataLength::get(),
+				Error::<T>::DecompressedDataTooLong
+			);
+			ensure!(
+				Self::is_valid_compression(&data, decompressed_len),
+				Error::<T>::InvalidCompressedData
+			);
+
+			Self::note_retention_period(None);
+			Self::deposit_data_locations(1);
+			let data_hash = blake2_256(&data);
+			Self::deposit_event(Event::CompressedDataSubmitted {
+				who,
+				data_hash: H256(data_hash),
+				decompressed_len,
+			});
+
+			Ok(().into())
+		}
+
+		/// Submits a batch of data for several applications under a single signature and base
+		/// fee. The batch is signed with `AppId(0)` and `CheckAppId` checks the application of each
+		/// entry instead: it must be registered, and its allowlist and quota apply. Every entry
+		/// gets its own leaf in the data root, in order of the batch, and a
+		/// `BatchDataSubmitted` event carrying its index in the batch.
+		#[pallet::call_index(12)]
+		#[pallet::weight((
+			weight_helper::submit_data_batch::<T>(batch),
+			DispatchClass::Normal,
+			SubmitDataFeeModifier::<T>::get()
+		))]
+		pub fn submit_data_batch(
+			origin: OriginFor<T>,
+			batch: DataBatchFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!batch.is_empty(), Error::<T>::EmptyDataBatch);
+
+			let next_app_id = Self::peek_next_application_id();
+			for (id, data) in batch.iter() {
+				ensure!(!data.is_empty(), Error::<T>::DataCannotBeEmpty);
+				ensure!(*id < next_app_id, Error::<T>::UnknownAppId);
+				ensure!(
+					Self::is_allowed_submitter(*id, &who),
+					Error::<T>::NotAllowedSubmitter
+				);
+				ensure!(!Self::is_retired(*id), Error::<T>::RetiredAppId);
+				ensure!(!Self::is_paused(*id), Error::<T>::PausedAppId);
+			}
+
+			Self::note_retention_period(None);
+			Self::deposit_data_locations(batch.len() as u32);
+			for ((app_id, data), index) in batch.into_iter().zip(0u32..) {
+				Self::deposit_event(Event::BatchDataSubmitted {
+					who: who.clone(),
+					app_id,
+					index,
+					data_hash: H256(blake2_256(&data)),
+				});
+			}
+
+			Ok(().into())
+		}
+
+		/// Deposits `amount` into the sponsorship pool of the application of `key`. The pool
+		/// pays the fees of the data submitted with `submit_sponsored_data` by the allowlisted
+		/// submitters of the application. Only the owner of the key can fund its pool.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::fund_sponsorship_pool())]
+		pub fn fund_sponsorship_pool(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+			ensure!(
+				app_key_info.id != AppId(0),
+				Error::<T>::AppIdCannotBeRestricted
+			);
+
+			let pool = Self::sponsorship_pool_account(app_key_info.id);
+			T::Currency::transfer(&who, &pool, amount, ExistenceRequirement::KeepAlive)?;
+
+			Self::deposit_event(Event::SponsorshipPoolFunded {
+				id: app_key_info.id,
+				amount,
+			});
+
+			Ok(().into())
+		}
+
+		/// Withdraws `amount` from the sponsorship pool of the application of `key` back to its
+		/// owner.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::withdraw_sponsorship_pool())]
+		pub fn withdraw_sponsorship_pool(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+
+			let pool = Self::sponsorship_pool_account(app_key_info.id);
+			T::Currency::transfer(&pool, &who, amount, ExistenceRequirement::AllowDeath)?;
+
+			Self::deposit_event(Event::SponsorshipPoolWithdrawn {
+				id: app_key_info.id,
+				amount,
+			});
+
+			Ok(().into())
+		}
+
+		/// Submits `data` for `app_id` with the fees paid by its sponsorship pool. Only the
+		/// allowlisted submitters of the application can be sponsored, and the extrinsic must
+		/// be signed with the same `app_id`. The transaction fee is redirected to the pool by
+		/// [`SponsoredFeeAdapter`].
+		#[pallet::call_index(15)]
+		#[pallet::weight((
+			weight_helper::submit_data::<T>(data.len())
+				.saturating_add(T::DbWeight::get().reads(1)),
+			DispatchClass::Normal,
+			SubmitDataFeeModifier::<T>::get()
+		))]
+		pub fn submit_sponsored_data(
+			origin: OriginFor<T>,
+			app_id: AppId,
+			data: AppDataFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!data.is_empty(), Error::<T>::DataCannotBeEmpty);
+			ensure!(
+				Self::is_sponsored_submitter(app_id, &who),
+				Error::<T>::NotAllowedSubmitter
+			);
+
+			Self::note_retention_period(None);
+			Self::deposit_data_locations(1);
+			let data_hash = blake2_256(&data);
+			Self::deposit_event(Event::DataSubmitted {
+				who,
+				data_hash: H256(data_hash),
+				blob_hash: H256(keccak_256(&data)),
+			});
+
+			Ok(().into())
+		}
+
+		/// Removes the application key `key` and returns its deposit to the owner, along with
+		/// the balance left in its sponsorship pool. The restrictions of the application are
+		/// lifted, while its ID is never reassigned.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::remove_application_key())]
+		pub fn remove_application_key(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+			ensure!(
+				app_key_info.id != AppId(0),
+				Error::<T>::AppIdCannotBeRestricted
+			);
+			let id = app_key_info.id;
+			ensure!(
+				AppChildren::<T>::get(id).is_empty(),
+				Error::<T>::AppHasChildApps
+			);
+
+			AppKeys::<T>::remove(&key);
+			PendingAppKeyTransfers::<T>::remove(&key);
+			if let Some(parent) = AppParents::<T>::take(id) {
+				AppChildren::<T>::mutate(parent, |children| children.retain(|child| *child != id));
+			}
+			SubmitterAllowlists::<T>::remove(id);
+			AppMaxDataLengths::<T>::remove(id);
+			PausedApps::<T>::remove(id);
+			AppDataQuotas::<T>::remove(id);
+			AppDataUsages::<T>::remove(id);
+			Self::release_reserved_space(id);
+
+			T::Currency::unreserve(&who, app_key_info.deposit);
+			let pool = Self::sponsorship_pool_account(id);
+			let pool_balance = T::Currency::free_balance(&pool);
+			if !pool_balance.is_zero() {
+				T::Currency::transfer(&pool, &who, pool_balance, ExistenceRequirement::AllowDeath)?;
+			}
+
+			Self::deposit_event(Event::ApplicationKeyRemoved {
+				key,
+				owner: who,
+				id,
+			});
+
+			Ok(().into())
+		}
+
+		/// Reserves `bytes` of every block for the submissions of an application, or removes
+		/// its reservation. The reservations cannot exceed `MaxReservedBlockSpace` of the normal
+		/// block length in total.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_app_reserved_space())]
+		pub fn set_app_reserved_space(
+			origin: OriginFor<T>,
+			id: AppId,
+			bytes: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(id != AppId(0), Error::<T>::AppIdCannotBeRestricted);
+
+			let reserved_for_others = Self::release_reserved_space(id);
+			if let Some(bytes) = bytes {
+				let total = reserved_for_others
+					.checked_add(bytes)
+					.ok_or(Error::<T>::ReservedSpaceTooLarge)?;
+				ensure!(
+					total <= Self::max_reserved_space(),
+					Error::<T>::ReservedSpaceTooLarge
+				);
+				AppReservedSpaces::<T>::insert(id, bytes);
+				TotalReservedSpace::<T>::put(total);
+			}
+
+			Self::deposit_event(Event::AppReservedSpaceSet { id, bytes });
+
+			Ok(().into())
+		}
+
+		/// Submits `data`, suggesting it to be kept available for `retention` blocks, at most
+		/// `MaxRetentionPeriod`. The hints of a block are logged in its header as a
+		/// [`RetentionHint`].
+		#[pallet::call_index(18)]
+		#[pallet::weight((
+			weight_helper::submit_data::<T>(data.len()),
+			DispatchClass::Normal,
+			SubmitDataFeeModifier::<T>::get()
+		))]
+		pub fn submit_data_with_retention(
+			origin: OriginFor<T>,
+			data: AppDataFor<T>,
+			retention: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!data.is_empty(), Error::<T>::DataCannotBeEmpty);
+			ensure!(
+				retention <= T::MaxRetentionPeriod::get(),
+				Error::<T>::RetentionPeriodTooLong
+			);
+
+			Self::note_retention_period(Some(retention));
+			Self::deposit_data_locations(1);
+			let data_hash = blake2_256(&data);
+			Self::deposit_event(Event::DataSubmitted {
+				who,
+				data_hash: H256(data_hash),
+				blob_hash: H256(keccak_256(&data)),
+			});
+
+			Ok(().into())
+		}
+
+		/// Reserves `bytes` of the upcoming block `block` for the data of the caller, at most
+		/// `MaxIntentLookahead` blocks ahead. The price of the data is charged now, and the
+		/// reservation is filled with `submit_reserved_data` in `block`. Its space is taken out
+		/// of the space reserved for the applications, and it is forfeited if not filled.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::reserve_block_space())]
+		pub fn reserve_block_space(
+			origin: OriginFor<T>,
+			block: u32,
+			bytes: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let now: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+			ensure!(
+				block > now && block - now <= T::MaxIntentLookahead::get(),
+				Error::<T>::InvalidIntentBlock
+			);
+			ensure!(
+				bytes > 0 && bytes <= T::MaxAppDataLength::get(),
+				Error::<T>::InvalidIntentLength
+			);
+			ensure!(
+				!BlockSpaceIntentOf::<T>::contains_key(block, &who),
+				Error::<T>::BlockSpaceIntentExists
+			);
+
+			let mut intents = IntentSpaces::<T>::get(block);
+			intents.count = intents.count.saturating_add(1);
+			intents.bytes = intents.bytes.saturating_add(bytes);
+			let reserved = TotalReservedSpace::<T>::get().saturating_add(intents.bytes);
+			ensure!(
+				intents.count <= T::MaxBlockSpaceIntents::get()
+					&& reserved <= Self::max_reserved_space(),
+				Error::<T>::BlockSpaceUnavailable
+			);
+			Self::charge_data_fee(&who, Self::charged_data_len(bytes as usize))?;
+
+			IntentSpaces::<T>::insert(block, intents);
+			BlockSpaceIntentOf::<T>::insert(block, &who, bytes);
+			Self::deposit_event(Event::BlockSpaceReserved { who, block, bytes });
+
+			Ok(().into())
+		}
+
+		/// Submits `data` in the block space the caller reserved in the current block with
+		/// `reserve_block_space`. Its price was charged by the reservation, and the bytes it
+		/// leaves unused are released.
+		#[pallet::call_index(24)]
+		#[pallet::weight((
+			weight_helper::submit_data::<T>(data.len())
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2)),
+			DispatchClass::Normal,
+			SubmitDataFeeModifier::<T>::get()
+		))]
+		pub fn submit_reserved_data(
+			origin: OriginFor<T>,
+			data: AppDataFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!data.is_empty(), Error::<T>::DataCannotBeEmpty);
+			let block: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+			let bytes =
+				BlockSpaceIntentOf::<T>::get(block, &who).ok_or(Error::<T>::NoBlockSpaceIntent)?;
+			ensure!(
+				data.len() <= bytes as usize,
+				Error::<T>::BlockSpaceIntentExceeded
+			);
+
+			BlockSpaceIntentOf::<T>::remove(block, &who);
+			IntentSpaces::<T>::mutate(block, |intents| {
+				intents.count = intents.count.saturating_sub(1);
+				intents.bytes = intents.bytes.saturating_sub(bytes);
+			});
+
+			Self::note_retention_period(None);
+			Self::deposit_data_locations(1);
+			let data_hash = blake2_256(&data);
+			Self::deposit_event(Event::DataSubmitted {
+				who,
+				data_hash: H256(data_hash),
+				blob_hash: H256(keccak_256(&data)),
+			});
+
+			Ok(().into())
+		}
+
+		/// Creates the application key `key` as a child of the application of `parent_key`,
+		/// reserving `AppKeyDeposit` from the owner of the parent. A parent has up to
+		/// `MaxAppChildren` children, which cannot have children of their own.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::create_child_application_key())]
+		pub fn create_child_application_key(
+			origin: OriginFor<T>,
+			parent_key: AppKeyFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+
+			let parent_info = AppKeys::<T>::get(&parent_key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(parent_info.owner == owner, Error::<T>::NotAppKeyOwner);
+			ensure!(
+				parent_info.id != AppId(0),
+				Error::<T>::AppIdCannotBeRestricted
+			);
+			let parent = parent_info.id;
+			ensure!(
+				!AppParents::<T>::contains_key(parent),
+				Error::<T>::NestedChildApp
+			);
+
+			let id = Self::insert_application_key(&owner, &key)?;
+			AppChildren::<T>::try_append(parent, id).map_err(|_| Error::<T>::TooManyChildApps)?;
+			AppParents::<T>::insert(id, parent);
+
+			Self::deposit_event(Event::ChildApplicationKeyCreated {
+				key,
+				owner,
+				id,
+				parent,
+			});
+
+			Ok(().into())
+		}
+
+		/// Pauses the submissions of the application of `key`, which are rejected until its
+		/// owner unpauses them.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::pause_application())]
+		pub fn pause_application(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+			let id = app_key_info.id;
+			ensure!(id != AppId(0), Error::<T>::AppIdCannotBeRestricted);
+			ensure!(!Self::is_paused(id), Error::<T>::AppAlreadyPaused);
+
+			let now: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+			PausedApps::<T>::insert(id, now);
+
+			Self::deposit_event(Event::ApplicationPaused { key, id });
+			Ok(().into())
+		}
+
+		/// Resumes the submissions of the paused application of `key`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::unpause_application())]
+		pub fn unpause_application(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let app_key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(app_key_info.owner == who, Error::<T>::NotAppKeyOwner);
+			let id = app_key_info.id;
+			ensure!(
+				PausedApps::<T>::take(id).is_some(),
+				Error::<T>::AppNotPaused
+			);
+
+			Self::deposit_event(Event::ApplicationUnpaused { key, id });
+			Ok(().into())
+		}
+
+		/// Approves the pending block length proposal, announcing that it is enacted in
+		/// `BlockLengthChangeDelay` blocks. It replaces the block length change scheduled
+		/// already, if any.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::approve_block_length_proposal())]
+		pub fn approve_block_length_proposal(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let (rows, cols) =
+				PendingBlockLengthProposal::<T>::get().ok_or(Error::<T>::NoBlockLengthProposal)?;
+
+			let current_block_dimension = DynamicBlockLength::<T>::get();
+			let is_increase =
+				rows >= current_block_dimension.rows && cols >= current_block_dimension.cols;
+			ensure!(
+				is_increase || Self::is_block_weight_acceptable(),
+				Error::<T>::InvalidBlockWeightReduction
+			);
+
+			let block: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+			let at = block.saturating_add(T::BlockLengthChangeDelay::get());
+			PendingBlockLengthProposal::<T>::kill();
+			ScheduledBlockLengthChange::<T>::put(BlockLengthChange { rows, cols, at });
+
+			Self::deposit_event(Event::BlockLengthChangeAnnounced { rows, cols, at });
+			Ok(().into())
+		}
+	}
+
+	/// Event for the pallet.
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new application key was created.
+		ApplicationKeyCreated {
+			key: AppKeyFor<T>,
+			owner: T::AccountId,
+			id: AppId,
+		},
+		DataSubmitted {
+			who: T::AccountId,
+			/// Blake2 256 hash of the submitted data.
+			data_hash: H256,
+			/// Keccak 256 hash of the submitted data, to match it against external commitments.
+			blob_hash: H256,
+		},
+		BlockLengthProposalSubmitted {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+		},
+		ApplicationKeySet {
+			old_key: AppKeyFor<T>,
+			new_key: AppKeyFor<T>,
+		},
+		SubmitDataFeeModifierSet {
+			value: DispatchFeeModifier,
+		},
+		/// The metadata of an application key was set or cleared.
+		ApplicationKeyMetadataSet {
+			key: AppKeyFor<T>,
+			metadata: Option<AppKeyMetadata>,
+		},
+		/// The owner of an application key proposed a new owner.
+		ApplicationKeyTransferProposed {
+			key: AppKeyFor<T>,
+			owner: T::AccountId,
+			new_owner: T::AccountId,
+		},
+		/// The ownership of an application key was accepted by its new owner.
+		ApplicationKeyOwnershipTransferred {
+			key: AppKeyFor<T>,
+			old_owner: T::AccountId,
+			new_owner: T::AccountId,
+		},
+		/// The submitter allowlist of an application was set or lifted.
+		SubmitterAllowlistSet {
+			id: AppId,
+			submitters: Option<SubmittersFor<T>>,
+		},
+		/// The data quota of an application was set or removed.
+		AppDataQuotaSet {
+			id: AppId,
+			quota: Option<AppDataQuota>,
+		},
+		/// The maximum data length of an application was set or lifted.
+		AppMaxDataLengthSet {
+			id: AppId,
+			max_length: Option<u32>,
+		},
+		/// The sequence numbers of an application were enabled or disabled.
+		AppSequencingSet {
+			id: AppId,
+			enabled: bool,
+		},
+		/// Data submitted for an application numbering its submissions got `sequence`.
+		DataSequenced {
+			app_id: AppId,
+			sequence: u64,
+		},
+		/// The automatic block length governor was enabled or disabled.
+		BlockLengthGovernorSet {
+			bounds: Option<BlockLengthBounds>,
+		},
+		/// The block length governor adjusted the block length to the grid utilization.
+		BlockLengthAdjusted {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+			utilization: Perbill,
+		},
+		CompressedDataSubmitted {
+			who: T::AccountId,
+			data_hash: H256,
+			decompressed_len: u32,
+		},
+		BatchDataSubmitted {
+			who: T::AccountId,
+			app_id: AppId,
+			index: u32,
+			data_hash: H256,
+		},
+		SponsorshipPoolFunded {
+			id: AppId,
+			amount: BalanceOf<T>,
+		},
+		SponsorshipPoolWithdrawn {
+			id: AppId,
+			amount: BalanceOf<T>,
+		},
+		ApplicationKeyRemoved {
+			key: AppKeyFor<T>,
+			owner: T::AccountId,
+			id: AppId,
+		},
+		/// An application key will reject new submissions from `retires_at`.
+		ApplicationKeyRetiring {
+			key: AppKeyFor<T>,
+			id: AppId,
+			retires_at: u32,
+		},
+		/// The deposit of a retired application key was refunded to its owner.
+		ApplicationKeyRetired {
+			key: AppKeyFor<T>,
+			owner: T::AccountId,
+			id: AppId,
+		},
+		/// The owner of an application paused its submissions.
+		ApplicationPaused {
+			key: AppKeyFor<T>,
+			id: AppId,
+		},
+		/// The owner of an application resumed its submissions.
+		ApplicationUnpaused {
+			key: AppKeyFor<T>,
+			id: AppId,
+		},
+		/// A block length proposal was approved, and the block length changes at block `at`.
+		BlockLengthChangeAnnounced {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+			at: u32,
+		},
+		/// An announced block length change was enacted.
+		BlockLengthChanged {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+		},
+		/// An application key was created as a child of the application `parent`.
+		ChildApplicationKeyCreated {
+			key: AppKeyFor<T>,
+			owner: T::AccountId,
+			id: AppId,
+			parent: AppId,
+		},
+		/// Block space of an upcoming block was reserved for the data of `who`.
+		BlockSpaceReserved {
+			who: T::AccountId,
+			block: u32,
+			bytes: u32,
+		},
+		/// The block space reserved for an application was set or removed.
+		AppReservedSpaceSet {
+			id: AppId,
+			bytes: Option<u32>,
+		},
+		/// Submitted data of the extrinsic `extrinsic_index` is the leaf `leaf_index` of the
+		/// submitted data sub trie of the data root. Its rows in the grid are returned by the
+		/// `KateApi::data_location` runtime API once the block is built.
+		DataLocated {
+			extrinsic_index: u32,
+			leaf_index: u32,
+		},
+	}
+
+	/// Error for the System pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The application key already exists.
+		AppKeyAlreadyExists,
+		/// The application key is an empty string.
+		AppKeyCannotBeEmpty,
+		/// The last application ID overflowed.
+		LastAppIdOverflowed,
+		/// The submitted data is empty.
+		DataCannotBeEmpty,
+		/// The last block length proposal Id overflowed.
+		LastBlockLenProposalIdOverflowed,
+		/// The proposed block dimensions are out of bounds.
+		BlockDimensionsOutOfBounds,
+		/// The proposed block dimensions are too small.
+		BlockDimensionsTooSmall,
+		/// The request to reduce block dimensions was made in a non-empty block
+		InvalidBlockWeightReduction,
+		/// Submit data call outside of block execution context.
+		BadContext,
+		/// App info was not found for the given App key
+		UnknownAppKey,
+		/// Submit block length proposal was made with values not power of 2
+		NotPowerOfTwo,
+		/// The caller is not the owner of the application key
+		NotAppKeyOwner,
+		/// No ownership transfer is pending for the application key
+		NoPendingAppKeyTransfer,
+		/// The caller is not the proposed owner of the application key
+		NotPendingAppKeyOwner,
+		/// The application ID shared by all other transactions cannot restrict its submitters
+		AppIdCannotBeRestricted,
+		/// The minimum block length bounds exceed the maximum ones, or the lower utilization
+		/// target is not below the upper one
+		InvalidBlockLengthBounds,
+		/// The submitter cannot pay the per byte price of the data
+		InsufficientBalanceForDataFee,
+		/// The declared decompressed length exceeds the maximum length of the data
+		DecompressedDataTooLong,
+		/// The compressed data is not a zstd frame declaring the decompressed length
+		InvalidCompressedData,
+		/// The data batch has no entries
+		EmptyDataBatch,
+		/// The application ID is not registered
+		UnknownAppId,
+		/// The submitter is not in the allowlist of the application
+		NotAllowedSubmitter,
+		/// The data exceeds the quota of the application
+		AppDataQuotaExceeded,
+		/// The reserved block space exceeds the share of the block that can be reserved
+		ReservedSpaceTooLarge,
+		/// The suggested retention period exceeds `MaxRetentionPeriod`
+		RetentionPeriodTooLong,
+		/// The maximum data length of an application exceeds `MaxAppDataLength`
+		MaxDataLengthTooLarge,
+		/// The retirement of the application key was already announced
+		AppKeyAlreadyRetiring,
+		/// The application key is not retired yet
+		AppKeyNotRetired,
+		/// The application is retired and rejects new submissions
+		RetiredAppId,
+		/// Block space can only be reserved in the next `MaxIntentLookahead` blocks
+		InvalidIntentBlock,
+		/// The reserved block space is empty or exceeds `MaxAppDataLength`
+		InvalidIntentLength,
+		/// The caller already reserved block space in this block
+		BlockSpaceIntentExists,
+		/// Not enough block space is left to reserve
+		BlockSpaceUnavailable,
+		/// The caller did not reserve block space in the current block
+		NoBlockSpaceIntent,
+		/// The data exceeds the block space reserved by the caller
+		BlockSpaceIntentExceeded,
+		/// The parent application has `MaxAppChildren` children already
+		TooManyChildApps,
+		/// A child application cannot have children
+		NestedChildApp,
+		/// The application key cannot be removed before its child applications
+		AppHasChildApps,
+		/// The application is already paused
+		AppAlreadyPaused,
+		/// The application is not paused
+		AppNotPaused,
+		/// The application is paused by its owner and rejects new submissions
+		PausedAppId,
+		/// There is no pending block length proposal
+		NoBlockLengthProposal,
+	}
+
+	#[pallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		#[allow(clippy::type_complexity)]
+		pub app_keys: Vec<(Vec<u8>, (T::AccountId, u32))>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			// Ensure app ids are unique.
+			let mut app_keys = self.app_keys.clone();
+			app_keys.sort_by(|a, b| a.1 .1.cmp(&b.1 .1));
+
+			// With a sorted array, we can just use the id of the last element :)
+			let last_key = app_keys.last();
+			let last_id = last_key.map(|x| x.1 .1).unwrap_or(0);
+			let next_app_id = AppId(last_id.saturating_add(1));
+			NextAppId::<T>::put::<AppId>(next_app_id);
+
+			// Insert app keys. It verifies the length limitation of each key.
+			for (key, (owner, id)) in app_keys {
+				let key = AppKeyFor::<T>::try_from(key)
+					.expect("DA Control Genesis contains invalid keys");
+				AppKeys::<T>::insert(key, AppKeyInfo::new(owner, AppId(id)));
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the application `id` followed by its child applications.
+	pub fn app_subtree(id: AppId) -> Vec<AppId> {
+		let mut subtree = vec![id];
+		subtree.extend(AppChildren::<T>::get(id));
+		subtree
+	}
+
+	/// Returns whether the owner of the application `id` paused its submissions.
+	pub fn is_paused(id: AppId) -> bool {
+		PausedApps::<T>::contains_key(id)
+	}
+
+	/// Returns whether the application `id` is retired and rejects new submissions.
+	pub fn is_retired(id: AppId) -> bool {
+		let now: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+		AppRetirements::<T>::get(id).map_or(false, |retires_at| now >= retires_at)
+	}
+
+	/// Returns whether `who` is allowed to submit data for the application `id`.
+	pub fn is_allowed_submitter(id: AppId, who: &T::AccountId) -> bool {
+		SubmitterAllowlists::<T>::get(id).map_or(true, |submitters| submitters.contains(who))
+	}
+
+	/// Returns whether `who` is an allowlisted submitter of `id`, which its sponsorship pool
+	/// pays for. Applications without an allowlist sponsor nobody.
+	pub fn is_sponsored_submitter(id: AppId, who: &T::AccountId) -> bool {
+		id != AppId(0)
+			&& SubmitterAllowlists::<T>::get(id).is_some_and(|submitters| submitters.contains(who))
+	}
+
+	/// The account of the sponsorship pool of the application `id`.
+	pub fn sponsorship_pool_account(id: AppId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(id)
+	}
+
+	/// Returns the data usage of the application `id` once `len` more bytes are submitted, or
+	/// `None` if they exceed its `quota`.
+	pub fn next_app_data_usage(id: AppId, quota: &AppDataQuota, len: u32) -> Option<AppDataUsage> {
+		let block: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+		let era = Self::current_era();
+		let mut usage = AppDataUsages::<T>::get(id);
+		if usage.block != block {
+			usage.block = block;
+			usage.block_bytes = 0;
+		}
+		if usage.era != era {
+			usage.era = era;
+			usage.era_bytes = 0;
+		}
+
+		usage.block_bytes = usage.block_bytes.checked_add(len)?;
+		usage.era_bytes = usage.era_bytes.checked_add(len.into())?;
+		let within_quota = quota.per_block.map_or(true, |max| usage.block_bytes <= max)
+			&& quota.per_era.map_or(true, |max| usage.era_bytes <= max);
+
+		within_quota.then_some(usage)
+	}
+
+	/// Returns whether `len` bytes of data are within the maximum data length of `id`.
+	pub fn is_within_max_data_length(id: AppId, len: usize) -> bool {
+		AppMaxDataLengths::<T>::get(id).map_or(true, |max| len <= max as usize)
+	}
+
+	/// Assigns the next sequence number to data submitted for `id`, if it numbers its
+	/// submissions.
+	pub fn note_app_sequence(id: AppId) {
+		let Some(mut app_sequence) = AppSequences::<T>::get(id).filter(|s| s.enabled) else {
+			return;
+		};
+
+		let sequence = app_sequence.next;
+		app_sequence.next = sequence.saturating_add(1);
+		AppSequences::<T>::insert(id, app_sequence);
+		Self::deposit_event(Event::DataSequenced {
+			app_id: id,
+			sequence,
+		});
+	}
+
+	/// Enacts the block length change scheduled at `block`, if any.
+	fn enact_block_length_change(block: u32) -> Weight {
+		let Some(change) = ScheduledBlockLengthChange::<T>::get().filter(|c| c.at <= block) else {
+			return T::DbWeight::get().reads(1);
+		};
+		ScheduledBlockLengthChange::<T>::kill();
+
+		let BlockLengthChange { rows, cols, .. } = change;
+		if let Ok(block_length) =
+			BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+		{
+			DynamicBlockLength::<T>::put(block_length);
+			Self::deposit_event(Event::BlockLengthChanged { rows, cols });
+		}
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	/// Counts a data submission of `id` in the submission receipts of the current block.
+	pub fn note_submission_receipt(id: AppId) {
+		let block: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+		SubmissionReceipts::<T>::mutate(block, id, |count| {
+			if *count == 0 {
+				SubmissionReceiptApps::<T>::mutate(block, |apps| *apps = apps.saturating_add(1));
+			}
+			*count = count.saturating_add(1);
+		});
+	}
+
+	/// Returns the number of data submissions of each application in `block`, if its receipts
+	/// are not pruned yet.
+	pub fn submission_receipts(block: u32) -> Vec<(AppId, u32)> {
+		SubmissionReceipts::<T>::iter_prefix(block).collect()
+	}
+
+	/// Prunes the submission receipts of the block leaving the receipt period at `block`.
+	fn prune_submission_receipts(block: u32) -> Weight {
+		let Some(pruned) = block.checked_sub(T::SubmissionReceiptPeriod::get()) else {
+			return Weight::zero();
+		};
+		let apps = SubmissionReceiptApps::<T>::take(pruned);
+		if apps > 0 {
+			let _ = SubmissionReceipts::<T>::clear_prefix(pruned, apps, None);
+		}
+		T::DbWeight::get().reads_writes(1, u64::from(apps).saturating_add(1))
+	}
+
+	/// Returns the current era of the per era data quotas and usage statistics.
+	pub fn current_era() -> u32 {
+		let block: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+		block / T::AppQuotaEraLength::get().max(1)
+	}
+
+	/// Adds `bytes` of data submitted for `id` to its usage statistics of the current era,
+	/// dropping its oldest era once the history is full.
+	pub fn note_app_usage(id: AppId, bytes: u32) {
+		let era = Self::current_era();
+		AppUsageHistory::<T>::mutate(id, |history| {
+			if history.last().map_or(true, |stats| stats.era != era) {
+				let stats = AppUsageStats {
+					era,
+					..Default::default()
+				};
+				if history.try_push(stats).is_err() && !history.is_empty() {
+					history.remove(0);
+					let _ = history.try_push(stats);
+				}
+			}
+			if let Some(stats) = history.last_mut() {
+				stats.bytes = stats.bytes.saturating_add(bytes.into());
+				stats.submissions = stats.submissions.saturating_add(1);
+			}
+		});
+	}
+
+	/// Returns the number of leaves `call` adds to the submitted data sub trie of the data root.
+	/// Like the header extension, it skips the submissions of empty data.
+	pub fn data_leaves(call: &Call<T>) -> u32 {
+		match call {
+			Call::submit_data { data }
+			| Call::submit_compressed_data { data, .. }
+			| Call::submit_sponsored_data { data, .. }
+			| Call::submit_data_with_retention { data, .. }
+			| Call::submit_reserved_data { data } => u32::from(!data.is_empty()),
+			Call::submit_data_batch { batch } if batch.iter().all(|(_, data)| !data.is_empty()) => {
+				batch.len() as u32
+			},
+			_ => 0,
+		}
+	}
+
+	/// Emits the location of the last `leaves` submitted data, added by the current extrinsic.
+	fn deposit_data_locations(leaves: u32) {
+		let extrinsic_index = <frame_system::Pallet<T>>::extrinsic_index().unwrap_or_default();
+		let first = SubmittedDataLeaves::<T>::get().saturating_sub(leaves);
+		for leaf_index in first..first.saturating_add(leaves) {
+			Self::deposit_event(Event::DataLocated {
+				extrinsic_index,
+				leaf_index,
+			});
+		}
+	}
+
+	/// Notes the retention period suggested for data submitted in the current block, `None` if
+	/// the data has no retention hint.
+	fn note_retention_period(period: Option<u32>) {
+		let period = period.unwrap_or(u32::MAX);
+		BlockRetentionPeriod::<T>::mutate(|longest| {
+			*longest = Some(longest.map_or(period, |longest| longest.max(period)));
+		});
+	}
+
+	/// Logs the retention hint of the data submitted in block `now`, if all of it has one.
+	fn log_retention_hint(now: u32) {
+		let Some(period) = BlockRetentionPeriod::<T>::take() else {
+			return;
+		};
+		if period != u32::MAX {
+			let hint = RetentionHint {
+				expires_at: now.saturating_add(period),
+			};
+			<frame_system::Pallet<T>>::deposit_log(hint.into());
+		}
+	}
+
+	/// Maximum number of bytes of a block that can be reserved for the applications.
+	pub fn max_reserved_space() -> u32 {
+		let block_length = <T as frame_system::Config>::BlockLength::get();
+		T::MaxReservedBlockSpace::get().mul_floor(*block_length.max.get(DispatchClass::Normal))
+	}
+
+	/// Removes the block space reserved for the application `id`, returning the space still
+	/// reserved for the other applications.
+	fn release_reserved_space(id: AppId) -> u32 {
+		let reserved = AppReservedSpaces::<T>::take(id).unwrap_or_default();
+		ReservedSpaceUsages::<T>::remove(id);
+		TotalReservedSpace::<T>::mutate(|total| {
+			*total = total.saturating_sub(reserved);
+			*total
+		})
+	}
+
+	/// Releases the block space of the intents of `block` which were not filled.
+	fn release_block_space_intents(block: u32) {
+		let intents = IntentSpaces::<T>::take(block);
+		if intents.count > 0 {
+			let _ = BlockSpaceIntentOf::<T>::clear_prefix(block, intents.count, None);
+		}
+	}
+
+	/// Returns the reserved space usage of the application `id` and of all the applications
+	/// once an extrinsic of `len` bytes of `id` brings the length of the block to `block_len`.
+	/// The extrinsic first uses the space reserved for `id`, and `None` is returned if the rest
+	/// of it eats into the unused space reserved for the other applications or by the block
+	/// space intents.
+	pub fn next_reserved_space_usage(
+		id: AppId,
+		len: u32,
+		block_len: u32,
+	) -> Option<(ReservedSpaceUsage, ReservedSpaceUsage)> {
+		let block: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+		let in_block = |usage: ReservedSpaceUsage| {
+			if usage.block == block {
+				usage
+			} else {
+				ReservedSpaceUsage { block, bytes: 0 }
+			}
+		};
+		let mut app_usage = in_block(ReservedSpaceUsages::<T>::get(id));
+		let mut total_usage = in_block(TotalReservedSpaceUsage::<T>::get());
+
+		let reserved = AppReservedSpaces::<T>::get(id).unwrap_or_default();
+		let from_reserved = reserved.saturating_sub(app_usage.bytes).min(len);
+		app_usage.bytes = app_usage.bytes.saturating_add(from_reserved);
+		total_usage.bytes = total_usage.bytes.saturating_add(from_reserved);
+
+		let unused_reserved = TotalReservedSpace::<T>::get()
+			.saturating_sub(total_usage.bytes)
+			.saturating_add(IntentSpaces::<T>::get(block).bytes);
+		let block_length = <T as frame_system::Config>::BlockLength::get();
+		let max_len = *block_length.max.get(DispatchClass::Normal);
+		(block_len.checked_add(unused_reserved)? <= max_len).then_some((app_usage, total_usage))
+	}
+
+	/// Returns whether `data` is a zstd frame declaring `decompressed_len` as its content size.
+	pub fn is_valid_compression(data: &[u8], decompressed_len: u32) -> bool {
+		decompressed_len > 0
+			&& compression::zstd_frame_content_size(data) == Some(u64::from(decompressed_len))
+	}
+
+	/// Returns the number of bytes compressed `data` declaring `decompressed_len` is charged
+	/// and bounded on. The frame header is written by the submitter and the data is never
+	/// decompressed, so it is never less than the compressed length.
+	pub fn compressed_data_len(data: &[u8], decompressed_len: u32) -> usize {
+		data.len().max(decompressed_len as usize)
+	}
+
+	/// Returns the price per byte of submitted data.
+	pub fn data_byte_price() -> BalanceOf<T> {
+		DataBytePrice::<T>::get().max(T::MinDataBytePrice::get())
+	}
+
+	/// Returns the number of bytes a blob of `len` bytes is charged for.
+	pub fn charged_data_len(len: usize) -> usize {
+		len.max(T::MinChargedDataLength::get() as usize)
+	}
+
+	/// Returns the dimensions of the grid holding `blobs`, the application and length of each
+	/// blob, in the current block length, and the fee charged for their data.
+	pub fn grid_estimate(blobs: &[(AppId, u32)]) -> (GridEstimate, BalanceOf<T>) {
+		let estimate = grid::grid_estimate(blobs, &DynamicBlockLength::<T>::get());
+		let data_len = blobs.iter().fold(0usize, |total, &(_, len)| {
+			total.saturating_add(Self::charged_data_len(len as usize))
+		});
+		let data_fee = Self::data_byte_price().saturating_mul(data_len.saturated_into());
+		(estimate, data_fee)
+	}
+
+	/// Returns the account paying the data fee of `call` signed by `who`, and the number of
+	/// bytes it is charged for. The pool of a sponsored application only pays for its
+	/// allowlisted submitters.
+	pub fn data_fee_payer(who: &T::AccountId, call: &Call<T>) -> Option<(T::AccountId, usize)> {
+		match call {
+			Call::submit_data { data } | Call::submit_data_with_retention { data, .. } => {
+				Some((who.clone(), Self::charged_data_len(data.len())))
+			},
+			Call::submit_compressed_data {
+				data,
+				decompressed_len,
+			} => Some((
+				who.clone(),
+				Self::charged_data_len(Self::compressed_data_len(data, *decompressed_len)),
+			)),
+			Call::submit_sponsored_data { app_id, data } => {
+				let payer = if Self::is_sponsored_submitter(*app_id, who) {
+					Self::sponsorship_pool_account(*app_id)
+				} else {
+					who.clone()
+				};
+				Some((payer, Self::charged_data_len(data.len())))
+			},
+			Call::submit_data_batch { batch } => {
+				let len = batch.iter().fold(0usize, |len, (_, data)| {
+					len.saturating_add(Self::charged_data_len(data.len()))
+				});
+				Some((who.clone(), len))
+			},
+			_ => None,
+		}
+	}
+
+	/// Charges the data fee of `call` signed by `who`, or only checks that it can be paid if
+	/// `charge` is not set. The header extension commits the submitted data even if the
+	/// dispatch of `call` fails, so `CheckAppId` charges its fee before the dispatch.
+	pub fn charge_submitted_data_fee(
+		who: &T::AccountId,
+		call: &Call<T>,
+		charge: bool,
+	) -> DispatchResult {
+		let Some((payer, len)) = Self::data_fee_payer(who, call) else {
+			return Ok(());
+		};
+
+		if charge {
+			return Self::charge_data_fee(&payer, len);
+		}
+
+		let fee = Self::data_byte_price().saturating_mul(len.saturated_into());
+		let can_pay = fee.is_zero()
+			|| T::Currency::free_balance(&payer)
+				.checked_sub(&fee)
+				.filter(|new_balance| *new_balance >= T::Currency::minimum_balance())
+				.is_some_and(|new_balance| {
+					T::Currency::ensure_can_withdraw(&payer, fee, WithdrawReasons::FEE, new_balance)
+						.is_ok()
+				});
+		ensure!(can_pay, Error::<T>::InsufficientBalanceForDataFee);
+		Ok(())
+	}
+
+	/// Burns the price of `len` bytes of submitted data from `who`.
+	fn charge_data_fee(who: &T::AccountId, len: usize) -> DispatchResult {
+		let fee = Self::data_byte_price().saturating_mul(len.saturated_into());
+		if fee.is_zero() {
+			return Ok(());
+		}
+
+		// Dropping the imbalance burns the fee.
+		T::Currency::withdraw(
+			who,
+			fee,
+			WithdrawReasons::FEE,
+			ExistenceRequirement::KeepAlive,
+		)
+		.map_err(|_| Error::<T>::InsufficientBalanceForDataFee)?;
+		Ok(())
+	}
+
+	/// Moves the price per byte towards the congestion of the grid: it rises by up to
+	/// `1 / DataBytePriceChangeDenominator` after a full block and decays by as much after an
+	/// empty one.
+	fn update_data_byte_price(utilization: Perbill) {
+		let target = T::TargetGridFill::get();
+		let denominator: BalanceOf<T> = T::DataBytePriceChangeDenominator::get().max(1).into();
+		let price = Self::data_byte_price();
+
+		let price = if utilization > target {
+			let excess = Perbill::from_rational(
+				utilization.deconstruct() - target.deconstruct(),
+				target.deconstruct().max(1),
+			);
+			price.saturating_add((excess * price / denominator).max(One::one()))
+		} else {
+			let shortfall = Perbill::from_rational(
+				target.deconstruct() - utilization.deconstruct(),
+				target.deconstruct().max(1),
+			);
+			price.saturating_sub(shortfall * price / denominator)
+		};
+		DataBytePrice::<T>::put(price.max(T::MinDataBytePrice::get()));
+	}
+
+	/// Returns the share of the grid filled by the extrinsics of the current block.
+	fn block_grid_utilization() -> Perbill {
+		let block_length = DynamicBlockLength::<T>::get();
+		let max_scalars = block_length.rows.0.saturating_mul(block_length.cols.0);
+		let scalars = AllExtrinsicsLen::<T>::get()
+			.and_then(|len| len.total_num_scalars())
+			.unwrap_or_default();
+		Perbill::from_rational(scalars, max_scalars.max(1))
+	}
+
+	/// Folds the `utilization` of the grid by the current block into the average utilization.
+	fn note_grid_utilization(utilization: Perbill) {
+		GridUtilization::<T>::mutate(|average| {
+			let previous = u64::from(average.deconstruct());
+			let smoothing = u64::from(GRID_UTILIZATION_SMOOTHING);
+			let parts =
+				(previous * (smoothing - 1) + u64::from(utilization.deconstruct())) / smoothing;
+			*average = Perbill::from_parts(parts.saturated_into());
+		});
+	}
+
+	/// Doubles or halves the block length within `bounds` when the average utilization is
+	/// outside of their targets. Rows are grown before columns and columns shrunk before rows.
+	fn govern_block_length(bounds: BlockLengthBounds) {
+		let utilization = GridUtilization::<T>::get();
+		let current = DynamicBlockLength::<T>::get();
+		let (mut rows, mut cols) = (current.rows, current.cols);
+
+		if utilization > bounds.upper_target {
+			if rows.0 < bounds.max_rows.0 {
+				rows = BlockLengthRows(rows.0.saturating_mul(2));
+			} else if cols.0 < bounds.max_cols.0 {
+				cols = BlockLengthColumns(cols.0.saturating_mul(2));
+			}
+		} else if utilization < bounds.lower_target {
+			if cols.0 > bounds.min_cols.0 {
+				cols = BlockLengthColumns(cols.0 / 2);
+			} else if rows.0 > bounds.min_rows.0 {
+				rows = BlockLengthRows(rows.0 / 2);
+			}
+		}
+		if rows == current.rows && cols == current.cols {
+			return;
+		}
+
+		let Ok(block_length) =
+			BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+		else {
+			log::warn!(target: LOG_TARGET, "Cannot adjust the block length to {rows:?}x{cols:?}");
+			return;
+		};
+		DynamicBlockLength::<T>::put(block_length);
+
+		// The same data fills half of a grid twice as large and vice versa.
+		let parts = if rows.0 > current.rows.0 || cols.0 > current.cols.0 {
+			utilization.deconstruct() / 2
+		} else {
+			utilization.deconstruct().saturating_mul(2)
+		};
+		let utilization = Perbill::from_parts(parts);
+		GridUtilization::<T>::put(utilization);
+
+		Self::deposit_event(Event::BlockLengthAdjusted {
+			rows,
+			cols,
+			utilization,
+		});
+	}
+
+	/// Returns the latest available application ID and increases it.
+	pub fn next_application_id() -> Result<AppId, Error<T>> {
+		NextAppId::<T>::try_mutate(|id| {
+			let new_id = AppId(id.0.checked_add(1).ok_or(Error::<T>::LastAppIdOverflowed)?);
+			Ok(replace(id, new_id))
+		})
+	}
+
+	/// Creates the application key `key` owned by `owner` with the next application ID,
+	/// reserving `AppKeyDeposit` from the owner.
+	fn insert_application_key(
+		owner: &T::AccountId,
+		key: &AppKeyFor<T>,
+	) -> Result<AppId, DispatchError> {
+		ensure!(!key.is_empty(), Error::<T>::AppKeyCannotBeEmpty);
+		let deposit = T::AppKeyDeposit::get();
+		let id = AppKeys::<T>::try_mutate(key, |key_info| -> Result<AppId, Error<T>> {
+			ensure!(key_info.is_none(), Error::<T>::AppKeyAlreadyExists);
+
+			let id = Self::next_application_id()?;
+			*key_info = Some(AppKeyInfo::new(owner.clone(), id).with_deposit(deposit));
+
+			Ok(id)
+		})?;
+		T::Currency::reserve(owner, deposit)?;
+
+		Ok(id)
+	}
+
+	/// Check if the block weight is acceptable to execute the extrinsic
+	/// We check the current normal ratio weight, if it's too high, it means we won't reduce the block size
+	pub fn is_block_weight_acceptable() -> bool {
+		let current_weight = <frame_system::Pallet<T>>::block_weight();
+		let current_normal_weight: &Weight = current_weight.get(DispatchClass::Normal);
+		// Offsetting the base_weight multiplication done for all txs
+		let base_weight = ExtrinsicBaseWeight::get().saturating_mul(100);
+		let acceptable_limit: Weight = T::WeightInfo::approve_block_length_proposal()
+			.saturating_mul(5)
+			.saturating_add(base_weight);
+		current_normal_weight.all_lte(acceptable_limit)
+	}
+}
+
+pub mod weight_helper {
+
+	use super::*;
+
+	/// Weight for `dataAvailability::submit_data`.
+	pub fn submit_data<T: Config>(data_len: usize) -> Weight {
 		/* Compute regular substrate weight. */
 		let data_len: u32 = data_len.saturated_into();
 		let data_prefix_len: u32 =
@@ -455,17 +2374,37 @@ pub mod weight_helper {
 		scalar_based_weight.max(regular_weight)
 	}
 
+	/// Weight for `dataAvailability::submit_data_batch`.
+	pub fn submit_data_batch<T: Config>(batch: &DataBatchFor<T>) -> Weight {
+		batch.iter().fold(Weight::zero(), |acc, (_, data)| {
+			// The registration and allowlist of the entry's application.
+			acc.saturating_add(submit_data::<T>(data.len()))
+				.saturating_add(T::DbWeight::get().reads(2))
+		})
+	}
+
 	fn compact_len(value: &u32) -> Option<u32> {
 		let len = Compact::<u32>::compact_len(value);
 		u32::try_from(len).ok()
 	}
 }
 
-impl<Acc> AppKeyInfo<Acc>
+impl<Acc, Balance> AppKeyInfo<Acc, Balance>
 where
 	Acc: PartialEq,
+	Balance: PartialEq + Default,
 {
 	pub fn new(owner: Acc, id: AppId) -> Self {
-		Self { owner, id }
+		Self {
+			owner,
+			id,
+			metadata: None,
+			deposit: Balance::default(),
+		}
+	}
+
+	pub fn with_deposit(mut self, deposit: Balance) -> Self {
+		self.deposit = deposit;
+		self
 	}
 }
@@ -0,0 +1,153 @@
+use crate::{AppKeyFor, AppKeyInfo, AppKeyMetadata, AppKeys, Config, Pallet};
+use avail_core::AppId;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency},
+};
+use sp_runtime::traits::Zero;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Adds the optional metadata to the application keys.
+pub mod v1 {
+	use super::*;
+
+	mod v0 {
+		use super::*;
+
+		#[derive(Decode)]
+		pub struct AppKeyInfo<Acc> {
+			pub owner: Acc,
+			pub id: AppId,
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	pub(super) struct AppKeyInfo<Acc> {
+		pub owner: Acc,
+		pub id: AppId,
+		pub metadata: Option<AppKeyMetadata>,
+	}
+
+	/// The application keys in the v1 layout.
+	#[frame_support::storage_alias]
+	pub(super) type AppKeys<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		AppKeyFor<T>,
+		AppKeyInfo<<T as frame_system::Config>::AccountId>,
+	>;
+
+	/// Migrates the application keys to the layout with metadata, leaving the metadata unset.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				log::info!(target: crate::LOG_TARGET, "DA control storage is already migrated to v1");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut keys = 0u64;
+			AppKeys::<T>::translate::<v0::AppKeyInfo<T::AccountId>, _>(|_, old| {
+				keys += 1;
+				Some(AppKeyInfo {
+					owner: old.owner,
+					id: old.id,
+					metadata: None,
+				})
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: crate::LOG_TARGET, "Migrated {keys} application keys to v1");
+
+			T::DbWeight::get().reads_writes(keys + 1, keys + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((AppKeys::<T>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let keys = u64::decode(&mut state.as_slice())
+				.map_err(|_| sp_runtime::TryRuntimeError::Other("Cannot decode key count"))?;
+			ensure!(
+				AppKeys::<T>::iter_values().count() as u64 == keys,
+				"Every application key must be migrated"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"Storage version must be 1"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Reserves the deposit of the application keys from their owners.
+pub mod v2 {
+	use super::*;
+
+	/// Migrates the application keys to the layout with a deposit. The deposit is reserved from
+	/// the owner of each key, and left unset for the owners who cannot afford it.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				log::info!(target: crate::LOG_TARGET, "DA control storage is already migrated to v2");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let deposit = T::AppKeyDeposit::get();
+			let (mut keys, mut unreserved) = (0u64, 0u64);
+			AppKeys::<T>::translate::<v1::AppKeyInfo<T::AccountId>, _>(|_, old| {
+				keys += 1;
+				let deposit =
+					if old.id != AppId(0) && T::Currency::reserve(&old.owner, deposit).is_ok() {
+						deposit
+					} else {
+						unreserved += 1;
+						Zero::zero()
+					};
+				Some(AppKeyInfo {
+					owner: old.owner,
+					id: old.id,
+					metadata: old.metadata,
+					deposit,
+				})
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(
+				target: crate::LOG_TARGET,
+				"Migrated {keys} application keys to v2, {unreserved} without a deposit"
+			);
+
+			// Each reserved deposit reads and writes the account of the owner.
+			T::DbWeight::get().reads_writes(2 * keys + 1, 2 * keys + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((AppKeys::<T>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let keys = u64::decode(&mut state.as_slice())
+				.map_err(|_| sp_runtime::TryRuntimeError::Other("Cannot decode key count"))?;
+			ensure!(
+				AppKeys::<T>::iter_values().count() as u64 == keys,
+				"Every application key must be migrated"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"Storage version must be 2"
+			);
+			Ok(())
+		}
+	}
+}
@@ -3,6 +3,7 @@
 use avail_core::currency::{Balance, AVAIL};
 use frame_support::{
 	derive_impl, parameter_types,
+	traits::ConstU128,
 	weights::{ConstantMultiplier, IdentityFee},
 };
 use frame_system::{
@@ -35,6 +36,7 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: BlockNumber = 250;
 	pub static ExistentialDeposit: u64 = 1;
+	pub static MinChargedDataLength: u32 = 0;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
@@ -55,7 +57,7 @@ parameter_types! {
 #[derive_impl(pallet_transaction_payment::config_preludes::TestDefaultConfig as pallet_transaction_payment::DefaultConfig)]
 impl pallet_transaction_payment::Config for Test {
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
-	type OnChargeTransaction = FungibleAdapter<Balances, ()>;
+	type OnChargeTransaction = da_control::SponsoredFeeAdapter<Test, FungibleAdapter<Balances, ()>>;
 	type WeightToFee = IdentityFee<Balance>;
 }
 
@@ -78,7 +80,13 @@ impl pallet_utility::Config for Test {
 }
 
 #[derive_impl(da_control::config_preludes::TestDefaultConfig)]
-impl da_control::Config for Test {}
+impl da_control::Config for Test {
+	type Currency = Balances;
+	type AppOriginFilter = ();
+	type MinDataBytePrice = ConstU128<1>;
+	type AppKeyDeposit = ConstU128<{ 10 * AVAIL }>;
+	type MinChargedDataLength = MinChargedDataLength;
+}
 
 /// Create new externalities for `System` module tests.
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -0,0 +1,28 @@
+//! Origin of the data submissions wrapped in calls dispatching them on behalf of an account.
+//!
+//! Proxy and multisig calls dispatch the call they wrap with the origin of another account, so
+//! rollup teams can submit data and manage their application keys from operational keys. The
+//! data submissions they wrap are validated against that account, which must own or be allowed
+//! to submit for their application. Multisig calls only dispatch the call they wrap on its final
+//! approval, so the previous approvals are not data submissions.
+
+/// Unwraps the calls dispatching another call on behalf of an account.
+pub trait AppOriginFilter<AccountId, Call> {
+	/// Returns the call wrapped by `call`, if it dispatches it on behalf of an account when `who`
+	/// submits it.
+	fn inner_call<'a>(who: &AccountId, call: &'a Call) -> Option<&'a Call>;
+
+	/// Returns the account the call wrapped by `call` is dispatched for, if `who` is allowed to
+	/// dispatch it on its behalf.
+	fn inner_origin(who: &AccountId, call: &Call) -> Option<AccountId>;
+}
+
+impl<AccountId, Call> AppOriginFilter<AccountId, Call> for () {
+	fn inner_call<'a>(_: &AccountId, _: &'a Call) -> Option<&'a Call> {
+		None
+	}
+
+	fn inner_origin(_: &AccountId, _: &Call) -> Option<AccountId> {
+		None
+	}
+}
@@ -0,0 +1,60 @@
+//! Grid space of the data submissions queued in the transaction pool.
+//!
+//! The pool validation of a data submission notes the grid scalars it takes in the memory
+//! temporary storage of the node. The pallet forgets them at the start of every block, and the
+//! pool notes again the submissions it keeps as it revalidates them. A submission which does
+//! not fit the grid alongside the queued ones is rejected, instead of lingering in the pool
+//! until it fails at block building.
+use avail_base::MemoryTemporaryStorage;
+use sp_core::H256;
+use sp_std::collections::btree_map::BTreeMap;
+
+/// Key of the memory temporary storage holding the grid scalars of the queued submissions.
+pub const QUEUED_SUBMISSIONS_ID: &[u8] = b"da:queued_submissions";
+
+/// Notes the submission `key` taking `scalars` grid scalars as queued, and returns whether it
+/// fits a grid of `max_scalars` alongside the other queued submissions. A submission which
+/// does not fit is not noted.
+pub fn queue_submission(key: H256, scalars: u32, max_scalars: u32) -> bool {
+	queue_submission_under(QUEUED_SUBMISSIONS_ID, key, scalars, max_scalars)
+}
+
+/// Forgets the queued submissions.
+pub fn clear_queued_submissions() {
+	MemoryTemporaryStorage::remove(QUEUED_SUBMISSIONS_ID);
+}
+
+fn queue_submission_under(id: &[u8], key: H256, scalars: u32, max_scalars: u32) -> bool {
+	let mut queued = MemoryTemporaryStorage::get::<BTreeMap<H256, u32>>(id).unwrap_or_default();
+	// A revalidated submission replaces its previous note.
+	queued.remove(&key);
+	let total = queued
+		.values()
+		.fold(scalars, |total, scalars| total.saturating_add(*scalars));
+	if total >= max_scalars {
+		return false;
+	}
+
+	queued.insert(key, scalars);
+	MemoryTemporaryStorage::insert(id.to_vec(), queued);
+	true
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn queued_submissions_share_the_grid() {
+		// The memory temporary storage is shared by the tests.
+		let id = b"da:queued_submissions_test";
+		let key = |i: u64| H256::from_low_u64_be(i);
+
+		assert!(queue_submission_under(id, key(1), 60, 100));
+		// Revalidating a queued submission does not count it twice.
+		assert!(queue_submission_under(id, key(1), 60, 100));
+		assert!(!queue_submission_under(id, key(2), 40, 100));
+		assert!(queue_submission_under(id, key(2), 39, 100));
+		MemoryTemporaryStorage::remove(id);
+	}
+}
@@ -0,0 +1,53 @@
+//! Retention hints of the submitted data.
+//!
+//! Submitters can suggest how many blocks their data should be kept available. The hints of a
+//! block are aggregated into a digest item of its header, so pruning nodes and light clients
+//! agree on when the cells of the block can be dropped. A block holding any data submitted
+//! without a hint has no retention hint.
+use codec::{Decode, Encode};
+use sp_runtime::{Digest, DigestItem};
+
+/// Prefix of the `DigestItem::Other` holding the retention hint of a block.
+pub const RETENTION_HINT_ID: [u8; 4] = *b"rtnh";
+
+/// Retention hint of the data submitted in a block.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq)]
+pub struct RetentionHint {
+	/// Last block the submitted data should be kept available until.
+	pub expires_at: u32,
+}
+
+impl RetentionHint {
+	/// Returns the retention hint logged in `digest`, if any.
+	pub fn from_digest(digest: &Digest) -> Option<Self> {
+		digest.logs().iter().find_map(|item| {
+			let DigestItem::Other(data) = item else {
+				return None;
+			};
+			let (id, hint) = <([u8; 4], Self)>::decode(&mut data.as_slice()).ok()?;
+			(id == RETENTION_HINT_ID).then_some(hint)
+		})
+	}
+}
+
+impl From<RetentionHint> for DigestItem {
+	fn from(hint: RetentionHint) -> Self {
+		DigestItem::Other((RETENTION_HINT_ID, hint).encode())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn retention_hint_roundtrips_through_digest() {
+		let hint = RetentionHint { expires_at: 42 };
+		let mut digest = Digest::default();
+		assert_eq!(RetentionHint::from_digest(&digest), None);
+
+		digest.push(DigestItem::Other(b"other".to_vec()));
+		digest.push(hint.into());
+		assert_eq!(RetentionHint::from_digest(&digest), Some(hint));
+	}
+}
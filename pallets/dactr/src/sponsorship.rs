@@ -0,0 +1,90 @@
+//! Payment of the transaction fees of sponsored data submissions.
+use crate::{Call, Config, Pallet};
+
+use frame_support::{ensure, traits::IsSubType};
+use pallet_transaction_payment::{Config as PaymentConfig, OnChargeTransaction};
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use sp_std::marker::PhantomData;
+
+/// Charges the transaction fees of `submit_sponsored_data` to the sponsorship pool of its
+/// application, and those of any other call to its signer through `OCT`. The tip of a sponsored
+/// submission is chosen by its signer, so the signer pays it.
+pub struct SponsoredFeeAdapter<T, OCT>(PhantomData<(T, OCT)>);
+
+impl<T, OCT> OnChargeTransaction<T> for SponsoredFeeAdapter<T, OCT>
+where
+	T: PaymentConfig + Config,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	OCT: OnChargeTransaction<T>,
+{
+	type Balance = OCT::Balance;
+	/// The sponsorship pool that paid the fee and its liquidity info of `OCT`, if any, and the
+	/// liquidity info of `OCT` of the signer.
+	type LiquidityInfo = (
+		Option<(T::AccountId, OCT::LiquidityInfo)>,
+		OCT::LiquidityInfo,
+	);
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let pool = match call.is_sub_type() {
+			Some(Call::<T>::submit_sponsored_data { app_id, .. }) => {
+				ensure!(
+					<Pallet<T>>::is_sponsored_submitter(*app_id, who),
+					InvalidTransaction::Payment
+				);
+				Some(<Pallet<T>>::sponsorship_pool_account(*app_id))
+			},
+			_ => None,
+		};
+
+		let Some(pool) = pool else {
+			let liquidity = OCT::withdraw_fee(who, call, dispatch_info, fee, tip)?;
+			return Ok((None, liquidity));
+		};
+
+		// `fee` includes `tip`, which the signer pays on its own.
+		let tip_liquidity = OCT::withdraw_fee(who, call, dispatch_info, tip, tip)?;
+		let pool_fee = fee.saturating_sub(tip);
+		let pool_liquidity = OCT::withdraw_fee(&pool, call, dispatch_info, pool_fee, Zero::zero())?;
+		Ok((Some((pool, pool_liquidity)), tip_liquidity))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		(pool, liquidity): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let Some((pool, pool_liquidity)) = pool else {
+			return OCT::correct_and_deposit_fee(
+				who,
+				dispatch_info,
+				post_info,
+				corrected_fee,
+				tip,
+				liquidity,
+			);
+		};
+
+		OCT::correct_and_deposit_fee(
+			&pool,
+			dispatch_info,
+			post_info,
+			corrected_fee.saturating_sub(tip),
+			Zero::zero(),
+			pool_liquidity,
+		)?;
+		OCT::correct_and_deposit_fee(who, dispatch_info, post_info, tip, tip, liquidity)
+	}
+}
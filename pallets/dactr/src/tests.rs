@@ -1,19 +1,26 @@
 use avail_core::{BlockLengthColumns, BlockLengthRows, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO};
-use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use frame_support::{
+	assert_noop, assert_ok,
+	error::BadOrigin,
+	traits::{Currency, Get},
+};
 use frame_system::{limits::BlockLength, RawOrigin};
 use sp_core::H256;
 
 use crate::config_preludes::{
-	MaxAppDataLength, MaxBlockCols, MaxBlockRows, MinBlockCols, MinBlockRows,
+	BlockLengthChangeDelay, MaxAppDataLength, MaxBlockCols, MaxBlockRows, MinBlockCols,
+	MinBlockRows,
 };
 use crate::{
-	mock::{new_test_ext, DataAvailability, RuntimeEvent, RuntimeOrigin, System, Test},
-	AppDataFor, AppKeyFor, AppKeyInfoFor, Event,
+	mock::{new_test_ext, Balances, DataAvailability, RuntimeEvent, RuntimeOrigin, System, Test},
+	AppDataFor, AppDataQuota, AppDataQuotas, AppKeyFor, AppKeyInfoFor, AppKeyMetadata, Event,
+	PendingAppKeyTransfers, SubmittedDataLeaves, SubmitterAllowlists,
 };
 
 type Error = crate::Error<Test>;
 
 const ALICE: u64 = 1;
+const BOB: u64 = 2;
 
 mod create_application_key {
 	use super::*;
@@ -30,13 +37,12 @@ mod create_application_key {
 				alice,
 				new_key.clone()
 			));
+			let deposit = <Test as crate::Config>::AppKeyDeposit::get();
 			assert_eq!(
 				DataAvailability::application_key(&new_key),
-				Some(AppKeyInfoFor::<Test> {
-					id: new_id,
-					owner: ALICE
-				})
+				Some(AppKeyInfoFor::<Test>::new(ALICE, new_id).with_deposit(deposit))
 			);
+			assert_eq!(Balances::reserved_balance(ALICE), deposit);
 
 			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyCreated {
 				key: new_key,
@@ -73,6 +79,17 @@ mod create_application_key {
 			assert_noop!(err, Error::AppKeyAlreadyExists);
 		})
 	}
+
+	#[test]
+	fn deposit_must_be_affordable() {
+		new_test_ext().execute_with(|| {
+			let origin: RuntimeOrigin = RawOrigin::Signed(4).into();
+			let new_key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+
+			let err = DataAvailability::create_application_key(origin, new_key);
+			assert_noop!(err, pallet_balances::Error::<Test>::InsufficientBalance);
+		})
+	}
 }
 
 mod submit_data {
@@ -85,17 +102,37 @@ mod submit_data {
 			let max_app_key_length: usize = MaxAppDataLength::get().try_into().unwrap();
 			let data = AppDataFor::<Test>::try_from(vec![b'X'; max_app_key_length]).unwrap();
 			let data_hash = H256(sp_io::hashing::blake2_256(&data));
+			let blob_hash = H256(sp_io::hashing::keccak_256(&data));
 
 			assert_ok!(DataAvailability::submit_data(alice, data));
 
 			let event = RuntimeEvent::DataAvailability(Event::DataSubmitted {
 				who: ALICE,
 				data_hash,
+				blob_hash,
 			});
 			System::assert_last_event(event);
 		})
 	}
 
+	#[test]
+	fn data_is_located() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let data = AppDataFor::<Test>::try_from(vec![b'X'; 32]).unwrap();
+			System::set_extrinsic_index(1);
+			SubmittedDataLeaves::<Test>::put(1);
+
+			assert_ok!(DataAvailability::submit_data(alice, data));
+
+			let event = RuntimeEvent::DataAvailability(Event::DataLocated {
+				extrinsic_index: 1,
+				leaf_index: 0,
+			});
+			System::assert_has_event(event);
+		})
+	}
+
 	#[test]
 	fn data_cannot_be_empty() {
 		new_test_ext().execute_with(|| {
@@ -118,8 +155,210 @@ mod submit_data {
 	}
 }
 
+mod submit_compressed_data {
+	use super::*;
+	use crate::{mock::Balances, DataBytePrice};
+	use frame_support::traits::Currency;
+
+	/// Single segment zstd frame holding `hello` in a raw block.
+	fn hello() -> AppDataFor<Test> {
+		let frame = vec![
+			0x28, 0xB5, 0x2F, 0xFD, 0x20, 0x05, 0x29, 0x00, 0x00, b'h', b'e', b'l', b'l', b'o',
+		];
+		AppDataFor::<Test>::try_from(frame).unwrap()
+	}
+
+	#[test]
+	fn submit_compressed_data() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let data = hello();
+			let data_hash = H256(sp_io::hashing::blake2_256(&data));
+			DataBytePrice::<Test>::put(1_000);
+			let balance = Balances::free_balance(ALICE);
+
+			let call = crate::Call::submit_compressed_data {
+				data: data.clone(),
+				decompressed_len: 5,
+			};
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&ALICE, &call, true
+			));
+			assert_ok!(DataAvailability::submit_compressed_data(alice, data, 5));
+
+			// The 14 bytes frame is longer than its declared content, so it is charged for.
+			assert_eq!(Balances::free_balance(ALICE), balance - 14_000);
+			let event = RuntimeEvent::DataAvailability(Event::CompressedDataSubmitted {
+				who: ALICE,
+				data_hash,
+				decompressed_len: 5,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn decompressed_length_must_match_frame() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::submit_compressed_data(alice.clone(), hello(), 4);
+			assert_noop!(err, Error::InvalidCompressedData);
+
+			let data = AppDataFor::<Test>::try_from(b"hello".to_vec()).unwrap();
+			let err = DataAvailability::submit_compressed_data(alice, data, 5);
+			assert_noop!(err, Error::InvalidCompressedData);
+		})
+	}
+
+	#[test]
+	fn under_declared_frame_is_charged_for_its_length() {
+		new_test_ext().execute_with(|| {
+			// The header declares a single byte of content, followed by 100 bytes of data.
+			let mut frame = vec![0x28, 0xB5, 0x2F, 0xFD, 0x20, 0x01];
+			frame.extend([0xAA; 100]);
+			let data = AppDataFor::<Test>::try_from(frame).unwrap();
+			assert!(DataAvailability::is_valid_compression(&data, 1));
+			DataBytePrice::<Test>::put(1_000);
+			let balance = Balances::free_balance(ALICE);
+
+			let call = crate::Call::submit_compressed_data {
+				data,
+				decompressed_len: 1,
+			};
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&ALICE, &call, true
+			));
+			assert_eq!(Balances::free_balance(ALICE), balance - 106_000);
+		})
+	}
+
+	#[test]
+	fn decompressed_data_too_long() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let len = MaxAppDataLength::get() + 1;
+
+			let err = DataAvailability::submit_compressed_data(alice, hello(), len);
+			assert_noop!(err, Error::DecompressedDataTooLong);
+		})
+	}
+}
+
+mod submit_data_batch {
+	use super::*;
+	use crate::{DataBatchFor, SubmittersFor};
+	use avail_core::AppId;
+
+	fn batch(entries: Vec<(u32, &[u8])>) -> DataBatchFor<Test> {
+		entries
+			.into_iter()
+			.map(|(id, data)| {
+				(
+					AppId(id),
+					AppDataFor::<Test>::try_from(data.to_vec()).unwrap(),
+				)
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap()
+	}
+
+	#[test]
+	fn submit_data_batch() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			assert_ok!(DataAvailability::submit_data_batch(
+				alice,
+				batch(vec![(1, b"first"), (2, b"second")])
+			));
+
+			for (app_id, index, data) in [(1, 0, &b"first"[..]), (2, 1, &b"second"[..])] {
+				let event = RuntimeEvent::DataAvailability(Event::BatchDataSubmitted {
+					who: ALICE,
+					app_id: AppId(app_id),
+					index,
+					data_hash: H256(sp_io::hashing::blake2_256(data)),
+				});
+				System::assert_has_event(event);
+			}
+		})
+	}
+
+	#[test]
+	fn batch_data_is_located() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			// A submission was already included, `CheckAppId` counts the leaves of the batch.
+			System::set_extrinsic_index(2);
+			SubmittedDataLeaves::<Test>::put(3);
+
+			assert_ok!(DataAvailability::submit_data_batch(
+				alice,
+				batch(vec![(1, b"first"), (2, b"second")])
+			));
+
+			for leaf_index in [1, 2] {
+				let event = RuntimeEvent::DataAvailability(Event::DataLocated {
+					extrinsic_index: 2,
+					leaf_index,
+				});
+				System::assert_has_event(event);
+			}
+		})
+	}
+
+	#[test]
+	fn batch_cannot_be_empty() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::submit_data_batch(alice.clone(), batch(vec![]));
+			assert_noop!(err, Error::EmptyDataBatch);
+
+			let err = DataAvailability::submit_data_batch(alice, batch(vec![(1, b"a"), (2, b"")]));
+			assert_noop!(err, Error::DataCannotBeEmpty);
+		})
+	}
+
+	#[test]
+	fn entries_must_use_registered_apps() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err =
+				DataAvailability::submit_data_batch(alice, batch(vec![(1, b"a"), (100, b"b")]));
+			assert_noop!(err, Error::UnknownAppId);
+		})
+	}
+
+	#[test]
+	fn entries_follow_app_restrictions() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let submitters: SubmittersFor<Test> = vec![BOB].try_into().unwrap();
+			SubmitterAllowlists::<Test>::insert(AppId(1), submitters);
+
+			let err = DataAvailability::submit_data_batch(alice, batch(vec![(1, b"a")]));
+			assert_noop!(err, Error::NotAllowedSubmitter);
+		})
+	}
+}
+
 mod submit_block_length_proposal {
 	use super::*;
+	use crate::PendingBlockLengthProposal;
+	use frame_support::traits::Hooks;
+
+	fn approve_and_enact() {
+		let root: RuntimeOrigin = RawOrigin::Root.into();
+		assert_ok!(DataAvailability::approve_block_length_proposal(root));
+
+		let at = System::block_number() + BlockLengthChangeDelay::get();
+		System::set_block_number(at);
+		DataAvailability::on_initialize(at);
+	}
 
 	#[test]
 	fn submit_block_length_proposal() {
@@ -132,14 +371,22 @@ mod submit_block_length_proposal {
 				root, rows.0, cols.0
 			));
 
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthProposalSubmitted { rows, cols });
+			System::assert_last_event(event);
+			assert_eq!(
+				PendingBlockLengthProposal::<Test>::get(),
+				Some((rows, cols))
+			);
+
+			approve_and_enact();
 			let dynamic_block_length = System::block_length();
 			let new_block_length =
 				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
 					.unwrap();
 			assert_eq!(dynamic_block_length, new_block_length);
 
-			let event =
-				RuntimeEvent::DataAvailability(Event::BlockLengthProposalSubmitted { rows, cols });
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthChanged { rows, cols });
 			System::assert_last_event(event);
 		})
 	}
@@ -155,14 +402,22 @@ mod submit_block_length_proposal {
 				root, rows.0, cols.0
 			));
 
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthProposalSubmitted { rows, cols });
+			System::assert_last_event(event);
+			assert_eq!(
+				PendingBlockLengthProposal::<Test>::get(),
+				Some((rows, cols))
+			);
+
+			approve_and_enact();
 			let dynamic_block_length = System::block_length();
 			let new_block_length =
 				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
 					.unwrap();
 			assert_eq!(dynamic_block_length, new_block_length);
 
-			let event =
-				RuntimeEvent::DataAvailability(Event::BlockLengthProposalSubmitted { rows, cols });
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthChanged { rows, cols });
 			System::assert_last_event(event);
 		})
 	}
@@ -178,14 +433,22 @@ mod submit_block_length_proposal {
 				root, rows.0, cols.0
 			));
 
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthProposalSubmitted { rows, cols });
+			System::assert_last_event(event);
+			assert_eq!(
+				PendingBlockLengthProposal::<Test>::get(),
+				Some((rows, cols))
+			);
+
+			approve_and_enact();
 			let dynamic_block_length = System::block_length();
 			let new_block_length =
 				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
 					.unwrap();
 			assert_eq!(dynamic_block_length, new_block_length);
 
-			let event =
-				RuntimeEvent::DataAvailability(Event::BlockLengthProposalSubmitted { rows, cols });
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthChanged { rows, cols });
 			System::assert_last_event(event);
 		})
 	}
@@ -251,6 +514,68 @@ mod submit_block_length_proposal {
 	}
 }
 
+mod approve_block_length_proposal {
+	use super::*;
+	use crate::{PendingBlockLengthProposal, ScheduledBlockLengthChange};
+	use frame_support::traits::Hooks;
+
+	#[test]
+	fn change_is_announced_before_its_enactment() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(128);
+			let block_length = System::block_length();
+			assert_ok!(DataAvailability::submit_block_length_proposal(
+				root.clone(),
+				rows.0,
+				cols.0
+			));
+
+			assert_ok!(DataAvailability::approve_block_length_proposal(root));
+			let at = 1 + BlockLengthChangeDelay::get();
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthChangeAnnounced {
+				rows,
+				cols,
+				at,
+			});
+			System::assert_last_event(event);
+			assert_eq!(PendingBlockLengthProposal::<Test>::get(), None);
+
+			DataAvailability::on_initialize(at - 1);
+			assert_eq!(System::block_length(), block_length);
+
+			DataAvailability::on_initialize(at);
+			assert_eq!(System::block_length().cols, cols);
+			assert_eq!(System::block_length().rows, rows);
+			assert_eq!(ScheduledBlockLengthChange::<Test>::get(), None);
+		})
+	}
+
+	#[test]
+	fn no_block_length_proposal() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let err = DataAvailability::approve_block_length_proposal(root);
+			assert_noop!(err, Error::NoBlockLengthProposal);
+		})
+	}
+
+	#[test]
+	fn bad_origin() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			assert_ok!(DataAvailability::submit_block_length_proposal(
+				root, 128, 128
+			));
+
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let err = DataAvailability::approve_block_length_proposal(alice);
+			assert_noop!(err, BadOrigin);
+		})
+	}
+}
+
 mod set_application_key {
 	use super::*;
 
@@ -369,3 +694,1378 @@ mod set_submit_data_fee_modifier {
 		})
 	}
 }
+
+mod app_key_ownership {
+	use super::*;
+
+	#[test]
+	fn set_application_key_metadata() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+			let metadata = AppKeyMetadata {
+				display_name_hash: H256::repeat_byte(1),
+				contact: b"team@avail.so".to_vec().try_into().unwrap(),
+			};
+
+			let err = DataAvailability::set_application_key_metadata(bob, key.clone(), None);
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			assert_ok!(DataAvailability::set_application_key_metadata(
+				alice,
+				key.clone(),
+				Some(metadata.clone())
+			));
+			let info = DataAvailability::application_key(&key).unwrap();
+			assert_eq!(info.metadata, Some(metadata.clone()));
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyMetadataSet {
+				key,
+				metadata: Some(metadata),
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn transfer_app_key_ownership() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+
+			let err = DataAvailability::accept_app_key_ownership(bob.clone(), key.clone());
+			assert_noop!(err, Error::NoPendingAppKeyTransfer);
+			let err = DataAvailability::transfer_app_key_ownership(bob.clone(), key.clone(), BOB);
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			assert_ok!(DataAvailability::transfer_app_key_ownership(
+				alice.clone(),
+				key.clone(),
+				BOB
+			));
+			assert_eq!(PendingAppKeyTransfers::<Test>::get(&key), Some(BOB));
+			// The ownership only moves once accepted.
+			assert_eq!(
+				DataAvailability::application_key(&key).unwrap().owner,
+				ALICE
+			);
+
+			let err = DataAvailability::accept_app_key_ownership(alice, key.clone());
+			assert_noop!(err, Error::NotPendingAppKeyOwner);
+
+			assert_ok!(DataAvailability::accept_app_key_ownership(bob, key.clone()));
+			assert_eq!(DataAvailability::application_key(&key).unwrap().owner, BOB);
+			assert_eq!(PendingAppKeyTransfers::<Test>::get(&key), None);
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyOwnershipTransferred {
+				key,
+				old_owner: ALICE,
+				new_owner: BOB,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn deposit_follows_ownership() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let deposit = <Test as crate::Config>::AppKeyDeposit::get();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_ok!(DataAvailability::transfer_app_key_ownership(
+				alice,
+				key.clone(),
+				BOB
+			));
+			assert_ok!(DataAvailability::accept_app_key_ownership(bob, key));
+
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::reserved_balance(BOB), deposit);
+		})
+	}
+}
+
+mod remove_application_key {
+	use super::*;
+	use avail_core::{currency::AVAIL, AppId};
+
+	#[test]
+	fn remove_application_key() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let id = DataAvailability::peek_next_application_id();
+			let balance = Balances::free_balance(ALICE);
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_ok!(DataAvailability::fund_sponsorship_pool(
+				alice.clone(),
+				key.clone(),
+				100 * AVAIL
+			));
+			AppDataQuotas::<Test>::insert(id, AppDataQuota::default());
+
+			assert_ok!(DataAvailability::remove_application_key(alice, key.clone()));
+			assert_eq!(DataAvailability::application_key(&key), None);
+			assert_eq!(AppDataQuotas::<Test>::get(id), None);
+			// The deposit and the sponsorship pool are returned to the owner.
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), balance);
+			// The ID of the removed key is not reassigned.
+			assert_ne!(DataAvailability::peek_next_application_id(), id);
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyRemoved {
+				key,
+				owner: ALICE,
+				id,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn only_owner_removes_app_key() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"A Brave New World".to_vec()).unwrap();
+
+			let err = DataAvailability::remove_application_key(alice, key);
+			assert_noop!(err, Error::NotAppKeyOwner);
+		})
+	}
+
+	#[test]
+	fn app_id_cannot_be_removed() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+			assert_eq!(
+				DataAvailability::application_key(&key).unwrap().id,
+				AppId(0)
+			);
+
+			let err = DataAvailability::remove_application_key(alice, key);
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+}
+
+mod set_submitter_allowlist {
+	use super::*;
+	use avail_core::AppId;
+
+	#[test]
+	fn set_submitter_allowlist() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+			let submitters = vec![ALICE].try_into().unwrap();
+
+			let err = DataAvailability::set_submitter_allowlist(alice, key.clone(), None);
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			assert!(DataAvailability::is_allowed_submitter(AppId(1), &BOB));
+			assert_ok!(DataAvailability::set_submitter_allowlist(
+				bob.clone(),
+				key.clone(),
+				Some(submitters)
+			));
+			assert!(DataAvailability::is_allowed_submitter(AppId(1), &ALICE));
+			assert!(!DataAvailability::is_allowed_submitter(AppId(1), &BOB));
+
+			assert_ok!(DataAvailability::set_submitter_allowlist(bob, key, None));
+			assert_eq!(SubmitterAllowlists::<Test>::get(AppId(1)), None);
+			assert!(DataAvailability::is_allowed_submitter(AppId(1), &BOB));
+
+			let event = RuntimeEvent::DataAvailability(Event::SubmitterAllowlistSet {
+				id: AppId(1),
+				submitters: None,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn app_id_cannot_be_restricted() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+
+			let err = DataAvailability::set_submitter_allowlist(alice, key, None);
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+}
+
+mod set_app_max_data_length {
+	use super::*;
+	use crate::AppMaxDataLengths;
+	use avail_core::AppId;
+
+	#[test]
+	fn set_app_max_data_length() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+
+			let err = DataAvailability::set_app_max_data_length(alice, key.clone(), Some(100));
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			assert!(DataAvailability::is_within_max_data_length(AppId(1), 101));
+			assert_ok!(DataAvailability::set_app_max_data_length(
+				bob.clone(),
+				key.clone(),
+				Some(100)
+			));
+			assert!(DataAvailability::is_within_max_data_length(AppId(1), 100));
+			assert!(!DataAvailability::is_within_max_data_length(AppId(1), 101));
+
+			assert_ok!(DataAvailability::set_app_max_data_length(bob, key, None));
+			assert_eq!(AppMaxDataLengths::<Test>::get(AppId(1)), None);
+
+			let event = RuntimeEvent::DataAvailability(Event::AppMaxDataLengthSet {
+				id: AppId(1),
+				max_length: None,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn max_data_length_too_large() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+			let max_length = MaxAppDataLength::get() + 1;
+
+			let err = DataAvailability::set_app_max_data_length(bob, key, Some(max_length));
+			assert_noop!(err, Error::MaxDataLengthTooLarge);
+		})
+	}
+
+	#[test]
+	fn app_id_cannot_be_restricted() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+
+			let err = DataAvailability::set_app_max_data_length(alice, key, Some(100));
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+}
+
+mod retire_application_key {
+	use super::*;
+	use crate::AppRetirements;
+	use avail_core::AppId;
+
+	#[test]
+	fn retire_application_key() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let id = DataAvailability::peek_next_application_id();
+			let balance = Balances::free_balance(ALICE);
+			let period = <Test as crate::Config>::AppKeyRetirementPeriod::get();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_ok!(DataAvailability::retire_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(
+				Event::ApplicationKeyRetiring {
+					key: key.clone(),
+					id,
+					retires_at: 1 + period,
+				},
+			));
+
+			let err = DataAvailability::retire_application_key(alice.clone(), key.clone());
+			assert_noop!(err, Error::AppKeyAlreadyRetiring);
+
+			// The application accepts submissions during the grace period.
+			assert!(!DataAvailability::is_retired(id));
+			let err = DataAvailability::complete_app_key_retirement(alice.clone(), key.clone());
+			assert_noop!(err, Error::AppKeyNotRetired);
+
+			System::set_block_number(1 + period);
+			assert!(DataAvailability::is_retired(id));
+			assert_ok!(DataAvailability::complete_app_key_retirement(
+				alice,
+				key.clone()
+			));
+			// The deposit is refunded and the key keeps its ID.
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), balance);
+			let info = DataAvailability::application_key(&key).unwrap();
+			assert_eq!((info.id, info.deposit), (id, 0));
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyRetired {
+				key,
+				owner: ALICE,
+				id,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn only_owner_retires_app_key() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"A Brave New World".to_vec()).unwrap();
+
+			let err = DataAvailability::retire_application_key(alice.clone(), key.clone());
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			AppRetirements::<Test>::insert(AppId(2), 0);
+			let err = DataAvailability::complete_app_key_retirement(alice, key);
+			assert_noop!(err, Error::NotAppKeyOwner);
+		})
+	}
+
+	#[test]
+	fn retired_apps_reject_batch_entries() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			AppRetirements::<Test>::insert(AppId(2), 1);
+			let data = AppDataFor::<Test>::try_from(b"X".to_vec()).unwrap();
+			let batch = vec![(AppId(2), data)].try_into().unwrap();
+
+			let err = DataAvailability::submit_data_batch(alice, batch);
+			assert_noop!(err, Error::RetiredAppId);
+		})
+	}
+
+	#[test]
+	fn app_id_cannot_be_restricted() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+
+			let err = DataAvailability::retire_application_key(alice, key);
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+}
+
+mod set_app_sequencing {
+	use super::*;
+	use crate::AppSequences;
+	use avail_core::AppId;
+
+	#[test]
+	fn set_app_sequencing() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+
+			let err = DataAvailability::set_app_sequencing(alice, key.clone(), true);
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			assert_ok!(DataAvailability::set_app_sequencing(
+				bob.clone(),
+				key.clone(),
+				true
+			));
+			DataAvailability::note_app_sequence(AppId(1));
+			DataAvailability::note_app_sequence(AppId(1));
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::DataSequenced {
+				app_id: AppId(1),
+				sequence: 1,
+			}));
+
+			// Sequence numbers resume where they stopped.
+			assert_ok!(DataAvailability::set_app_sequencing(
+				bob.clone(),
+				key.clone(),
+				false
+			));
+			DataAvailability::note_app_sequence(AppId(1));
+			assert_ok!(DataAvailability::set_app_sequencing(bob, key, true));
+			DataAvailability::note_app_sequence(AppId(1));
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::DataSequenced {
+				app_id: AppId(1),
+				sequence: 2,
+			}));
+			assert_eq!(AppSequences::<Test>::get(AppId(1)).map(|s| s.next), Some(3));
+		})
+	}
+
+	#[test]
+	fn app_id_cannot_be_restricted() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+
+			let err = DataAvailability::set_app_sequencing(alice, key, true);
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+}
+
+mod set_app_data_quota {
+	use super::*;
+	use avail_core::AppId;
+
+	#[test]
+	fn set_app_data_quota() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let quota = AppDataQuota {
+				per_block: Some(100),
+				per_era: None,
+			};
+
+			assert_ok!(DataAvailability::set_app_data_quota(
+				root.clone(),
+				AppId(1),
+				Some(quota)
+			));
+			assert_eq!(AppDataQuotas::<Test>::get(AppId(1)), Some(quota));
+
+			let event = RuntimeEvent::DataAvailability(Event::AppDataQuotaSet {
+				id: AppId(1),
+				quota: Some(quota),
+			});
+			System::assert_last_event(event);
+
+			let err = DataAvailability::set_app_data_quota(root, AppId(0), Some(quota));
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+
+	#[test]
+	fn quotas_reset_every_block_and_era() {
+		new_test_ext().execute_with(|| {
+			let quota = AppDataQuota {
+				per_block: Some(100),
+				per_era: Some(150),
+			};
+
+			let usage = DataAvailability::next_app_data_usage(AppId(1), &quota, 100).unwrap();
+			assert_eq!(
+				DataAvailability::next_app_data_usage(AppId(1), &quota, 101),
+				None
+			);
+			crate::AppDataUsages::<Test>::insert(AppId(1), usage);
+			assert_eq!(
+				DataAvailability::next_app_data_usage(AppId(1), &quota, 1),
+				None
+			);
+
+			// A new block resets the block usage but not the era one.
+			System::set_block_number(2);
+			assert!(DataAvailability::next_app_data_usage(AppId(1), &quota, 50).is_some());
+			assert_eq!(
+				DataAvailability::next_app_data_usage(AppId(1), &quota, 51),
+				None
+			);
+
+			// A new era resets both.
+			System::set_block_number(crate::config_preludes::AppQuotaEraLength::get());
+			assert!(DataAvailability::next_app_data_usage(AppId(1), &quota, 100).is_some());
+		})
+	}
+}
+
+mod app_usage_history {
+	use super::*;
+	use crate::{
+		config_preludes::{AppQuotaEraLength, MaxAppUsageHistory},
+		AppUsageHistory,
+	};
+	use avail_core::AppId;
+
+	#[test]
+	fn usage_is_recorded_per_era() {
+		new_test_ext().execute_with(|| {
+			DataAvailability::note_app_usage(AppId(1), 100);
+			DataAvailability::note_app_usage(AppId(1), 50);
+			System::set_block_number(AppQuotaEraLength::get());
+			DataAvailability::note_app_usage(AppId(1), 10);
+
+			let history = AppUsageHistory::<Test>::get(AppId(1));
+			let usage = history
+				.iter()
+				.map(|stats| (stats.era, stats.bytes, stats.submissions))
+				.collect::<Vec<_>>();
+			assert_eq!(usage, vec![(0, 150, 2), (1, 10, 1)]);
+			assert!(AppUsageHistory::<Test>::get(AppId(2)).is_empty());
+		})
+	}
+
+	#[test]
+	fn history_is_bounded() {
+		new_test_ext().execute_with(|| {
+			let eras = MaxAppUsageHistory::get() + 2;
+			for era in 0..eras {
+				System::set_block_number(era * AppQuotaEraLength::get());
+				DataAvailability::note_app_usage(AppId(1), 1);
+			}
+
+			let history = AppUsageHistory::<Test>::get(AppId(1));
+			assert_eq!(history.len() as u32, MaxAppUsageHistory::get());
+			assert_eq!(history.first().map(|stats| stats.era), Some(2));
+			assert_eq!(history.last().map(|stats| stats.era), Some(eras - 1));
+		})
+	}
+}
+
+mod submission_receipts {
+	use super::*;
+	use crate::{config_preludes::SubmissionReceiptPeriod, SubmissionReceiptApps};
+	use avail_core::AppId;
+	use frame_support::traits::Hooks;
+
+	#[test]
+	fn submissions_are_counted_per_block_and_app() {
+		new_test_ext().execute_with(|| {
+			DataAvailability::note_submission_receipt(AppId(1));
+			DataAvailability::note_submission_receipt(AppId(2));
+			DataAvailability::note_submission_receipt(AppId(1));
+			System::set_block_number(2);
+			DataAvailability::note_submission_receipt(AppId(2));
+
+			let mut receipts = DataAvailability::submission_receipts(1);
+			receipts.sort();
+			assert_eq!(receipts, vec![(AppId(1), 2), (AppId(2), 1)]);
+			assert_eq!(
+				DataAvailability::submission_receipts(2),
+				vec![(AppId(2), 1)]
+			);
+			assert_eq!(SubmissionReceiptApps::<Test>::get(1), 2);
+		})
+	}
+
+	#[test]
+	fn receipts_are_pruned_after_their_period() {
+		new_test_ext().execute_with(|| {
+			DataAvailability::note_submission_receipt(AppId(1));
+			DataAvailability::note_submission_receipt(AppId(2));
+
+			let period = SubmissionReceiptPeriod::get();
+			DataAvailability::on_initialize(period);
+			assert_eq!(DataAvailability::submission_receipts(1).len(), 2);
+
+			DataAvailability::on_initialize(period + 1);
+			assert!(DataAvailability::submission_receipts(1).is_empty());
+			assert_eq!(SubmissionReceiptApps::<Test>::get(1), 0);
+		})
+	}
+}
+
+mod set_app_reserved_space {
+	use super::*;
+	use crate::{ReservedSpaceUsages, TotalReservedSpace};
+	use avail_core::AppId;
+
+	#[test]
+	fn set_app_reserved_space() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let max = DataAvailability::max_reserved_space();
+
+			assert_ok!(DataAvailability::set_app_reserved_space(
+				root.clone(),
+				AppId(1),
+				Some(max - 100)
+			));
+			let event = RuntimeEvent::DataAvailability(Event::AppReservedSpaceSet {
+				id: AppId(1),
+				bytes: Some(max - 100),
+			});
+			System::assert_last_event(event);
+
+			let err = DataAvailability::set_app_reserved_space(root.clone(), AppId(2), Some(101));
+			assert_noop!(err, Error::ReservedSpaceTooLarge);
+			assert_ok!(DataAvailability::set_app_reserved_space(
+				root.clone(),
+				AppId(2),
+				Some(100)
+			));
+			assert_eq!(TotalReservedSpace::<Test>::get(), max);
+
+			// Replacing a reservation releases the previous one.
+			assert_ok!(DataAvailability::set_app_reserved_space(
+				root.clone(),
+				AppId(1),
+				Some(50)
+			));
+			assert_ok!(DataAvailability::set_app_reserved_space(
+				root.clone(),
+				AppId(2),
+				None
+			));
+			assert_eq!(TotalReservedSpace::<Test>::get(), 50);
+
+			let err = DataAvailability::set_app_reserved_space(root, AppId(0), Some(50));
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+
+	#[test]
+	fn reservations_reset_every_block() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			assert_ok!(DataAvailability::set_app_reserved_space(
+				root,
+				AppId(1),
+				Some(100)
+			));
+
+			let (usage, total) =
+				DataAvailability::next_reserved_space_usage(AppId(1), 150, 150).unwrap();
+			assert_eq!((usage.bytes, total.bytes), (100, 100));
+			ReservedSpaceUsages::<Test>::insert(AppId(1), usage);
+			crate::TotalReservedSpaceUsage::<Test>::put(total);
+			let (usage, _) =
+				DataAvailability::next_reserved_space_usage(AppId(1), 50, 200).unwrap();
+			assert_eq!(usage.bytes, 100);
+
+			// A new block restores the whole reservation.
+			System::set_block_number(2);
+			let (usage, _) = DataAvailability::next_reserved_space_usage(AppId(1), 50, 50).unwrap();
+			assert_eq!(usage.bytes, 50);
+		})
+	}
+}
+
+mod block_space_intents {
+	use super::*;
+	use crate::{BlockSpaceIntentOf, IntentSpaces, TotalReservedSpace};
+	use avail_core::AppId;
+	use frame_support::{dispatch::DispatchClass, traits::Hooks};
+
+	fn data(len: usize) -> AppDataFor<Test> {
+		AppDataFor::<Test>::try_from(vec![b'X'; len]).unwrap()
+	}
+
+	#[test]
+	fn reserve_and_fill_block_space() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let balance = Balances::free_balance(ALICE);
+
+			assert_ok!(DataAvailability::reserve_block_space(alice.clone(), 3, 100));
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::BlockSpaceReserved {
+				who: ALICE,
+				block: 3,
+				bytes: 100,
+			}));
+			// The price of the data is charged by the reservation.
+			assert_eq!(Balances::free_balance(ALICE), balance - 100);
+
+			let err = DataAvailability::reserve_block_space(alice.clone(), 3, 10);
+			assert_noop!(err, Error::BlockSpaceIntentExists);
+
+			// The reservation can only be filled in its block.
+			let err = DataAvailability::submit_reserved_data(alice.clone(), data(10));
+			assert_noop!(err, Error::NoBlockSpaceIntent);
+			System::set_block_number(3);
+			let err = DataAvailability::submit_reserved_data(alice.clone(), data(101));
+			assert_noop!(err, Error::BlockSpaceIntentExceeded);
+
+			assert_ok!(DataAvailability::submit_reserved_data(alice, data(60)));
+			assert_eq!(Balances::free_balance(ALICE), balance - 100);
+			assert_eq!(BlockSpaceIntentOf::<Test>::get(3, ALICE), None);
+			assert_eq!(IntentSpaces::<Test>::get(3).bytes, 0);
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::DataSubmitted {
+				who: ALICE,
+				data_hash: H256(sp_io::hashing::blake2_256(&[b'X'; 60])),
+				blob_hash: H256(sp_io::hashing::keccak_256(&[b'X'; 60])),
+			}));
+		})
+	}
+
+	#[test]
+	fn reservations_are_bounded() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let lookahead = <Test as crate::Config>::MaxIntentLookahead::get();
+			let max = DataAvailability::max_reserved_space();
+
+			let err = DataAvailability::reserve_block_space(alice.clone(), 1, 10);
+			assert_noop!(err, Error::InvalidIntentBlock);
+			let err = DataAvailability::reserve_block_space(alice.clone(), 2 + lookahead, 10);
+			assert_noop!(err, Error::InvalidIntentBlock);
+			let err = DataAvailability::reserve_block_space(alice.clone(), 2, 0);
+			assert_noop!(err, Error::InvalidIntentLength);
+
+			// Intents share the reservable space with the applications.
+			TotalReservedSpace::<Test>::put(max - 100);
+			assert_ok!(DataAvailability::reserve_block_space(alice, 2, 60));
+			let err = DataAvailability::reserve_block_space(bob.clone(), 2, 41);
+			assert_noop!(err, Error::BlockSpaceUnavailable);
+			assert_ok!(DataAvailability::reserve_block_space(bob, 2, 40));
+		})
+	}
+
+	#[test]
+	fn intents_protect_their_space() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let block_length = <Test as frame_system::Config>::BlockLength::get();
+			let max_len = *block_length.max.get(DispatchClass::Normal);
+
+			assert_ok!(DataAvailability::reserve_block_space(alice, 2, 100));
+			System::set_block_number(2);
+			let next = |block_len| {
+				DataAvailability::next_reserved_space_usage(AppId(1), 0, block_len).is_some()
+			};
+			assert!(next(max_len - 100));
+			assert!(!next(max_len - 99));
+		})
+	}
+
+	#[test]
+	fn unfilled_intents_are_released() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			assert_ok!(DataAvailability::reserve_block_space(alice, 2, 100));
+			assert_ok!(DataAvailability::reserve_block_space(bob, 2, 100));
+
+			DataAvailability::on_finalize(2);
+			assert_eq!(IntentSpaces::<Test>::get(2), Default::default());
+			assert_eq!(BlockSpaceIntentOf::<Test>::get(2, ALICE), None);
+			assert_eq!(BlockSpaceIntentOf::<Test>::get(2, BOB), None);
+		})
+	}
+}
+
+mod submit_data_with_retention {
+	use super::*;
+	use crate::{config_preludes::MaxRetentionPeriod, RetentionHint};
+	use frame_support::traits::Hooks;
+
+	fn data() -> AppDataFor<Test> {
+		AppDataFor::<Test>::try_from(b"hello".to_vec()).unwrap()
+	}
+
+	#[test]
+	fn longest_retention_period_is_logged() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			System::set_block_number(10);
+
+			assert_ok!(DataAvailability::submit_data_with_retention(
+				alice.clone(),
+				data(),
+				50
+			));
+			assert_ok!(DataAvailability::submit_data_with_retention(
+				alice,
+				data(),
+				20
+			));
+			DataAvailability::on_finalize(10);
+
+			let hint = RetentionHint::from_digest(&System::digest());
+			assert_eq!(hint, Some(RetentionHint { expires_at: 60 }));
+		})
+	}
+
+	#[test]
+	fn data_without_retention_hint_is_kept() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			System::set_block_number(10);
+
+			assert_ok!(DataAvailability::submit_data_with_retention(
+				alice.clone(),
+				data(),
+				50
+			));
+			assert_ok!(DataAvailability::submit_data(alice, data()));
+			DataAvailability::on_finalize(10);
+
+			assert_eq!(RetentionHint::from_digest(&System::digest()), None);
+		})
+	}
+
+	#[test]
+	fn retention_period_too_long() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let retention = MaxRetentionPeriod::get() + 1;
+
+			let err = DataAvailability::submit_data_with_retention(alice, data(), retention);
+			assert_noop!(err, Error::RetentionPeriodTooLong);
+		})
+	}
+}
+
+mod block_length_governor {
+	use super::*;
+	use frame_support::traits::Hooks;
+	use frame_system::{AllExtrinsicsLen, ExtrinsicLen};
+	use sp_runtime::Perbill;
+
+	fn bounds() -> BlockLengthBounds {
+		BlockLengthBounds {
+			min_rows: BlockLengthRows(64),
+			max_rows: BlockLengthRows(256),
+			min_cols: BlockLengthColumns(64),
+			max_cols: BlockLengthColumns(128),
+			upper_target: Perbill::from_percent(80),
+			lower_target: Perbill::from_percent(20),
+		}
+	}
+
+	#[test]
+	fn set_block_length_governor() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			let mut invalid = bounds();
+			invalid.lower_target = invalid.upper_target;
+			let err = DataAvailability::set_block_length_governor(root.clone(), Some(invalid));
+			assert_noop!(err, Error::InvalidBlockLengthBounds);
+
+			let mut invalid = bounds();
+			invalid.max_rows = BlockLengthRows(100);
+			let err = DataAvailability::set_block_length_governor(root.clone(), Some(invalid));
+			assert_noop!(err, Error::NotPowerOfTwo);
+
+			assert_ok!(DataAvailability::set_block_length_governor(
+				root,
+				Some(bounds())
+			));
+			assert_eq!(BlockLengthGovernor::<Test>::get(), Some(bounds()));
+		})
+	}
+
+	#[test]
+	fn block_length_follows_grid_utilization() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			assert_ok!(DataAvailability::submit_block_length_proposal(
+				root.clone(),
+				64,
+				64
+			));
+			assert_ok!(DataAvailability::set_block_length_governor(
+				root,
+				Some(bounds())
+			));
+
+			// Full blocks raise the average utilization until the rows are doubled.
+			AllExtrinsicsLen::<Test>::put(ExtrinsicLen::new(64 * 64 * 31));
+			for _ in 0..20 {
+				DataAvailability::on_finalize(1);
+				DataAvailability::on_initialize(2);
+			}
+			let block_length = System::block_length();
+			assert_eq!(block_length.rows, BlockLengthRows(128));
+			assert_eq!(block_length.cols, BlockLengthColumns(64));
+
+			// Empty blocks shrink the block length down to the lower bounds.
+			AllExtrinsicsLen::<Test>::kill();
+			GridUtilization::<Test>::put(Perbill::zero());
+			DataAvailability::on_initialize(3);
+			assert_eq!(System::block_length().rows, BlockLengthRows(64));
+
+			DataAvailability::on_initialize(4);
+			assert_eq!(System::block_length().rows, BlockLengthRows(64));
+		})
+	}
+}
+
+mod data_byte_price {
+	use super::*;
+	use crate::{
+		mock::{Balances, MinChargedDataLength},
+		DataBytePrice,
+	};
+	use avail_core::AppId;
+	use frame_support::traits::Currency;
+	use sp_runtime::Perbill;
+
+	fn submit_data(len: usize) -> crate::Call<Test> {
+		crate::Call::submit_data {
+			data: AppDataFor::<Test>::try_from(vec![b'X'; len]).unwrap(),
+		}
+	}
+
+	#[test]
+	fn submit_data_burns_data_fee() {
+		new_test_ext().execute_with(|| {
+			DataBytePrice::<Test>::put(1_000);
+			let balance = Balances::free_balance(ALICE);
+			let issuance = Balances::total_issuance();
+
+			let call = submit_data(10);
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&ALICE, &call, false
+			));
+			assert_eq!(Balances::free_balance(ALICE), balance);
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&ALICE, &call, true
+			));
+
+			assert_eq!(Balances::free_balance(ALICE), balance - 10_000);
+			assert_eq!(Balances::total_issuance(), issuance - 10_000);
+		})
+	}
+
+	#[test]
+	fn small_blobs_are_charged_the_minimum_length() {
+		new_test_ext().execute_with(|| {
+			MinChargedDataLength::set(32);
+			DataBytePrice::<Test>::put(1_000);
+			let balance = Balances::free_balance(ALICE);
+
+			let call = submit_data(10);
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&ALICE, &call, true
+			));
+			assert_eq!(Balances::free_balance(ALICE), balance - 32_000);
+
+			let call = submit_data(40);
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&ALICE, &call, true
+			));
+			assert_eq!(Balances::free_balance(ALICE), balance - 72_000);
+		})
+	}
+
+	#[test]
+	fn insufficient_balance_for_data_fee() {
+		new_test_ext().execute_with(|| {
+			let call = submit_data(10);
+
+			for charge in [false, true] {
+				let err = DataAvailability::charge_submitted_data_fee(&4, &call, charge);
+				assert_noop!(err, Error::InsufficientBalanceForDataFee);
+			}
+		})
+	}
+
+	#[test]
+	fn batch_is_charged_for_its_entries() {
+		new_test_ext().execute_with(|| {
+			MinChargedDataLength::set(32);
+			DataBytePrice::<Test>::put(1_000);
+			let balance = Balances::free_balance(ALICE);
+			let data = |len: usize| AppDataFor::<Test>::try_from(vec![b'X'; len]).unwrap();
+			let batch = vec![(AppId(1), data(10)), (AppId(2), data(40))];
+
+			let call = crate::Call::submit_data_batch {
+				batch: batch.try_into().unwrap(),
+			};
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&ALICE, &call, true
+			));
+			assert_eq!(Balances::free_balance(ALICE), balance - 72_000);
+		})
+	}
+
+	#[test]
+	fn price_follows_grid_fill() {
+		new_test_ext().execute_with(|| {
+			DataBytePrice::<Test>::put(800);
+
+			DataAvailability::update_data_byte_price(Perbill::one());
+			assert_eq!(DataAvailability::data_byte_price(), 900);
+
+			DataAvailability::update_data_byte_price(Perbill::from_percent(50));
+			assert_eq!(DataAvailability::data_byte_price(), 900);
+
+			DataAvailability::update_data_byte_price(Perbill::zero());
+			assert_eq!(DataAvailability::data_byte_price(), 788);
+		})
+	}
+
+	#[test]
+	fn price_is_bounded_by_min_price() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(DataAvailability::data_byte_price(), 1);
+
+			DataAvailability::update_data_byte_price(Perbill::zero());
+			assert_eq!(DataAvailability::data_byte_price(), 1);
+
+			DataAvailability::update_data_byte_price(Perbill::one());
+			assert_eq!(DataAvailability::data_byte_price(), 2);
+		})
+	}
+}
+
+mod sponsorship {
+	use super::*;
+	use crate::{mock::Balances, AppKeyFor, DataBytePrice, SubmitterAllowlists, SubmittersFor};
+	use avail_core::{currency::AVAIL, AppId};
+	use frame_support::{
+		dispatch::{DispatchInfo, PostDispatchInfo},
+		traits::Currency,
+	};
+	use pallet_transaction_payment::OnChargeTransaction;
+	use sp_runtime::transaction_validity::InvalidTransaction;
+
+	type FeeAdapter = <Test as pallet_transaction_payment::Config>::OnChargeTransaction;
+
+	const SPONSORED: u64 = 4;
+
+	fn key() -> AppKeyFor<Test> {
+		AppKeyFor::<Test>::try_from(b"A Brave New World".to_vec()).unwrap()
+	}
+
+	fn fund_pool(amount: u128) -> u64 {
+		let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+		let submitters: SubmittersFor<Test> = vec![SPONSORED].try_into().unwrap();
+		SubmitterAllowlists::<Test>::insert(AppId(2), submitters);
+		assert_ok!(DataAvailability::fund_sponsorship_pool(bob, key(), amount));
+		DataAvailability::sponsorship_pool_account(AppId(2))
+	}
+
+	#[test]
+	fn fund_and_withdraw_sponsorship_pool() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let balance = Balances::free_balance(BOB);
+
+			let pool = fund_pool(100 * AVAIL);
+			assert_eq!(Balances::free_balance(pool), 100 * AVAIL);
+			assert_eq!(Balances::free_balance(BOB), balance - 100 * AVAIL);
+			System::assert_last_event(RuntimeEvent::DataAvailability(
+				Event::SponsorshipPoolFunded {
+					id: AppId(2),
+					amount: 100 * AVAIL,
+				},
+			));
+
+			assert_ok!(DataAvailability::withdraw_sponsorship_pool(
+				bob,
+				key(),
+				100 * AVAIL
+			));
+			assert_eq!(Balances::free_balance(pool), 0);
+			assert_eq!(Balances::free_balance(BOB), balance);
+		})
+	}
+
+	#[test]
+	fn only_owner_manages_sponsorship_pool() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::fund_sponsorship_pool(alice.clone(), key(), AVAIL);
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			let err = DataAvailability::withdraw_sponsorship_pool(alice, key(), AVAIL);
+			assert_noop!(err, Error::NotAppKeyOwner);
+		})
+	}
+
+	#[test]
+	fn submit_sponsored_data() {
+		new_test_ext().execute_with(|| {
+			let pool = fund_pool(100 * AVAIL);
+			DataBytePrice::<Test>::put(1_000);
+			let data = AppDataFor::<Test>::try_from(vec![b'X'; 10]).unwrap();
+			let data_hash = H256(sp_io::hashing::blake2_256(&data));
+			let blob_hash = H256(sp_io::hashing::keccak_256(&data));
+
+			let call = crate::Call::submit_sponsored_data {
+				app_id: AppId(2),
+				data: data.clone(),
+			};
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&SPONSORED, &call, true
+			));
+			assert_eq!(Balances::free_balance(pool), 100 * AVAIL - 10_000);
+			// Other submitters pay for their own data.
+			let balance = Balances::free_balance(ALICE);
+			assert_ok!(DataAvailability::charge_submitted_data_fee(
+				&ALICE, &call, true
+			));
+			assert_eq!(Balances::free_balance(pool), 100 * AVAIL - 10_000);
+			assert_eq!(Balances::free_balance(ALICE), balance - 10_000);
+
+			let origin: RuntimeOrigin = RawOrigin::Signed(SPONSORED).into();
+			assert_ok!(DataAvailability::submit_sponsored_data(
+				origin,
+				AppId(2),
+				data.clone()
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::DataSubmitted {
+				who: SPONSORED,
+				data_hash,
+				blob_hash,
+			}));
+
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let err = DataAvailability::submit_sponsored_data(alice, AppId(2), data);
+			assert_noop!(err, Error::NotAllowedSubmitter);
+		})
+	}
+
+	#[test]
+	fn pool_pays_transaction_fee() {
+		new_test_ext().execute_with(|| {
+			let pool = fund_pool(100 * AVAIL);
+			let info = DispatchInfo::default();
+			let call =
+				crate::mock::RuntimeCall::DataAvailability(crate::Call::submit_sponsored_data {
+					app_id: AppId(2),
+					data: vec![b'X'].try_into().unwrap(),
+				});
+
+			assert_ok!(FeeAdapter::withdraw_fee(&SPONSORED, &call, &info, AVAIL, 0));
+			assert_eq!(Balances::free_balance(pool), 99 * AVAIL);
+
+			let err = FeeAdapter::withdraw_fee(&ALICE, &call, &info, AVAIL, 0);
+			assert_eq!(err.map(|_| ()), Err(InvalidTransaction::Payment.into()));
+		})
+	}
+
+	#[test]
+	fn submitter_pays_its_tip() {
+		new_test_ext().execute_with(|| {
+			let pool = fund_pool(100 * AVAIL);
+			let _ = Balances::deposit_creating(&SPONSORED, 10 * AVAIL);
+			let info = DispatchInfo::default();
+			let post_info = PostDispatchInfo::default();
+			let call =
+				crate::mock::RuntimeCall::DataAvailability(crate::Call::submit_sponsored_data {
+					app_id: AppId(2),
+					data: vec![b'X'].try_into().unwrap(),
+				});
+
+			// The fee includes the tip, which the pool does not pay.
+			let liquidity =
+				FeeAdapter::withdraw_fee(&SPONSORED, &call, &info, 3 * AVAIL, 2 * AVAIL).unwrap();
+			assert_eq!(Balances::free_balance(pool), 99 * AVAIL);
+			assert_eq!(Balances::free_balance(SPONSORED), 8 * AVAIL);
+
+			// Only the fee of the pool is refunded.
+			assert_ok!(FeeAdapter::correct_and_deposit_fee(
+				&SPONSORED,
+				&info,
+				&post_info,
+				2 * AVAIL + AVAIL / 2,
+				2 * AVAIL,
+				liquidity
+			));
+			assert_eq!(Balances::free_balance(pool), 99 * AVAIL + AVAIL / 2);
+			assert_eq!(Balances::free_balance(SPONSORED), 8 * AVAIL);
+
+			// The signer cannot make the pool pay a tip it cannot afford.
+			let err = FeeAdapter::withdraw_fee(&SPONSORED, &call, &info, 20 * AVAIL, 19 * AVAIL);
+			assert_eq!(err.map(|_| ()), Err(InvalidTransaction::Payment.into()));
+			assert_eq!(Balances::free_balance(pool), 99 * AVAIL + AVAIL / 2);
+		})
+	}
+}
+
+mod create_child_application_key {
+	use super::*;
+	use crate::{AppChildren, AppParents};
+	use avail_core::AppId;
+
+	fn app_key(key: &[u8]) -> AppKeyFor<Test> {
+		AppKeyFor::<Test>::try_from(key.to_vec()).unwrap()
+	}
+
+	#[test]
+	fn create_child_application_key() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let parent_key = app_key(b"Reserved-1");
+			let key = app_key(b"Reserved-1/blobs");
+			let id = DataAvailability::peek_next_application_id();
+
+			assert_ok!(DataAvailability::create_child_application_key(
+				bob,
+				parent_key,
+				key.clone()
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(
+				Event::ChildApplicationKeyCreated {
+					key: key.clone(),
+					owner: BOB,
+					id,
+					parent: AppId(1),
+				},
+			));
+
+			let info = DataAvailability::application_key(&key).unwrap();
+			assert_eq!((info.owner, info.id), (BOB, id));
+			let deposit = <Test as crate::Config>::AppKeyDeposit::get();
+			assert_eq!(Balances::reserved_balance(BOB), deposit);
+			assert_eq!(AppParents::<Test>::get(id), Some(AppId(1)));
+			assert_eq!(DataAvailability::app_subtree(AppId(1)), vec![AppId(1), id]);
+			assert_eq!(DataAvailability::app_subtree(id), vec![id]);
+		})
+	}
+
+	#[test]
+	fn only_parent_owner_creates_children() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = app_key(b"Child");
+
+			let err = DataAvailability::create_child_application_key(
+				alice.clone(),
+				app_key(b"Reserved-1"),
+				key.clone(),
+			);
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			let err = DataAvailability::create_child_application_key(
+				alice.clone(),
+				app_key(b"Unknown"),
+				key.clone(),
+			);
+			assert_noop!(err, Error::UnknownAppKey);
+
+			let err = DataAvailability::create_child_application_key(alice, app_key(b"Avail"), key);
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+
+	#[test]
+	fn children_are_bounded_and_cannot_nest() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let parent_key = app_key(b"Reserved-1");
+			let max = <Test as crate::Config>::MaxAppChildren::get();
+
+			for i in 0..max {
+				let key = app_key(format!("Child-{i}").as_bytes());
+				assert_ok!(DataAvailability::create_child_application_key(
+					bob.clone(),
+					parent_key.clone(),
+					key
+				));
+			}
+			assert_eq!(AppChildren::<Test>::get(AppId(1)).len() as u32, max);
+
+			let err = DataAvailability::create_child_application_key(
+				bob.clone(),
+				parent_key,
+				app_key(b"One too many"),
+			);
+			assert_noop!(err, Error::TooManyChildApps);
+
+			let err = DataAvailability::create_child_application_key(
+				bob,
+				app_key(b"Child-0"),
+				app_key(b"Grandchild"),
+			);
+			assert_noop!(err, Error::NestedChildApp);
+		})
+	}
+
+	#[test]
+	fn parents_are_removed_after_their_children() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let parent_key = app_key(b"Reserved-1");
+			let key = app_key(b"Child");
+			let id = DataAvailability::peek_next_application_id();
+
+			assert_ok!(DataAvailability::create_child_application_key(
+				bob.clone(),
+				parent_key.clone(),
+				key.clone()
+			));
+
+			let err = DataAvailability::remove_application_key(bob.clone(), parent_key.clone());
+			assert_noop!(err, Error::AppHasChildApps);
+
+			assert_ok!(DataAvailability::remove_application_key(bob.clone(), key));
+			assert_eq!(AppParents::<Test>::get(id), None);
+			assert_eq!(DataAvailability::app_subtree(AppId(1)), vec![AppId(1)]);
+			assert_ok!(DataAvailability::remove_application_key(bob, parent_key));
+		})
+	}
+}
+
+mod pause_application {
+	use super::*;
+	use crate::DataBatchFor;
+	use avail_core::AppId;
+
+	#[test]
+	fn pause_application() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+
+			assert_ok!(DataAvailability::pause_application(
+				bob.clone(),
+				key.clone()
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::ApplicationPaused {
+				key: key.clone(),
+				id: AppId(1),
+			}));
+			assert!(DataAvailability::is_paused(AppId(1)));
+
+			let err = DataAvailability::pause_application(bob.clone(), key.clone());
+			assert_noop!(err, Error::AppAlreadyPaused);
+
+			let data = AppDataFor::<Test>::try_from(b"Data".to_vec()).unwrap();
+			let batch = DataBatchFor::<Test>::try_from(vec![(AppId(1), data)]).unwrap();
+			let err = DataAvailability::submit_data_batch(bob.clone(), batch.clone());
+			assert_noop!(err, Error::PausedAppId);
+
+			assert_ok!(DataAvailability::unpause_application(
+				bob.clone(),
+				key.clone()
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::ApplicationUnpaused {
+				key: key.clone(),
+				id: AppId(1),
+			}));
+			assert!(!DataAvailability::is_paused(AppId(1)));
+			assert_ok!(DataAvailability::submit_data_batch(bob.clone(), batch));
+
+			let err = DataAvailability::unpause_application(bob, key);
+			assert_noop!(err, Error::AppNotPaused);
+		})
+	}
+
+	#[test]
+	fn only_owner_pauses_application() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+
+			let err = DataAvailability::pause_application(alice.clone(), key.clone());
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			assert_ok!(DataAvailability::pause_application(
+				RawOrigin::Signed(BOB).into(),
+				key.clone()
+			));
+			let err = DataAvailability::unpause_application(alice.clone(), key);
+			assert_noop!(err, Error::NotAppKeyOwner);
+
+			let avail = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+			let err = DataAvailability::pause_application(alice, avail);
+			assert_noop!(err, Error::AppIdCannotBeRestricted);
+		})
+	}
+}
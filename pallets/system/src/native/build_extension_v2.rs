@@ -4,7 +4,7 @@
 // !!!!
 #![cfg(feature = "std")]
 
-use super::hosted_header_builder::MIN_WIDTH;
+use super::{commitment_backend, hosted_header_builder::MIN_WIDTH};
 use crate::limits::BlockLength;
 use avail_base::metrics::avail::{
 	HeaderExtensionBuilderMetrics as Metrics, MetricObserver, ObserveKind,
@@ -14,21 +14,14 @@ use avail_core::{
 	header::{extension as he, HeaderExtension},
 	kate_commitment as kc, HeaderVersion,
 };
-use kate::{
-	couscous::multiproof_params,
-	gridgen::{AsBytes, EvaluationGrid},
-	pmp::m1_blst::M1NoPrecomp,
-	Seed,
-};
+use kate::{gridgen::EvaluationGrid, Seed};
 use sp_core::H256;
 use sp_runtime::SaturatedConversion;
-use std::{sync::OnceLock, vec::Vec};
+use std::vec::Vec;
 
 #[cfg(feature = "testing-environment")]
 use avail_base::testing_env::*;
 
-static PMP: OnceLock<M1NoPrecomp> = OnceLock::new();
-
 fn build_grid(
 	submitted: Vec<AppExtrinsic>,
 	block_length: BlockLength,
@@ -57,7 +50,7 @@ fn build_grid(
 	Ok(grid)
 }
 
-fn build_commitment(grid: &EvaluationGrid) -> Result<Vec<u8>, String> {
+fn build_commitment(grid: &EvaluationGrid, version: HeaderVersion) -> Result<Vec<u8>, String> {
 	let _metric_observer = MetricObserver::new(ObserveKind::HECommitment);
 
 	#[cfg(feature = "testing-environment")]
@@ -69,26 +62,7 @@ fn build_commitment(grid: &EvaluationGrid) -> Result<Vec<u8>, String> {
 		}
 	}
 
-	// couscous has pp for degree upto 1024
-	let pmp = PMP.get_or_init(multiproof_params);
-
-	let poly_grid = grid
-		.make_polynomial_grid()
-		.map_err(|e| format!("Make polynomial grid failed: {e:?}"))?;
-
-	let extended_grid = poly_grid
-		.extended_commitments(pmp, 2)
-		.map_err(|e| format!("Grid extension failed: {e:?}"))?;
-
-	let mut commitment = Vec::new();
-	for c in extended_grid.iter() {
-		match c.to_bytes() {
-			Ok(bytes) => commitment.extend(bytes),
-			Err(e) => return Err(format!("Commitment serialization failed: {:?}", e)),
-		}
-	}
-
-	Ok(commitment)
+	commitment_backend::commit(version, grid)
 }
 
 #[allow(unused_mut)]
@@ -133,7 +107,7 @@ pub fn build_extension(
 		},
 	};
 
-	let maybe_commitment = build_commitment(&grid);
+	let maybe_commitment = build_commitment(&grid, version);
 
 	// We get the commitment or return an empty header in case of an error
 	let commitment = match maybe_commitment {
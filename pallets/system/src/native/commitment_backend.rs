@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+//! Commitment schemes of the grid of a block.
+//!
+//! The header extension of each version is committed with a single backend, so alternative
+//! schemes can be prototyped behind [`CommitmentBackend`] and activated by a new header version
+//! without changing the commitments of existing blocks.
+
+use avail_base::{
+	commitment_pool,
+	metrics::avail::{MetricObserver, ObserveKind},
+};
+use avail_core::HeaderVersion;
+use kate::{
+	couscous::multiproof_params,
+	gridgen::{AsBytes, EvaluationGrid, PolynomialGrid},
+	pmp::m1_blst::M1NoPrecomp,
+};
+use std::{sync::OnceLock, vec::Vec};
+
+static PMP: OnceLock<M1NoPrecomp> = OnceLock::new();
+
+/// Commits to the rows of the grid of a block.
+pub trait CommitmentBackend {
+	/// Returns the commitments of the rows of `grid` and of its extension, serialized like the
+	/// commitment of a header.
+	fn commit(grid: &EvaluationGrid) -> Result<Vec<u8>, String>;
+}
+
+/// KZG commitments of `kate` over BLS12-381, with the rows extended twice.
+pub struct Kate;
+
+impl Kate {
+	/// Interpolates the rows of `grid` into polynomials.
+	pub fn polynomial_grid(grid: &EvaluationGrid) -> Result<PolynomialGrid, String> {
+		grid.make_polynomial_grid()
+			.map_err(|e| format!("Make polynomial grid failed: {e:?}"))
+	}
+
+	/// Returns the serialized commitments of the rows of `poly_grid` and of its extension.
+	pub fn extended_commitments(poly_grid: &PolynomialGrid) -> Result<Vec<u8>, String> {
+		// couscous has pp for degree upto 1024
+		let pmp = PMP.get_or_init(multiproof_params);
+
+		let extended_grid = commitment_pool::install(|| poly_grid.extended_commitments(pmp, 2))
+			.map_err(|e| format!("Grid extension failed: {e:?}"))?;
+
+		let mut commitment = Vec::new();
+		for c in extended_grid.iter() {
+			match c.to_bytes() {
+				Ok(bytes) => commitment.extend(bytes),
+				Err(e) => return Err(format!("Commitment serialization failed: {:?}", e)),
+			}
+		}
+
+		Ok(commitment)
+	}
+}
+
+impl CommitmentBackend for Kate {
+	fn commit(grid: &EvaluationGrid) -> Result<Vec<u8>, String> {
+		let poly_grid = {
+			let _metric_observer = MetricObserver::new(ObserveKind::HEPolynomialGrid);
+			Self::polynomial_grid(grid)?
+		};
+
+		let _metric_observer = MetricObserver::new(ObserveKind::HEExtendedCommitments);
+		Self::extended_commitments(&poly_grid)
+	}
+}
+
+/// Returns the commitments of `grid` with the backend of the header `version`.
+pub fn commit(version: HeaderVersion, grid: &EvaluationGrid) -> Result<Vec<u8>, String> {
+	match version {
+		HeaderVersion::V3 => Kate::commit(grid),
+	}
+}
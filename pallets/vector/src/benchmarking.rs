@@ -1,7 +1,12 @@
 use crate::{
-	state::Configuration, BalanceOf, Call, Config, ConfigurationStorage, ExecutionStateRoots,
-	FunctionIds, FunctionInput, FunctionOutput, FunctionProof, Headers, Pallet,
-	RotateVerificationKey, StepVerificationKey, Updater, ValidProof,
+	state::{
+		AssetInfo, AssetKind, Configuration, ConsensusFork, ExecutionMode,
+		ExecutionStateRootBranch, FinalityMode, MessageAbiVersion, PendingVerificationKey,
+		RateLimit, RelayerReward, SlotLayout, SlotTiming, SyncCommitteeBranch, VerificationKeyKind,
+	},
+	BalanceOf, Call, Config, ConfigurationStorage, ExecutionStateRoots, FunctionIds, FunctionInput,
+	FunctionOutput, FunctionProof, Headers, Pallet, RotateVerificationKey, StepVerificationKey,
+	Updater, ValidProof,
 };
 use avail_core::data_proof::BOUNDED_DATA_MAX_LENGTH;
 use avail_core::data_proof::{AddressedMessage, Message};
@@ -10,7 +15,7 @@ use frame_benchmarking::{
 };
 use frame_support::traits::DefensiveTruncateFrom;
 use frame_support::{traits::Currency, BoundedVec};
-use frame_system::RawOrigin;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use hex_literal::hex;
 use sp_core::{Get, H256};
 use sp_runtime::traits::Bounded;
@@ -165,13 +170,21 @@ pub const ROTATE_VK: &str = r#"{"vk_json":{
 }}"#;
 
 #[benchmarks(where
-[u8; 32]: From << T as frame_system::Config >::AccountId >,
 < T as frame_system::Config >::AccountId: From < [u8; 32] >,
 )]
 mod benchmarks {
 	use super::*;
-	use crate::Broadcasters;
+	use crate::storage_utils::{ExecutionFailureReason, FreezeReason, MessageStatusEnum};
+	use crate::{
+		Bonds, BroadcasterCodeHashes, Broadcasters, EmergencyPauseExpiry, ExecutionFees,
+		FeeMultipliers, MessageStatus, MessageTtl, MessageVolume, Operators, PausedDomains,
+		PendingVerificationKeys, ProvenMessages, ProverBond, Relayers, SlotSubmitters,
+		SourceChainFrozen, UnbondingUntil, WhitelistedDomains, MAX_INVALIDATED_MESSAGES,
+		MAX_INVALIDATED_SLOTS, MAX_OPERATORS, MAX_PROOF_BYTES,
+	};
+	use frame_support::traits::EnsureOrigin;
 	use sp_runtime::traits::AccountIdConversion;
+	use sp_std::vec::Vec;
 
 	#[benchmark]
 	fn send_message_arbitrary_message(
@@ -213,6 +226,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(
 			RawOrigin::Root,
+			2,
 			0,
 			BoundedVec::truncate_from(
 				hex!("0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df").to_vec(),
@@ -252,7 +266,7 @@ mod benchmarks {
 		};
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, config);
+		_(RawOrigin::Root, 2, config);
 
 		Ok(())
 	}
@@ -271,13 +285,16 @@ mod benchmarks {
 			hex!("0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df").to_vec(),
 		);
 
-		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), 931, hash).unwrap();
+		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), 2, 931, hash).unwrap();
 
 		Updater::<T>::set(H256(ACCOUNT1));
-		ConfigurationStorage::<T>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		ConfigurationStorage::<T>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 
 		let account = T::AccountId::from(ACCOUNT1);
 		let origin = RawOrigin::Signed(account.clone());
@@ -295,6 +312,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		fulfill_call(
 			origin,
+			2,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -312,15 +330,19 @@ mod benchmarks {
 			hex!("0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df").to_vec(),
 		);
 
-		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), 931, hash).unwrap();
+		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), 2, 931, hash).unwrap();
 
 		Updater::<T>::set(H256(ACCOUNT1));
-		ConfigurationStorage::<T>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 342,
-		});
+		ConfigurationStorage::<T>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
 
 		Headers::<T>::set(
+			2,
 			slot,
 			H256(hex!(
 				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
@@ -343,6 +365,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		fulfill_call(
 			origin,
+			2,
 			ROTATE_FUNCTION_ID,
 			get_valid_rotate_input(),
 			get_valid_rotate_output(),
@@ -364,6 +387,7 @@ mod benchmarks {
 		);
 
 		ExecutionStateRoots::<T>::set(
+			2,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -375,6 +399,8 @@ mod benchmarks {
 		T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value() / 2u32.into());
 		T::Currency::make_free_balance_be(&pot, BalanceOf::<T>::max_value() / 2u32.into());
 
+		ExecutionFees::<T>::insert(H256::zero(), 1_000u128);
+
 		let account_proof = get_valid_account_proof();
 		let storage_proof = get_valid_storage_proof();
 		let message = get_valid_message();
@@ -400,6 +426,7 @@ mod benchmarks {
 		let slot = 5085118;
 
 		ExecutionStateRoots::<T>::set(
+			2,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -466,6 +493,631 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_broadcaster_code_hash() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, 2, Some(H256([1u8; 32])));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_account_proof() -> Result<(), BenchmarkError> {
+		let slot = 8581263;
+		Broadcasters::<T>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		BroadcasterCodeHashes::<T>::set(
+			2,
+			Some(H256(hex!(
+				"54ccfa149cf4cf471e53bc5dfbfe8ae5c27fa96b0599f2747ffe6e1fb8bd4396"
+			))),
+		);
+		ExecutionStateRoots::<T>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		let account_proof = get_valid_account_proof();
+
+		#[block]
+		{
+			Pallet::<T>::broadcaster_storage_root(2, slot, &account_proof)?;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_message_ttl() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, Some(86400));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn mark_message_refundable() -> Result<(), BenchmarkError> {
+		let hash = H256(hex!(
+			"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+		));
+		Broadcasters::<T>::set(2, hash);
+
+		let slot = 5085118;
+		ExecutionStateRoots::<T>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		// Every message is expired with a TTL of zero.
+		MessageTtl::<T>::set(Some(0));
+
+		let account = T::AccountId::from(ACCOUNT1);
+		let origin = RawOrigin::Signed(account.clone());
+		let account_proof = get_valid_amb_account_proof();
+		let storage_proof = get_valid_amb_storage_proof();
+		let message = get_valid_amb_message();
+
+		#[extrinsic_call]
+		_(origin, slot, message, account_proof, storage_proof);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn pause_domain() -> Result<(), BenchmarkError> {
+		let origin = T::DomainPauseOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 2);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unpause_domain() -> Result<(), BenchmarkError> {
+		let origin = T::DomainPauseOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		PausedDomains::<T>::set(2, true);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 2);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn adjust_fee_multipliers(d: Linear<0, 100>) -> Result<(), BenchmarkError> {
+		let congested_volume = T::MessageVolumeTarget::get().saturating_add(1);
+		for domain in 0..d {
+			if domain % 2 == 0 {
+				MessageVolume::<T>::insert(domain, congested_volume);
+			} else {
+				FeeMultipliers::<T>::insert(domain, 2);
+			}
+		}
+
+		#[block]
+		{
+			Pallet::<T>::adjust_fee_multipliers();
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_relayer_reward() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let value = RelayerReward {
+			flat: 1_000u32.into(),
+			per_proof_byte: 1u32.into(),
+		};
+
+		#[extrinsic_call]
+		_(origin, value);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_message_fee() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, 1_000u32.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_whitelisted_domain() -> Result<(), BenchmarkError> {
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let domains = (0..9_999)
+			.filter(|domain| *domain != 5_000)
+			.collect::<Vec<u32>>();
+		WhitelistedDomains::<T>::put(BoundedVec::truncate_from(domains));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 5_000);
+
+		assert!(WhitelistedDomains::<T>::get().contains(&5_000));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_whitelisted_domain() -> Result<(), BenchmarkError> {
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let domains = (0..10_000).collect::<Vec<u32>>();
+		WhitelistedDomains::<T>::put(BoundedVec::truncate_from(domains));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0);
+
+		assert!(!WhitelistedDomains::<T>::get().contains(&0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_sync_committee_branch() -> Result<(), BenchmarkError> {
+		let branch = SyncCommitteeBranch {
+			state_root: H256::repeat_byte(1),
+			state_root_branch: BoundedVec::truncate_from(vec![H256::repeat_byte(2); 3]),
+			sync_committee_root: H256::repeat_byte(3),
+			sync_committee_branch: BoundedVec::truncate_from(vec![H256::repeat_byte(4); 6]),
+		};
+
+		#[block]
+		{
+			// The branch is not valid but every node of it is hashed anyway.
+			crate::ssz::verify_sync_committee_branch(
+				H256::repeat_byte(5),
+				ConsensusFork::Electra,
+				&branch,
+			);
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_consensus_fork() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, Some(ConsensusFork::Deneb));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_execution_state_root() -> Result<(), BenchmarkError> {
+		let branch = ExecutionStateRootBranch {
+			fork_digest: [0u8; 4],
+			body_root: H256::repeat_byte(1),
+			body_root_branch: BoundedVec::truncate_from(vec![H256::repeat_byte(2); 3]),
+			execution_state_root_branch: BoundedVec::truncate_from(vec![H256::repeat_byte(3); 9]),
+		};
+
+		#[block]
+		{
+			// The branch is not valid but every node of it is hashed anyway.
+			crate::ssz::verify_execution_state_root(
+				H256::repeat_byte(4),
+				H256::repeat_byte(5),
+				ConsensusFork::Electra,
+				&branch,
+			);
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fork_version() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, [4, 0, 0, 0], Some(ConsensusFork::Deneb));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn retry_execute() -> Result<(), BenchmarkError> {
+		let message = get_valid_message();
+		let message_root = H256(sp_io::hashing::keccak_256(
+			message.clone().abi_encode().as_slice(),
+		));
+		MessageStatus::<T>::set(
+			message_root,
+			MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::TransferFailed),
+		);
+		T::Currency::make_free_balance_be(
+			&Pallet::<T>::account_id(),
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		let origin = RawOrigin::Signed(T::AccountId::from(ACCOUNT1));
+
+		#[extrinsic_call]
+		_(origin, message);
+
+		assert_eq!(
+			MessageStatus::<T>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_finality_verification_key() -> Result<(), BenchmarkError> {
+		let value = Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, value);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_finality_mode() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, FinalityMode::ZkFinality);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_execution_mode() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, ExecutionMode::ReceiptProof);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_asset_rate_limit() -> Result<(), BenchmarkError> {
+		let limit = RateLimit {
+			max_amount: u128::MAX,
+			window: 100u32.into(),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, H256::zero(), Some(limit));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_asset_info() -> Result<(), BenchmarkError> {
+		let info = AssetInfo {
+			kind: AssetKind::Local(1),
+			decimals: 18,
+			min_amount: 1,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, H256::repeat_byte(1), Some(info));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn emergency_pause() -> Result<(), BenchmarkError> {
+		let origin = T::EmergencyPauseOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let duration = T::MaxEmergencyPauseDuration::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, duration);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn emergency_unpause() -> Result<(), BenchmarkError> {
+		let origin = T::EmergencyPauseOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		EmergencyPauseExpiry::<T>::put(T::MaxEmergencyPauseDuration::get());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_confirmation_depth() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, 64);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_ordered_delivery() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, H256::zero(), Some(1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_operator() -> Result<(), BenchmarkError> {
+		let operators = (1..MAX_OPERATORS).map(|i| H256::from_low_u64_be(i as u64));
+		Operators::<T>::put(BoundedVec::truncate_from(operators.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, H256::repeat_byte(0xff));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_operator() -> Result<(), BenchmarkError> {
+		let operators = (1..=MAX_OPERATORS).map(|i| H256::from_low_u64_be(i as u64));
+		Operators::<T>::put(BoundedVec::truncate_from(operators.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, H256::from_low_u64_be(1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rotate_operator() -> Result<(), BenchmarkError> {
+		let operators = (1..=MAX_OPERATORS).map(|i| H256::from_low_u64_be(i as u64));
+		Operators::<T>::put(BoundedVec::truncate_from(operators.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			H256::from_low_u64_be(1),
+			H256::repeat_byte(0xff),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fulfill_threshold() -> Result<(), BenchmarkError> {
+		let operators = (1..=MAX_OPERATORS).map(|i| H256::from_low_u64_be(i as u64));
+		Operators::<T>::put(BoundedVec::truncate_from(operators.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, MAX_OPERATORS);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_prover_bond() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 1_000_000_000u32.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn bond() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		ProverBond::<T>::put(BalanceOf::<T>::from(1_000_000_000u32));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unbond() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		Bonds::<T>::insert(&caller, BalanceOf::<T>::from(1_000_000_000u32));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_bond() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		ProverBond::<T>::put(BalanceOf::<T>::from(1_000_000_000u32));
+		Pallet::<T>::bond(RawOrigin::Signed(caller.clone()).into())?;
+		UnbondingUntil::<T>::insert(&caller, BlockNumberFor::<T>::from(0u32));
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn slash_submission() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		ProverBond::<T>::put(BalanceOf::<T>::from(1_000_000_000u32));
+		Pallet::<T>::bond(RawOrigin::Signed(caller.clone()).into())?;
+		UnbondingUntil::<T>::insert(&caller, BlockNumberFor::<T>::from(0u32));
+		SlotSubmitters::<T>::insert(2u32, 7634848u64, caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, 7634848);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_slot_layout() -> Result<(), BenchmarkError> {
+		let layout = SlotLayout { message_mapping: 3 };
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, Some(layout));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_slot_timing() -> Result<(), BenchmarkError> {
+		let timing = SlotTiming {
+			genesis_time: 1606824023,
+			seconds_per_slot: 12,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, Some(timing));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn invalidate_execution_state_roots(
+		s: Linear<1, MAX_INVALIDATED_SLOTS>,
+		m: Linear<0, MAX_INVALIDATED_MESSAGES>,
+	) -> Result<(), BenchmarkError> {
+		let origin = T::StateRootRollbackOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		for slot in 0..s as u64 {
+			ExecutionStateRoots::<T>::insert(2, slot, H256::repeat_byte(1));
+		}
+		for i in 0..m {
+			let message_root = H256::from_low_u64_be(i as u64);
+			ProvenMessages::<T>::insert((2u32, 0u64, message_root), ());
+			MessageStatus::<T>::insert(message_root, MessageStatusEnum::ExecutionSucceeded);
+		}
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 2, 0, s as u64 - 1, m);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_execution_fee() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, H256::zero(), Some(1_000));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_message_abi_version() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, MessageAbiVersion::V1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn bind_address() -> Result<(), BenchmarkError> {
+		let origin = T::AddressBindingOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let account: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(account));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_permissioned_relaying() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_relayer() -> Result<(), BenchmarkError> {
+		let relayer: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, relayer.clone());
+
+		assert!(Relayers::<T>::contains_key(relayer));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_relayer() -> Result<(), BenchmarkError> {
+		let relayer: T::AccountId = whitelisted_caller();
+		Relayers::<T>::insert(&relayer, ());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, relayer.clone());
+
+		assert!(!Relayers::<T>::contains_key(relayer));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn freeze_source_chain() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2, FreezeReason::FaultyStateRoot);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unfreeze_source_chain() -> Result<(), BenchmarkError> {
+		SourceChainFrozen::<T>::set(2, true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_verification_key() -> Result<(), BenchmarkError> {
+		let key = BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap();
+		let pending = PendingVerificationKey {
+			key,
+			version: 2,
+			activation: BlockNumberFor::<T>::max_value(),
+		};
+		PendingVerificationKeys::<T>::insert(VerificationKeyKind::Step, pending);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, VerificationKeyKind::Step);
+
+		assert!(PendingVerificationKeys::<T>::get(VerificationKeyKind::Step).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_storage_proof(b: Linear<0, MAX_PROOF_BYTES>) -> Result<(), BenchmarkError> {
+		// Nodes of the largest size, so the memory database hashes `b` bytes.
+		let proof = vec![0u8; b as usize]
+			.chunks(2048)
+			.map(|node| node.to_vec())
+			.collect::<Vec<_>>();
+
+		#[block]
+		{
+			let _ = crate::storage_utils::get_storage_value(H256::zero(), H256::zero(), proof);
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
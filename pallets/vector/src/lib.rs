@@ -1,38 +1,75 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "512"]
 
-use crate::{storage_utils::MessageStatusEnum, verifier::Verifier};
+use crate::verifier::Verifier;
 use avail_base::{MemoryTemporaryStorage, ProvidePostInherent};
-use avail_core::data_proof::{tx_uid, AddressedMessage, Message, MessageType};
+use avail_core::data_proof::{
+	tx_uid, AddressedMessage, Message, MessageType, BOUNDED_DATA_MAX_LENGTH,
+};
 
-use codec::Compact;
+use codec::{Compact, DecodeLimit};
 use frame_support::{
+	dispatch::DispatchFeeModifier,
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, UnixTime},
-	PalletId,
+	traits::{Currency, ExistenceRequirement, LockIdentifier, UnixTime, WithdrawReasons},
+	Deserialize, PalletId, Serialize,
 };
-use sp_core::H256;
+use sp_core::{H256, U256};
 use sp_runtime::SaturatedConversion;
 use sp_std::{vec, vec::Vec};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod constants;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
+pub mod offchain;
+mod ssz;
 mod state;
 mod storage_utils;
 #[cfg(test)]
 mod tests;
+pub mod traits;
 mod verifier;
 mod weights;
 
 pub use pallet::*;
+pub use storage_utils::{
+	ExecutionFailureReason, FreezeOrigin, FreezeReason, FreezeStatus, MessageOutcome,
+	MessageStatusEnum, VerifiedHead, WithdrawalReceipt,
+};
+pub use traits::{
+	decode_message_data, AccountMapping, AddressMapping, BridgedAssets, FungiblesAdapter,
+	HashedAddress, MessageHandler, OnMessageExecuted, OnNewVerifiedHead, TruncatedAddress,
+};
 
 pub type FunctionInput = BoundedVec<u8, ConstU32<256>>;
 pub type FunctionOutput = BoundedVec<u8, ConstU32<512>>;
 pub type FunctionProof = BoundedVec<u8, ConstU32<1048>>;
 pub type ValidProof = BoundedVec<BoundedVec<u8, ConstU32<2048>>, ConstU32<32>>;
+pub type ExecuteBatch = BoundedVec<(AddressedMessage, ValidProof), ConstU32<MAX_EXECUTE_BATCH_LEN>>;
+pub type SendBatch = BoundedVec<(Message, H256), ConstU32<MAX_SEND_BATCH_LEN>>;
+
+/// Light client domain set up at genesis next to the broadcaster domain.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainGenesisConfig {
+	pub domain: u32,
+	pub broadcaster: H256,
+	pub slots_per_period: u64,
+	pub finality_threshold: u16,
+	/// Sync committee period of `sync_committee_poseidon`.
+	pub period: u64,
+	pub sync_committee_poseidon: U256,
+}
+
+/// Id of the message at `index` of a `send_messages` batch sent by extrinsic `tx_index` of
+/// `block`. The first message has the id of the extrinsic and the following ones are spaced
+/// above the extrinsic indices, so ids stay unique while a block has fewer than 2^16 extrinsics.
+pub fn batch_message_id(block: u32, tx_index: u32, index: u32) -> u64 {
+	tx_uid(block, (index << 16) | tx_index)
+}
 
 // Avail asset is supported for now
 pub const SUPPORTED_ASSET_ID: H256 = H256::zero();
@@ -40,6 +77,34 @@ pub const FAILED_SEND_MSG_ID: &[u8] = b"vector:failed_send_msg_txs";
 pub const LOG_TARGET: &str = "runtime::vector";
 pub const ROTATE_POSEIDON_OUTPUT_LENGTH: u32 = 32;
 pub const STEP_OUTPUT_LENGTH: u32 = 74;
+pub const MAX_EXECUTE_BATCH_LEN: u32 = 16;
+pub const MAX_SEND_BATCH_LEN: u32 = 16;
+pub const MAX_OPERATORS: u32 = 16;
+pub const MAX_INVALIDATED_SLOTS: u32 = 256;
+pub const MAX_INVALIDATED_MESSAGES: u32 = 1_000;
+/// Maximum number of bytes of the nodes of a trie proof.
+pub const MAX_PROOF_BYTES: u32 = 32 * 2048;
+/// Maximum length of the SCALE encoded preimage of a sent message.
+pub const MAX_MESSAGE_PREIMAGE_LEN: u32 = BOUNDED_DATA_MAX_LENGTH + 256;
+/// Identifier of the lock that holds the bond of a prover.
+pub const PROVER_BOND_ID: LockIdentifier = *b"vec/bond";
+/// Maximum nesting depth of a bridged runtime call.
+pub const MAX_BRIDGED_CALL_DECODE_DEPTH: u32 = 64;
+/// Signature of the event the broadcaster emits for every sent message, with the message id and
+/// the message root as indexed topics.
+pub const MESSAGE_SENT_EVENT_SIGNATURE: &[u8] = b"MessageSent(uint256,bytes32)";
+
+/// Topic of the message id in the `MessageSent` event.
+fn message_id_topic(message_id: u64) -> H256 {
+	let mut topic = [0u8; 32];
+	topic[24..].copy_from_slice(&message_id.to_be_bytes());
+	H256(topic)
+}
+
+/// Total number of bytes of all the nodes in `proof`.
+fn proof_len(proof: &ValidProof) -> u32 {
+	proof.iter().map(|node| node.len() as u32).sum()
+}
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -49,21 +114,30 @@ pub mod pallet {
 	use ethabi::Token;
 	use ethabi::Token::Uint;
 	use frame_support::dispatch::GetDispatchInfo;
-	use frame_support::traits::{LockableCurrency, UnfilteredDispatchable};
+	use frame_support::storage::with_storage_layer;
+	use frame_support::traits::{Imbalance, LockableCurrency, UnfilteredDispatchable};
 	use frame_support::{pallet_prelude::ValueQuery, DefaultNoBound};
 	use frame_system::pallet_prelude::*;
 	use primitive_types::H160;
 	use primitive_types::{H256, U256};
 	use sp_io::hashing::keccak_256;
 	use sp_io::hashing::sha2_256;
-	use sp_runtime::traits::AccountIdConversion;
+	use sp_runtime::traits::{AccountIdConversion, One, Zero};
 	pub use weights::WeightInfo;
 
-	use crate::state::Configuration;
+	use crate::ssz::{
+		compute_fork_digest, verify_execution_state_root, verify_receipts_root,
+		verify_sync_committee_branch,
+	};
 	use crate::state::{
-		parse_rotate_output, parse_step_output, VerifiedRotate, VerifiedStep, VerifiedStepOutput,
+		AssetInfo, AssetKind, BridgedVolume, Configuration, ConsensusFork, ExecutionMode,
+		ExecutionStateRootBranch, FinalityMode, MessageAbiVersion, PendingVerificationKey,
+		RateLimit, ReceiptProof, RelayerReward, SlotLayout, SlotTiming, SyncCommitteeBranch,
+		VerificationKeyKind,
+	};
+	use crate::storage_utils::{
+		get_code_hash, get_receipt_log, get_storage_root, get_storage_value,
 	};
-	use crate::storage_utils::{get_storage_root, get_storage_value};
 	use crate::verifier::encode_packed;
 
 	use super::*;
@@ -113,42 +187,189 @@ pub mod pallet {
 		CannotParseOutputData,
 		/// Cannot get current message id
 		CurrentMessageIdNotFound,
+		/// Cannot get code hash of the broadcaster contract from the account proof
+		CannotGetCodeHash,
+		/// Code hash of the broadcaster contract does not match the approved one
+		BroadcasterCodeHashMismatch,
+		/// Batch does not contain any messages
+		EmptyBatch,
+		/// All messages in a batch must originate from the same domain
+		BatchOriginDomainMismatch,
+		/// Message is older than the configured TTL and cannot be executed anymore
+		MessageExpired,
+		/// Message has not expired yet and cannot be marked as refundable
+		MessageNotExpired,
+		/// Domain is paused and its messages cannot be sent or executed
+		DomainPaused,
+		/// Bridge is paused in an emergency and no messages can be sent or executed
+		EmergencyPaused,
+		/// Emergency pause is longer than the maximum emergency pause duration
+		EmergencyPauseTooLong,
+		/// Slot is not yet old enough to prove messages against
+		SlotNotConfirmed,
+		/// Domain is already whitelisted.
+		DomainAlreadyWhitelisted,
+		/// Domain is not whitelisted.
+		DomainNotWhitelisted,
+		/// Maximum number of whitelisted domains is reached.
+		TooManyWhitelistedDomains,
+		/// Rotates of a domain with a known consensus fork must carry a sync committee branch
+		SyncCommitteeBranchRequired,
+		/// Sync committee branch does not prove the next sync committee of the finalized header
+		InvalidSyncCommitteeBranch,
+		/// Consensus fork of the domain is not set
+		ConsensusForkNotSet,
+		/// Fork digest is not registered for the domain
+		UnknownForkDigest,
+		/// Execution state root branch does not prove the execution state root of the finalized header
+		InvalidExecutionStateRootBranch,
+		/// Message did not fail to execute and cannot be retried
+		MessageNotFailed,
+		/// Retried message failed to execute again
+		ExecutionStillFailing,
+		/// Finality of the domain is proven in a different mode
+		FinalityModeMismatch,
+		/// Messages of the domain are proven in a different mode
+		ExecutionModeMismatch,
+		/// Bridged amount is below the minimum amount of the asset
+		AmountBelowMinimum,
+		/// Receipts root branch does not prove the receipts root of the finalized header
+		InvalidReceiptsRootBranch,
+		/// Cannot get the log from the receipt proof
+		CannotGetReceiptLog,
+		/// Log is not the `MessageSent` log of the message emitted by the broadcaster
+		InvalidMessageLog,
+		/// Bridged runtime call cannot be decoded
+		CannotDecodeBridgedCall,
+		/// Bridged runtime call is heavier than the maximum bridged call weight
+		BridgedCallTooHeavy,
+		/// Batch of messages to send is empty
+		EmptySendBatch,
+		/// Extrinsic index is too high to assign unique ids to a batch of messages
+		BatchMessageIdOverflow,
+		/// Account is already an operator
+		AlreadyOperator,
+		/// Account is not an operator
+		NotOperator,
+		/// Maximum number of operators is reached
+		TooManyOperators,
+		/// Fulfill threshold is higher than the number of operators
+		FulfillThresholdTooHigh,
+		/// Operator already approved the fulfill call
+		AlreadyApproved,
+		/// Account has not bonded the prover bond
+		NotBonded,
+		/// Account has already bonded
+		AlreadyBonded,
+		/// Bond of the account is not unbonding
+		NotUnbonding,
+		/// Unbonding period of the bond is not over yet
+		BondStillUnbonding,
+		/// No submitter is recorded for the slot
+		SubmissionNotFound,
+		/// Free balance is lower than the prover bond
+		InsufficientBalance,
+		/// Slot range is empty or longer than MAX_INVALIDATED_SLOTS
+		InvalidSlotRange,
+		/// More messages were proven in the slot range than allowed
+		TooManyInvalidatedMessages,
+		/// Only registered relayers can execute messages
+		RelayerNotAllowed,
+		/// Account is already a registered relayer
+		AlreadyRelayer,
+		/// Account is not a registered relayer
+		NotRelayer,
+		/// Source chain is not frozen
+		SourceChainNotFrozen,
+		/// No verification key is pending for the circuit
+		NoPendingVerificationKey,
 	}
 
+	/// Origin of runtime calls sent by `sender` on the source chain `origin_domain`.
+	#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct BridgedOrigin(pub u32, pub H256);
+
+	#[pallet::origin]
+	pub type Origin = BridgedOrigin;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Emit event once the head is updated.
 		HeadUpdated {
+			domain: u32,
 			slot: u64,
 			finalization_root: H256,
 			execution_state_root: H256,
 		},
 		/// Emit event once the sync committee updates.
-		SyncCommitteeUpdated { period: u64, root: U256 },
+		SyncCommitteeUpdated {
+			domain: u32,
+			period: u64,
+			root: U256,
+		},
 		/// Emit when new updater is set.
 		BroadcasterUpdated { old: H256, new: H256, domain: u32 },
-		/// Emit when message gets executed.
+		/// Emit when message gets executed. `message_root` is the keccak hash of the ABI encoded
+		/// message and `result` is the outcome of a bridged runtime call, `Ok` for other messages.
 		MessageExecuted {
 			from: H256,
 			to: H256,
 			message_id: u64,
 			message_root: H256,
+			origin_domain: u32,
+			destination_domain: u32,
+			result: DispatchResult,
+		},
+		/// Emit when a proven message fails to execute and can be retried.
+		MessageExecutionFailed {
+			from: H256,
+			to: H256,
+			message_id: u64,
+			message_root: H256,
+			reason: ExecutionFailureReason,
 		},
 		/// Emit if source chain gets frozen.
 		SourceChainFrozen { source_chain_id: u32, frozen: bool },
-		/// Emit when message is submitted.
+		/// Emit when message is submitted. `message_hash` is the keccak hash of the ABI encoded
+		/// message and `leaf_index` its index in the bridge root of the block.
 		MessageSubmitted {
 			from: T::AccountId,
 			to: H256,
 			message_type: MessageType,
+			origin_domain: u32,
 			destination_domain: u32,
 			message_id: u64,
+			message_hash: H256,
+			leaf_index: u32,
 		},
 		/// Emit whitelisted domains that are updated.
 		WhitelistedDomainsUpdated,
+		/// Emit when the SSZ root of the sync committee of a period is verified.
+		SyncCommitteeHashUpdated {
+			domain: u32,
+			period: u64,
+			root: H256,
+		},
+		/// Emit when the consensus fork of a domain is updated.
+		ConsensusForkUpdated {
+			domain: u32,
+			fork: Option<ConsensusFork>,
+		},
+		/// Emit when a fork version of a domain is registered or removed.
+		ForkVersionUpdated {
+			domain: u32,
+			fork_version: [u8; 4],
+			fork_digest: [u8; 4],
+			fork: Option<ConsensusFork>,
+		},
+		/// Emit when a domain is added to the whitelisted domains.
+		WhitelistedDomainAdded { domain: u32 },
+		/// Emit when a domain is removed from the whitelisted domains.
+		WhitelistedDomainRemoved { domain: u32 },
 		/// Emit when configuration is updated.
 		ConfigurationUpdated {
+			domain: u32,
 			slots_per_period: u64,
 			finality_threshold: u16,
 		},
@@ -162,36 +383,251 @@ pub mod pallet {
 		RotateVerificationKeyUpdated {
 			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
 		},
+		/// Emit updated finality verification key.
+		FinalityVerificationKeyUpdated {
+			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
+		},
+		/// Emit when the finality mode of a domain is updated.
+		FinalityModeUpdated { domain: u32, mode: FinalityMode },
+		/// Emit when the execution mode of a domain is updated.
+		ExecutionModeUpdated { domain: u32, mode: ExecutionMode },
+		/// Emit when the rate limit of an asset is updated.
+		AssetRateLimitUpdated {
+			asset_id: H256,
+			limit: Option<RateLimit<BlockNumberFor<T>>>,
+		},
+		/// Emit when an asset is added to, updated in or removed from the asset registry.
+		AssetInfoUpdated {
+			asset_id: H256,
+			info: Option<AssetInfo>,
+		},
+		/// Emit when a transfer is held back by the rate limit of its asset.
+		TransferThrottled {
+			asset_id: H256,
+			amount: u128,
+			message_id: u64,
+		},
 		/// Emit new updater.
 		NewUpdater { old: H256, new: H256 },
+		/// Emit when the approved broadcaster code hash is updated.
+		BroadcasterCodeHashUpdated {
+			domain: u32,
+			code_hash: Option<H256>,
+		},
+		/// Emit when the message TTL is updated.
+		MessageTtlUpdated { value: Option<u64> },
+		/// Emit when an expired message is marked as refundable.
+		MessageRefundable {
+			from: H256,
+			to: H256,
+			message_id: u64,
+			message_root: H256,
+		},
+		/// Emit when a domain is paused.
+		DomainPaused { domain: u32 },
+		/// Emit when the bridge is paused in an emergency until block `until`.
+		EmergencyPaused { until: BlockNumberFor<T> },
+		/// Emit when the confirmation depth of a domain is updated.
+		ConfirmationDepthUpdated { domain: u32, depth: u64 },
+		/// Emit when ordered delivery of a sender is enabled or disabled.
+		OrderedDeliveryUpdated {
+			domain: u32,
+			sender: H256,
+			next_id: Option<u64>,
+		},
+		/// Emit when the emergency pause is lifted before it expired.
+		EmergencyUnpaused,
+		/// Emit when the emergency pause expired.
+		EmergencyPauseExpired,
+		/// Emit when a bridged runtime call is dispatched.
+		BridgedCallDispatched {
+			origin_domain: u32,
+			sender: H256,
+			message_id: u64,
+			result: DispatchResult,
+		},
+		/// Emit when a domain is unpaused.
+		DomainUnpaused { domain: u32 },
+		/// Emit when the relayer reward is updated.
+		RelayerRewardUpdated { value: RelayerReward<BalanceOf<T>> },
+		/// Emit when the message fee is updated.
+		MessageFeeUpdated { value: BalanceOf<T> },
+		/// Emit when a relayer is rewarded for executing messages.
+		RelayerRewarded {
+			relayer: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Emit when a batch of messages is sent. The messages are consecutive leaves of the bridge
+		/// root of the block, starting at `leaf_index`.
+		MessagesSubmitted {
+			from: T::AccountId,
+			origin_domain: u32,
+			destination_domain: u32,
+			message_ids: Vec<u64>,
+			message_hashes: Vec<H256>,
+			leaf_index: u32,
+		},
+		/// Emit when an operator is added.
+		OperatorAdded { operator: H256 },
+		/// Emit when an operator is removed.
+		OperatorRemoved { operator: H256 },
+		/// Emit when an operator is replaced by another account.
+		OperatorRotated { old: H256, new: H256 },
+		/// Emit when the fulfill threshold is updated.
+		FulfillThresholdUpdated { threshold: u32 },
+		/// Emit when an operator approves a fulfill call that is short of the threshold.
+		FulfillApproved {
+			operator: H256,
+			call_hash: H256,
+			approvals: u32,
+		},
+		/// Emit when the prover bond is updated.
+		ProverBondUpdated { value: BalanceOf<T> },
+		/// Emit when a prover bonds.
+		Bonded {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Emit when a prover starts unbonding.
+		Unbonding {
+			who: T::AccountId,
+			until: BlockNumberFor<T>,
+		},
+		/// Emit when a prover withdraws its bond.
+		BondWithdrawn {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Emit when the bond of the submitter of an invalid slot is slashed.
+		ProverSlashed {
+			who: T::AccountId,
+			domain: u32,
+			slot: u64,
+			amount: BalanceOf<T>,
+		},
+		/// Emit when the storage layout of the broadcaster of a domain is updated.
+		SlotLayoutUpdated {
+			domain: u32,
+			layout: Option<SlotLayout>,
+		},
+		/// Emit when a message proven against an invalidated execution state root is marked.
+		MessageInvalidated {
+			domain: u32,
+			slot: u64,
+			message_root: H256,
+			previous: MessageStatusEnum,
+		},
+		/// Emit when the execution state roots of a slot range are invalidated. `executed` counts
+		/// the messages that were already executed and `pending` the ones that were not.
+		ExecutionStateRootsInvalidated {
+			domain: u32,
+			from_slot: u64,
+			to_slot: u64,
+			roots: u32,
+			executed: u32,
+			pending: u32,
+		},
+		/// Emit when the execution fee of an asset is updated.
+		ExecutionFeeUpdated { asset_id: H256, fee: Option<u128> },
+		/// Emit when the execution fee of a transfer is paid to the relayer.
+		ExecutionFeePaid {
+			relayer: T::AccountId,
+			asset_id: H256,
+			amount: u128,
+			message_id: u64,
+		},
+		/// Emit when the ABI version of the messages of a domain is updated.
+		MessageAbiVersionUpdated {
+			domain: u32,
+			version: MessageAbiVersion,
+		},
+		/// Emit when a foreign address is bound to or unbound from a local account.
+		AddressBound {
+			address: H256,
+			account: Option<T::AccountId>,
+		},
+		/// Emit when execution is restricted to registered relayers or opened to everyone.
+		PermissionedRelayingUpdated { enabled: bool },
+		/// Emit when a relayer is registered.
+		RelayerAdded { relayer: T::AccountId },
+		/// Emit when a relayer is deregistered.
+		RelayerRemoved { relayer: T::AccountId },
+		/// Emit when a source chain is frozen, with the reason and the origin that froze it.
+		SourceChainFreezeStarted {
+			domain: u32,
+			reason: FreezeReason,
+			frozen_by: FreezeOrigin,
+		},
+		/// Emit when a frozen source chain is unfrozen.
+		SourceChainFreezeLifted { domain: u32 },
+		/// Emit when a verification key is scheduled for activation.
+		VerificationKeyScheduled {
+			kind: VerificationKeyKind,
+			version: u32,
+			key_hash: H256,
+			activation: BlockNumberFor<T>,
+		},
+		/// Emit when a scheduled verification key becomes active.
+		VerificationKeyActivated {
+			kind: VerificationKeyKind,
+			version: u32,
+		},
+		/// Emit when a scheduled verification key is cancelled before its activation.
+		VerificationKeyCancelled {
+			kind: VerificationKeyKind,
+			version: u32,
+		},
+		/// Emit when the slot timing of the source chain of a domain is updated.
+		SlotTimingUpdated {
+			domain: u32,
+			timing: Option<SlotTiming>,
+		},
 	}
 
-	/// Storage for a head updates.
+	/// Storage for a head updates per light client domain.
 	#[pallet::storage]
 	#[pallet::getter(fn head)]
-	pub type Head<T: Config> = StorageValue<_, u64, ValueQuery>;
+	pub type Head<T: Config> = StorageMap<_, Identity, u32, u64, ValueQuery>;
 
-	/// Maps from a slot to a block header root.
+	/// Maps from a domain and a slot to a block header root.
 	#[pallet::storage]
 	#[pallet::getter(fn headers)]
-	pub type Headers<T> = StorageMap<_, Identity, u64, H256, ValueQuery>;
+	pub type Headers<T> = StorageDoubleMap<_, Identity, u32, Identity, u64, H256, ValueQuery>;
 
-	/// Maps slot to the timestamp of when the headers mapping was updated with slot as a key
+	/// Maps domain and slot to the timestamp of when the headers mapping was updated with slot as a key
 	#[pallet::storage]
-	pub type Timestamps<T> = StorageMap<_, Identity, u64, u64, ValueQuery>;
+	pub type Timestamps<T> = StorageDoubleMap<_, Identity, u32, Identity, u64, u64, ValueQuery>;
 
-	/// Maps from a slot to the current finalized ethereum execution state root.
+	/// Maps from a domain and a slot to the current finalized execution state root.
 	#[pallet::storage]
-	pub type ExecutionStateRoots<T> = StorageMap<_, Identity, u64, H256, ValueQuery>;
+	pub type ExecutionStateRoots<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, H256, ValueQuery>;
 
-	/// Maps from a period to the poseidon commitment for the sync committee.
+	/// Maps from a domain and a period to the poseidon commitment for the sync committee.
 	#[pallet::storage]
 	#[pallet::getter(fn sync_committee_poseidons)]
-	pub type SyncCommitteePoseidons<T> = StorageMap<_, Identity, u64, U256, ValueQuery>;
+	pub type SyncCommitteePoseidons<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, U256, ValueQuery>;
+
+	/// Maps from a domain and a period to the SSZ root of the sync committee.
+	#[pallet::storage]
+	pub type SyncCommitteeHashes<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, H256, ValueQuery>;
+
+	/// Consensus fork a light client domain follows. Rotates of domains with a known fork must
+	/// prove the next sync committee against the finalized header.
+	#[pallet::storage]
+	pub type ConsensusForks<T> = StorageMap<_, Identity, u32, ConsensusFork, OptionQuery>;
+
+	/// Maps from a domain and a fork digest to the consensus fork whose header layout applies.
+	#[pallet::storage]
+	pub type ForkDigests<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, [u8; 4], ConsensusFork, OptionQuery>;
 
-	/// Storage for a config of finality threshold and slots per period.
+	/// Storage for a config of finality threshold and slots per period per light client domain.
 	#[pallet::storage]
-	pub type ConfigurationStorage<T: Config> = StorageValue<_, Configuration, ValueQuery>;
+	pub type ConfigurationStorage<T: Config> =
+		StorageMap<_, Identity, u32, Configuration, ValueQuery>;
 
 	/// Maps status of the message to the message root.
 	#[pallet::storage]
@@ -201,11 +637,93 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Broadcasters<T> = StorageMap<_, Identity, u32, H256, ValueQuery>;
 
+	/// Maps from a domain to the approved code hash of the broadcaster contract on that domain.
+	/// If set, the account proof provided in `execute` must resolve to this code hash.
+	#[pallet::storage]
+	pub type BroadcasterCodeHashes<T> = StorageMap<_, Identity, u32, H256, OptionQuery>;
+
+	/// Reward paid out of the reward pot to the account executing messages.
+	#[pallet::storage]
+	pub type RelayerRewards<T: Config> = StorageValue<_, RelayerReward<BalanceOf<T>>, ValueQuery>;
+
+	/// Bond that accounts must lock before submitting light client updates. No bond is required
+	/// while it is zero.
+	#[pallet::storage]
+	pub type ProverBond<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Amount bonded by a prover.
+	#[pallet::storage]
+	pub type Bonds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Block after which an unbonding prover can withdraw its bond.
+	#[pallet::storage]
+	pub type UnbondingUntil<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Account that submitted the roots of a slot.
+	#[pallet::storage]
+	pub type SlotSubmitters<T: Config> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, T::AccountId, OptionQuery>;
+
+	/// Fee charged on `send_message` and deposited into the reward pot.
+	#[pallet::storage]
+	pub type MessageFee<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Number of messages sent to or executed from a domain in the current block.
+	#[pallet::storage]
+	pub type MessageVolume<T> = StorageMap<_, Identity, u32, u32, ValueQuery>;
+
+	/// Number of messages sent in the current block, which is the leaf index of the next message
+	/// in the bridge root of the block.
+	#[pallet::storage]
+	pub type OutboundMessageCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// SCALE encoded addressed messages sent from Avail by block and message id. Kept for
+	/// `MessagePreimageRetention` blocks.
+	#[pallet::storage]
+	pub type MessagePreimages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Identity,
+		u64,
+		BoundedVec<u8, ConstU32<MAX_MESSAGE_PREIMAGE_LEN>>,
+		OptionQuery,
+	>;
+
+	/// Receipts of the withdrawals sent from Avail by block and message id. Kept for
+	/// `MessagePreimageRetention` blocks.
+	#[pallet::storage]
+	pub type WithdrawalReceipts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Identity,
+		u64,
+		WithdrawalReceipt,
+		OptionQuery,
+	>;
+
+	/// Fee multiplier of `execute` and `send_message` per domain. Raised while the message volume
+	/// of a domain is above `MessageVolumeTarget` and decayed otherwise. Absent means no multiplier.
+	#[pallet::storage]
+	pub type FeeMultipliers<T> = StorageMap<_, Identity, u32, u32, OptionQuery>;
+
+	/// Flags domains that are paused. Messages from or to a paused domain cannot be executed or sent.
+	#[pallet::storage]
+	pub type PausedDomains<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
+
 	/// Flags source chain to be frozen.
 	#[pallet::storage]
 	pub type SourceChainFrozen<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
 
-	/// List of permitted domains.
+	/// Reason, origin and block of the freeze of a frozen source chain.
+	#[pallet::storage]
+	pub type SourceChainFreezes<T: Config> =
+		StorageMap<_, Identity, u32, FreezeStatus<BlockNumberFor<T>>, OptionQuery>;
+
+	/// List of permitted domains, kept sorted.
 	#[pallet::storage]
 	pub type WhitelistedDomains<T> = StorageValue<_, BoundedVec<u32, ConstU32<10_000>>, ValueQuery>;
 
@@ -230,6 +748,144 @@ pub mod pallet {
 	pub type RotateVerificationKey<T: Config> =
 		StorageValue<_, Option<BoundedVec<u8, ConstU32<10_000>>>, ValueQuery>;
 
+	/// Verification key of the proofs of Ethereum finality.
+	#[pallet::storage]
+	pub type FinalityVerificationKey<T: Config> =
+		StorageValue<_, BoundedVec<u8, ConstU32<10_000>>, OptionQuery>;
+
+	/// Version of the active verification key of each circuit, bumped on every activation.
+	#[pallet::storage]
+	pub type VerificationKeyVersions<T> =
+		StorageMap<_, Identity, VerificationKeyKind, u32, ValueQuery>;
+
+	/// Verification keys waiting for their activation block, at most one per circuit.
+	#[pallet::storage]
+	pub type PendingVerificationKeys<T: Config> = StorageMap<
+		_,
+		Identity,
+		VerificationKeyKind,
+		PendingVerificationKey<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// How the finality of the headers of a light client domain is proven.
+	#[pallet::storage]
+	pub type FinalityModes<T> = StorageMap<_, Identity, u32, FinalityMode, ValueQuery>;
+
+	/// How messages of a domain are proven to be sent.
+	#[pallet::storage]
+	pub type ExecutionModes<T> = StorageMap<_, Identity, u32, ExecutionMode, ValueQuery>;
+
+	/// Roots of the messages proven against the execution state root of a domain and slot.
+	#[pallet::storage]
+	pub type ProvenMessages<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, u64>,
+			NMapKey<Identity, H256>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	/// Storage layout of the broadcaster of a domain. Absent means the message mapping is at
+	/// `MessageMappingStorageIndex`.
+	#[pallet::storage]
+	pub type SlotLayouts<T> = StorageMap<_, Identity, u32, SlotLayout, OptionQuery>;
+
+	/// Slot timing of the source chain of a domain, which dates its slots. Absent means the
+	/// source chain starts at `GenesisTimestamp` with slots of `SecondsPerSlot`.
+	#[pallet::storage]
+	pub type SlotTimings<T> = StorageMap<_, Identity, u32, SlotTiming, OptionQuery>;
+
+	/// Number of slots the head of a domain must be ahead of a slot before messages can be
+	/// proven against it.
+	#[pallet::storage]
+	pub type ConfirmationDepths<T> = StorageMap<_, Identity, u32, u64, ValueQuery>;
+
+	/// Roots of the messages executed in a block.
+	#[pallet::storage]
+	pub type ExecutedMessages<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, H256, (), OptionQuery>;
+
+	/// Message id, origin domain, asset and amount of the transfers executed in a block, by
+	/// message root.
+	#[pallet::storage]
+	pub type ExecutedTransfers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Identity,
+		H256,
+		(u64, u32, H256, u128),
+		OptionQuery,
+	>;
+
+	/// Next message id expected from a sender of a domain with ordered delivery. Messages of
+	/// senders without an entry are executed in any order.
+	#[pallet::storage]
+	pub type OrderedDelivery<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, H256, u64, OptionQuery>;
+
+	/// Roots of the messages of ordered senders that were executed out of order, queued until
+	/// the preceding messages are executed.
+	#[pallet::storage]
+	pub type QueuedMessages<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, H256>,
+			NMapKey<Identity, u64>,
+		),
+		H256,
+		OptionQuery,
+	>;
+
+	/// Block at which the emergency pause of the bridge expires.
+	#[pallet::storage]
+	pub type EmergencyPauseExpiry<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Registry of the bridged assets and their local representation.
+	#[pallet::storage]
+	pub type AssetRegistry<T> = StorageMap<_, Identity, H256, AssetInfo, OptionQuery>;
+
+	/// Amount of a non-native asset minted by the bridge and not yet burned.
+	#[pallet::storage]
+	pub type BridgedSupply<T> = StorageMap<_, Identity, H256, u128, ValueQuery>;
+
+	/// Fee in units of a bridged asset that is deducted from executed transfers of the asset and
+	/// paid to the relayer. Absent means no fee.
+	#[pallet::storage]
+	pub type ExecutionFees<T> = StorageMap<_, Identity, H256, u128, OptionQuery>;
+
+	/// ABI encoding of the messages sent from a domain.
+	#[pallet::storage]
+	pub type MessageAbiVersions<T> = StorageMap<_, Identity, u32, MessageAbiVersion, ValueQuery>;
+
+	/// Restricts the execution of messages to the registered `Relayers`.
+	#[pallet::storage]
+	pub type PermissionedRelaying<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// Relayers that can execute messages while `PermissionedRelaying` is enabled.
+	#[pallet::storage]
+	pub type Relayers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Local accounts that receive the transfers bridged to a foreign address, set by the address
+	/// itself. Takes precedence over `AddressMapping`.
+	#[pallet::storage]
+	pub type AddressBindings<T: Config> = StorageMap<_, Identity, H256, T::AccountId, OptionQuery>;
+
+	/// Maximum amount of an asset that may be bridged in per window.
+	#[pallet::storage]
+	pub type AssetRateLimits<T: Config> =
+		StorageMap<_, Identity, H256, RateLimit<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Amount of an asset bridged in during its current rate limit window.
+	#[pallet::storage]
+	pub type BridgedVolumes<T: Config> =
+		StorageMap<_, Identity, H256, BridgedVolume<BlockNumberFor<T>>, ValueQuery>;
+
 	/// Genesis validator root, used to check initialization.
 	#[pallet::storage]
 	#[pallet::getter(fn genesis_validator_root)]
@@ -250,11 +906,36 @@ pub mod pallet {
 	#[pallet::getter(fn source_chain_id)]
 	pub type SourceChainId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// Operators that can submit updates next to the updater.
+	#[pallet::storage]
+	pub type Operators<T> = StorageValue<_, BoundedVec<H256, ConstU32<MAX_OPERATORS>>, ValueQuery>;
+
+	/// Number of distinct operators that must submit the same `fulfill_call`,
+	/// `fulfill_rotate_call`, `fulfill_step_call` or `fulfill_finality` before it is applied. A
+	/// threshold of 0 or 1 applies every call right away.
+	#[pallet::storage]
+	pub type FulfillThreshold<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Operators that approved a pending fulfill call, by hash of the call.
+	#[pallet::storage]
+	pub type FulfillApprovals<T> = StorageMap<
+		_,
+		Identity,
+		H256,
+		BoundedVec<H256, ConstU32<{ MAX_OPERATORS + 1 }>>,
+		ValueQuery,
+	>;
+
 	/// Updater that can submit updates
 	#[pallet::storage]
 	#[pallet::getter(fn updater)]
 	pub type Updater<T: Config> = StorageValue<_, H256, ValueQuery>;
 
+	/// Time to live of inbound messages in seconds, measured from the timestamp of the slot
+	/// they are proven against. Expired messages can only be marked as refundable.
+	#[pallet::storage]
+	pub type MessageTtl<T: Config> = StorageValue<_, u64, OptionQuery>;
+
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
@@ -264,6 +945,7 @@ pub mod pallet {
 
 		parameter_types! {
 			pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+			pub const MaxBridgedCallWeight: Weight = Weight::from_parts(500_000_000, 64 * 1024);
 		}
 
 		/// Provides a viable default config that can be used with
@@ -284,6 +966,15 @@ pub mod pallet {
 			#[inject_runtime_type]
 			type RuntimeCall = ();
 			type PalletId = BridgePalletId;
+			type MessageHandler = ();
+			type Assets = ();
+			type AddressMapping = TruncatedAddress;
+			type AccountMapping = TruncatedAddress;
+			type OnNewVerifiedHead = ();
+			type OnMessageExecuted = ();
+			type MaxBridgedCallWeight = MaxBridgedCallWeight;
+			type MessageVolumeTarget = ConstU32<10>;
+			type MaxFeeMultiplier = ConstU32<100>;
 		}
 	}
 
@@ -315,6 +1006,69 @@ pub mod pallet {
 		/// Unique value associated with Avail Network. Used to distinguish messages between Avail and non-Avail networks.
 		#[pallet::constant]
 		type AvailDomain: Get<u32>;
+		/// Handler that `ArbitraryMessage`s are dispatched to once executed.
+		type MessageHandler: MessageHandler;
+		/// Local assets that bridged non-native tokens are minted into and burned from.
+		#[pallet::no_default_bounds]
+		type Assets: BridgedAssets<Self::AccountId>;
+		/// Maps the recipients of bridged transfers that are not bound in `AddressBindings` to
+		/// local accounts.
+		#[pallet::no_default_bounds]
+		type AddressMapping: AddressMapping<Self::AccountId>;
+		/// Maps local accounts to the addresses they are known by on the other domains.
+		#[pallet::no_default_bounds]
+		type AccountMapping: AccountMapping<Self::AccountId>;
+		/// Handler notified of every new head verified by the light clients.
+		type OnNewVerifiedHead: OnNewVerifiedHead;
+		/// Handler notified of the outcome of every executed message.
+		type OnMessageExecuted: OnMessageExecuted;
+		/// Origin of the foreign addresses that bind themselves to a local account.
+		#[pallet::no_default]
+		type AddressBindingOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = BridgedOrigin>;
+		/// Origin that can pause and unpause a single domain.
+		#[pallet::no_default]
+		type DomainPauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin that can add and remove whitelisted domains.
+		#[pallet::no_default]
+		type WhitelistOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Runtime origin that bridged runtime calls are dispatched with.
+		#[pallet::no_default]
+		type RuntimeBridgedOrigin: From<Origin> + Into<OriginFor<Self>>;
+		/// Maximum weight of a bridged runtime call.
+		#[pallet::constant]
+		type MaxBridgedCallWeight: Get<Weight>;
+		/// Origin that can pause the whole bridge in an emergency.
+		#[pallet::no_default]
+		type EmergencyPauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Maximum number of blocks the bridge can be paused for in an emergency.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type MaxEmergencyPauseDuration: Get<BlockNumberFor<Self>>;
+		/// Origin that can invalidate faulty execution state roots.
+		#[pallet::no_default]
+		type StateRootRollbackOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Number of blocks a prover bond stays slashable after unbonding.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type ProverUnbondingPeriod: Get<BlockNumberFor<Self>>;
+		/// Number of blocks the preimages of sent messages are kept for.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type MessagePreimageRetention: Get<BlockNumberFor<Self>>;
+		/// Number of blocks between scheduling a verification key and its activation.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type VerificationKeyActivationDelay: Get<BlockNumberFor<Self>>;
+		/// Price per byte of the payload of arbitrary messages, charged on top of `MessageFee`.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type ArbitraryMessageByteFee: Get<BalanceOf<Self>>;
+		/// Number of messages per block and domain above which the fee multiplier rises.
+		#[pallet::constant]
+		type MessageVolumeTarget: Get<u32>;
+		/// Upper bound of the per domain fee multiplier.
+		#[pallet::constant]
+		type MaxFeeMultiplier: Get<u32>;
 	}
 
 	#[pallet::genesis_config]
@@ -334,19 +1088,39 @@ pub mod pallet {
 		pub genesis_time: u64,
 		pub seconds_per_slot: u64,
 		pub source_chain_id: u64,
+		/// Further light client domains, whitelisted along with `whitelisted_domains`.
+		pub domains: Vec<DomainGenesisConfig>,
 		pub _phantom: PhantomData<T>,
 	}
 
+	impl<T: Config> GenesisConfig<T> {
+		/// Sets up the light client of `domain` at genesis.
+		pub fn with_domain(mut self, domain: DomainGenesisConfig) -> Self {
+			self.domains.push(domain);
+			self
+		}
+
+		/// Whitelists `domains` at genesis.
+		pub fn with_whitelisted_domains(mut self, domains: Vec<u32>) -> Self {
+			self.whitelisted_domains.extend(domains);
+			self
+		}
+	}
+
 	#[pallet::genesis_build]
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
 			// Preconfigure init data
-			<ConfigurationStorage<T>>::put(Configuration {
-				slots_per_period: self.slots_per_period,
-				finality_threshold: self.finality_threshold,
-			});
+			<ConfigurationStorage<T>>::insert(
+				self.broadcaster_domain,
+				Configuration {
+					slots_per_period: self.slots_per_period,
+					finality_threshold: self.finality_threshold,
+				},
+			);
 
 			let mut domains = self.whitelisted_domains.clone();
+			domains.extend(self.domains.iter().map(|config| config.domain));
 
 			// Whitelisted domains sanitization.
 			domains.sort();
@@ -356,19 +1130,26 @@ pub mod pallet {
 			WhitelistedDomains::<T>::put(domains);
 
 			Broadcasters::<T>::set(self.broadcaster_domain, self.broadcaster);
+			AssetRegistry::<T>::insert(SUPPORTED_ASSET_ID, AssetInfo::native());
 
 			FunctionIds::<T>::set(Some(self.function_ids));
 
 			let step_verification_key = BoundedVec::try_from(self.step_verification_key.clone())
 				.expect("Step verification key should be valid at genesis.");
 			StepVerificationKey::<T>::set(Some(step_verification_key));
+			VerificationKeyVersions::<T>::insert(VerificationKeyKind::Step, 1);
 
 			let rotate_verification_key =
 				BoundedVec::try_from(self.rotate_verification_key.clone())
 					.expect("Rotate verification key should be valid at genesis.");
 			RotateVerificationKey::<T>::set(Some(rotate_verification_key));
+			VerificationKeyVersions::<T>::insert(VerificationKeyKind::Rotate, 1);
 
-			SyncCommitteePoseidons::<T>::insert(self.period, self.sync_committee_poseidon);
+			SyncCommitteePoseidons::<T>::insert(
+				self.broadcaster_domain,
+				self.period,
+				self.sync_committee_poseidon,
+			);
 
 			GenesisValidatorRoot::<T>::set(self.genesis_validator_root);
 
@@ -377,31 +1158,68 @@ pub mod pallet {
 			SecondsPerSlot::<T>::set(self.seconds_per_slot);
 
 			SourceChainId::<T>::set(self.source_chain_id);
+
+			for config in &self.domains {
+				ConfigurationStorage::<T>::insert(
+					config.domain,
+					Configuration {
+						slots_per_period: config.slots_per_period,
+						finality_threshold: config.finality_threshold,
+					},
+				);
+				Broadcasters::<T>::set(config.domain, config.broadcaster);
+				SyncCommitteePoseidons::<T>::insert(
+					config.domain,
+					config.period,
+					config.sync_committee_poseidon,
+				);
+			}
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			if let Some(failed_txs) =
 				MemoryTemporaryStorage::take::<Vec<Compact<u32>>>(FAILED_SEND_MSG_ID)
 			{
 				log::trace!(target: LOG_TARGET, "Failed Txs cleaned: {failed_txs:?}");
 			}
 
-			Weight::zero()
+			OutboundMessageCount::<T>::kill();
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			if EmergencyPauseExpiry::<T>::get().is_some_and(|expiry| n >= expiry) {
+				EmergencyPauseExpiry::<T>::kill();
+				Self::deposit_event(Event::EmergencyPauseExpired);
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
+			}
+			weight = weight.saturating_add(Self::prune_message_preimages(n));
+			weight = weight.saturating_add(Self::activate_verification_keys(n));
+
+			let domains = Self::adjust_fee_multipliers();
+			weight.saturating_add(T::WeightInfo::adjust_fee_multipliers(domains))
+		}
+
+		fn offchain_worker(_n: BlockNumberFor<T>) {
+			Self::prevalidate_queued_proofs();
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
 		}
 	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T>
-	where
-		[u8; 32]: From<T::AccountId>,
-	{
+	impl<T: Config> Pallet<T> {
 		/// The entrypoint for fulfilling a call.
+		/// domain Light client domain to update.
 		/// function_id Function identifier.
 		/// input Function input.
 		/// output Function output.
@@ -411,55 +1229,58 @@ pub mod pallet {
 		#[pallet::weight(weight_helper::fulfill_call::<T>(* function_id))]
 		pub fn fulfill_call(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			function_id: H256,
 			input: FunctionInput,
 			output: FunctionOutput,
 			proof: FunctionProof,
 			#[pallet::compact] slot: u64,
 		) -> DispatchResultWithPostInfo {
-			let sender: [u8; 32] = ensure_signed(origin)?.into();
-			let updater = Updater::<T>::get();
-			// ensure sender is preconfigured
-			ensure!(H256(sender) == updater, Error::<T>::UpdaterMisMatch);
+			let who = Self::ensure_updater(origin)?;
+			let operator = T::AccountMapping::into_address(&who);
+			let call = (domain, function_id, &input, &output, slot);
+			if !Self::approve_fulfill(operator, call)? {
+				return Ok(().into());
+			}
 
-			let config = ConfigurationStorage::<T>::get();
-			let input_hash = H256(sha2_256(input.as_slice()));
-			let output_hash = H256(sha2_256(output.as_slice()));
+			let config = ConfigurationStorage::<T>::get(domain);
 			let (step_function_id, rotate_function_id) = Self::get_function_ids()?;
-			let verifier = Self::get_verifier(function_id, step_function_id, rotate_function_id)?;
-
-			let is_success = verifier
-				.verify(input_hash, output_hash, proof.to_vec())
-				.map_err(|_| Error::<T>::VerificationError)?;
-
-			// make sure that verification call is valid
-			ensure!(is_success, Error::<T>::VerificationFailed);
+			let input_hash = Self::verify_function_call(
+				function_id,
+				step_function_id,
+				rotate_function_id,
+				&input,
+				&output,
+				proof,
+			)?;
 
 			// verification is success and, we can safely parse and validate output
 			if function_id == step_function_id {
 				let step_output = parse_step_output(output.to_vec())
 					.map_err(|_| Error::<T>::CannotParseOutputData)?;
 
-				let vs = VerifiedStep::new(function_id, input_hash, step_output);
-
-				if Self::step_into(slot, &config, &vs, step_function_id)? {
-					Self::deposit_event(Event::HeadUpdated {
-						slot: vs.verified_output.finalized_slot,
-						finalization_root: vs.verified_output.finalized_header_root,
-						execution_state_root: vs.verified_output.execution_state_root,
-					});
-				}
+				Self::step_with_output(
+					&who,
+					domain,
+					slot,
+					&config,
+					step_function_id,
+					input_hash,
+					step_output,
+				)?;
 			} else if function_id == rotate_function_id {
-				let rotate_output = parse_rotate_output(output.to_vec())
-					.map_err(|_| Error::<T>::CannotParseOutputData)?;
-
-				let vr = VerifiedRotate::new(function_id, input_hash, rotate_output);
-
-				let period = Self::rotate_into(slot, &config, &vr, rotate_function_id)?;
-				Self::deposit_event(Event::SyncCommitteeUpdated {
-					period,
-					root: vr.sync_committee_poseidon,
-				});
+				ensure!(
+					ConsensusForks::<T>::get(domain).is_none(),
+					Error::<T>::SyncCommitteeBranchRequired
+				);
+				Self::rotate_with_output(
+					domain,
+					slot,
+					&config,
+					rotate_function_id,
+					input_hash,
+					&output,
+				)?;
 			} else {
 				return Err(Error::<T>::FunctionIdNotKnown.into());
 			}
@@ -468,13 +1289,14 @@ pub mod pallet {
 		}
 
 		/// Executes message if a valid proofs are provided for the supported message type, assets and domains.
+		/// The weight declared for the largest proofs is refunded down to the provided proof bytes.
 		#[pallet::call_index(1)]
-		#[pallet::weight({
-			match addr_message.message {
-				Message::ArbitraryMessage(ref data) => T::WeightInfo::execute_arbitrary_message(data.len() as u32),
-				Message::FungibleToken {..} => T::WeightInfo::execute_fungible_token(),
-			}
-		})]
+		#[pallet::weight((
+			weight_helper::execute::<T>(addr_message)
+				.saturating_add(weight_helper::proof_bytes::<T>(2 * MAX_PROOF_BYTES)),
+			DispatchClass::Normal,
+			weight_helper::fee_modifier::<T>(addr_message.origin_domain)
+		))]
 		pub fn execute(
 			origin: OriginFor<T>,
 			#[pallet::compact] slot: u64,
@@ -482,72 +1304,34 @@ pub mod pallet {
 			account_proof: ValidProof,
 			storage_proof: ValidProof,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
-			let encoded_data = addr_message.clone().abi_encode();
-			let message_root = H256(keccak_256(encoded_data.as_slice()));
+			let who = ensure_signed(origin)?;
+			Self::ensure_relayer(&who)?;
+			let message_root = Self::message_root(&addr_message);
 
 			Self::check_preconditions(&addr_message, message_root)?;
-
 			ensure!(
-				!SourceChainFrozen::<T>::get(addr_message.origin_domain),
-				Error::<T>::SourceChainFrozen
+				!Self::is_expired(addr_message.origin_domain, slot),
+				Error::<T>::MessageExpired
 			);
-			let root = ExecutionStateRoots::<T>::get(slot);
-			let broadcaster = Broadcasters::<T>::get(addr_message.origin_domain);
-
-			// extract contract address
-			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
-			let account_proof_vec = account_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
 
 			let storage_root =
-				get_storage_root(account_proof_vec, contract_broadcaster_address, root)
-					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
-
-			let message_id = Uint(U256::from(addr_message.id));
-			let mm_idx = Uint(U256::from(T::MessageMappingStorageIndex::get()));
-			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
+				Self::broadcaster_storage_root(addr_message.origin_domain, slot, &account_proof)?;
+
+			let proof_bytes = proof_len(&account_proof).saturating_add(proof_len(&storage_proof));
+			let actual_weight = weight_helper::execute::<T>(&addr_message)
+				.saturating_add(weight_helper::proof_bytes::<T>(proof_bytes));
+			Self::execute_message(
+				&who,
+				addr_message,
+				message_root,
+				slot,
+				storage_root,
+				storage_proof,
+			)?;
+			Self::reward_relayer(&who, 1, proof_bytes)?;
 
-			let storage_proof_vec = storage_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
-			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
-				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
-
-			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
-
-			if let Message::FungibleToken { asset_id, amount } = &addr_message.message {
-				ensure!(
-					SUPPORTED_ASSET_ID == *asset_id,
-					Error::<T>::AssetNotSupported
-				);
-
-				let destination_account_id =
-					T::AccountId::decode(&mut &addr_message.to.encode()[..])
-						.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
-
-				T::Currency::transfer(
-					&Self::account_id(),
-					&destination_account_id,
-					(*amount).saturated_into(),
-					ExistenceRequirement::AllowDeath,
-				)?;
-			}
-
-			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
-			Self::deposit_event(Event::<T>::MessageExecuted {
-				from: addr_message.from,
-				to: addr_message.to,
-				message_id: addr_message.id,
-				message_root,
-			});
-
-			Ok(().into())
-		}
+			Ok(Some(actual_weight).into())
+		}
 
 		/// source_chain_froze froze source chain and prevent messages to be executed.
 		//
@@ -561,7 +1345,15 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
-			SourceChainFrozen::<T>::set(source_chain_id, frozen);
+			if frozen {
+				Self::freeze(
+					source_chain_id,
+					FreezeReason::Unspecified,
+					FreezeOrigin::Root,
+				);
+			} else if SourceChainFrozen::<T>::get(source_chain_id) {
+				Self::unfreeze(source_chain_id);
+			}
 			Self::deposit_event(Event::<T>::SourceChainFrozen {
 				source_chain_id,
 				frozen,
@@ -578,12 +1370,14 @@ pub mod pallet {
 		//	send_message_arbitrary_message_works(), send_message_arbitrary_message_doesnt_accept_value(),
 		//	send_message_arbitrary_message_doesnt_accept_asset_id(), send_message_arbitrary_message_doesnt_accept_empty_data()
 		#[pallet::call_index(3)]
-		#[pallet::weight({
+		#[pallet::weight((
 			match message {
 				Message::ArbitraryMessage(ref data) => T::WeightInfo::send_message_arbitrary_message(data.len() as u32),
 				Message::FungibleToken{..} => T::WeightInfo::send_message_fungible_token(),
-			}
-		})]
+			},
+			DispatchClass::Normal,
+			weight_helper::fee_modifier::<T>(*domain)
+		))]
 		pub fn send_message(
 			origin: OriginFor<T>,
 			message: Message,
@@ -592,21 +1386,10 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let dispatch = Self::do_send_message(who, message, to, domain);
+			let from = T::AccountMapping::into_address(&who);
+			let dispatch = Self::do_send_message(who, from, message, to, domain);
 			if dispatch.is_err() {
-				let _ = MemoryTemporaryStorage::update::<Vec<Compact<u32>>, _>(
-					FAILED_SEND_MSG_ID.to_vec(),
-					|failed| {
-						let tx_idx_result = <frame_system::Pallet<T>>::extrinsic_index();
-						// this should never happen and we can just log warn
-						if tx_idx_result.is_none() {
-							log::warn!(target: LOG_TARGET, "Transaction index is none!");
-						}
-						let tx_idx = tx_idx_result.unwrap_or_default();
-						failed.push(tx_idx.into());
-						log::trace!(target: LOG_TARGET, "Send Message failed txs: {failed:?}");
-					},
-				);
+				Self::note_failed_send();
 			}
 
 			dispatch
@@ -619,6 +1402,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_poseidon_hash())]
 		pub fn set_poseidon_hash(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			#[pallet::compact] period: u64,
 			poseidon_hash: BoundedVec<u8, ConstU32<200>>,
 		) -> DispatchResultWithPostInfo {
@@ -632,8 +1416,12 @@ pub mod pallet {
 
 			let hash = U256::from(poseidon_hash.to_vec().as_slice());
 
-			SyncCommitteePoseidons::<T>::insert(period, hash);
-			Self::deposit_event(Event::SyncCommitteeUpdated { period, root: hash });
+			SyncCommitteePoseidons::<T>::insert(domain, period, hash);
+			Self::deposit_event(Event::SyncCommitteeUpdated {
+				domain,
+				period,
+				root: hash,
+			});
 			Ok(().into())
 		}
 
@@ -672,7 +1460,11 @@ pub mod pallet {
 			value: BoundedVec<u32, ConstU32<10_000>>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			WhitelistedDomains::<T>::put(value);
+			// Kept sorted so that single domains can be added and removed by binary search.
+			let mut domains = value.into_inner();
+			domains.sort();
+			domains.dedup();
+			WhitelistedDomains::<T>::put(BoundedVec::truncate_from(domains));
 
 			Self::deposit_event(Event::WhitelistedDomainsUpdated);
 
@@ -685,11 +1477,16 @@ pub mod pallet {
 		// Test names: set_configuration_works_with_root(), set_configuration_does_not_work_with_non_root()
 		#[pallet::call_index(7)]
 		#[pallet::weight(T::WeightInfo::set_configuration())]
-		pub fn set_configuration(origin: OriginFor<T>, value: Configuration) -> DispatchResult {
+		pub fn set_configuration(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			value: Configuration,
+		) -> DispatchResult {
 			ensure_root(origin)?;
-			ConfigurationStorage::<T>::put(value);
+			ConfigurationStorage::<T>::insert(domain, value);
 
 			Self::deposit_event(Event::ConfigurationUpdated {
+				domain,
 				slots_per_period: value.slots_per_period,
 				finality_threshold: value.finality_threshold,
 			});
@@ -723,9 +1520,7 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
 			}
 
-			StepVerificationKey::<T>::put(value.clone());
-
-			Self::deposit_event(Event::StepVerificationKeyUpdated { value });
+			Self::update_verification_key(VerificationKeyKind::Step, value);
 
 			Ok(())
 		}
@@ -742,9 +1537,7 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
 			}
 
-			RotateVerificationKey::<T>::put(value.clone());
-
-			Self::deposit_event(Event::RotateVerificationKeyUpdated { value });
+			Self::update_verification_key(VerificationKeyKind::Rotate, value);
 
 			Ok(())
 		}
@@ -780,200 +1573,2604 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::NewUpdater { old, new: updater });
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn do_send_message(
-			who: T::AccountId,
-			message: Message,
-			to: H256,
-			domain: u32,
+		/// set_broadcaster_code_hash sets the approved code hash of the broadcaster contract on the origin chain.
+		/// Passing `None` disables the code hash check for the domain.
+		//
+		// Test names: set_broadcaster_code_hash_works_with_root(), set_broadcaster_code_hash_does_not_work_with_non_root()
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_broadcaster_code_hash())]
+		pub fn set_broadcaster_code_hash(
+			origin: OriginFor<T>,
+			#[pallet::compact] broadcaster_domain: u32,
+			code_hash: Option<H256>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			BroadcasterCodeHashes::<T>::set(broadcaster_domain, code_hash);
+
+			Self::deposit_event(Event::BroadcasterCodeHashUpdated {
+				domain: broadcaster_domain,
+				code_hash,
+			});
+
+			Ok(())
+		}
+
+		/// execute_batch executes multiple messages of the same origin domain against the
+		/// execution state root of a single slot. The account proof is verified only once.
+		/// The weight declared for the largest proofs is refunded down to the provided proof bytes.
+		//
+		// Test names: execute_batch_works(), execute_batch_fails_with_empty_batch(), execute_batch_fails_with_mixed_origin_domains(), execute_batch_refunds_unused_proof_weight()
+		#[pallet::call_index(14)]
+		#[pallet::weight((
+			weight_helper::execute_batch::<T>(messages)
+				.saturating_add(weight_helper::proof_bytes::<T>(weight_helper::max_batch_proof_bytes(messages))),
+			DispatchClass::Normal,
+			weight_helper::fee_modifier::<T>(messages.first().map_or(0, |(m, _)| m.origin_domain))
+		))]
+		pub fn execute_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			messages: ExecuteBatch,
+			account_proof: ValidProof,
 		) -> DispatchResultWithPostInfo {
-			// Ensure the domain is currently supported
+			let who = ensure_signed(origin)?;
+			Self::ensure_relayer(&who)?;
+			let origin_domain = messages
+				.first()
+				.map(|(message, _)| message.origin_domain)
+				.ok_or(Error::<T>::EmptyBatch)?;
 			ensure!(
-				Self::is_domain_valid(domain),
-				Error::<T>::DomainNotSupported
+				messages
+					.iter()
+					.all(|(message, _)| message.origin_domain == origin_domain),
+				Error::<T>::BatchOriginDomainMismatch
+			);
+			ensure!(
+				!Self::is_expired(origin_domain, slot),
+				Error::<T>::MessageExpired
 			);
-			// Check MessageType and enforce the rules
-			let message_type = message.r#type();
-			match message {
-				Message::FungibleToken { asset_id, amount } => {
-					ensure!(
-						SUPPORTED_ASSET_ID == asset_id,
-						Error::<T>::AssetNotSupported
-					);
-					ensure!(
-						amount.saturated_into::<u128>() > 0,
-						Error::<T>::InvalidBridgeInputs
-					);
-					T::Currency::transfer(
-						&who,
-						&Self::account_id(),
-						amount.saturated_into(),
-						ExistenceRequirement::KeepAlive,
-					)?;
-				},
-				Message::ArbitraryMessage(data) => {
-					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
-				},
-			};
 
-			let message_id = Self::fetch_curr_message_id()?;
+			let storage_root = Self::broadcaster_storage_root(origin_domain, slot, &account_proof)?;
+
+			let message_count = messages.len() as u32;
+			let batch_weight = weight_helper::execute_batch::<T>(&messages);
+			let mut proof_bytes = proof_len(&account_proof);
+			for (addr_message, storage_proof) in messages {
+				let message_root = Self::message_root(&addr_message);
+
+				Self::check_preconditions(&addr_message, message_root)?;
+				proof_bytes = proof_bytes.saturating_add(proof_len(&storage_proof));
+				Self::execute_message(
+					&who,
+					addr_message,
+					message_root,
+					slot,
+					storage_root,
+					storage_proof,
+				)?;
+			}
 
-			Self::deposit_event(Event::MessageSubmitted {
-				from: who,
-				to,
-				message_type,
-				destination_domain: domain,
-				message_id,
-			});
+			Self::reward_relayer(&who, message_count, proof_bytes)?;
 
-			Ok(().into())
+			let actual_weight =
+				batch_weight.saturating_add(weight_helper::proof_bytes::<T>(proof_bytes));
+			Ok(Some(actual_weight).into())
 		}
 
-		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
-			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
-			let tx_index_option = <frame_system::Pallet<T>>::extrinsic_index();
+		/// set_message_ttl sets the time to live of inbound messages. `None` disables expiration.
+		//
+		// Test names: set_message_ttl_works_with_root(), set_message_ttl_does_not_work_with_non_root()
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::set_message_ttl())]
+		pub fn set_message_ttl(origin: OriginFor<T>, value: Option<u64>) -> DispatchResult {
+			ensure_root(origin)?;
+			MessageTtl::<T>::set(value);
 
-			match tx_index_option {
-				Some(tx_index) => Ok(tx_uid(number, tx_index)),
-				None => Err(Error::<T>::CurrentMessageIdNotFound.into()),
-			}
+			Self::deposit_event(Event::MessageTtlUpdated { value });
+
+			Ok(())
 		}
 
-		fn check_preconditions(
-			message: &AddressedMessage,
-			message_root: H256,
-		) -> Result<(), DispatchError> {
-			let message_status = MessageStatus::<T>::get(message_root);
-			// Message must not be executed
-			ensure!(
-				message_status == MessageStatusEnum::NotExecuted,
-				Error::<T>::MessageAlreadyExecuted
-			);
+		/// mark_message_refundable marks an expired message as refundable so that the sender
+		/// can be refunded on the origin chain. The message can no longer be executed.
+		//
+		// Test names: mark_message_refundable_works(), mark_message_refundable_fails_when_not_expired(), execute_fails_with_expired_message()
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::mark_message_refundable())]
+		pub fn mark_message_refundable(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			addr_message: AddressedMessage,
+			account_proof: ValidProof,
+			storage_proof: ValidProof,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let message_root = Self::message_root(&addr_message);
 
+			Self::check_preconditions(&addr_message, message_root)?;
 			ensure!(
-				message.destination_domain == T::AvailDomain::get(),
-				Error::<T>::WrongDestinationChain
+				Self::is_expired(addr_message.origin_domain, slot),
+				Error::<T>::MessageNotExpired
 			);
 
-			ensure!(
-				WhitelistedDomains::<T>::get().contains(&message.origin_domain),
-				Error::<T>::UnsupportedOriginChain
-			);
+			let storage_root =
+				Self::broadcaster_storage_root(addr_message.origin_domain, slot, &account_proof)?;
+			Self::verify_message_inclusion(
+				addr_message.origin_domain,
+				addr_message.id,
+				message_root,
+				storage_root,
+				storage_proof,
+			)?;
 
-			let source_chain = Broadcasters::<T>::get(message.origin_domain);
-			ensure!(
-				source_chain != H256::zero(),
-				Error::<T>::BroadcasterSourceChainNotSet
-			);
+			ProvenMessages::<T>::insert((addr_message.origin_domain, slot, message_root), ());
+			MessageStatus::<T>::set(message_root, MessageStatusEnum::Refundable);
+			Self::deposit_event(Event::<T>::MessageRefundable {
+				from: addr_message.from,
+				to: addr_message.to,
+				message_id: addr_message.id,
+				message_root,
+			});
 
 			Ok(())
 		}
 
-		/// The account ID of the bridge's pot.
-		pub fn account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
-		}
+		/// pause_domain pauses sending and executing messages of a single domain.
+		//
+		// Test names: pause_domain_works(), pause_domain_does_not_work_with_non_pause_origin(), execute_fails_with_paused_domain(), send_message_fails_with_paused_domain()
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::pause_domain())]
+		pub fn pause_domain(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+		) -> DispatchResult {
+			T::DomainPauseOrigin::ensure_origin(origin)?;
+			PausedDomains::<T>::set(domain, true);
 
-		fn rotate_into(
-			finalized_slot: u64,
-			cfg: &Configuration,
-			verified_rotate_call: &VerifiedRotate,
-			rotate_function_id: H256,
-		) -> Result<u64, DispatchError> {
-			let finalized_header_root = Headers::<T>::get(finalized_slot);
-			ensure!(
-				finalized_header_root != H256::zero(),
-				Error::<T>::HeaderRootNotSet
-			);
+			Self::deposit_event(Event::DomainPaused { domain });
 
-			let input = ethabi::encode(&[Token::FixedBytes(finalized_header_root.0.to_vec())]);
-			let sync_committee_poseidon: U256 =
-				Self::verified_rotate_call(rotate_function_id, input, verified_rotate_call)?;
+			Ok(())
+		}
 
-			let period = finalized_slot
-				.checked_div(cfg.slots_per_period)
-				.ok_or(Error::<T>::ConfigurationNotSet)?;
-			let next_period = period + 1;
+		/// unpause_domain resumes sending and executing messages of a paused domain.
+		//
+		// Test names: unpause_domain_works()
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::unpause_domain())]
+		pub fn unpause_domain(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+		) -> DispatchResult {
+			T::DomainPauseOrigin::ensure_origin(origin)?;
+			PausedDomains::<T>::remove(domain);
 
-			Self::set_sync_committee_poseidon(next_period, sync_committee_poseidon)?;
+			Self::deposit_event(Event::DomainUnpaused { domain });
 
-			Ok(next_period)
+			Ok(())
 		}
 
-		fn step_into(
-			attested_slot: u64,
-			cfg: &Configuration,
-			verified_step_call: &VerifiedStep,
-			step_function_id: H256,
-		) -> Result<bool, DispatchError> {
-			let period = attested_slot
-				.checked_div(cfg.slots_per_period)
-				.ok_or(Error::<T>::ConfigurationNotSet)?;
+		/// set_relayer_reward sets the reward paid to relayers for executing messages.
+		//
+		// Test names: set_relayer_reward_works_with_root(), set_relayer_reward_does_not_work_with_non_root()
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::set_relayer_reward())]
+		pub fn set_relayer_reward(
+			origin: OriginFor<T>,
+			value: RelayerReward<BalanceOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			RelayerRewards::<T>::put(value);
 
-			let sc_poseidon = SyncCommitteePoseidons::<T>::get(period);
-			ensure!(sc_poseidon != U256::zero(), Error::<T>::SyncCommitteeNotSet);
+			Self::deposit_event(Event::RelayerRewardUpdated { value });
 
-			let input = encode_packed(sc_poseidon, attested_slot);
-			let result = Self::verified_step_call(step_function_id, input, verified_step_call)?;
-			ensure!(
-				result.participation >= cfg.finality_threshold,
-				Error::<T>::NotEnoughParticipants
-			);
+			Ok(())
+		}
 
-			let head = Head::<T>::get();
-			ensure!(result.finalized_slot > head, Error::<T>::SlotBehindHead);
+		/// set_message_fee sets the fee charged on `send_message` that funds relayer rewards.
+		//
+		// Test names: set_message_fee_works_with_root(), set_message_fee_does_not_work_with_non_root()
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_message_fee())]
+		pub fn set_message_fee(origin: OriginFor<T>, value: BalanceOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+			MessageFee::<T>::put(value);
 
-			let updated = Self::set_slot_roots(result)?;
+			Self::deposit_event(Event::MessageFeeUpdated { value });
 
-			Ok(updated)
+			Ok(())
 		}
 
-		///  Sets the current slot for the chain the light client is reflecting.
-		/// checks is the roots exists for the slot already. If there is
-		/// an existing header but no conflict, do nothing. Avoids timestamp renewal DoS attacks.
-		fn set_slot_roots(step_output: VerifiedStepOutput) -> Result<bool, DispatchError> {
-			let header = Headers::<T>::get(step_output.finalized_slot);
-			ensure!(header == H256::zero(), Error::<T>::HeaderRootAlreadySet);
+		/// add_whitelisted_domain adds a domain to the whitelisted domains.
+		//
+		// Test names: add_whitelisted_domain_works(), add_whitelisted_domain_does_not_work_with_non_whitelist_origin(), add_whitelisted_domain_fails_if_already_whitelisted()
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::add_whitelisted_domain())]
+		pub fn add_whitelisted_domain(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			WhitelistedDomains::<T>::try_mutate(|domains| -> DispatchResult {
+				let index = domains
+					.binary_search(&domain)
+					.err()
+					.ok_or(Error::<T>::DomainAlreadyWhitelisted)?;
+				domains
+					.try_insert(index, domain)
+					.map_err(|_| Error::<T>::TooManyWhitelistedDomains)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::WhitelistedDomainAdded { domain });
 
-			let execution_state_root = ExecutionStateRoots::<T>::get(step_output.finalized_slot);
-			ensure!(
-				execution_state_root == H256::zero(),
-				Error::<T>::StateRootAlreadySet
-			);
+			Ok(())
+		}
 
-			Head::<T>::set(step_output.finalized_slot);
-			Headers::<T>::insert(
-				step_output.finalized_slot,
-				step_output.finalized_header_root,
-			);
-			ExecutionStateRoots::<T>::insert(
-				step_output.finalized_slot,
-				step_output.execution_state_root,
-			);
+		/// remove_whitelisted_domain removes a domain from the whitelisted domains.
+		//
+		// Test names: remove_whitelisted_domain_works(), remove_whitelisted_domain_fails_if_not_whitelisted()
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::remove_whitelisted_domain())]
+		pub fn remove_whitelisted_domain(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			WhitelistedDomains::<T>::try_mutate(|domains| -> DispatchResult {
+				let index = domains
+					.binary_search(&domain)
+					.map_err(|_| Error::<T>::DomainNotWhitelisted)?;
+				domains.remove(index);
+				Ok(())
+			})?;
 
-			Timestamps::<T>::insert(step_output.finalized_slot, T::TimeProvider::now().as_secs());
+			Self::deposit_event(Event::WhitelistedDomainRemoved { domain });
 
-			Ok(true)
+			Ok(())
 		}
 
-		/// Sets the sync committee poseidon for a given period.
-		fn set_sync_committee_poseidon(period: u64, poseidon: U256) -> Result<(), DispatchError> {
-			let sync_committee_poseidons = SyncCommitteePoseidons::<T>::get(period);
+		/// fulfill_rotate_call rotates the sync committee of a domain that follows a known consensus
+		/// fork. Besides the rotate proof, the SSZ branch of the next sync committee is verified
+		/// against the finalized header so the committee is not trusted opaquely.
+		//
+		// Test names: fulfill_rotate_call_fails_without_consensus_fork(), fulfill_rotate_call_fails_with_invalid_branch(), fulfill_call_rotate_requires_branch_with_consensus_fork(), fulfill_rotate_call_waits_for_threshold()
+		#[pallet::call_index(23)]
+		#[pallet::weight(weight_helper::fulfill_rotate_call::<T>())]
+		pub fn fulfill_rotate_call(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			input: FunctionInput,
+			output: FunctionOutput,
+			proof: FunctionProof,
+			#[pallet::compact] slot: u64,
+			branch: SyncCommitteeBranch,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_updater(origin)?;
+			let operator = T::AccountMapping::into_address(&who);
+			let (step_function_id, rotate_function_id) = Self::get_function_ids()?;
+			let call = (domain, rotate_function_id, &input, &output, slot, &branch);
+			if !Self::approve_fulfill(operator, call)? {
+				return Ok(().into());
+			}
+
+			let fork = ConsensusForks::<T>::get(domain).ok_or(Error::<T>::ConsensusForkNotSet)?;
+			let finalized_header_root = Headers::<T>::get(domain, slot);
 			ensure!(
-				sync_committee_poseidons == U256::zero(),
-				Error::<T>::SyncCommitteeAlreadySet
+				finalized_header_root != H256::zero(),
+				Error::<T>::HeaderRootNotSet
+			);
+			ensure!(
+				verify_sync_committee_branch(finalized_header_root, fork, &branch),
+				Error::<T>::InvalidSyncCommitteeBranch
 			);
 
-			SyncCommitteePoseidons::<T>::set(period, poseidon);
+			let config = ConfigurationStorage::<T>::get(domain);
+			let input_hash = Self::verify_function_call(
+				rotate_function_id,
+				step_function_id,
+				rotate_function_id,
+				&input,
+				&output,
+				proof,
+			)?;
+			let period = Self::rotate_with_output(
+				domain,
+				slot,
+				&config,
+				rotate_function_id,
+				input_hash,
+				&output,
+			)?;
+
+			SyncCommitteeHashes::<T>::insert(domain, period, branch.sync_committee_root);
+			Self::deposit_event(Event::SyncCommitteeHashUpdated {
+				domain,
+				period,
+				root: branch.sync_committee_root,
+			});
 
-			Ok(())
+			Ok(().into())
 		}
 
-		/// get_verifier returns verifier based on the provided function id.
-		fn get_verifier(
-			function_id: H256,
+		/// set_consensus_fork sets the consensus fork a domain follows. Once set, rotates of the
+		/// domain have to go through fulfill_rotate_call.
+		//
+		// Test names: set_consensus_fork_works_with_root(), set_consensus_fork_does_not_work_with_non_root()
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_consensus_fork())]
+		pub fn set_consensus_fork(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			fork: Option<ConsensusFork>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ConsensusForks::<T>::set(domain, fork);
+
+			Self::deposit_event(Event::ConsensusForkUpdated { domain, fork });
+
+			Ok(())
+		}
+
+		/// set_fork_version registers the consensus fork of a fork version of a domain, or removes it.
+		/// Headers are looked up by the digest of their fork version, so upcoming forks can be
+		/// registered ahead of time.
+		//
+		// Test names: set_fork_version_works_with_root(), set_fork_version_does_not_work_with_non_root()
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_fork_version())]
+		pub fn set_fork_version(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			fork_version: [u8; 4],
+			fork: Option<ConsensusFork>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let fork_digest = compute_fork_digest(fork_version, GenesisValidatorRoot::<T>::get());
+			ForkDigests::<T>::set(domain, fork_digest, fork);
+
+			Self::deposit_event(Event::ForkVersionUpdated {
+				domain,
+				fork_version,
+				fork_digest,
+				fork,
+			});
+
+			Ok(())
+		}
+
+		/// fulfill_step_call updates the head of a domain like a step through fulfill_call, but also
+		/// verifies the execution state root against the finalized header using the header layout
+		/// of its fork digest.
+		//
+		// Test names: fulfill_step_call_fails_with_unknown_fork_digest(), fulfill_step_call_fails_with_invalid_branch(), fulfill_step_call_waits_for_threshold()
+		#[pallet::call_index(26)]
+		#[pallet::weight(weight_helper::fulfill_step_call::<T>())]
+		pub fn fulfill_step_call(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			input: FunctionInput,
+			output: FunctionOutput,
+			proof: FunctionProof,
+			#[pallet::compact] slot: u64,
+			branch: ExecutionStateRootBranch,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_updater(origin)?;
+			let operator = T::AccountMapping::into_address(&who);
+			let (step_function_id, rotate_function_id) = Self::get_function_ids()?;
+			let call = (domain, step_function_id, &input, &output, slot, &branch);
+			if !Self::approve_fulfill(operator, call)? {
+				return Ok(().into());
+			}
+
+			let fork = ForkDigests::<T>::get(domain, branch.fork_digest)
+				.ok_or(Error::<T>::UnknownForkDigest)?;
+			let config = ConfigurationStorage::<T>::get(domain);
+			let input_hash = Self::verify_function_call(
+				step_function_id,
+				step_function_id,
+				rotate_function_id,
+				&input,
+				&output,
+				proof,
+			)?;
+
+			let step_output = parse_step_output(output.to_vec())
+				.map_err(|_| Error::<T>::CannotParseOutputData)?;
+			ensure!(
+				verify_execution_state_root(
+					step_output.finalized_header_root,
+					step_output.execution_state_root,
+					fork,
+					&branch
+				),
+				Error::<T>::InvalidExecutionStateRootBranch
+			);
+
+			Self::step_with_output(
+				&who,
+				domain,
+				slot,
+				&config,
+				step_function_id,
+				input_hash,
+				step_output,
+			)?;
+
+			Ok(().into())
+		}
+
+		/// retry_execute executes a message again whose execution failed for a reason that may have
+		/// gone away since. The message was already proven, so no proofs are needed.
+		//
+		// Test names: retry_execute_works(), retry_execute_fails_if_not_failed(), retry_execute_fails_if_still_failing()
+		#[pallet::call_index(27)]
+		#[pallet::weight((
+			T::WeightInfo::retry_execute()
+				.saturating_add(weight_helper::rate_limit::<T>())
+				.saturating_add(weight_helper::ordered_delivery::<T>())
+				.saturating_add(T::OnMessageExecuted::weight())
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Normal,
+			weight_helper::fee_modifier::<T>(addr_message.origin_domain)
+		))]
+		pub fn retry_execute(
+			origin: OriginFor<T>,
+			addr_message: AddressedMessage,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_relayer(&who)?;
+			let message_root = Self::message_root(&addr_message);
+
+			let status = MessageStatus::<T>::get(message_root);
+			ensure!(
+				matches!(status, MessageStatusEnum::ExecutionFailed(_)),
+				Error::<T>::MessageNotFailed
+			);
+			ensure!(
+				!SourceChainFrozen::<T>::get(addr_message.origin_domain),
+				Error::<T>::SourceChainFrozen
+			);
+			Self::ensure_not_paused(addr_message.origin_domain)?;
+
+			let result = Self::dispatch_message(&who, &addr_message)?
+				.map_err(|_| Error::<T>::ExecutionStillFailing)?;
+			if status == MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::OutOfOrder) {
+				QueuedMessages::<T>::remove((
+					addr_message.origin_domain,
+					addr_message.from,
+					addr_message.id,
+				));
+			}
+			Self::mark_executed(&addr_message, message_root, result);
+
+			Ok(())
+		}
+
+		/// fulfill_finality updates the head of a domain with a proof of Ethereum finality. The proof
+		/// attests that the header of `output` is finalized on top of the current head, so no sync
+		/// committee is involved.
+		//
+		// Test names: fulfill_finality_fails_with_sync_committee_mode(), fulfill_finality_fails_without_verification_key(), fulfill_finality_fails_with_invalid_proof(), fulfill_call_step_fails_with_zk_finality_mode(), fulfill_finality_waits_for_threshold()
+		#[pallet::call_index(28)]
+		#[pallet::weight(weight_helper::fulfill_finality::<T>())]
+		pub fn fulfill_finality(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			output: FunctionOutput,
+			proof: FunctionProof,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_updater(origin)?;
+			let operator = T::AccountMapping::into_address(&who);
+			if !Self::approve_fulfill(operator, (domain, &output))? {
+				return Ok(().into());
+			}
+
+			ensure!(
+				FinalityModes::<T>::get(domain) == FinalityMode::ZkFinality,
+				Error::<T>::FinalityModeMismatch
+			);
+
+			let head = Head::<T>::get(domain);
+			let head_root = Headers::<T>::get(domain, head);
+			ensure!(head_root != H256::zero(), Error::<T>::HeaderRootNotSet);
+
+			// The proof is bound to the trusted head it was generated from.
+			let input = ethabi::encode(&[
+				Token::FixedBytes(head_root.0.to_vec()),
+				Uint(U256::from(head)),
+			]);
+			let input_hash = H256(sha2_256(input.as_slice()));
+			let output_hash = H256(sha2_256(output.as_slice()));
+
+			let vk =
+				FinalityVerificationKey::<T>::get().ok_or(Error::<T>::VerificationKeyIsNotSet)?;
+			let verifier = Verifier::from_json_u8_slice(vk.as_slice())
+				.map_err(|_| Error::<T>::MalformedVerificationKey)?;
+			let is_success = verifier
+				.verify(input_hash, output_hash, proof.to_vec())
+				.map_err(|_| Error::<T>::VerificationError)?;
+			ensure!(is_success, Error::<T>::VerificationFailed);
+
+			let finality_output = parse_step_output(output.to_vec())
+				.map_err(|_| Error::<T>::CannotParseOutputData)?;
+			ensure!(
+				finality_output.finalized_slot > head,
+				Error::<T>::SlotBehindHead
+			);
+
+			if Self::set_slot_roots(domain, finality_output)? {
+				SlotSubmitters::<T>::insert(domain, finality_output.finalized_slot, who);
+				Self::deposit_event(Event::HeadUpdated {
+					domain,
+					slot: finality_output.finalized_slot,
+					finalization_root: finality_output.finalized_header_root,
+					execution_state_root: finality_output.execution_state_root,
+				});
+			}
+
+			Ok(().into())
+		}
+
+		/// set_finality_verification_key schedules the verification key of the proofs of Ethereum
+		/// finality for activation after `VerificationKeyActivationDelay` blocks.
+		//
+		// Test names: set_finality_verification_key_works_with_root(), set_finality_verification_key_does_not_work_with_non_root()
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_finality_verification_key())]
+		pub fn set_finality_verification_key(
+			origin: OriginFor<T>,
+			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(vk) = value.as_ref() {
+				let _ = Verifier::from_json_u8_slice(vk.as_slice())
+					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
+			}
+
+			Self::update_verification_key(VerificationKeyKind::Finality, value);
+
+			Ok(())
+		}
+
+		/// set_finality_mode selects how the finality of the headers of a domain is proven.
+		//
+		// Test names: set_finality_mode_works_with_root(), set_finality_mode_does_not_work_with_non_root()
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::set_finality_mode())]
+		pub fn set_finality_mode(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			mode: FinalityMode,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			FinalityModes::<T>::insert(domain, mode);
+
+			Self::deposit_event(Event::FinalityModeUpdated { domain, mode });
+
+			Ok(())
+		}
+
+		/// execute_with_receipt executes a message of a domain in receipt mode. Instead of a storage
+		/// proof, the `MessageSent` log of the broadcaster is proven through the receipts of a
+		/// finalized execution payload. The weight declared for the largest receipt proof is refunded
+		/// down to the provided proof bytes.
+		//
+		// Test names: execute_with_receipt_works(), execute_with_receipt_fails_with_storage_proof_mode(), execute_with_receipt_fails_with_invalid_branch(), execute_with_receipt_fails_with_wrong_log(), execute_fails_with_receipt_proof_mode(), execute_with_receipt_refunds_unused_proof_weight()
+		#[pallet::call_index(31)]
+		#[pallet::weight((
+			weight_helper::execute_with_receipt::<T>(addr_message)
+				.saturating_add(weight_helper::proof_bytes::<T>(MAX_PROOF_BYTES)),
+			DispatchClass::Normal,
+			weight_helper::fee_modifier::<T>(addr_message.origin_domain)
+		))]
+		pub fn execute_with_receipt(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			addr_message: AddressedMessage,
+			proof: ReceiptProof,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_relayer(&who)?;
+			let message_root = Self::message_root(&addr_message);
+
+			Self::check_preconditions(&addr_message, message_root)?;
+			ensure!(
+				!Self::is_expired(addr_message.origin_domain, slot),
+				Error::<T>::MessageExpired
+			);
+
+			let proof_bytes = proof_len(&proof.receipt_proof);
+			let actual_weight = weight_helper::execute_with_receipt::<T>(&addr_message)
+				.saturating_add(weight_helper::proof_bytes::<T>(proof_bytes));
+			Self::execute_message_with_receipt(&who, addr_message, message_root, slot, proof)?;
+			Self::reward_relayer(&who, 1, proof_bytes)?;
+
+			Ok(Some(actual_weight).into())
+		}
+
+		/// set_execution_mode selects how messages of a domain are proven to be sent.
+		//
+		// Test names: set_execution_mode_works_with_root(), set_execution_mode_does_not_work_with_non_root()
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_execution_mode())]
+		pub fn set_execution_mode(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			mode: ExecutionMode,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ExecutionModes::<T>::insert(domain, mode);
+
+			Self::deposit_event(Event::ExecutionModeUpdated { domain, mode });
+
+			Ok(())
+		}
+
+		/// set_asset_rate_limit sets the maximum amount of an asset that may be bridged in per
+		/// window of blocks. Transfers above the limit fail and can be retried in a later window.
+		//
+		// Test names: set_asset_rate_limit_works_with_root(), set_asset_rate_limit_does_not_work_with_non_root(), execute_throttled_by_rate_limit(), rate_limit_window_resets()
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_asset_rate_limit())]
+		pub fn set_asset_rate_limit(
+			origin: OriginFor<T>,
+			asset_id: H256,
+			limit: Option<RateLimit<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			AssetRateLimits::<T>::set(asset_id, limit);
+
+			Self::deposit_event(Event::AssetRateLimitUpdated { asset_id, limit });
+
+			Ok(())
+		}
+
+		/// set_asset_info adds an asset to the asset registry, updates its local representation or
+		/// removes it from the registry when `info` is `None`.
+		//
+		// Test names: set_asset_info_works_with_root(), set_asset_info_does_not_work_with_non_root(), execute_unregistered_asset_fails(), execute_below_min_amount_fails()
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::set_asset_info())]
+		pub fn set_asset_info(
+			origin: OriginFor<T>,
+			asset_id: H256,
+			info: Option<AssetInfo>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			AssetRegistry::<T>::set(asset_id, info);
+
+			Self::deposit_event(Event::AssetInfoUpdated { asset_id, info });
+
+			Ok(())
+		}
+
+		/// emergency_pause pauses sending and executing messages of all domains for `duration`
+		/// blocks. The pause expires on its own so it cannot outlive the emergency.
+		//
+		// Test names: emergency_pause_works(), emergency_pause_does_not_work_with_non_pause_origin(), emergency_pause_fails_if_too_long(), emergency_pause_expires(), emergency_unpause_works()
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::emergency_pause())]
+		pub fn emergency_pause(
+			origin: OriginFor<T>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::EmergencyPauseOrigin::ensure_origin(origin)?;
+			ensure!(
+				duration <= T::MaxEmergencyPauseDuration::get(),
+				Error::<T>::EmergencyPauseTooLong
+			);
+
+			let until = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			EmergencyPauseExpiry::<T>::put(until);
+
+			Self::deposit_event(Event::EmergencyPaused { until });
+
+			Ok(())
+		}
+
+		/// emergency_unpause lifts the emergency pause before it expires.
+		//
+		// Test names: emergency_unpause_works()
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::emergency_unpause())]
+		pub fn emergency_unpause(origin: OriginFor<T>) -> DispatchResult {
+			T::EmergencyPauseOrigin::ensure_origin(origin)?;
+			EmergencyPauseExpiry::<T>::kill();
+
+			Self::deposit_event(Event::EmergencyUnpaused);
+
+			Ok(())
+		}
+
+		/// set_confirmation_depth sets how many slots the head of a domain must be ahead of a slot
+		/// before messages can be executed against it. This guards against reorgs of the source
+		/// chain close to the finality boundary.
+		//
+		// Test names: set_confirmation_depth_works_with_root(), set_confirmation_depth_does_not_work_with_non_root(), execute_fails_with_unconfirmed_slot()
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::set_confirmation_depth())]
+		pub fn set_confirmation_depth(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			#[pallet::compact] depth: u64,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ConfirmationDepths::<T>::insert(domain, depth);
+
+			Self::deposit_event(Event::ConfirmationDepthUpdated { domain, depth });
+
+			Ok(())
+		}
+
+		/// set_ordered_delivery enables ordered delivery of the messages of `sender` on `domain`,
+		/// starting at `next_id`, or disables it if `next_id` is `None`. Messages of an ordered
+		/// sender are executed in sequence and out-of-order messages are queued for a retry.
+		//
+		// Test names: set_ordered_delivery_works_with_root(), set_ordered_delivery_does_not_work_with_non_root(), execute_queues_out_of_order_message(), retry_execute_works_with_queued_message()
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_ordered_delivery())]
+		pub fn set_ordered_delivery(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			sender: H256,
+			next_id: Option<u64>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			OrderedDelivery::<T>::set(domain, sender, next_id);
+
+			Self::deposit_event(Event::OrderedDeliveryUpdated {
+				domain,
+				sender,
+				next_id,
+			});
+
+			Ok(())
+		}
+
+		/// send_messages sends a batch of messages to the destination chain. The message fee is
+		/// paid once for the whole batch and the messages get ids in order of the batch.
+		//
+		// Test names: send_messages_works(), send_messages_fails_with_empty_batch(), send_messages_fails_with_invalid_message()
+		#[pallet::call_index(39)]
+		#[pallet::weight((
+			weight_helper::send_messages::<T>(messages),
+			DispatchClass::Normal,
+			weight_helper::fee_modifier::<T>(*domain)
+		))]
+		pub fn send_messages(
+			origin: OriginFor<T>,
+			messages: SendBatch,
+			#[pallet::compact] domain: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let from = T::AccountMapping::into_address(&who);
+			let dispatch = Self::do_send_messages(who, from, messages, domain);
+			if dispatch.is_err() {
+				Self::note_failed_send();
+			}
+
+			dispatch
+		}
+
+		/// add_operator adds an account to the operators that can submit updates.
+		//
+		// Test names: add_operator_works(), add_operator_does_not_work_with_non_root(), add_operator_fails_if_already_operator()
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::add_operator())]
+		pub fn add_operator(origin: OriginFor<T>, operator: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			Operators::<T>::try_mutate(|operators| -> DispatchResult {
+				let index = operators
+					.binary_search(&operator)
+					.err()
+					.ok_or(Error::<T>::AlreadyOperator)?;
+				operators
+					.try_insert(index, operator)
+					.map_err(|_| Error::<T>::TooManyOperators)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::OperatorAdded { operator });
+
+			Ok(())
+		}
+
+		/// remove_operator removes an account from the operators.
+		//
+		// Test names: remove_operator_works(), remove_operator_fails_if_not_operator()
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::remove_operator())]
+		pub fn remove_operator(origin: OriginFor<T>, operator: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			Operators::<T>::try_mutate(|operators| -> DispatchResult {
+				let index = operators
+					.binary_search(&operator)
+					.map_err(|_| Error::<T>::NotOperator)?;
+				operators.remove(index);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::OperatorRemoved { operator });
+
+			Ok(())
+		}
+
+		/// rotate_operator replaces the operator `old` by the account `new`, e.g. when the key of
+		/// an operator is rotated.
+		//
+		// Test names: rotate_operator_works(), rotate_operator_fails_if_not_operator()
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::rotate_operator())]
+		pub fn rotate_operator(origin: OriginFor<T>, old: H256, new: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			Operators::<T>::try_mutate(|operators| -> DispatchResult {
+				let index = operators
+					.binary_search(&old)
+					.map_err(|_| Error::<T>::NotOperator)?;
+				operators.remove(index);
+				let index = operators
+					.binary_search(&new)
+					.err()
+					.ok_or(Error::<T>::AlreadyOperator)?;
+				operators
+					.try_insert(index, new)
+					.map_err(|_| Error::<T>::TooManyOperators)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::OperatorRotated { old, new });
+
+			Ok(())
+		}
+
+		/// set_fulfill_threshold sets how many distinct operators, the updater included, must
+		/// submit the same `fulfill_call` before it is applied.
+		//
+		// Test names: set_fulfill_threshold_works_with_root(), set_fulfill_threshold_fails_if_too_high(), fulfill_call_waits_for_threshold()
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::set_fulfill_threshold())]
+		pub fn set_fulfill_threshold(
+			origin: OriginFor<T>,
+			#[pallet::compact] threshold: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				threshold <= Self::operator_count(),
+				Error::<T>::FulfillThresholdTooHigh
+			);
+			FulfillThreshold::<T>::put(threshold);
+
+			Self::deposit_event(Event::FulfillThresholdUpdated { threshold });
+
+			Ok(())
+		}
+
+		/// set_prover_bond sets the bond that accounts must lock before submitting light client
+		/// updates. Bonds that are already locked are not topped up.
+		//
+		// Test names: set_prover_bond_works_with_root(), set_prover_bond_does_not_work_with_non_root()
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::set_prover_bond())]
+		pub fn set_prover_bond(origin: OriginFor<T>, value: BalanceOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+			ProverBond::<T>::put(value);
+
+			Self::deposit_event(Event::ProverBondUpdated { value });
+
+			Ok(())
+		}
+
+		/// bond locks the prover bond of the caller so it can submit light client updates.
+		//
+		// Test names: bond_works(), bond_fails_if_already_bonded(), fulfill_call_fails_without_bond()
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::bond())]
+		pub fn bond(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Bonds::<T>::get(&who).is_zero(), Error::<T>::AlreadyBonded);
+
+			let amount = ProverBond::<T>::get();
+			ensure!(
+				T::Currency::free_balance(&who) >= amount,
+				Error::<T>::InsufficientBalance
+			);
+			T::Currency::set_lock(PROVER_BOND_ID, &who, amount, WithdrawReasons::all());
+			Bonds::<T>::insert(&who, amount);
+
+			Self::deposit_event(Event::Bonded { who, amount });
+
+			Ok(())
+		}
+
+		/// unbond stops the caller from submitting light client updates. The bond stays locked
+		/// and slashable for the unbonding period.
+		//
+		// Test names: unbond_works(), withdraw_bond_works(), withdraw_bond_fails_while_unbonding()
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::unbond())]
+		pub fn unbond(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Bonds::<T>::contains_key(&who), Error::<T>::NotBonded);
+
+			let until = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::ProverUnbondingPeriod::get());
+			UnbondingUntil::<T>::insert(&who, until);
+
+			Self::deposit_event(Event::Unbonding { who, until });
+
+			Ok(())
+		}
+
+		/// withdraw_bond unlocks the bond of the caller once its unbonding period is over.
+		//
+		// Test names: withdraw_bond_works(), withdraw_bond_fails_while_unbonding()
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::withdraw_bond())]
+		pub fn withdraw_bond(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let until = UnbondingUntil::<T>::get(&who).ok_or(Error::<T>::NotUnbonding)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > until,
+				Error::<T>::BondStillUnbonding
+			);
+
+			UnbondingUntil::<T>::remove(&who);
+			let amount = Bonds::<T>::take(&who);
+			T::Currency::remove_lock(PROVER_BOND_ID, &who);
+
+			Self::deposit_event(Event::BondWithdrawn { who, amount });
+
+			Ok(())
+		}
+
+		/// slash_submission slashes the whole bond of the account that submitted the roots of
+		/// `slot`, after governance ruled that they are invalid. The slashed amount is moved to
+		/// the reward pot.
+		//
+		// Test names: slash_submission_works(), slash_submission_fails_without_submitter()
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::slash_submission())]
+		pub fn slash_submission(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			#[pallet::compact] slot: u64,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let who =
+				SlotSubmitters::<T>::take(domain, slot).ok_or(Error::<T>::SubmissionNotFound)?;
+
+			let bond = Bonds::<T>::take(&who);
+			UnbondingUntil::<T>::remove(&who);
+			T::Currency::remove_lock(PROVER_BOND_ID, &who);
+			let (imbalance, _) = T::Currency::slash(&who, bond);
+			let amount = imbalance.peek();
+			T::Currency::resolve_creating(&Self::reward_pot_account_id(), imbalance);
+
+			Self::deposit_event(Event::ProverSlashed {
+				who,
+				domain,
+				slot,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// set_slot_layout sets the storage layout of the broadcaster of a domain, so a redeployed
+		/// broadcaster with a different layout does not need a runtime upgrade. `None` restores
+		/// the default layout.
+		//
+		// Test names: set_slot_layout_works_with_root(), set_slot_layout_does_not_work_with_non_root(), execute_works_with_slot_layout()
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::set_slot_layout())]
+		pub fn set_slot_layout(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			layout: Option<SlotLayout>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			SlotLayouts::<T>::set(domain, layout);
+
+			Self::deposit_event(Event::SlotLayoutUpdated { domain, layout });
+
+			Ok(())
+		}
+
+		/// invalidate_execution_state_roots removes the execution state roots of a domain from
+		/// `from_slot` to `to_slot` after a source chain consensus fault or a bad verification key.
+		/// Every message proven against them is marked as invalidated, executed or not.
+		/// `max_messages` bounds the number of marked messages.
+		//
+		// Test names: invalidate_execution_state_roots_works(), invalidate_execution_state_roots_does_not_work_with_non_rollback_origin(), invalidate_execution_state_roots_fails_with_invalid_range(), invalidate_execution_state_roots_fails_with_too_many_messages()
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::invalidate_execution_state_roots(
+			weight_helper::slot_range_len(*from_slot, *to_slot),
+			*max_messages
+		))]
+		pub fn invalidate_execution_state_roots(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			#[pallet::compact] from_slot: u64,
+			#[pallet::compact] to_slot: u64,
+			#[pallet::compact] max_messages: u32,
+		) -> DispatchResultWithPostInfo {
+			T::StateRootRollbackOrigin::ensure_origin(origin)?;
+			ensure!(
+				from_slot <= to_slot && to_slot - from_slot < MAX_INVALIDATED_SLOTS as u64,
+				Error::<T>::InvalidSlotRange
+			);
+			ensure!(
+				max_messages <= MAX_INVALIDATED_MESSAGES,
+				Error::<T>::TooManyInvalidatedMessages
+			);
+
+			let mut roots = 0u32;
+			let mut executed = 0u32;
+			let mut pending = 0u32;
+			for slot in from_slot..=to_slot {
+				if ExecutionStateRoots::<T>::take(domain, slot) != H256::zero() {
+					roots += 1;
+				}
+
+				for (message_root, ()) in ProvenMessages::<T>::drain_prefix((domain, slot)) {
+					ensure!(
+						executed + pending < max_messages,
+						Error::<T>::TooManyInvalidatedMessages
+					);
+					let previous = MessageStatus::<T>::get(message_root);
+					if previous == MessageStatusEnum::ExecutionSucceeded {
+						executed += 1;
+					} else {
+						pending += 1;
+					}
+					MessageStatus::<T>::set(message_root, MessageStatusEnum::Invalidated);
+					Self::deposit_event(Event::MessageInvalidated {
+						domain,
+						slot,
+						message_root,
+						previous,
+					});
+				}
+			}
+
+			Self::deposit_event(Event::ExecutionStateRootsInvalidated {
+				domain,
+				from_slot,
+				to_slot,
+				roots,
+				executed,
+				pending,
+			});
+
+			let slots = weight_helper::slot_range_len(from_slot, to_slot);
+			Ok(Some(T::WeightInfo::invalidate_execution_state_roots(
+				slots,
+				executed + pending,
+			))
+			.into())
+		}
+
+		/// set_execution_fee sets the fee that is deducted from executed transfers of an asset and
+		/// paid to the relayer, so recipients without a balance can be served by any relayer.
+		/// `None` removes the fee.
+		//
+		// Test names: set_execution_fee_works_with_root(), set_execution_fee_does_not_work_with_non_root(), execute_pays_execution_fee_to_relayer(), execute_fails_with_amount_below_fee()
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::set_execution_fee())]
+		pub fn set_execution_fee(
+			origin: OriginFor<T>,
+			asset_id: H256,
+			fee: Option<u128>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ExecutionFees::<T>::set(asset_id, fee);
+
+			Self::deposit_event(Event::ExecutionFeeUpdated { asset_id, fee });
+
+			Ok(())
+		}
+
+		/// set_message_abi_version sets the ABI encoding of the messages sent from a domain, so
+		/// older bridge contract deployments keep working while new ones use an updated encoding.
+		//
+		// Test names: set_message_abi_version_works_with_root(), set_message_abi_version_does_not_work_with_non_root(), execute_works_with_message_abi_version()
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::set_message_abi_version())]
+		pub fn set_message_abi_version(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			version: MessageAbiVersion,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			MessageAbiVersions::<T>::insert(domain, version);
+
+			Self::deposit_event(Event::MessageAbiVersionUpdated { domain, version });
+
+			Ok(())
+		}
+
+		/// bind_address binds the foreign address that dispatches it through a bridged call to a
+		/// local account, which then receives the transfers bridged to the address. `None`
+		/// removes the binding.
+		//
+		// Test names: bind_address_works_with_bridged_origin(), bind_address_does_not_work_with_signed_origin(), execute_transfers_to_bound_address()
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::bind_address())]
+		pub fn bind_address(origin: OriginFor<T>, account: Option<T::AccountId>) -> DispatchResult {
+			let BridgedOrigin(_, address) = T::AddressBindingOrigin::ensure_origin(origin)?;
+			AddressBindings::<T>::set(address, account.clone());
+
+			Self::deposit_event(Event::AddressBound { address, account });
+
+			Ok(())
+		}
+
+		/// set_permissioned_relaying restricts the execution of messages to the registered
+		/// relayers, or opens it to everyone again.
+		//
+		// Test names: set_permissioned_relaying_works_with_root(), set_permissioned_relaying_does_not_work_with_non_root(), execute_fails_with_unregistered_relayer()
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::set_permissioned_relaying())]
+		pub fn set_permissioned_relaying(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			ensure_root(origin)?;
+			PermissionedRelaying::<T>::put(enabled);
+
+			Self::deposit_event(Event::PermissionedRelayingUpdated { enabled });
+
+			Ok(())
+		}
+
+		/// add_relayer registers a relayer that can execute messages while relaying is
+		/// permissioned.
+		//
+		// Test names: add_relayer_works(), add_relayer_does_not_work_with_non_root(), add_relayer_fails_if_already_relayer(), execute_fails_with_unregistered_relayer()
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::add_relayer())]
+		pub fn add_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				!Relayers::<T>::contains_key(&relayer),
+				Error::<T>::AlreadyRelayer
+			);
+			Relayers::<T>::insert(&relayer, ());
+
+			Self::deposit_event(Event::RelayerAdded { relayer });
+
+			Ok(())
+		}
+
+		/// remove_relayer deregisters a relayer.
+		//
+		// Test names: remove_relayer_works(), remove_relayer_fails_if_not_relayer()
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::remove_relayer())]
+		pub fn remove_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				Relayers::<T>::take(&relayer).is_some(),
+				Error::<T>::NotRelayer
+			);
+
+			Self::deposit_event(Event::RelayerRemoved { relayer });
+
+			Ok(())
+		}
+
+		/// freeze_source_chain freezes a source chain like source_chain_froze and records the
+		/// reason and the origin of the freeze.
+		//
+		// Test names: freeze_source_chain_works_with_root(), freeze_source_chain_does_not_work_with_signed_origin()
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::freeze_source_chain())]
+		pub fn freeze_source_chain(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			reason: FreezeReason,
+		) -> DispatchResult {
+			let frozen_by = Self::ensure_freeze_origin(origin)?;
+			Self::freeze(domain, reason, frozen_by);
+
+			Ok(())
+		}
+
+		/// unfreeze_source_chain lifts the freeze of a source chain.
+		//
+		// Test names: unfreeze_source_chain_works(), unfreeze_source_chain_fails_if_not_frozen()
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::unfreeze_source_chain())]
+		pub fn unfreeze_source_chain(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+		) -> DispatchResult {
+			Self::ensure_freeze_origin(origin)?;
+			ensure!(
+				SourceChainFrozen::<T>::get(domain),
+				Error::<T>::SourceChainNotFrozen
+			);
+			Self::unfreeze(domain);
+
+			Ok(())
+		}
+
+		/// fulfill_and_execute applies a light client update with fulfill_call and executes a
+		/// batch of messages of the updated domain with execute_batch in one atomic call, so a
+		/// relayer does not race others between the update and the execution. The messages are
+		/// proven against the execution state root of `execute_slot`. The weight declared for
+		/// the largest proofs is refunded down to the provided proof bytes.
+		//
+		// Test names: fulfill_and_execute_works(), fulfill_and_execute_reverts_update_on_failed_execution(), fulfill_and_execute_fails_with_other_origin_domain()
+		#[pallet::call_index(59)]
+		#[pallet::weight((
+			weight_helper::fulfill_call::<T>(*function_id).0
+				.saturating_add(weight_helper::execute_batch::<T>(messages))
+				.saturating_add(weight_helper::proof_bytes::<T>(weight_helper::max_batch_proof_bytes(messages))),
+			DispatchClass::Normal,
+			weight_helper::fee_modifier::<T>(*domain)
+		))]
+		pub fn fulfill_and_execute(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			function_id: H256,
+			input: FunctionInput,
+			output: FunctionOutput,
+			proof: FunctionProof,
+			#[pallet::compact] slot: u64,
+			#[pallet::compact] execute_slot: u64,
+			messages: ExecuteBatch,
+			account_proof: ValidProof,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				messages
+					.iter()
+					.all(|(message, _)| message.origin_domain == domain),
+				Error::<T>::BatchOriginDomainMismatch
+			);
+			let fulfill_weight = weight_helper::fulfill_call::<T>(function_id).0;
+			Self::fulfill_call(
+				origin.clone(),
+				domain,
+				function_id,
+				input,
+				output,
+				proof,
+				slot,
+			)?;
+			let post_info = Self::execute_batch(origin, execute_slot, messages, account_proof)?;
+
+			Ok(post_info
+				.actual_weight
+				.map(|weight| weight.saturating_add(fulfill_weight))
+				.into())
+		}
+
+		/// cancel_verification_key removes the verification key scheduled for a circuit before
+		/// its activation.
+		//
+		// Test names: cancel_verification_key_works(), cancel_verification_key_fails_without_pending_key()
+		#[pallet::call_index(60)]
+		#[pallet::weight(T::WeightInfo::cancel_verification_key())]
+		pub fn cancel_verification_key(
+			origin: OriginFor<T>,
+			kind: VerificationKeyKind,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let pending = PendingVerificationKeys::<T>::take(kind)
+				.ok_or(Error::<T>::NoPendingVerificationKey)?;
+
+			Self::deposit_event(Event::VerificationKeyCancelled {
+				kind,
+				version: pending.version,
+			});
+
+			Ok(())
+		}
+
+		/// set_slot_timing sets the genesis time and slot duration of the source chain of a
+		/// domain, which date its slots for the message TTL and the verified heads. `None`
+		/// restores the timing of the broadcaster domain.
+		//
+		// Test names: set_slot_timing_works_with_root(), set_slot_timing_does_not_work_with_non_root(), execute_expires_with_slot_timing_of_origin_domain()
+		#[pallet::call_index(61)]
+		#[pallet::weight(T::WeightInfo::set_slot_timing())]
+		pub fn set_slot_timing(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			timing: Option<SlotTiming>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			SlotTimings::<T>::set(domain, timing);
+
+			Self::deposit_event(Event::SlotTimingUpdated { domain, timing });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn do_send_message(
+			who: T::AccountId,
+			from: H256,
+			message: Message,
+			to: H256,
+			domain: u32,
+		) -> DispatchResultWithPostInfo {
+			// Ensure the domain is currently supported
+			ensure!(
+				Self::is_domain_valid(domain),
+				Error::<T>::DomainNotSupported
+			);
+			Self::ensure_not_paused(domain)?;
+			let message_type = message.r#type();
+			Self::lock_message(&who, message.clone())?;
+			Self::charge_message_fee(&who, Self::payload_len(&message))?;
+
+			let message_id = Self::fetch_curr_message_id()?;
+			MessageVolume::<T>::mutate(domain, |volume| *volume = volume.saturating_add(1));
+			let leaf_index = Self::take_leaf_indices(1);
+			let message_hash =
+				Self::note_outbound_message(message, from, to, domain, message_id, leaf_index);
+
+			Self::deposit_event(Event::MessageSubmitted {
+				from: who,
+				to,
+				message_type,
+				origin_domain: T::AvailDomain::get(),
+				destination_domain: domain,
+				message_id,
+				message_hash,
+				leaf_index,
+			});
+
+			Ok(().into())
+		}
+
+		fn do_send_messages(
+			who: T::AccountId,
+			from: H256,
+			messages: SendBatch,
+			domain: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure!(!messages.is_empty(), Error::<T>::EmptySendBatch);
+			ensure!(
+				Self::is_domain_valid(domain),
+				Error::<T>::DomainNotSupported
+			);
+			Self::ensure_not_paused(domain)?;
+			let mut payload_len = 0u32;
+			for (message, _) in messages.iter() {
+				Self::lock_message(&who, message.clone())?;
+				payload_len = payload_len.saturating_add(Self::payload_len(message));
+			}
+			Self::charge_message_fee(&who, payload_len)?;
+
+			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let tx_index = <frame_system::Pallet<T>>::extrinsic_index()
+				.ok_or(Error::<T>::CurrentMessageIdNotFound)?;
+			ensure!(
+				tx_index <= u16::MAX as u32,
+				Error::<T>::BatchMessageIdOverflow
+			);
+			let len = messages.len() as u32;
+			let leaf_index = Self::take_leaf_indices(len);
+			let (message_ids, message_hashes) = messages
+				.into_iter()
+				.zip(0u32..)
+				.map(|((message, to), index)| {
+					let message_id = batch_message_id(number, tx_index, index);
+					let message_hash = Self::note_outbound_message(
+						message,
+						from,
+						to,
+						domain,
+						message_id,
+						leaf_index.saturating_add(index),
+					);
+					(message_id, message_hash)
+				})
+				.unzip::<_, _, Vec<_>, Vec<_>>();
+			MessageVolume::<T>::mutate(domain, |volume| *volume = volume.saturating_add(len));
+
+			Self::deposit_event(Event::MessagesSubmitted {
+				from: who,
+				origin_domain: T::AvailDomain::get(),
+				destination_domain: domain,
+				message_ids,
+				message_hashes,
+				leaf_index,
+			});
+
+			Ok(().into())
+		}
+
+		/// Stores the preimage of a message sent from Avail at `leaf_index` of the bridge root,
+		/// and the receipt of a withdrawal, and returns the keccak hash of the ABI encoded message,
+		/// as committed in the bridge root.
+		fn note_outbound_message(
+			message: Message,
+			from: H256,
+			to: H256,
+			domain: u32,
+			message_id: u64,
+			leaf_index: u32,
+		) -> H256 {
+			let block = <frame_system::Pallet<T>>::block_number();
+			if let Message::FungibleToken { asset_id, amount } = message {
+				let receipt = WithdrawalReceipt {
+					tx_index: <frame_system::Pallet<T>>::extrinsic_index().unwrap_or_default(),
+					leaf_index,
+					asset_id,
+					amount: amount.saturated_into(),
+					recipient: to,
+					destination_domain: domain,
+				};
+				WithdrawalReceipts::<T>::insert(block, message_id, receipt);
+			}
+
+			let addr_message =
+				AddressedMessage::new(message, from, to, T::AvailDomain::get(), domain, message_id);
+			match BoundedVec::try_from(addr_message.encode()) {
+				Ok(preimage) => MessagePreimages::<T>::insert(block, message_id, preimage),
+				Err(_) => {
+					log::warn!(target: LOG_TARGET, "Preimage of message {message_id} too long");
+				},
+			}
+			H256(keccak_256(addr_message.abi_encode().as_slice()))
+		}
+
+		/// Removes the message preimages and withdrawal receipts of the block that left the
+		/// retention window at `n`.
+		fn prune_message_preimages(n: BlockNumberFor<T>) -> Weight {
+			let retention = T::MessagePreimageRetention::get();
+			if n <= retention {
+				return Weight::zero();
+			}
+			let preimages = MessagePreimages::<T>::clear_prefix(n - retention, u32::MAX, None);
+			let receipts = WithdrawalReceipts::<T>::clear_prefix(n - retention, u32::MAX, None);
+			T::DbWeight::get().reads_writes(
+				preimages.loops.saturating_add(receipts.loops).into(),
+				preimages.unique.saturating_add(receipts.unique).into(),
+			)
+		}
+
+		/// Reserves the next `count` leaves of the bridge root of the block and returns the index
+		/// of the first one.
+		fn take_leaf_indices(count: u32) -> u32 {
+			OutboundMessageCount::<T>::mutate(|next| {
+				let leaf_index = *next;
+				*next = next.saturating_add(count);
+				leaf_index
+			})
+		}
+
+		/// Number of accounts that can approve a fulfill call, the updater included.
+		fn operator_count() -> u32 {
+			(Operators::<T>::decode_len().unwrap_or_default() as u32).saturating_add(1)
+		}
+
+		/// Checks the rules of `message` and locks or burns the bridged amount of `who`.
+		fn lock_message(who: &T::AccountId, message: Message) -> DispatchResult {
+			match message {
+				Message::FungibleToken { asset_id, amount } => {
+					let asset =
+						AssetRegistry::<T>::get(asset_id).ok_or(Error::<T>::AssetNotSupported)?;
+					ensure!(
+						amount.saturated_into::<u128>() > 0,
+						Error::<T>::InvalidBridgeInputs
+					);
+					ensure!(amount >= asset.min_amount, Error::<T>::AmountBelowMinimum);
+					match asset.kind {
+						AssetKind::Native => T::Currency::transfer(
+							who,
+							&Self::account_id(),
+							amount.saturated_into(),
+							ExistenceRequirement::KeepAlive,
+						)?,
+						AssetKind::Local(id) => {
+							T::Assets::burn(id, who, amount)?;
+							BridgedSupply::<T>::mutate(asset_id, |supply| {
+								*supply = supply.saturating_sub(amount)
+							});
+						},
+					}
+				},
+				Message::ArbitraryMessage(data) => {
+					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
+				},
+			};
+
+			Ok(())
+		}
+
+		/// Pays the message fee of `who` into the relayer reward pot.
+		fn charge_message_fee(who: &T::AccountId, payload_len: u32) -> DispatchResult {
+			let fee = Self::message_fee(payload_len);
+			if !fee.is_zero() {
+				T::Currency::transfer(
+					who,
+					&Self::reward_pot_account_id(),
+					fee,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+
+			Ok(())
+		}
+
+		/// Fee of sending messages with `payload_len` bytes of arbitrary message payload.
+		pub fn message_fee(payload_len: u32) -> BalanceOf<T> {
+			let byte_fee = T::ArbitraryMessageByteFee::get().saturating_mul(payload_len.into());
+			MessageFee::<T>::get().saturating_add(byte_fee)
+		}
+
+		/// Length of the payload of `message` charged by `ArbitraryMessageByteFee`.
+		fn payload_len(message: &Message) -> u32 {
+			match message {
+				Message::ArbitraryMessage(data) => data.len() as u32,
+				Message::FungibleToken { .. } => 0,
+			}
+		}
+
+		/// Records the current extrinsic as a failed send, so that its messages are left out of
+		/// the bridge root of the block.
+		fn note_failed_send() {
+			let _ = MemoryTemporaryStorage::update::<Vec<Compact<u32>>, _>(
+				FAILED_SEND_MSG_ID.to_vec(),
+				|failed| {
+					let tx_idx_result = <frame_system::Pallet<T>>::extrinsic_index();
+					// this should never happen and we can just log warn
+					if tx_idx_result.is_none() {
+						log::warn!(target: LOG_TARGET, "Transaction index is none!");
+					}
+					let tx_idx = tx_idx_result.unwrap_or_default();
+					failed.push(tx_idx.into());
+					log::trace!(target: LOG_TARGET, "Send Message failed txs: {failed:?}");
+				},
+			);
+		}
+
+		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
+			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let tx_index_option = <frame_system::Pallet<T>>::extrinsic_index();
+
+			match tx_index_option {
+				Some(tx_index) => Ok(tx_uid(number, tx_index)),
+				None => Err(Error::<T>::CurrentMessageIdNotFound.into()),
+			}
+		}
+
+		/// Verifies the account proof of the `origin_domain` broadcaster against the execution
+		/// state root of `slot` and returns the storage root of the broadcaster contract.
+		pub(crate) fn broadcaster_storage_root(
+			origin_domain: u32,
+			slot: u64,
+			account_proof: &ValidProof,
+		) -> Result<H256, DispatchError> {
+			ensure!(
+				!SourceChainFrozen::<T>::get(origin_domain),
+				Error::<T>::SourceChainFrozen
+			);
+			ensure!(
+				ExecutionModes::<T>::get(origin_domain) == ExecutionMode::StorageProof,
+				Error::<T>::ExecutionModeMismatch
+			);
+			Self::ensure_confirmed(origin_domain, slot)?;
+			let root = ExecutionStateRoots::<T>::get(origin_domain, slot);
+			let broadcaster = Broadcasters::<T>::get(origin_domain);
+
+			// extract contract address
+			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
+			let account_proof_vec: Vec<Vec<u8>> = account_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			if let Some(expected_code_hash) = BroadcasterCodeHashes::<T>::get(origin_domain) {
+				let code_hash = get_code_hash(
+					account_proof_vec.clone(),
+					contract_broadcaster_address,
+					root,
+				)
+				.map_err(|_| Error::<T>::CannotGetCodeHash)?;
+				ensure!(
+					code_hash == expected_code_hash,
+					Error::<T>::BroadcasterCodeHashMismatch
+				);
+			}
+
+			let storage_root =
+				get_storage_root(account_proof_vec, contract_broadcaster_address, root)
+					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
+
+			Ok(storage_root)
+		}
+
+		/// Verifies the storage proof of the message against the broadcaster `storage_root`
+		/// and executes it.
+		fn execute_message(
+			relayer: &T::AccountId,
+			addr_message: AddressedMessage,
+			message_root: H256,
+			slot: u64,
+			storage_root: H256,
+			storage_proof: ValidProof,
+		) -> DispatchResult {
+			Self::verify_message_inclusion(
+				addr_message.origin_domain,
+				addr_message.id,
+				message_root,
+				storage_root,
+				storage_proof,
+			)?;
+
+			Self::execute_proven_message(relayer, addr_message, message_root, slot)
+		}
+
+		/// Verifies that the receipt `proof` holds the `MessageSent` log of the message and
+		/// executes it.
+		fn execute_message_with_receipt(
+			relayer: &T::AccountId,
+			addr_message: AddressedMessage,
+			message_root: H256,
+			slot: u64,
+			proof: ReceiptProof,
+		) -> DispatchResult {
+			let origin_domain = addr_message.origin_domain;
+			ensure!(
+				!SourceChainFrozen::<T>::get(origin_domain),
+				Error::<T>::SourceChainFrozen
+			);
+			ensure!(
+				ExecutionModes::<T>::get(origin_domain) == ExecutionMode::ReceiptProof,
+				Error::<T>::ExecutionModeMismatch
+			);
+
+			Self::ensure_confirmed(origin_domain, slot)?;
+
+			let header_root = Headers::<T>::get(origin_domain, slot);
+			ensure!(header_root != H256::zero(), Error::<T>::HeaderRootNotSet);
+			let fork = ForkDigests::<T>::get(origin_domain, proof.fork_digest)
+				.ok_or(Error::<T>::UnknownForkDigest)?;
+			ensure!(
+				verify_receipts_root(header_root, fork, &proof),
+				Error::<T>::InvalidReceiptsRootBranch
+			);
+
+			let receipt_proof_vec = proof
+				.receipt_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+			let log = get_receipt_log(
+				receipt_proof_vec,
+				proof.receipts_root,
+				proof.tx_index,
+				proof.log_index,
+			)
+			.map_err(|_| Error::<T>::CannotGetReceiptLog)?;
+
+			let broadcaster = Broadcasters::<T>::get(origin_domain);
+			let expected_topics = [
+				H256(keccak_256(MESSAGE_SENT_EVENT_SIGNATURE)),
+				message_id_topic(addr_message.id),
+				message_root,
+			];
+			ensure!(
+				log.address == H160::from_slice(broadcaster[..20].as_ref())
+					&& log.topics.as_slice() == expected_topics.as_slice(),
+				Error::<T>::InvalidMessageLog
+			);
+
+			Self::execute_proven_message(relayer, addr_message, message_root, slot)
+		}
+
+		/// Executes a message whose inclusion on the source chain is proven at `slot`. Transient
+		/// failures are recorded so that the message can be retried.
+		fn execute_proven_message(
+			relayer: &T::AccountId,
+			addr_message: AddressedMessage,
+			message_root: H256,
+			slot: u64,
+		) -> DispatchResult {
+			ProvenMessages::<T>::insert((addr_message.origin_domain, slot, message_root), ());
+			match Self::dispatch_message(relayer, &addr_message)? {
+				Ok(result) => Self::mark_executed(&addr_message, message_root, result),
+				Err(reason) => {
+					if reason == ExecutionFailureReason::OutOfOrder {
+						QueuedMessages::<T>::insert(
+							(
+								addr_message.origin_domain,
+								addr_message.from,
+								addr_message.id,
+							),
+							message_root,
+						);
+					}
+					MessageStatus::<T>::set(
+						message_root,
+						MessageStatusEnum::ExecutionFailed(reason),
+					);
+					Self::deposit_event(Event::<T>::MessageExecutionFailed {
+						from: addr_message.from,
+						to: addr_message.to,
+						message_id: addr_message.id,
+						message_root,
+						reason,
+					});
+					T::OnMessageExecuted::on_message_executed(
+						&addr_message,
+						&MessageOutcome::Failed(reason),
+					);
+				},
+			}
+			MessageVolume::<T>::mutate(addr_message.origin_domain, |volume| {
+				*volume = volume.saturating_add(1)
+			});
+
+			Ok(())
+		}
+
+		/// Delivers a proven message to its recipient. The execution fee of a bridged asset is
+		/// paid to `relayer` out of the transferred amount. Failures that may go away later are
+		/// returned as the inner error so the message can be retried. A delivered message returns
+		/// the result of its bridged runtime call, or `Ok` for other messages.
+		fn dispatch_message(
+			relayer: &T::AccountId,
+			addr_message: &AddressedMessage,
+		) -> Result<Result<DispatchResult, ExecutionFailureReason>, DispatchError> {
+			let next_id = OrderedDelivery::<T>::get(addr_message.origin_domain, addr_message.from);
+			if next_id.is_some_and(|next_id| next_id != addr_message.id) {
+				return Ok(Err(ExecutionFailureReason::OutOfOrder));
+			}
+
+			let result = match &addr_message.message {
+				Message::FungibleToken { asset_id, amount } => {
+					let Some(asset) = AssetRegistry::<T>::get(asset_id) else {
+						return Ok(Err(ExecutionFailureReason::AssetNotSupported));
+					};
+					if *amount < asset.min_amount {
+						return Ok(Err(ExecutionFailureReason::AmountBelowMinimum));
+					}
+
+					let destination_account_id = Self::destination_account(addr_message.to)
+						.ok_or(Error::<T>::CannotDecodeDestinationAccountId)?;
+
+					let volume = match AssetRateLimits::<T>::get(asset_id) {
+						Some(limit) => {
+							let volume = Self::bridged_volume(*asset_id, *amount, &limit);
+							if volume.amount > limit.max_amount {
+								Self::deposit_event(Event::TransferThrottled {
+									asset_id: *asset_id,
+									amount: *amount,
+									message_id: addr_message.id,
+								});
+								return Ok(Err(ExecutionFailureReason::RateLimited));
+							}
+							Some(volume)
+						},
+						None => None,
+					};
+
+					let fee = ExecutionFees::<T>::get(asset_id).unwrap_or_default();
+					if !fee.is_zero() && *amount <= fee {
+						return Ok(Err(ExecutionFailureReason::AmountBelowFee));
+					}
+
+					let transfer = with_storage_layer(|| -> DispatchResult {
+						Self::pay_out(asset.kind, &destination_account_id, *amount - fee)?;
+						if !fee.is_zero() {
+							Self::pay_out(asset.kind, relayer, fee)?;
+						}
+						if let AssetKind::Local(_) = asset.kind {
+							BridgedSupply::<T>::mutate(asset_id, |supply| {
+								*supply = supply.saturating_add(*amount)
+							});
+						}
+						Ok(())
+					});
+					if transfer.is_err() {
+						return Ok(Err(ExecutionFailureReason::TransferFailed));
+					}
+					if let Some(volume) = volume {
+						BridgedVolumes::<T>::insert(asset_id, volume);
+					}
+					if !fee.is_zero() {
+						Self::deposit_event(Event::ExecutionFeePaid {
+							relayer: relayer.clone(),
+							asset_id: *asset_id,
+							amount: fee,
+							message_id: addr_message.id,
+						});
+					}
+					Ok(())
+				},
+				Message::ArbitraryMessage(data) if Self::is_bridged_call(addr_message) => {
+					let result = Self::dispatch_bridged_call(
+						addr_message.origin_domain,
+						addr_message.from,
+						data.as_slice(),
+					);
+					Self::deposit_event(Event::BridgedCallDispatched {
+						origin_domain: addr_message.origin_domain,
+						sender: addr_message.from,
+						message_id: addr_message.id,
+						result,
+					});
+					result
+				},
+				Message::ArbitraryMessage(data) => {
+					T::MessageHandler::handle(
+						addr_message.origin_domain,
+						addr_message.from,
+						addr_message.to,
+						data.as_slice(),
+					)?;
+					Ok(())
+				},
+			};
+
+			if next_id.is_some() {
+				OrderedDelivery::<T>::insert(
+					addr_message.origin_domain,
+					addr_message.from,
+					addr_message.id.saturating_add(1),
+				);
+			}
+
+			Ok(Ok(result))
+		}
+
+		/// Pays out `amount` of a bridged asset of `kind` to `who`, from the bridge account for the
+		/// native asset or by minting a local asset.
+		fn pay_out(kind: AssetKind, who: &T::AccountId, amount: u128) -> DispatchResult {
+			match kind {
+				AssetKind::Native => T::Currency::transfer(
+					&Self::account_id(),
+					who,
+					amount.saturated_into(),
+					ExistenceRequirement::AllowDeath,
+				),
+				AssetKind::Local(id) => T::Assets::mint(id, who, amount),
+			}
+		}
+
+		/// Returns the bridged volume of `asset_id` once `amount` is bridged in. A new window is
+		/// started if the current one is over.
+		fn bridged_volume(
+			asset_id: H256,
+			amount: u128,
+			limit: &RateLimit<BlockNumberFor<T>>,
+		) -> BridgedVolume<BlockNumberFor<T>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut volume = BridgedVolumes::<T>::get(asset_id);
+			if now >= volume.window_start.saturating_add(limit.window) {
+				volume = BridgedVolume {
+					window_start: now,
+					amount: 0,
+				};
+			}
+			volume.amount = volume.amount.saturating_add(amount);
+			volume
+		}
+
+		/// Ensures that `origin` can freeze source chains and returns which origin it is.
+		fn ensure_freeze_origin(origin: OriginFor<T>) -> Result<FreezeOrigin, DispatchError> {
+			if ensure_root(origin.clone()).is_ok() {
+				return Ok(FreezeOrigin::Root);
+			}
+			T::DomainPauseOrigin::ensure_origin(origin)?;
+			Ok(FreezeOrigin::DomainPauseOrigin)
+		}
+
+		/// Freezes the source chain `domain` and records the freeze.
+		fn freeze(domain: u32, reason: FreezeReason, frozen_by: FreezeOrigin) {
+			SourceChainFrozen::<T>::set(domain, true);
+			SourceChainFreezes::<T>::insert(
+				domain,
+				FreezeStatus {
+					reason,
+					frozen_by,
+					frozen_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::SourceChainFreezeStarted {
+				domain,
+				reason,
+				frozen_by,
+			});
+		}
+
+		/// Schedules `value` as the verification key of `kind` after the activation delay. A
+		/// cleared key takes effect immediately and cancels the scheduled one.
+		fn update_verification_key(
+			kind: VerificationKeyKind,
+			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
+		) {
+			let Some(key) = value else {
+				if let Some(pending) = PendingVerificationKeys::<T>::take(kind) {
+					Self::deposit_event(Event::VerificationKeyCancelled {
+						kind,
+						version: pending.version,
+					});
+				}
+				Self::set_verification_key(kind, None);
+				return;
+			};
+
+			let version = VerificationKeyVersions::<T>::get(kind).saturating_add(1);
+			let activation = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::VerificationKeyActivationDelay::get());
+			let key_hash = H256(keccak_256(key.as_slice()));
+			PendingVerificationKeys::<T>::insert(
+				kind,
+				PendingVerificationKey {
+					key,
+					version,
+					activation,
+				},
+			);
+
+			Self::deposit_event(Event::VerificationKeyScheduled {
+				kind,
+				version,
+				key_hash,
+				activation,
+			});
+		}
+
+		/// Activates the scheduled verification keys due at `n`.
+		fn activate_verification_keys(n: BlockNumberFor<T>) -> Weight {
+			let kinds = [
+				VerificationKeyKind::Step,
+				VerificationKeyKind::Rotate,
+				VerificationKeyKind::Finality,
+			];
+			let mut weight = T::DbWeight::get().reads(kinds.len() as u64);
+			for kind in kinds {
+				let Some(pending) =
+					PendingVerificationKeys::<T>::get(kind).filter(|p| n >= p.activation)
+				else {
+					continue;
+				};
+
+				PendingVerificationKeys::<T>::remove(kind);
+				VerificationKeyVersions::<T>::insert(kind, pending.version);
+				Self::set_verification_key(kind, Some(pending.key));
+				Self::deposit_event(Event::VerificationKeyActivated {
+					kind,
+					version: pending.version,
+				});
+				weight = weight.saturating_add(T::DbWeight::get().writes(3));
+			}
+
+			weight
+		}
+
+		/// Sets the active verification key of `kind`.
+		fn set_verification_key(
+			kind: VerificationKeyKind,
+			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
+		) {
+			match kind {
+				VerificationKeyKind::Step => {
+					StepVerificationKey::<T>::put(value.clone());
+					Self::deposit_event(Event::StepVerificationKeyUpdated { value });
+				},
+				VerificationKeyKind::Rotate => {
+					RotateVerificationKey::<T>::put(value.clone());
+					Self::deposit_event(Event::RotateVerificationKeyUpdated { value });
+				},
+				VerificationKeyKind::Finality => {
+					FinalityVerificationKey::<T>::set(value.clone());
+					Self::deposit_event(Event::FinalityVerificationKeyUpdated { value });
+				},
+			}
+		}
+
+		/// Unfreezes the source chain `domain`.
+		fn unfreeze(domain: u32) {
+			SourceChainFrozen::<T>::remove(domain);
+			SourceChainFreezes::<T>::remove(domain);
+
+			Self::deposit_event(Event::SourceChainFreezeLifted { domain });
+		}
+
+		/// Ensures that `who` may execute messages.
+		fn ensure_relayer(who: &T::AccountId) -> DispatchResult {
+			ensure!(
+				!PermissionedRelaying::<T>::get() || Relayers::<T>::contains_key(who),
+				Error::<T>::RelayerNotAllowed
+			);
+			Ok(())
+		}
+
+		/// Local account that receives the transfers bridged to `address`.
+		pub(crate) fn destination_account(address: H256) -> Option<T::AccountId> {
+			AddressBindings::<T>::get(address)
+				.or_else(|| T::AddressMapping::into_account_id(address))
+		}
+
+		/// Returns true if `addr_message` is addressed to the bridge itself, in which case its
+		/// payload is a SCALE encoded runtime call.
+		pub(crate) fn is_bridged_call(addr_message: &AddressedMessage) -> bool {
+			matches!(addr_message.message, Message::ArbitraryMessage(_))
+				&& T::AccountId::decode(&mut &addr_message.to.encode()[..])
+					.is_ok_and(|to| to == Self::account_id())
+		}
+
+		/// Dispatches the runtime call `data` with the `BridgedOrigin` of `sender` on
+		/// `origin_domain`. A failing call still consumes the message.
+		fn dispatch_bridged_call(origin_domain: u32, sender: H256, data: &[u8]) -> DispatchResult {
+			let call = <T as Config>::RuntimeCall::decode_all_with_depth_limit(
+				MAX_BRIDGED_CALL_DECODE_DEPTH,
+				&mut &data[..],
+			)
+			.map_err(|_| Error::<T>::CannotDecodeBridgedCall)?;
+			ensure!(
+				call.get_dispatch_info()
+					.weight
+					.all_lte(T::MaxBridgedCallWeight::get()),
+				Error::<T>::BridgedCallTooHeavy
+			);
+
+			let origin: OriginFor<T> =
+				T::RuntimeBridgedOrigin::from(BridgedOrigin(origin_domain, sender)).into();
+			call.dispatch_bypass_filter(origin)
+				.map(|_| ())
+				.map_err(|e| e.error)
+		}
+
+		/// Marks a message as successfully executed.
+		fn mark_executed(
+			addr_message: &AddressedMessage,
+			message_root: H256,
+			result: DispatchResult,
+		) {
+			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
+			let block = frame_system::Pallet::<T>::block_number();
+			ExecutedMessages::<T>::insert(block, message_root, ());
+			if let Message::FungibleToken { asset_id, amount } = addr_message.message {
+				let transfer = (
+					addr_message.id,
+					addr_message.origin_domain,
+					asset_id,
+					amount.saturated_into(),
+				);
+				ExecutedTransfers::<T>::insert(block, message_root, transfer);
+			}
+			Self::deposit_event(Event::<T>::MessageExecuted {
+				from: addr_message.from,
+				to: addr_message.to,
+				message_id: addr_message.id,
+				message_root,
+				origin_domain: addr_message.origin_domain,
+				destination_domain: addr_message.destination_domain,
+				result,
+			});
+			T::OnMessageExecuted::on_message_executed(
+				addr_message,
+				&MessageOutcome::Executed(result),
+			);
+		}
+
+		/// Root of a message sent from another domain, hashed with the ABI version of the domain.
+		pub(crate) fn message_root(addr_message: &AddressedMessage) -> H256 {
+			let version = MessageAbiVersions::<T>::get(addr_message.origin_domain);
+			H256(keccak_256(&version.encode_message(addr_message)))
+		}
+
+		/// Base slot of the message mapping of the broadcaster of `domain`.
+		fn message_mapping_slot(domain: u32) -> u64 {
+			SlotLayouts::<T>::get(domain)
+				.map_or_else(T::MessageMappingStorageIndex::get, |layout| {
+					layout.message_mapping
+				})
+		}
+
+		/// Verifies that the storage of the broadcaster of `domain` under `message_id` holds
+		/// `message_root`.
+		fn verify_message_inclusion(
+			domain: u32,
+			message_id: u64,
+			message_root: H256,
+			storage_root: H256,
+			storage_proof: ValidProof,
+		) -> DispatchResult {
+			let message_id = Uint(U256::from(message_id));
+			let mm_idx = Uint(U256::from(Self::message_mapping_slot(domain)));
+			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
+
+			let storage_proof_vec = storage_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
+				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+
+			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
+
+			Ok(())
+		}
+
+		/// Raises the fee multiplier of every domain whose message volume in the previous block
+		/// was above the target and decays the multiplier of all other domains.
+		/// Returns the number of processed domains.
+		pub(crate) fn adjust_fee_multipliers() -> u32 {
+			let target = T::MessageVolumeTarget::get();
+			let max = T::MaxFeeMultiplier::get();
+			let volumes: Vec<(u32, u32)> = MessageVolume::<T>::drain().collect();
+			let multipliers: Vec<(u32, u32)> = FeeMultipliers::<T>::iter().collect();
+
+			for (domain, multiplier) in multipliers.iter() {
+				if volumes.iter().any(|(d, v)| d == domain && *v > target) {
+					continue;
+				}
+				let multiplier = multiplier.saturating_sub(1);
+				if multiplier > 1 {
+					FeeMultipliers::<T>::insert(domain, multiplier);
+				} else {
+					FeeMultipliers::<T>::remove(domain);
+				}
+			}
+
+			for (domain, _) in volumes.iter().filter(|(_, v)| *v > target) {
+				FeeMultipliers::<T>::mutate(domain, |multiplier| {
+					let next = multiplier.unwrap_or(1).saturating_add(1).min(max);
+					*multiplier = Some(next);
+				});
+			}
+
+			(volumes.len() + multipliers.len()) as u32
+		}
+
+		/// Returns true if messages proven against `slot` of `domain` are older than the message
+		/// TTL.
+		fn is_expired(domain: u32, slot: u64) -> bool {
+			let Some(ttl) = MessageTtl::<T>::get() else {
+				return false;
+			};
+			let now = T::TimeProvider::now().as_secs();
+
+			now >= Self::slot_timestamp(domain, slot).saturating_add(ttl)
+		}
+
+		/// Unix timestamp of `slot` on the source chain of `domain`, in seconds.
+		fn slot_timestamp(domain: u32, slot: u64) -> u64 {
+			let timing = SlotTimings::<T>::get(domain).unwrap_or_else(|| SlotTiming {
+				genesis_time: GenesisTimestamp::<T>::get(),
+				seconds_per_slot: SecondsPerSlot::<T>::get(),
+			});
+			timing
+				.genesis_time
+				.saturating_add(slot.saturating_mul(timing.seconds_per_slot))
+		}
+
+		fn check_preconditions(
+			message: &AddressedMessage,
+			message_root: H256,
+		) -> Result<(), DispatchError> {
+			let message_status = MessageStatus::<T>::get(message_root);
+			// Message must not be executed
+			ensure!(
+				message_status == MessageStatusEnum::NotExecuted,
+				Error::<T>::MessageAlreadyExecuted
+			);
+
+			ensure!(
+				message.destination_domain == T::AvailDomain::get(),
+				Error::<T>::WrongDestinationChain
+			);
+
+			ensure!(
+				Self::is_domain_valid(message.origin_domain),
+				Error::<T>::UnsupportedOriginChain
+			);
+
+			Self::ensure_not_paused(message.origin_domain)?;
+
+			let source_chain = Broadcasters::<T>::get(message.origin_domain);
+			ensure!(
+				source_chain != H256::zero(),
+				Error::<T>::BroadcasterSourceChainNotSet
+			);
+
+			Ok(())
+		}
+
+		/// Ensures that `slot` is at least the confirmation depth of `domain` behind its head.
+		/// Domains without a confirmation depth accept any slot with a known root.
+		fn ensure_confirmed(domain: u32, slot: u64) -> DispatchResult {
+			let depth = ConfirmationDepths::<T>::get(domain);
+			if depth == 0 {
+				return Ok(());
+			}
+			ensure!(
+				slot.saturating_add(depth) <= Head::<T>::get(domain),
+				Error::<T>::SlotNotConfirmed
+			);
+			Ok(())
+		}
+
+		/// Ensures that neither `domain` nor the whole bridge is paused.
+		fn ensure_not_paused(domain: u32) -> DispatchResult {
+			ensure!(!PausedDomains::<T>::get(domain), Error::<T>::DomainPaused);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				!EmergencyPauseExpiry::<T>::get().is_some_and(|expiry| now < expiry),
+				Error::<T>::EmergencyPaused
+			);
+			Ok(())
+		}
+
+		/// Execution status of the message with `message_root`.
+		pub fn message_status(message_root: H256) -> MessageStatusEnum {
+			MessageStatus::<T>::get(message_root)
+		}
+
+		/// Most recent head verified by the light client of `domain`, if any.
+		pub fn latest_verified_head(domain: u32) -> Option<VerifiedHead> {
+			let slot = Head::<T>::get(domain);
+			let header_root = Headers::<T>::get(domain, slot);
+			if header_root == H256::zero() {
+				return None;
+			}
+			Some(VerifiedHead {
+				slot,
+				timestamp: Self::slot_timestamp(domain, slot),
+				header_root,
+				execution_state_root: ExecutionStateRoots::<T>::get(domain, slot),
+			})
+		}
+
+		/// Freeze of the source chain `domain`, if it is frozen.
+		pub fn freeze_status(domain: u32) -> Option<FreezeStatus<BlockNumberFor<T>>> {
+			SourceChainFreezes::<T>::get(domain)
+		}
+
+		/// Message sent from Avail with `message_id`, if its preimage is still retained.
+		pub fn message_preimage(message_id: u64) -> Option<AddressedMessage> {
+			// Message ids start with the number of the block the message was sent in.
+			let block = BlockNumberFor::<T>::from((message_id >> 32) as u32);
+			let preimage = MessagePreimages::<T>::get(block, message_id)?;
+			AddressedMessage::decode(&mut preimage.as_slice()).ok()
+		}
+
+		/// Block and receipt of the withdrawal `message_id` sent from Avail, if still retained.
+		pub fn withdrawal_receipt(
+			message_id: u64,
+		) -> Option<(BlockNumberFor<T>, WithdrawalReceipt)> {
+			// Message ids start with the number of the block the message was sent in.
+			let block = BlockNumberFor::<T>::from((message_id >> 32) as u32);
+			WithdrawalReceipts::<T>::get(block, message_id).map(|receipt| (block, receipt))
+		}
+
+		/// Roots of the messages executed in the blocks `from` to `to`, inclusive.
+		pub fn executed_in_range(from: BlockNumberFor<T>, to: BlockNumberFor<T>) -> Vec<H256> {
+			let mut roots = Vec::new();
+			let mut block = from;
+			while block <= to {
+				roots.extend(ExecutedMessages::<T>::iter_key_prefix(block));
+				if block == to {
+					break;
+				}
+				block = block.saturating_add(One::one());
+			}
+			roots
+		}
+
+		/// Message id, origin domain, asset and amount of the transfers executed in the blocks
+		/// `from` to `to`, inclusive.
+		pub fn executed_transfers_in_range(
+			from: BlockNumberFor<T>,
+			to: BlockNumberFor<T>,
+		) -> Vec<(u64, u32, H256, u128)> {
+			let mut transfers = Vec::new();
+			let mut block = from;
+			while block <= to {
+				transfers.extend(ExecutedTransfers::<T>::iter_prefix_values(block));
+				if block == to {
+					break;
+				}
+				block = block.saturating_add(One::one());
+			}
+			transfers
+		}
+
+		/// The account ID of the bridge's pot.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// The account ID of the pot that collects message fees and pays relayer rewards.
+		pub fn reward_pot_account_id() -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(b"rewards")
+		}
+
+		/// Pays the relayer of `messages` executed messages out of the reward pot.
+		/// Pays at most what is left in the pot.
+		fn reward_relayer(
+			relayer: &T::AccountId,
+			messages: u32,
+			proof_bytes: u32,
+		) -> DispatchResult {
+			let reward = RelayerRewards::<T>::get();
+			let amount = reward
+				.flat
+				.saturating_mul(messages.into())
+				.saturating_add(reward.per_proof_byte.saturating_mul(proof_bytes.into()));
+
+			let pot = Self::reward_pot_account_id();
+			let amount = amount.min(T::Currency::free_balance(&pot));
+			if amount.is_zero() {
+				return Ok(());
+			}
+
+			T::Currency::transfer(&pot, relayer, amount, ExistenceRequirement::AllowDeath)?;
+			Self::deposit_event(Event::RelayerRewarded {
+				relayer: relayer.clone(),
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Ensures that the origin is a bonded updater or operator and returns it.
+		fn ensure_updater(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+			let who = ensure_signed(origin)?;
+			let sender = T::AccountMapping::into_address(&who);
+			ensure!(
+				sender == Updater::<T>::get() || Operators::<T>::get().contains(&sender),
+				Error::<T>::UpdaterMisMatch
+			);
+			Self::ensure_bonded(&who)?;
+			Ok(who)
+		}
+
+		/// Ensures that `who` has bonded the prover bond and is not unbonding.
+		fn ensure_bonded(who: &T::AccountId) -> DispatchResult {
+			let bond = ProverBond::<T>::get();
+			if bond.is_zero() {
+				return Ok(());
+			}
+			ensure!(
+				Bonds::<T>::get(who) >= bond && !UnbondingUntil::<T>::contains_key(who),
+				Error::<T>::NotBonded
+			);
+			Ok(())
+		}
+
+		/// Records the approval of `operator` for a fulfill call, identified by the arguments
+		/// `call` it is applied with, and returns true once the call is approved by the fulfill
+		/// threshold of operators.
+		fn approve_fulfill(operator: H256, call: impl Encode) -> Result<bool, DispatchError> {
+			let threshold = FulfillThreshold::<T>::get();
+			if threshold <= 1 {
+				return Ok(true);
+			}
+
+			let call_hash = H256(keccak_256(&call.encode()));
+			let mut approvals = FulfillApprovals::<T>::get(call_hash);
+			ensure!(!approvals.contains(&operator), Error::<T>::AlreadyApproved);
+			approvals
+				.try_push(operator)
+				.map_err(|_| Error::<T>::TooManyOperators)?;
+
+			if approvals.len() as u32 >= threshold {
+				FulfillApprovals::<T>::remove(call_hash);
+				return Ok(true);
+			}
+
+			Self::deposit_event(Event::FulfillApproved {
+				operator,
+				call_hash,
+				approvals: approvals.len() as u32,
+			});
+			FulfillApprovals::<T>::insert(call_hash, approvals);
+			Ok(false)
+		}
+
+		/// Verifies the proof of a step or rotate call and returns the hash of its input.
+		pub(crate) fn verify_function_call(
+			function_id: H256,
+			step_function_id: H256,
+			rotate_function_id: H256,
+			input: &FunctionInput,
+			output: &FunctionOutput,
+			proof: FunctionProof,
+		) -> Result<H256, DispatchError> {
+			let input_hash = H256(sha2_256(input.as_slice()));
+			let output_hash = H256(sha2_256(output.as_slice()));
+			let verifier = Self::get_verifier(function_id, step_function_id, rotate_function_id)?;
+
+			let is_success = verifier
+				.verify(input_hash, output_hash, proof.to_vec())
+				.map_err(|_| Error::<T>::VerificationError)?;
+
+			// make sure that verification call is valid
+			ensure!(is_success, Error::<T>::VerificationFailed);
+
+			Ok(input_hash)
+		}
+
+		/// Updates the head of `domain` with the verified step output.
+		fn step_with_output(
+			submitter: &T::AccountId,
+			domain: u32,
+			slot: u64,
+			config: &Configuration,
+			step_function_id: H256,
+			input_hash: H256,
+			step_output: VerifiedStepOutput,
+		) -> DispatchResult {
+			ensure!(
+				FinalityModes::<T>::get(domain) == FinalityMode::SyncCommittee,
+				Error::<T>::FinalityModeMismatch
+			);
+
+			let vs = VerifiedStep::new(step_function_id, input_hash, step_output);
+
+			if Self::step_into(domain, slot, config, &vs, step_function_id)? {
+				SlotSubmitters::<T>::insert(domain, vs.verified_output.finalized_slot, submitter);
+				Self::deposit_event(Event::HeadUpdated {
+					domain,
+					slot: vs.verified_output.finalized_slot,
+					finalization_root: vs.verified_output.finalized_header_root,
+					execution_state_root: vs.verified_output.execution_state_root,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Rotates the sync committee of `domain` with the verified rotate `output`.
+		fn rotate_with_output(
+			domain: u32,
+			slot: u64,
+			config: &Configuration,
+			rotate_function_id: H256,
+			input_hash: H256,
+			output: &FunctionOutput,
+		) -> Result<u64, DispatchError> {
+			let rotate_output = parse_rotate_output(output.to_vec())
+				.map_err(|_| Error::<T>::CannotParseOutputData)?;
+
+			let vr = VerifiedRotate::new(rotate_function_id, input_hash, rotate_output);
+
+			let period = Self::rotate_into(domain, slot, config, &vr, rotate_function_id)?;
+			Self::deposit_event(Event::SyncCommitteeUpdated {
+				domain,
+				period,
+				root: vr.sync_committee_poseidon,
+			});
+
+			Ok(period)
+		}
+
+		fn rotate_into(
+			domain: u32,
+			finalized_slot: u64,
+			cfg: &Configuration,
+			verified_rotate_call: &VerifiedRotate,
+			rotate_function_id: H256,
+		) -> Result<u64, DispatchError> {
+			let finalized_header_root = Headers::<T>::get(domain, finalized_slot);
+			ensure!(
+				finalized_header_root != H256::zero(),
+				Error::<T>::HeaderRootNotSet
+			);
+
+			let input = ethabi::encode(&[Token::FixedBytes(finalized_header_root.0.to_vec())]);
+			let sync_committee_poseidon: U256 =
+				Self::verified_rotate_call(rotate_function_id, input, verified_rotate_call)?;
+
+			let period = finalized_slot
+				.checked_div(cfg.slots_per_period)
+				.ok_or(Error::<T>::ConfigurationNotSet)?;
+			let next_period = period + 1;
+
+			Self::set_sync_committee_poseidon(domain, next_period, sync_committee_poseidon)?;
+
+			Ok(next_period)
+		}
+
+		fn step_into(
+			domain: u32,
+			attested_slot: u64,
+			cfg: &Configuration,
+			verified_step_call: &VerifiedStep,
+			step_function_id: H256,
+		) -> Result<bool, DispatchError> {
+			let period = attested_slot
+				.checked_div(cfg.slots_per_period)
+				.ok_or(Error::<T>::ConfigurationNotSet)?;
+
+			let sc_poseidon = SyncCommitteePoseidons::<T>::get(domain, period);
+			ensure!(sc_poseidon != U256::zero(), Error::<T>::SyncCommitteeNotSet);
+
+			let input = encode_packed(sc_poseidon, attested_slot);
+			let result = Self::verified_step_call(step_function_id, input, verified_step_call)?;
+			ensure!(
+				result.participation >= cfg.finality_threshold,
+				Error::<T>::NotEnoughParticipants
+			);
+
+			let head = Head::<T>::get(domain);
+			ensure!(result.finalized_slot > head, Error::<T>::SlotBehindHead);
+
+			let updated = Self::set_slot_roots(domain, result)?;
+
+			Ok(updated)
+		}
+
+		///  Sets the current slot for the chain the light client is reflecting.
+		/// checks is the roots exists for the slot already. If there is
+		/// an existing header but no conflict, do nothing. Avoids timestamp renewal DoS attacks.
+		fn set_slot_roots(
+			domain: u32,
+			step_output: VerifiedStepOutput,
+		) -> Result<bool, DispatchError> {
+			let header = Headers::<T>::get(domain, step_output.finalized_slot);
+			ensure!(header == H256::zero(), Error::<T>::HeaderRootAlreadySet);
+
+			let execution_state_root =
+				ExecutionStateRoots::<T>::get(domain, step_output.finalized_slot);
+			ensure!(
+				execution_state_root == H256::zero(),
+				Error::<T>::StateRootAlreadySet
+			);
+
+			Head::<T>::insert(domain, step_output.finalized_slot);
+			Headers::<T>::insert(
+				domain,
+				step_output.finalized_slot,
+				step_output.finalized_header_root,
+			);
+			ExecutionStateRoots::<T>::insert(
+				domain,
+				step_output.finalized_slot,
+				step_output.execution_state_root,
+			);
+
+			Timestamps::<T>::insert(
+				domain,
+				step_output.finalized_slot,
+				T::TimeProvider::now().as_secs(),
+			);
+
+			let head = VerifiedHead {
+				slot: step_output.finalized_slot,
+				timestamp: Self::slot_timestamp(domain, step_output.finalized_slot),
+				header_root: step_output.finalized_header_root,
+				execution_state_root: step_output.execution_state_root,
+			};
+			T::OnNewVerifiedHead::on_new_verified_head(domain, &head);
+
+			Ok(true)
+		}
+
+		/// Sets the sync committee poseidon for a given domain and period.
+		fn set_sync_committee_poseidon(
+			domain: u32,
+			period: u64,
+			poseidon: U256,
+		) -> Result<(), DispatchError> {
+			let sync_committee_poseidons = SyncCommitteePoseidons::<T>::get(domain, period);
+			ensure!(
+				sync_committee_poseidons == U256::zero(),
+				Error::<T>::SyncCommitteeAlreadySet
+			);
+
+			SyncCommitteePoseidons::<T>::set(domain, period, poseidon);
+
+			Ok(())
+		}
+
+		/// get_verifier returns verifier based on the provided function id.
+		fn get_verifier(
+			function_id: H256,
 			step_function_id: H256,
 			rotate_function_id: H256,
 		) -> Result<Verifier, Error<T>> {
@@ -1041,7 +4238,9 @@ pub mod pallet {
 
 		/// Check if the given domain is supported or not
 		fn is_domain_valid(domain: u32) -> bool {
-			WhitelistedDomains::<T>::get().contains(&domain)
+			WhitelistedDomains::<T>::get()
+				.binary_search(&domain)
+				.is_ok()
 		}
 
 		fn get_function_ids() -> Result<(H256, H256), DispatchError> {
@@ -1052,12 +4251,60 @@ pub mod pallet {
 			}
 		}
 	}
+
+	#[cfg(any(feature = "try-runtime", test))]
+	impl<T: Config> Pallet<T> {
+		/// Checks the invariants of the light client and message storage:
+		/// - every message indexed as executed is marked as executed in the status map and
+		///   there are no more indexed messages than executed ones;
+		/// - timestamps of the verified slots of a domain increase with the slot;
+		/// - no domain has execution state roots beyond its latest verified slot.
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			let is_executed = |status: &MessageStatusEnum| {
+				matches!(
+					status,
+					MessageStatusEnum::ExecutionSucceeded | MessageStatusEnum::Invalidated
+				)
+			};
+			let mut indexed = 0usize;
+			for (_, message_root, ()) in ExecutedMessages::<T>::iter() {
+				ensure!(
+					is_executed(&MessageStatus::<T>::get(message_root)),
+					"Executed message must be marked as executed"
+				);
+				indexed += 1;
+			}
+			let executed = MessageStatus::<T>::iter_values()
+				.filter(is_executed)
+				.count();
+			// Messages executed before the index was introduced are not indexed.
+			ensure!(
+				indexed <= executed,
+				"Executed message count must match the message status map"
+			);
+
+			let mut timestamps: Vec<(u32, u64, u64)> = Timestamps::<T>::iter().collect();
+			timestamps.sort_unstable();
+			ensure!(
+				timestamps
+					.windows(2)
+					.all(|pair| pair[0].0 != pair[1].0 || pair[0].2 <= pair[1].2),
+				"Timestamps must be monotonic per domain"
+			);
+
+			for (domain, slot, _) in ExecutionStateRoots::<T>::iter() {
+				ensure!(
+					slot <= Head::<T>::get(domain),
+					"Execution state root must not be beyond the latest verified slot"
+				);
+			}
+
+			Ok(())
+		}
+	}
 }
 
-impl<T: Config> ProvidePostInherent for Pallet<T>
-where
-	[u8; 32]: From<T::AccountId>,
-{
+impl<T: Config> ProvidePostInherent for Pallet<T> {
 	type Call = Call<T>;
 	type Error = ();
 
@@ -1084,16 +4331,181 @@ where
 	}
 }
 
+/// Ensures that the origin is a `BridgedOrigin` and returns it.
+pub struct EnsureBridgedOrigin;
+
+impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for EnsureBridgedOrigin {
+	type Success = BridgedOrigin;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(BridgedOrigin(0, H256::zero())))
+	}
+}
+
 pub mod weight_helper {
 	use super::*;
 
 	/// Weight for `dataAvailability::submit_data`.
 	pub fn fulfill_call<T: Config>(function_id: H256) -> (Weight, DispatchClass) {
+		let approval = approval::<T>().saturating_add(prover::<T>());
 		if let Some((step_function_id, _)) = FunctionIds::<T>::get() {
 			if step_function_id == function_id {
-				return (T::WeightInfo::fulfill_call_step(), DispatchClass::Normal);
+				return (
+					T::WeightInfo::fulfill_call_step()
+						.saturating_add(approval)
+						.saturating_add(T::OnNewVerifiedHead::weight()),
+					DispatchClass::Normal,
+				);
 			}
 		}
-		(T::WeightInfo::fulfill_call_rotate(), DispatchClass::Normal)
+		(
+			T::WeightInfo::fulfill_call_rotate().saturating_add(approval),
+			DispatchClass::Normal,
+		)
+	}
+
+	/// Weight for `vector::fulfill_rotate_call`.
+	pub fn fulfill_rotate_call<T: Config>() -> Weight {
+		T::WeightInfo::fulfill_call_rotate()
+			.saturating_add(T::WeightInfo::verify_sync_committee_branch())
+			// `ConsensusForks` read and `SyncCommitteeHashes` write.
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+			.saturating_add(approval::<T>())
+			.saturating_add(prover::<T>())
+	}
+
+	/// Weight for `vector::fulfill_step_call`.
+	pub fn fulfill_step_call<T: Config>() -> Weight {
+		T::WeightInfo::fulfill_call_step()
+			.saturating_add(T::WeightInfo::verify_execution_state_root())
+			// `ForkDigests` read.
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(approval::<T>())
+			.saturating_add(prover::<T>())
+			.saturating_add(T::OnNewVerifiedHead::weight())
+	}
+
+	/// Weight for `vector::fulfill_finality`. The finality proof is as expensive to verify as a
+	/// step proof and the same slot roots are written.
+	pub fn fulfill_finality<T: Config>() -> Weight {
+		T::WeightInfo::fulfill_call_step()
+			.saturating_add(approval::<T>())
+			.saturating_add(prover::<T>())
+			.saturating_add(T::OnNewVerifiedHead::weight())
+	}
+
+	/// Weight of recording the approval of an operator for a fulfill call.
+	pub fn approval<T: Config>() -> Weight {
+		// `FulfillThreshold` and `FulfillApprovals` reads and `FulfillApprovals` write.
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	/// Weight of checking that the submitter of a light client update is a bonded operator and
+	/// recording it as the submitter of the slot.
+	pub fn prover<T: Config>() -> Weight {
+		// `Operators`, `ProverBond`, `Bonds` and `UnbondingUntil` reads and `SlotSubmitters`
+		// write.
+		T::DbWeight::get().reads_writes(4, 1)
+	}
+
+	/// Fee modifier for `vector::execute` and `vector::send_message` of `domain`.
+	pub fn fee_modifier<T: Config>(domain: u32) -> DispatchFeeModifier {
+		DispatchFeeModifier {
+			weight_fee_multiplier: FeeMultipliers::<T>::get(domain),
+			..Default::default()
+		}
+	}
+
+	/// Weight for `vector::execute`.
+	pub fn execute<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		let message = match addr_message.message {
+			Message::ArbitraryMessage(ref data) if Pallet::<T>::is_bridged_call(addr_message) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+					.saturating_add(T::MaxBridgedCallWeight::get())
+			},
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+					.saturating_add(T::MessageHandler::weight(data.len() as u32))
+			},
+			Message::FungibleToken { .. } => {
+				T::WeightInfo::execute_fungible_token().saturating_add(rate_limit::<T>())
+			},
+		};
+		message
+			.saturating_add(ordered_delivery::<T>())
+			.saturating_add(T::OnMessageExecuted::weight())
+			// `ExecutedMessages` write.
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Weight of verifying trie proofs with `bytes` of nodes, on top of the proofs of the execute
+	/// benchmarks.
+	pub fn proof_bytes<T: Config>(bytes: u32) -> Weight {
+		T::WeightInfo::verify_storage_proof(bytes)
+			.saturating_sub(T::WeightInfo::verify_storage_proof(0))
+	}
+
+	/// Weight of enforcing the rate limit of a transferred asset.
+	pub fn rate_limit<T: Config>() -> Weight {
+		// `AssetRateLimits` and `BridgedVolumes` reads and `BridgedVolumes` write.
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	/// Weight of enforcing the ordered delivery of a sender.
+	pub fn ordered_delivery<T: Config>() -> Weight {
+		// `OrderedDelivery` read and write and `QueuedMessages` write.
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	/// Weight for `vector::execute_with_receipt`. Proving the receipt costs about as much as
+	/// proving the storage of the broadcaster, plus the SSZ branch of the receipts root.
+	pub fn execute_with_receipt<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		execute::<T>(addr_message)
+			.saturating_add(T::WeightInfo::verify_execution_state_root())
+			// `ExecutionModes`, `Headers` and `ForkDigests` reads.
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
+
+	/// Number of slots from `from_slot` to `to_slot`, capped at `MAX_INVALIDATED_SLOTS`.
+	pub fn slot_range_len(from_slot: u64, to_slot: u64) -> u32 {
+		to_slot
+			.saturating_sub(from_slot)
+			.saturating_add(1)
+			.min(MAX_INVALIDATED_SLOTS as u64) as u32
+	}
+
+	/// Weight for `vector::send_messages`.
+	pub fn send_messages<T: Config>(messages: &SendBatch) -> Weight {
+		messages.iter().fold(Weight::zero(), |acc, (message, _)| {
+			acc.saturating_add(match message {
+				Message::ArbitraryMessage(ref data) => {
+					T::WeightInfo::send_message_arbitrary_message(data.len() as u32)
+				},
+				Message::FungibleToken { .. } => T::WeightInfo::send_message_fungible_token(),
+			})
+		})
+	}
+
+	/// Largest proof bytes of `vector::execute_batch`, with an account proof and a storage proof
+	/// per message.
+	pub fn max_batch_proof_bytes(messages: &ExecuteBatch) -> u32 {
+		(messages.len() as u32)
+			.saturating_add(1)
+			.saturating_mul(MAX_PROOF_BYTES)
+	}
+
+	/// Weight for `vector::execute_batch`. The account proof is only paid for once.
+	pub fn execute_batch<T: Config>(messages: &ExecuteBatch) -> Weight {
+		let account_proof = T::WeightInfo::verify_account_proof();
+		messages
+			.iter()
+			.fold(account_proof, |acc, (addr_message, _)| {
+				acc.saturating_add(execute::<T>(addr_message).saturating_sub(account_proof))
+			})
 	}
 }
@@ -0,0 +1,239 @@
+use crate::{
+	state::{AssetInfo, Configuration},
+	AssetRegistry, Config, ConfigurationStorage, ExecutionStateRoots, Head, Headers, Pallet,
+	SyncCommitteePoseidons, Timestamps, WhitelistedDomains,
+};
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_core::{H256, U256};
+use sp_std::vec::Vec;
+
+/// Moves the single light client state into per domain storage.
+pub mod v1 {
+	use super::*;
+
+	/// Maximum number of light client entries migrated at once, which keeps the migration of
+	/// every entry within a single block. The migration is skipped above it.
+	pub const MAX_MIGRATED_ENTRIES: u64 = 10_000;
+
+	mod v0 {
+		use super::*;
+
+		#[storage_alias]
+		pub type Head<T: Config> = StorageValue<Pallet<T>, u64, ValueQuery>;
+
+		#[storage_alias]
+		pub type Headers<T: Config> = StorageMap<Pallet<T>, Identity, u64, H256, ValueQuery>;
+
+		#[storage_alias]
+		pub type Timestamps<T: Config> = StorageMap<Pallet<T>, Identity, u64, u64, ValueQuery>;
+
+		#[storage_alias]
+		pub type ExecutionStateRoots<T: Config> =
+			StorageMap<Pallet<T>, Identity, u64, H256, ValueQuery>;
+
+		#[storage_alias]
+		pub type SyncCommitteePoseidons<T: Config> =
+			StorageMap<Pallet<T>, Identity, u64, U256, ValueQuery>;
+
+		#[storage_alias]
+		pub type ConfigurationStorage<T: Config> =
+			StorageValue<Pallet<T>, Configuration, ValueQuery>;
+	}
+
+	/// Migrates the existing light client state to the light client of `Domain`.
+	pub struct MigrateToV1<T, Domain>(PhantomData<(T, Domain)>);
+
+	impl<T: Config, Domain: Get<u32>> OnRuntimeUpgrade for MigrateToV1<T, Domain> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				log::info!(target: crate::LOG_TARGET, "Vector storage is already migrated to v1");
+				return T::DbWeight::get().reads(1);
+			}
+
+			// The entries are counted up to the bound, so that counting them is bounded too.
+			let bound = MAX_MIGRATED_ENTRIES as usize + 1;
+			let entries = [
+				v0::Headers::<T>::iter_keys().take(bound).count(),
+				v0::Timestamps::<T>::iter_keys().take(bound).count(),
+				v0::ExecutionStateRoots::<T>::iter_keys()
+					.take(bound)
+					.count(),
+				v0::SyncCommitteePoseidons::<T>::iter_keys()
+					.take(bound)
+					.count(),
+			]
+			.iter()
+			.sum::<usize>() as u64;
+			if entries > MAX_MIGRATED_ENTRIES {
+				log::error!(
+					target: crate::LOG_TARGET,
+					"Too many light client entries to migrate in a single block, skipping v1 migration"
+				);
+				return T::DbWeight::get().reads(entries + 1);
+			}
+
+			let domain = Domain::get();
+			// Every map shares the pallet prefix with its new double map version, so the
+			// entries are collected before being written back under the new layout.
+			let headers: Vec<(u64, H256)> = v0::Headers::<T>::drain().collect();
+			let timestamps: Vec<(u64, u64)> = v0::Timestamps::<T>::drain().collect();
+			let roots: Vec<(u64, H256)> = v0::ExecutionStateRoots::<T>::drain().collect();
+			let poseidons: Vec<(u64, U256)> = v0::SyncCommitteePoseidons::<T>::drain().collect();
+
+			for (slot, header) in headers {
+				Headers::<T>::insert(domain, slot, header);
+			}
+			for (slot, timestamp) in timestamps {
+				Timestamps::<T>::insert(domain, slot, timestamp);
+			}
+			for (slot, root) in roots {
+				ExecutionStateRoots::<T>::insert(domain, slot, root);
+			}
+			for (period, poseidon) in poseidons {
+				SyncCommitteePoseidons::<T>::insert(domain, period, poseidon);
+			}
+
+			Head::<T>::insert(domain, v0::Head::<T>::take());
+			ConfigurationStorage::<T>::insert(domain, v0::ConfigurationStorage::<T>::take());
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(
+				target: crate::LOG_TARGET,
+				"Migrated {entries} light client entries to domain {domain}"
+			);
+
+			T::DbWeight::get().reads_writes(entries * 2 + 3, entries * 2 + 5)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			// The v0 maps share their prefixes with the migrated double maps.
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return Ok(None::<(u64, u64)>.encode());
+			}
+
+			let entries = (v0::Headers::<T>::iter_keys().count()
+				+ v0::Timestamps::<T>::iter_keys().count()
+				+ v0::ExecutionStateRoots::<T>::iter_keys().count()
+				+ v0::SyncCommitteePoseidons::<T>::iter_keys().count()) as u64;
+			ensure!(
+				entries <= MAX_MIGRATED_ENTRIES,
+				"Too many light client entries to migrate in a single block"
+			);
+			Ok(Some((v0::Head::<T>::get(), entries)).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let state = <Option<(u64, u64)>>::decode(&mut state.as_slice())
+				.map_err(|_| sp_runtime::TryRuntimeError::Other("Cannot decode head"))?;
+			let Some((head, entries)) = state else {
+				return Ok(());
+			};
+			let domain = Domain::get();
+			ensure!(
+				Head::<T>::get(domain) == head,
+				"Head must be migrated to the light client domain"
+			);
+			let migrated = (Headers::<T>::iter_prefix(domain).count()
+				+ Timestamps::<T>::iter_prefix(domain).count()
+				+ ExecutionStateRoots::<T>::iter_prefix(domain).count()
+				+ SyncCommitteePoseidons::<T>::iter_prefix(domain).count()) as u64;
+			ensure!(
+				migrated == entries,
+				"Every light client entry must be migrated"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"Storage version must be 1"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Sorts the whitelisted domains so single domains can be added and removed.
+pub mod v2 {
+	use super::*;
+
+	/// Sorts and deduplicates the whitelisted domains.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				log::info!(target: crate::LOG_TARGET, "Vector storage is not at v1, skipping v2 migration");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut domains = WhitelistedDomains::<T>::get().into_inner();
+			domains.sort();
+			domains.dedup();
+			let count = domains.len();
+			WhitelistedDomains::<T>::put(BoundedVec::truncate_from(domains));
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(
+				target: crate::LOG_TARGET,
+				"Migrated {count} whitelisted domains to v2"
+			);
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let domains = WhitelistedDomains::<T>::get();
+			ensure!(
+				domains.windows(2).all(|pair| pair[0] < pair[1]),
+				"Whitelisted domains must be sorted and unique"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"Storage version must be 2"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Registers the native asset in the asset registry.
+pub mod v3 {
+	use super::*;
+
+	/// Registers the native asset under `SUPPORTED_ASSET_ID`, the only asset bridged so far.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 2 {
+				log::info!(target: crate::LOG_TARGET, "Vector storage is not at v2, skipping v3 migration");
+				return T::DbWeight::get().reads(1);
+			}
+
+			AssetRegistry::<T>::insert(crate::SUPPORTED_ASSET_ID, AssetInfo::native());
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+			log::info!(target: crate::LOG_TARGET, "Registered the native asset");
+
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				AssetRegistry::<T>::get(crate::SUPPORTED_ASSET_ID) == Some(AssetInfo::native()),
+				"Native asset must be registered"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"Storage version must be 3"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,4 +1,12 @@
-use frame_support::{derive_impl, parameter_types, traits::ConstU64, PalletId};
+use avail_core::data_proof::AddressedMessage;
+use frame_support::{
+	derive_impl,
+	dispatch::DispatchResult,
+	parameter_types,
+	traits::{ConstU32, ConstU64},
+	weights::Weight,
+	PalletId,
+};
 use frame_system::{native::hosted_header_builder::da, test_utils::TestRandomness};
 use hex_literal::hex;
 use primitive_types::H256;
@@ -210,12 +218,98 @@ impl pallet_timestamp::Config for Test {
 
 parameter_types! {
 	pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+	pub static HandledMessages: Vec<(u32, H256, H256, Vec<u8>)> = vec![];
+	pub static MessageHandlerFails: bool = false;
+	pub static AssetBalances: Vec<(u32, AccountId32, u128)> = vec![];
+	pub static VerifiedHeads: Vec<(u32, vector_bridge::VerifiedHead)> = vec![];
+	pub static ArbitraryMessageByteFee: u128 = 0;
+	pub static MessageOutcomes: Vec<(u64, vector_bridge::MessageOutcome)> = vec![];
+}
+
+/// Keeps the balances of local assets in `AssetBalances`.
+pub struct TestAssets;
+impl vector_bridge::BridgedAssets<AccountId32> for TestAssets {
+	fn mint(asset: u32, who: &AccountId32, amount: u128) -> DispatchResult {
+		AssetBalances::mutate(
+			|b| match b.iter_mut().find(|(a, w, _)| *a == asset && w == who) {
+				Some(entry) => entry.2 += amount,
+				None => b.push((asset, who.clone(), amount)),
+			},
+		);
+		Ok(())
+	}
+
+	fn burn(asset: u32, who: &AccountId32, amount: u128) -> DispatchResult {
+		AssetBalances::mutate(|b| {
+			let index = b
+				.iter()
+				.position(|(a, w, v)| *a == asset && w == who && *v >= amount)
+				.ok_or(sp_runtime::DispatchError::Other("InsufficientBalance"))?;
+			b[index].2 -= amount;
+			Ok(())
+		})
+	}
+}
+
+/// Records every handled message, or fails if `MessageHandlerFails` is set.
+pub struct TestMessageHandler;
+impl vector_bridge::MessageHandler for TestMessageHandler {
+	fn handle(origin_domain: u32, from: H256, to: H256, data: &[u8]) -> DispatchResult {
+		if MessageHandlerFails::get() {
+			return Err(sp_runtime::DispatchError::Other("MessageHandlerFailed"));
+		}
+		HandledMessages::mutate(|m| m.push((origin_domain, from, to, data.to_vec())));
+		Ok(())
+	}
+
+	fn weight(_len: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Records every verified head in `VerifiedHeads`.
+pub struct TestOnNewVerifiedHead;
+impl vector_bridge::OnNewVerifiedHead for TestOnNewVerifiedHead {
+	fn on_new_verified_head(domain: u32, head: &vector_bridge::VerifiedHead) {
+		VerifiedHeads::mutate(|heads| heads.push((domain, *head)));
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Records the outcome of every executed message in `MessageOutcomes`.
+pub struct TestOnMessageExecuted;
+impl vector_bridge::OnMessageExecuted for TestOnMessageExecuted {
+	fn on_message_executed(message: &AddressedMessage, outcome: &vector_bridge::MessageOutcome) {
+		MessageOutcomes::mutate(|outcomes| outcomes.push((message.id, *outcome)));
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 #[derive_impl(crate::config_preludes::TestDefaultConfig as crate::DefaultConfig)]
 impl vector_bridge::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type MessageHandler = TestMessageHandler;
+	type Assets = TestAssets;
+	type OnNewVerifiedHead = TestOnNewVerifiedHead;
+	type OnMessageExecuted = TestOnMessageExecuted;
+	type AddressBindingOrigin = vector_bridge::EnsureBridgedOrigin;
+	type RuntimeBridgedOrigin = RuntimeOrigin;
+	type DomainPauseOrigin = frame_system::EnsureRoot<AccountId32>;
+	type WhitelistOrigin = frame_system::EnsureRoot<AccountId32>;
+	type EmergencyPauseOrigin = frame_system::EnsureRoot<AccountId32>;
+	type MaxEmergencyPauseDuration = ConstU32<100>;
+	type StateRootRollbackOrigin = frame_system::EnsureRoot<AccountId32>;
+	type ProverUnbondingPeriod = ConstU32<10>;
+	type MessagePreimageRetention = ConstU32<10>;
+	type VerificationKeyActivationDelay = ConstU32<5>;
+	type ArbitraryMessageByteFee = ArbitraryMessageByteFee;
 }
 
 /// Create new externalities for `Vector` module tests.
@@ -238,6 +332,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		step_verification_key: STEP_VK.as_bytes().to_vec(),
 		rotate_verification_key: ROTATE_VK.as_bytes().to_vec(),
 		whitelisted_domains: vec![2],
+		broadcaster_domain: 2,
 		..Default::default()
 	}
 	.assimilate_storage(&mut t)
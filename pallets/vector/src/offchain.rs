@@ -0,0 +1,105 @@
+//! Off-chain prevalidation of proof submissions.
+//!
+//! Node operators queue the proof submissions seen from relayers in the persistent local storage
+//! under `PROOF_QUEUE_KEY`. The off-chain worker verifies them against the current verification
+//! keys and publishes the outcome under `PROOF_METRICS_KEY`, so malformed relayer traffic is
+//! noticed before it lands on chain.
+use crate::{
+	state::parse_step_output, Config, FunctionIds, FunctionInput, FunctionOutput, FunctionProof,
+	Pallet, LOG_TARGET,
+};
+use codec::{Decode, Encode};
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_runtime::offchain::storage::StorageValueRef;
+use sp_std::vec::Vec;
+
+/// Local storage key of the queue of proof submissions to prevalidate.
+pub const PROOF_QUEUE_KEY: &[u8] = b"vector::proof_queue";
+/// Local storage key of the prevalidation metrics.
+pub const PROOF_METRICS_KEY: &[u8] = b"vector::proof_metrics";
+/// Maximum number of queued proof submissions prevalidated per block.
+pub const MAX_PREVALIDATED_PROOFS: usize = 16;
+
+/// Proof submission queued for prevalidation.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq)]
+pub struct QueuedProof {
+	pub function_id: H256,
+	pub input: FunctionInput,
+	pub output: FunctionOutput,
+	pub proof: FunctionProof,
+}
+
+/// Outcome of the prevalidation of the queued proof submissions.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, Default)]
+pub struct ProofMetrics {
+	/// Number of prevalidated proof submissions.
+	pub checked: u64,
+	/// Number of proof submissions that would fail on chain.
+	pub malformed: u64,
+	/// Hash of the last malformed proof submission.
+	pub last_malformed: Option<H256>,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Prevalidates up to `MAX_PREVALIDATED_PROOFS` queued proof submissions and updates the
+	/// metrics with their outcome.
+	pub(crate) fn prevalidate_queued_proofs() {
+		let Some((step_function_id, rotate_function_id)) = FunctionIds::<T>::get() else {
+			return;
+		};
+
+		let mut proofs = Vec::new();
+		let queue = StorageValueRef::persistent(PROOF_QUEUE_KEY);
+		let taken = queue.mutate(|queued: Result<Option<Vec<QueuedProof>>, _>| {
+			let mut queued = queued.ok().flatten().unwrap_or_default();
+			let rest = queued.split_off(queued.len().min(MAX_PREVALIDATED_PROOFS));
+			proofs = queued;
+			Ok::<_, ()>(rest)
+		});
+		if taken.is_err() || proofs.is_empty() {
+			return;
+		}
+
+		let mut metrics = ProofMetrics::default();
+		for queued in proofs {
+			let hash = H256(blake2_256(&queued.encode()));
+			let result = Self::verify_function_call(
+				queued.function_id,
+				step_function_id,
+				rotate_function_id,
+				&queued.input,
+				&queued.output,
+				queued.proof,
+			)
+			.and_then(|_| {
+				if queued.function_id == step_function_id {
+					parse_step_output(queued.output.to_vec())
+						.map_err(|_| crate::Error::<T>::CannotParseOutputData)?;
+				}
+				Ok(())
+			});
+
+			metrics.checked += 1;
+			if let Err(err) = result {
+				log::warn!(
+					target: LOG_TARGET,
+					"Malformed proof submission {hash:?} for function {:?}: {err:?}",
+					queued.function_id
+				);
+				metrics.malformed += 1;
+				metrics.last_malformed = Some(hash);
+			}
+		}
+
+		let _ = StorageValueRef::persistent(PROOF_METRICS_KEY).mutate(
+			|total: Result<Option<ProofMetrics>, _>| {
+				let mut total = total.ok().flatten().unwrap_or_default();
+				total.checked = total.checked.saturating_add(metrics.checked);
+				total.malformed = total.malformed.saturating_add(metrics.malformed);
+				total.last_malformed = metrics.last_malformed.or(total.last_malformed);
+				Ok::<_, ()>(total)
+			},
+		);
+	}
+}
@@ -0,0 +1,275 @@
+use crate::state::{ConsensusFork, ExecutionStateRootBranch, ReceiptProof, SyncCommitteeBranch};
+use sp_core::H256;
+use sp_io::hashing::sha2_256;
+
+/// Generalized index of `state_root` in a `BeaconBlockHeader`.
+pub const STATE_ROOT_GINDEX: u64 = 11;
+/// Generalized index of `body_root` in a `BeaconBlockHeader`.
+pub const BODY_ROOT_GINDEX: u64 = 12;
+
+/// compute_fork_digest returns the first four bytes of the `ForkData` root of `fork_version`.
+pub fn compute_fork_digest(fork_version: [u8; 4], genesis_validators_root: H256) -> [u8; 4] {
+	let mut buf = [0u8; 64];
+	buf[..4].copy_from_slice(&fork_version);
+	buf[32..].copy_from_slice(genesis_validators_root.as_bytes());
+	let root = sha2_256(&buf);
+	[root[0], root[1], root[2], root[3]]
+}
+
+/// is_valid_merkle_branch checks that `leaf` sits at the generalized index `gindex` of the SSZ
+/// merkle tree with the given `root`.
+pub fn is_valid_merkle_branch(leaf: H256, branch: &[H256], gindex: u64, root: H256) -> bool {
+	if gindex == 0 {
+		return false;
+	}
+	let depth = 63 - gindex.leading_zeros();
+	if branch.len() as u32 != depth {
+		return false;
+	}
+
+	let mut value = leaf;
+	let mut buf = [0u8; 64];
+	for (i, node) in branch.iter().enumerate() {
+		if (gindex >> i) & 1 == 1 {
+			buf[..32].copy_from_slice(node.as_bytes());
+			buf[32..].copy_from_slice(value.as_bytes());
+		} else {
+			buf[..32].copy_from_slice(value.as_bytes());
+			buf[32..].copy_from_slice(node.as_bytes());
+		}
+		value = H256(sha2_256(&buf));
+	}
+
+	value == root
+}
+
+/// verify_sync_committee_branch checks that the `next_sync_committee` of the beacon state proven
+/// by `branch` belongs to the beacon block header with `header_root`.
+pub fn verify_sync_committee_branch(
+	header_root: H256,
+	fork: ConsensusFork,
+	branch: &SyncCommitteeBranch,
+) -> bool {
+	is_valid_merkle_branch(
+		branch.state_root,
+		&branch.state_root_branch,
+		STATE_ROOT_GINDEX,
+		header_root,
+	) && is_valid_merkle_branch(
+		branch.sync_committee_root,
+		&branch.sync_committee_branch,
+		fork.next_sync_committee_gindex(),
+		branch.state_root,
+	)
+}
+
+/// verify_execution_state_root checks that `execution_state_root` is the state root of the
+/// execution payload of the beacon block header with `header_root`, laid out as in `fork`.
+pub fn verify_execution_state_root(
+	header_root: H256,
+	execution_state_root: H256,
+	fork: ConsensusFork,
+	branch: &ExecutionStateRootBranch,
+) -> bool {
+	verify_execution_payload_field(
+		header_root,
+		execution_state_root,
+		fork.execution_state_root_gindex(),
+		branch.body_root,
+		&branch.body_root_branch,
+		&branch.execution_state_root_branch,
+	)
+}
+
+/// verify_receipts_root checks that the receipts root of `proof` is the receipts root of the
+/// execution payload of the beacon block header with `header_root`, laid out as in `fork`.
+pub fn verify_receipts_root(header_root: H256, fork: ConsensusFork, proof: &ReceiptProof) -> bool {
+	verify_execution_payload_field(
+		header_root,
+		proof.receipts_root,
+		fork.receipts_root_gindex(),
+		proof.body_root,
+		&proof.body_root_branch,
+		&proof.receipts_root_branch,
+	)
+}
+
+/// Checks that `leaf` sits at `gindex` of the beacon block body whose root is proven by
+/// `body_root_branch` to be part of the header with `header_root`.
+fn verify_execution_payload_field(
+	header_root: H256,
+	leaf: H256,
+	gindex: Option<u64>,
+	body_root: H256,
+	body_root_branch: &[H256],
+	leaf_branch: &[H256],
+) -> bool {
+	let Some(gindex) = gindex else {
+		return false;
+	};
+
+	is_valid_merkle_branch(body_root, body_root_branch, BODY_ROOT_GINDEX, header_root)
+		&& is_valid_merkle_branch(leaf, leaf_branch, gindex, body_root)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::BoundedVec;
+	use sp_std::vec::Vec;
+
+	fn hash_pair(left: H256, right: H256) -> H256 {
+		let mut buf = [0u8; 64];
+		buf[..32].copy_from_slice(left.as_bytes());
+		buf[32..].copy_from_slice(right.as_bytes());
+		H256(sha2_256(&buf))
+	}
+
+	/// Builds a branch for `leaf` at `gindex` out of the given siblings and returns the root.
+	fn root_of(leaf: H256, branch: &[H256], gindex: u64) -> H256 {
+		branch.iter().enumerate().fold(leaf, |value, (i, node)| {
+			if (gindex >> i) & 1 == 1 {
+				hash_pair(*node, value)
+			} else {
+				hash_pair(value, *node)
+			}
+		})
+	}
+
+	fn siblings(depth: u8) -> Vec<H256> {
+		(1..=depth).map(|i| H256::repeat_byte(i)).collect()
+	}
+
+	fn branch_for(fork: ConsensusFork) -> (H256, SyncCommitteeBranch) {
+		let sync_committee_root = H256::repeat_byte(0xaa);
+		let gindex = fork.next_sync_committee_gindex();
+		let sync_committee_branch = siblings(63 - gindex.leading_zeros() as u8);
+		let state_root = root_of(sync_committee_root, &sync_committee_branch, gindex);
+		let state_root_branch = siblings(3);
+		let header_root = root_of(state_root, &state_root_branch, STATE_ROOT_GINDEX);
+
+		let branch = SyncCommitteeBranch {
+			state_root,
+			state_root_branch: BoundedVec::truncate_from(state_root_branch),
+			sync_committee_root,
+			sync_committee_branch: BoundedVec::truncate_from(sync_committee_branch),
+		};
+		(header_root, branch)
+	}
+
+	#[test]
+	fn test_verify_sync_committee_branch() {
+		let (header_root, branch) = branch_for(ConsensusFork::Deneb);
+		assert!(verify_sync_committee_branch(
+			header_root,
+			ConsensusFork::Deneb,
+			&branch
+		));
+
+		let (header_root, branch) = branch_for(ConsensusFork::Electra);
+		assert!(verify_sync_committee_branch(
+			header_root,
+			ConsensusFork::Electra,
+			&branch
+		));
+	}
+
+	#[test]
+	fn test_verify_sync_committee_branch_wrong_fork() {
+		let (header_root, branch) = branch_for(ConsensusFork::Deneb);
+		assert!(!verify_sync_committee_branch(
+			header_root,
+			ConsensusFork::Electra,
+			&branch
+		));
+	}
+
+	#[test]
+	fn test_verify_sync_committee_branch_wrong_root() {
+		let (header_root, mut branch) = branch_for(ConsensusFork::Capella);
+		branch.sync_committee_root = H256::repeat_byte(0xbb);
+		assert!(!verify_sync_committee_branch(
+			header_root,
+			ConsensusFork::Capella,
+			&branch
+		));
+	}
+
+	#[test]
+	fn test_is_valid_merkle_branch_wrong_depth() {
+		let leaf = H256::repeat_byte(1);
+		let branch = siblings(2);
+		let root = root_of(leaf, &branch, STATE_ROOT_GINDEX);
+		assert!(!is_valid_merkle_branch(
+			leaf,
+			&branch,
+			STATE_ROOT_GINDEX,
+			root
+		));
+	}
+
+	fn execution_branch_for(fork: ConsensusFork) -> (H256, H256, ExecutionStateRootBranch) {
+		let execution_state_root = H256::repeat_byte(0xcc);
+		let gindex = fork.execution_state_root_gindex().unwrap();
+		let execution_state_root_branch = siblings(63 - gindex.leading_zeros() as u8);
+		let body_root = root_of(execution_state_root, &execution_state_root_branch, gindex);
+		let body_root_branch = siblings(3);
+		let header_root = root_of(body_root, &body_root_branch, BODY_ROOT_GINDEX);
+
+		let branch = ExecutionStateRootBranch {
+			fork_digest: [0u8; 4],
+			body_root,
+			body_root_branch: BoundedVec::truncate_from(body_root_branch),
+			execution_state_root_branch: BoundedVec::truncate_from(execution_state_root_branch),
+		};
+		(header_root, execution_state_root, branch)
+	}
+
+	#[test]
+	fn test_verify_execution_state_root() {
+		for fork in [
+			ConsensusFork::Bellatrix,
+			ConsensusFork::Capella,
+			ConsensusFork::Deneb,
+			ConsensusFork::Electra,
+		] {
+			let (header_root, execution_state_root, branch) = execution_branch_for(fork);
+			assert!(verify_execution_state_root(
+				header_root,
+				execution_state_root,
+				fork,
+				&branch
+			));
+		}
+	}
+
+	#[test]
+	fn test_verify_execution_state_root_wrong_fork() {
+		let (header_root, execution_state_root, branch) =
+			execution_branch_for(ConsensusFork::Capella);
+		assert!(!verify_execution_state_root(
+			header_root,
+			execution_state_root,
+			ConsensusFork::Deneb,
+			&branch
+		));
+		assert!(!verify_execution_state_root(
+			header_root,
+			execution_state_root,
+			ConsensusFork::Altair,
+			&branch
+		));
+	}
+
+	#[test]
+	fn test_compute_fork_digest() {
+		// Deneb on Ethereum mainnet.
+		let genesis_validators_root = H256(hex_literal::hex!(
+			"4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95"
+		));
+		assert_eq!(
+			compute_fork_digest([0x04, 0x00, 0x00, 0x00], genesis_validators_root),
+			[0x6a, 0x95, 0xa1, 0xa9]
+		);
+	}
+}
@@ -1,13 +1,14 @@
 use crate::state::ParseError::ParsingError;
-use crate::{ROTATE_POSEIDON_OUTPUT_LENGTH, STEP_OUTPUT_LENGTH};
+use crate::{ValidProof, ROTATE_POSEIDON_OUTPUT_LENGTH, STEP_OUTPUT_LENGTH};
 use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
 use ark_ff::QuadExtField;
 use ark_groth16::Proof;
 use ark_std::str::FromStr;
 use ark_std::string::String;
 use ark_std::string::ToString;
+use avail_core::data_proof::AddressedMessage;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{Deserialize, Serialize};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec, Deserialize, Serialize};
 use scale_info::TypeInfo;
 use sp_core::{H256, U256};
 use sp_std::prelude::*;
@@ -91,6 +92,237 @@ pub struct Configuration {
 	pub finality_threshold: u16,
 }
 
+/// Reward paid to the relayer of executed messages.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default)]
+pub struct RelayerReward<Balance> {
+	/// Paid for every executed message.
+	pub flat: Balance,
+	/// Paid for every byte of the provided account and storage proofs.
+	pub per_proof_byte: Balance,
+}
+
+/// Local representation of a bridged asset.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum AssetKind {
+	/// Native balance of the chain.
+	Native,
+	/// Asset of `pallet-assets` with the given id.
+	Local(#[codec(compact)] u32),
+}
+
+/// Entry of the registry of bridged assets.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct AssetInfo {
+	/// How the asset is represented on this chain.
+	pub kind: AssetKind,
+	/// Decimals of the asset.
+	pub decimals: u8,
+	/// Smallest amount that may be bridged.
+	pub min_amount: u128,
+}
+
+impl AssetInfo {
+	/// The native asset, bridged under `SUPPORTED_ASSET_ID`.
+	pub fn native() -> Self {
+		AssetInfo {
+			kind: AssetKind::Native,
+			decimals: 18,
+			min_amount: 0,
+		}
+	}
+}
+
+/// Maximum amount of an asset that may be bridged in within a window of blocks.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RateLimit<BlockNumber> {
+	/// Maximum amount bridged in per window.
+	pub max_amount: u128,
+	/// Length of the window in blocks.
+	pub window: BlockNumber,
+}
+
+/// Amount of an asset bridged in during the current rate limit window.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default)]
+pub struct BridgedVolume<BlockNumber> {
+	/// Block the window started at.
+	pub window_start: BlockNumber,
+	/// Amount bridged in since the start of the window.
+	pub amount: u128,
+}
+
+/// Ethereum consensus fork a light client domain follows. Forks change the layout of the
+/// beacon state and therefore the generalized indices of its fields.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum ConsensusFork {
+	Altair,
+	Bellatrix,
+	Capella,
+	Deneb,
+	Electra,
+}
+
+impl ConsensusFork {
+	/// Generalized index of `next_sync_committee` in the beacon state of this fork.
+	pub const fn next_sync_committee_gindex(&self) -> u64 {
+		match self {
+			ConsensusFork::Altair
+			| ConsensusFork::Bellatrix
+			| ConsensusFork::Capella
+			| ConsensusFork::Deneb => 55,
+			ConsensusFork::Electra => 87,
+		}
+	}
+
+	/// Generalized index of the execution payload `state_root` in the beacon block body of this
+	/// fork, if the fork has an execution payload.
+	pub const fn execution_state_root_gindex(&self) -> Option<u64> {
+		match self {
+			ConsensusFork::Altair => None,
+			ConsensusFork::Bellatrix | ConsensusFork::Capella => Some(402),
+			ConsensusFork::Deneb | ConsensusFork::Electra => Some(802),
+		}
+	}
+
+	/// Generalized index of the execution payload `receipts_root` in the beacon block body of
+	/// this fork, if the fork has an execution payload.
+	pub const fn receipts_root_gindex(&self) -> Option<u64> {
+		match self {
+			ConsensusFork::Altair => None,
+			ConsensusFork::Bellatrix | ConsensusFork::Capella => Some(403),
+			ConsensusFork::Deneb | ConsensusFork::Electra => Some(803),
+		}
+	}
+}
+
+/// How the finality of the headers of a light client domain is proven.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default)]
+pub enum FinalityMode {
+	/// Step proofs checked against the participation of the sync committee.
+	#[default]
+	SyncCommittee,
+	/// Proofs of Ethereum finality verified with the finality verification key.
+	ZkFinality,
+}
+
+/// SSZ branches that prove the next sync committee of a finalized beacon block header.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SyncCommitteeBranch {
+	/// State root of the finalized beacon block header.
+	pub state_root: H256,
+	/// Branch from the state root to the beacon block header root.
+	pub state_root_branch: BoundedVec<H256, ConstU32<3>>,
+	/// SSZ hash tree root of the next sync committee.
+	pub sync_committee_root: H256,
+	/// Branch from the next sync committee to the state root.
+	pub sync_committee_branch: BoundedVec<H256, ConstU32<6>>,
+}
+
+/// SSZ branches that prove the execution state root of a finalized beacon block header.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ExecutionStateRootBranch {
+	/// Fork digest of the finalized beacon block header, selects the layout of its body.
+	pub fork_digest: [u8; 4],
+	/// Body root of the finalized beacon block header.
+	pub body_root: H256,
+	/// Branch from the body root to the beacon block header root.
+	pub body_root_branch: BoundedVec<H256, ConstU32<3>>,
+	/// Branch from the execution state root to the body root.
+	pub execution_state_root_branch: BoundedVec<H256, ConstU32<9>>,
+}
+
+/// How messages of a domain are proven to be sent.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default)]
+pub enum ExecutionMode {
+	/// Storage proof of the message in the messages mapping of the broadcaster.
+	#[default]
+	StorageProof,
+	/// Receipts trie proof of the `MessageSent` log of the broadcaster.
+	ReceiptProof,
+}
+
+/// ABI encoding of the messages sent by the bridge contracts of a domain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default)]
+pub enum MessageAbiVersion {
+	/// ABI encoding of the addressed message.
+	#[default]
+	V0,
+	/// ABI encoding of the addressed message prefixed with the version byte, which separates the
+	/// message roots of updated deployments from those of older ones.
+	V1,
+}
+
+impl MessageAbiVersion {
+	/// Encodes `message` the way the bridge contracts hash it into the message root.
+	pub fn encode_message(&self, message: &AddressedMessage) -> Vec<u8> {
+		let encoded = message.clone().abi_encode();
+		match self {
+			MessageAbiVersion::V0 => encoded,
+			MessageAbiVersion::V1 => [&[1u8][..], &encoded].concat(),
+		}
+	}
+}
+
+/// Circuit a verification key belongs to.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum VerificationKeyKind {
+	Step,
+	Rotate,
+	Finality,
+}
+
+/// Verification key waiting for its activation block.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PendingVerificationKey<BlockNumber> {
+	/// Key that becomes active.
+	pub key: BoundedVec<u8, ConstU32<10_000>>,
+	/// Version of the key once active.
+	pub version: u32,
+	/// First block the key is active at.
+	pub activation: BlockNumber,
+}
+
+/// Storage layout of the broadcaster contract of a domain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SlotLayout {
+	/// Base slot of the mapping from message id to message root.
+	#[codec(compact)]
+	pub message_mapping: u64,
+}
+
+/// Slot timing of the source chain of a domain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SlotTiming {
+	/// Unix timestamp of the genesis of the source chain, in seconds.
+	#[codec(compact)]
+	pub genesis_time: u64,
+	/// Duration of a slot of the source chain, in seconds.
+	#[codec(compact)]
+	pub seconds_per_slot: u64,
+}
+
+/// Proof of a log in the receipts of a finalized execution payload.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ReceiptProof {
+	/// Fork digest of the finalized beacon block header, selects the layout of its body.
+	pub fork_digest: [u8; 4],
+	/// Body root of the finalized beacon block header.
+	pub body_root: H256,
+	/// Branch from the body root to the beacon block header root.
+	pub body_root_branch: BoundedVec<H256, ConstU32<3>>,
+	/// Receipts root of the execution payload.
+	pub receipts_root: H256,
+	/// Branch from the receipts root to the body root.
+	pub receipts_root_branch: BoundedVec<H256, ConstU32<9>>,
+	/// Index of the transaction in the execution payload.
+	#[codec(compact)]
+	pub tx_index: u64,
+	/// Index of the log in the receipt of the transaction.
+	#[codec(compact)]
+	pub log_index: u32,
+	/// Receipts trie proof of the receipt of the transaction.
+	pub receipt_proof: ValidProof,
+}
+
 /// VerifiedStep struct that holds verified params from a step call.
 #[derive(Default, Debug)]
 pub struct VerifiedStep {
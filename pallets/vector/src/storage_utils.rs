@@ -4,6 +4,7 @@ use primitive_types::{H160, H256};
 use rlp::Rlp;
 use scale_info::TypeInfo;
 use sp_io::hashing::keccak_256 as keccak256;
+use sp_runtime::DispatchResult;
 use sp_std::vec::Vec;
 use trie_db::{Trie, TrieDBBuilder};
 
@@ -12,13 +13,121 @@ pub enum MessageStatusEnum {
 	#[default]
 	NotExecuted,
 	ExecutionSucceeded,
+	Refundable,
+	ExecutionFailed(ExecutionFailureReason),
+	/// Message was proven against an execution state root that governance invalidated.
+	Invalidated,
 }
 
+/// Reason a proven message could not be executed. These failures may go away later, so the
+/// message can be retried without providing the proofs again.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum ExecutionFailureReason {
+	/// Asset of the message is not supported yet.
+	AssetNotSupported,
+	/// Bridge account could not pay out the transferred amount.
+	TransferFailed,
+	/// Transfer would exceed the rate limit of the asset in the current window.
+	RateLimited,
+	/// Transferred amount is below the minimum amount of the asset.
+	AmountBelowMinimum,
+	/// Message of an ordered sender is not the next one in sequence.
+	OutOfOrder,
+	/// Transferred amount does not cover the execution fee of the asset.
+	AmountBelowFee,
+}
+
+/// Receipt of a withdrawal sent from Avail, locating its leaf in the bridge root of its block
+/// for the claim on the destination domain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct WithdrawalReceipt {
+	/// Index of the extrinsic that sent the withdrawal.
+	pub tx_index: u32,
+	/// Index of the withdrawal in the bridge leaves of the block.
+	pub leaf_index: u32,
+	/// Withdrawn asset.
+	pub asset_id: H256,
+	/// Amount locked or burned on Avail.
+	pub amount: u128,
+	/// Recipient on the destination domain.
+	pub recipient: H256,
+	/// Domain the withdrawal is claimed on.
+	pub destination_domain: u32,
+}
+
+/// Outcome of the execution of a proven message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageOutcome {
+	/// Message was delivered, with the result of its bridged runtime call if it carried one.
+	Executed(DispatchResult),
+	/// Message could not be executed for a reason that may go away, so it can be retried.
+	Failed(ExecutionFailureReason),
+}
+
+/// Reason code recorded when a source chain is frozen.
+#[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum FreezeReason {
+	/// No reason was given.
+	#[default]
+	Unspecified,
+	/// An execution state root of the source chain is suspected to be faulty.
+	FaultyStateRoot,
+	/// The bridge contracts on the source chain are compromised.
+	ContractCompromised,
+	/// The light client of the source chain halted or diverged from the canonical chain.
+	LightClientFault,
+	/// Planned maintenance of the bridge to the source chain.
+	Maintenance,
+}
+
+/// Origin that froze a source chain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum FreezeOrigin {
+	/// Root.
+	Root,
+	/// The `DomainPauseOrigin`, such as a technical committee.
+	DomainPauseOrigin,
+}
+
+/// Why, by whom and since when a source chain is frozen.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct FreezeStatus<BlockNumber> {
+	pub reason: FreezeReason,
+	pub frozen_by: FreezeOrigin,
+	pub frozen_at: BlockNumber,
+}
+
+/// Most recent head verified by the light client of a domain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct VerifiedHead {
+	/// Finalized slot of the head.
+	pub slot: u64,
+	/// Unix timestamp of the slot on the source chain, in seconds.
+	pub timestamp: u64,
+	/// Root of the finalized beacon block header.
+	pub header_root: H256,
+	/// Execution state root of the finalized beacon block.
+	pub execution_state_root: H256,
+}
+
+const ACCOUNT_STORAGE_ROOT_INDEX: usize = 2;
+const ACCOUNT_CODE_HASH_INDEX: usize = 3;
+const RECEIPT_LOGS_INDEX: usize = 3;
+
 #[derive(Debug, PartialEq)]
 pub enum StorageError {
 	StorageValueError,
 	AccountNotFound,
 	CannotDecodeItems,
+	LogNotFound,
+}
+
+/// Log emitted by a contract, as included in a transaction receipt.
+#[derive(Debug, PartialEq)]
+pub struct ReceiptLog {
+	pub address: H160,
+	pub topics: Vec<H256>,
+	pub data: Vec<u8>,
 }
 
 /// get_storage_value returns a storage value based on the proof that is provided.
@@ -49,11 +158,90 @@ pub fn get_storage_value(
 	Ok(storage_value)
 }
 
+/// get_receipt_log returns the log at `log_index` of the receipt of the transaction at
+/// `tx_index` based on the receipts trie proof that is provided.
+pub fn get_receipt_log(
+	proof: Vec<Vec<u8>>,
+	receipts_root: H256,
+	tx_index: u64,
+	log_index: u32,
+) -> Result<ReceiptLog, StorageError> {
+	let key = rlp::encode(&tx_index);
+	let db = StorageProof::new(proof).into_memory_db::<keccak256::KeccakHasher>();
+	let trie =
+		TrieDBBuilder::<EIP1186Layout<keccak256::KeccakHasher>>::new(&db, &receipts_root).build();
+
+	let Ok(Some(receipt)) = trie.get(&key) else {
+		return Err(StorageError::StorageValueError);
+	};
+
+	// Typed receipts are prefixed with the type of their transaction.
+	let receipt = match receipt.first() {
+		Some(tx_type) if *tx_type < 0x7f => &receipt[1..],
+		_ => &receipt[..],
+	};
+
+	let Ok(log) = Rlp::new(receipt)
+		.at(RECEIPT_LOGS_INDEX)
+		.and_then(|logs| logs.at(log_index as usize))
+	else {
+		return Err(StorageError::LogNotFound);
+	};
+
+	let Ok(address) = log.at(0).and_then(|address| address.data()) else {
+		return Err(StorageError::CannotDecodeItems);
+	};
+	if address.len() != 20 {
+		return Err(StorageError::CannotDecodeItems);
+	}
+
+	let Ok(topics) = log.at(1) else {
+		return Err(StorageError::CannotDecodeItems);
+	};
+	let topics = topics
+		.iter()
+		.map(|topic| match topic.data() {
+			Ok(topic) if topic.len() == 32 => Ok(H256::from_slice(topic)),
+			_ => Err(StorageError::CannotDecodeItems),
+		})
+		.collect::<Result<Vec<H256>, StorageError>>()?;
+
+	let Ok(data) = log.at(2).and_then(|data| data.data()) else {
+		return Err(StorageError::CannotDecodeItems);
+	};
+
+	Ok(ReceiptLog {
+		address: H160::from_slice(address),
+		topics,
+		data: data.to_vec(),
+	})
+}
+
 /// get_storage_root returns storage root based on the provided proof.
 pub fn get_storage_root(
 	proof: Vec<Vec<u8>>,
 	address: H160,
 	state_root: H256,
+) -> Result<H256, StorageError> {
+	get_account_field(proof, address, state_root, ACCOUNT_STORAGE_ROOT_INDEX)
+}
+
+/// get_code_hash returns the code hash of the account based on the provided proof.
+pub fn get_code_hash(
+	proof: Vec<Vec<u8>>,
+	address: H160,
+	state_root: H256,
+) -> Result<H256, StorageError> {
+	get_account_field(proof, address, state_root, ACCOUNT_CODE_HASH_INDEX)
+}
+
+/// get_account_field returns the item at `index` of the RLP encoded account
+/// `[nonce, balance, storage_root, code_hash]` based on the provided proof.
+fn get_account_field(
+	proof: Vec<Vec<u8>>,
+	address: H160,
+	state_root: H256,
+	index: usize,
 ) -> Result<H256, StorageError> {
 	let key = keccak256(address.as_bytes());
 	let db = StorageProof::new(proof).into_memory_db::<keccak256::KeccakHasher>();
@@ -74,13 +262,13 @@ pub fn get_storage_root(
 		return Err(StorageError::AccountNotFound);
 	}
 
-	let Ok(item) = r.at(2).and_then(|e| e.data()) else {
+	let Ok(item) = r.at(index).and_then(|e| e.data()) else {
 		return Err(StorageError::StorageValueError);
 	};
 
-	let storage_root = rlp_to_h256(item)?;
+	let value = rlp_to_h256(item)?;
 
-	Ok(storage_root)
+	Ok(value)
 }
 
 fn rlp_to_h256(value: &[u8]) -> Result<H256, StorageError> {
@@ -213,6 +401,30 @@ mod test {
 		assert_eq!(H256(expected_value), value.unwrap())
 	}
 
+	#[test]
+	fn test_code_hash() {
+		let expected_code_hash =
+			hex!("f99c7a628a59cf1d27d3a906618656d06e3cdcbcd5f91503c002ea2f2420bc01");
+
+		let proof = vec![
+            hex!("f90211a00089429375db917315fb4b8d67055bdf76e13d11292801af4a4a151f5760ff7aa02ebce9bb13a075ff89c5aae6b67f4d457525c53dfcc016ce72ea17e0e15a3718a04201c7d41a78f6906183b252fecbb231305d4e22c7e5b729b95a5a6ac53f4d46a06b61a1f5e208c3babf5fc1c9c4180af47769ec421c2c3125f313b5394014fa8aa0b2f35b0e2a84ce9e685b3e9558a0495552c80baec0bd687092220314850f543ba0244dca6d79c72abe8e3a12d49f2cf1976ee7bef58c5c6eb9ff6708fa138abfcca005631aa85658a9962bfee9a4827df5ca6f5461c4bc533591c897a66421f9abbfa0478ef142f553c91d672d865bed8d5175ebbbfc72be010d23b8d81cdcb41247e0a0365a9b70e7c6d82d3246b130bc27453ba77f0bcb4301d43c719eae676a7e0d17a001768b342f6cbc790d57276817d0853c94a682e295930951059bd1c24352b46ea0e3d9b775f71b4c1b2a0c35b1e492b0f2c6ce66c94cf2c8320276fe5cd5e427c8a03bd4160a5626c0d56a4435cb13b6cd3adb5f93793b71148cafa16e07f554fa41a052ab349de3157030b412abdd7353ee1d6476c09c153ddb1dba487294f11a5c7ca0ab71e81c1fc9e656fa8f0df6ee16efa5f105acce3c43ef172a04534f00e5d25ea05306a9ed38acb653787765466a764d4c8748c29b4e7a9ad4a75c61c0840b4a17a0699307b9c473f45858fec9fecd034fa0b3427c0efdd02d407c03201dcdaca02380").to_vec(),
+            hex!("f90211a0f7c14d7714348be36359dd28afd64e2fb72679a7ae09a27027fc95e335bcde1ca0824329840722c728e0f19ae424caad4581ac42015a4ab8e9d3ea550c857da804a040d48c9df564c00b11d304e2a2597a35b17b25429c3850c4e3fe4e9a278bec88a0a497297590785cfaa8491579745c077b1095348912d4e3288d8f00857ed9db5da0b0ea3abfcdab8c6cf03152cc7a57f602f85d86f4bdb3d1ca2242a5e737561bbda06bbe0e0416b59f1c4cba36afdee766ea4689f1c1ac8e2245f45c2631e2478119a0222dec72b36685a0ca89e49ce87262957f7f891e695ea8ec52e25fbc3a328589a00b3cac878feb2bcd5fc3d49fe5f607eabf75f014df74a268d4aaa1d25654d030a000deffa5e2879748ef9a634a3573484b4dd259c0d4c10453a7e1e3504b56322ea05c356b24b3b36089583f650cb954f884b05275b09b7715a2eb3cf6fa9175738ea093abf2b2cb15649c192d0d79f62627ce634843f84ec98eee99267c1354b5135aa059e9c60388154b3b810ffd41f81ed9128c8091a12e0c53062d9e7430fedf5939a06855c9a5622a40b5bce572522e4774986c7061557d2f1b8f7070d8d397888b4ea04d220a5fb22e38d64cdf4b46a42898b9f1ce9f316f1d332eebebd32c0cc59000a09004930139d4ae94070b29245230d5b28b25ac59c11339928a2eb547f0828341a00f37af44fb487a5ed675e12f0566a54e59cc025466e91cf56dcf348ff4049ed980").to_vec(),
+            hex!("f90211a0e9fa1abfa1f1d84a27da9448b42e3c0f5c60c54a1e8cb90c9e28b60824157380a05e977e1d37e502ac74fd54a2debf7e9b7b6e64c261e45e9b0610bcc201ddbe93a02f8a351ea5204d62c85fe6b564eab729fd556b1941a4f83f6f4b6e40e4102869a0a4b62da8ab84fcd0cf425fba4fd03ad7f1350217679e105e57ee146f64b07e07a061049f894647148c39ec3d8c4563d22670ee697f2e4a003513595f5074fe0166a0de1551dd310c9206da56ff9288dc518cccf7cdfa259cc3ff0318a6f3f7539988a00e600d8cb072056fbf1f5bf7d18aec2eb2ba57e93b5e6bb3f0d36042ec8fbe9ba0fa02eb32060ca2e3fd46e39a8456f02156b8efb457c74ccab5789bce1d142613a0919bb37876273e3283660eb2c575ddcfa99239ab79cf7edaf64d5591689c7777a052a8ee269c13ef214ba56ff0ef6b3cb11da6b12ddadbf1883831e91c6768bf60a0028fdfd852916e9cfa13eee9bf6c540bdc7f6d9b18eee15e11da66a8cdfc933ba09d581d74aa42d7974e122d3a3ec6febaa74ca9f714ddf5c52a5bfa9ee41471e5a0c5608d4aef23664aaaa38aa2425cf959b62d30cf22a0d14147a3cab3d4178fc3a0beb1d967ae4415f30d7730c1bfd43446e24c5f0210cb3a0f5a9bc67e9f63228ea03117ae91a22815aac4b1c9210ba7a6682697a73cd68d7741d693c1cbd1925063a032cf653822d7a618300ef2113f0ff0be132dda944106f25350b5f37451c740a280").to_vec(),
+            hex!("f90211a0f284a2e627542f07910ea0cb276b0b7813f3b9c056aafe496b3e7f93d1b3aa67a0d45d246efac9fb2e0c8052354aa0eebd68a28e9606efbbd4a5c2f9e990dc4d3ea0fd5d8349c16fda7a90a9c778cc74126188887aeacec8761349e1863d4008602fa022796160a8b1259fca46b22aa863131e970b077a449a5be4c486c9384335826da0b28076746e56b0bc37fb7586e2c4f23b624523d8e2f7abdffa73859cd531c12da08af556fb72bb802fde89a5562659959ef83a7846f0ced10ed6e139b44016bae9a0f948d4f88be556c183e053c131cd62aa278bcc83845437bfc03721828a3e2082a038c90f875a89a76b5b42d7c843ee790b759e482570a0bcb4d291496a40815093a031b88038ca3cd315ba56e783d4423c7c306cd9567f5a9eca972ac631c4c58e83a0858cbce5374ea0469281ee65c5a1aa5cfa19e7f7df02635821be244a5d39a38ea00cefc972ac8009f230bd9c8015753e98072b5f71d3a09093309ac6f09002f420a0e5fb8ae4800ad431a827003be4d719efcc29424f3ad2fbe483a42ab724a8610ea01a584c371a17ffc56a7713b2c6bb65bbcbf63c9d6382e0423dd577031c63842da0104f13e37d23eed61ebe6b78ee93ee9c30c3a92dab0ccbc57715051e9744eb58a0b211502efd34235ac7f948856c809f8aaf5e299df97ff24d4fb0d53caa3d1e83a043d845df46ad73ae3a9f2bfa319c19e7f760922f1268d8b96f0a54cb8ae88ab880").to_vec(),
+            hex!("f90211a071241195c881f3437ebd19a9eccd009595c10537df66917a8fab0eb664f834dda0122c775309b9cff05db80ba77a60604d0fcb8a836a5e79999943f0d150297e19a0c32190d1506259a9ffa2ec1fbff6b23bd35d4e6bcb063b19a22ec10b914981f4a022a77ca63522f76d016d04e680d4c27c3ceee14bc4548f9e08c2cc10f9e1b789a0c646ec46e8f8d5fb7de785fe967200994afec4c48b2bcb001b5aed20db936326a0e20c61d63a3ac612051c43ed1acce68e185a08154e5f44e8eceebac0f454202da05b17a5f4ba7ed711f694536b96a69549fe097ba32dee1f9c71eb19a0533d46baa04da0bc8c8f03ad8f1efdf0da738f24c9ec4549acc71d43ae6607f22601ac4f38a08ea8a34e48a70ccac672eaa2c3a4538d61d38cb5a143a4596d571904b6e3181ea0148252504cc36b4f6b1ef7183df2ce176963bacfc97ad3949fcb6da7d4095821a03d63131beaa2c1137d599528084b0aeb4bea87ee8da16f424dd93c3b90087a75a059f94b55179b81bb657f5021b161ab30fffc8620706a858de7103a0da99a262ea0bb62efd30271c9e2bfc8a4938ebcf4d90623d1d55ffb97399f6456c597599464a024a60032c223c88b91e1fc98db296e58468ebf38eed7bdab0e114cdd754bdc80a0271ec93cc3efaacce706f26a3aa42d6f7c9d8fd6944329149ad63b43c78aae34a07caa42499d46895c9b948f37479c6572573db5b644a0862168e25e4e3bfdb57e80").to_vec(),
+            hex!("f9015180a09089f0d1272f06751d391dfbc7b6d49b39731b8a14b5e5e97d45e34d89df0f3fa0820bbc641b62cf0f6a4c3836017cdef0bf7f43c1ee8cbc76ce7b5dcd80f58b9480a0fbe1f0ac8158473558c7b9964cc295027449f6e960f5f6407d9ca1c9ef15f7bca0a2fb890c487021019f73371bf6798e8db8b612ca3c7b30fc3495441a1f9518c4a02cd1ca2531caa6e63ac5f16e5ea76018826683f10442ab5c2b1f9963f23b011ca0429bcf37f564e67dd5764f96fa79532113668cbb32059affdfdc82cfdfd5d1e18080a09be000de088393ee33eac568ba00e318f0ed370eded1cdf38aa75ad55e63945380a0a9138320438845382842e94a5b4ea6756af0c82a0f6b4f17eaf049d617aba98ea0229898dbbae35aa9ef23f2a46c26d419257c35ba11aff1b02ca2024a057f8acaa0cc4c22a6806f250facbdecc1d8874d430ccc277d68ca91b5fb10b4d9f7c681578080").to_vec(),
+            hex!("f891808080a076082e119bb693f858172779676f80da4deb1fd75b39db89ec6c96e36125cf6a8080a02b87e60a23ebea051ea7f029c26c5fad0ba86fb8d6d5d4bb563f48ddbf7fa6aca0d9693138b984cccc06a7461c7f39cc28947c9dd95d94bdea1047ddd420b81360808080808080a0ae23c016152c96bfa600e365cd62d6ce721f0b0d310e3c7c18b8a293b722a4ab8080").to_vec(),
+            hex!("f8669d3e80870bed23e92a482b9f577efea539b7865c0383284e1bf8cb8ae0e3b846f8440280a06801798586ca88b0ef3b4fb3f83162a9f13e5e242b4c8024c490006054e43933a0f99c7a628a59cf1d27d3a906618656d06e3cdcbcd5f91503c002ea2f2420bc01").to_vec(),
+        ];
+
+		let key = H160::from_slice(hex!("426BdE66aBd85741be832B824eA65A3AaD70113E").as_slice());
+
+		let state_root = hex!("d6b8a2fb20ade94a56d9d87a07ca11e46cc169ed43dc0d2527a0d3ca2309ba9c");
+		let code_hash = get_code_hash(proof, key, H256(state_root));
+
+		assert_eq!(H256(expected_code_hash), code_hash.unwrap())
+	}
+
 	#[test]
 	fn test_abi_encoding() {
 		let expected_encoded_message = hex!("00000000000000000000000000000000000000000000000000000000000000200200000000000000000000000000000000000000000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb9226600000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000e00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec();
@@ -258,4 +470,70 @@ mod test {
 
 		assert_err!(error, StorageError::CannotDecodeItems);
 	}
+
+	/// Builds a receipts trie holding only the receipt of the first transaction.
+	fn single_receipt_trie(receipt: Vec<u8>) -> (H256, Vec<Vec<u8>>) {
+		let mut path = vec![0x20];
+		path.extend(rlp::encode(&0u64).to_vec());
+		let mut leaf = rlp::RlpStream::new_list(2);
+		leaf.append(&path);
+		leaf.append(&receipt);
+		let leaf = leaf.out().to_vec();
+
+		(H256(keccak_256(&leaf)), vec![leaf])
+	}
+
+	fn receipt_with_log(address: H160, topics: &[H256], data: &[u8]) -> Vec<u8> {
+		let mut log = rlp::RlpStream::new_list(3);
+		log.append(&address.as_bytes().to_vec());
+		log.begin_list(topics.len());
+		for topic in topics {
+			log.append(&topic.as_bytes().to_vec());
+		}
+		log.append(&data.to_vec());
+
+		let mut receipt = rlp::RlpStream::new_list(4);
+		receipt.append(&1u8);
+		receipt.append(&21_000u64);
+		receipt.append(&vec![0u8; 256]);
+		receipt.begin_list(1);
+		receipt.append_raw(&log.out(), 1);
+
+		// EIP-1559 transaction receipt
+		let mut typed = vec![2u8];
+		typed.extend(receipt.out().to_vec());
+		typed
+	}
+
+	#[test]
+	fn test_receipt_log() {
+		let address = H160::repeat_byte(1);
+		let topics = [H256::repeat_byte(2), H256::repeat_byte(3)];
+		let (root, proof) = single_receipt_trie(receipt_with_log(address, &topics, &[4, 5]));
+
+		let log = get_receipt_log(proof, root, 0, 0).unwrap();
+		assert_eq!(
+			log,
+			ReceiptLog {
+				address,
+				topics: topics.to_vec(),
+				data: vec![4, 5],
+			}
+		);
+	}
+
+	#[test]
+	fn test_receipt_log_not_found() {
+		let address = H160::repeat_byte(1);
+		let (root, proof) = single_receipt_trie(receipt_with_log(address, &[], &[]));
+
+		assert_err!(
+			get_receipt_log(proof.clone(), root, 0, 1),
+			StorageError::LogNotFound
+		);
+		assert_err!(
+			get_receipt_log(proof, root, 1, 0),
+			StorageError::StorageValueError
+		);
+	}
 }
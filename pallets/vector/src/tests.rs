@@ -1,21 +1,37 @@
 use crate::{
 	mock::{
-		new_test_ext, Balances, Bridge, RuntimeEvent, RuntimeOrigin, System, Test,
-		ROTATE_FUNCTION_ID, ROTATE_VK, STEP_FUNCTION_ID, STEP_VK,
+		new_test_ext, ArbitraryMessageByteFee, AssetBalances, Balances, Bridge, HandledMessages,
+		MessageHandlerFails, MessageOutcomes, RuntimeCall, RuntimeEvent, RuntimeOrigin, System,
+		Test, Timestamp, VerifiedHeads, ROTATE_FUNCTION_ID, ROTATE_VK, STEP_FUNCTION_ID, STEP_VK,
 	},
-	state::Configuration,
-	storage_utils::MessageStatusEnum,
-	Broadcasters, ConfigurationStorage, Error, Event, ExecutionStateRoots, FunctionIds,
-	FunctionInput, FunctionOutput, FunctionProof, Head, Headers, MessageStatus,
-	RotateVerificationKey, SourceChainFrozen, StepVerificationKey, SyncCommitteePoseidons, Updater,
-	ValidProof, WhitelistedDomains,
+	state::{
+		AssetInfo, AssetKind, BridgedVolume, Configuration, ConsensusFork, ExecutionMode,
+		ExecutionStateRootBranch, FinalityMode, MessageAbiVersion, RateLimit, ReceiptProof,
+		RelayerReward, SlotLayout, SlotTiming, SyncCommitteeBranch, VerificationKeyKind,
+	},
+	storage_utils::{
+		ExecutionFailureReason, FreezeOrigin, FreezeReason, FreezeStatus, MessageOutcome,
+		MessageStatusEnum, VerifiedHead, WithdrawalReceipt,
+	},
+	AddressBindings, AssetRateLimits, AssetRegistry, Bonds, BridgedOrigin, BridgedSupply,
+	BridgedVolumes, BroadcasterCodeHashes, Broadcasters, ConfigurationStorage, ConfirmationDepths,
+	ConsensusForks, DomainGenesisConfig, EmergencyPauseExpiry, EnsureBridgedOrigin, Error, Event,
+	ExecuteBatch, ExecutionFees, ExecutionModes, ExecutionStateRoots, FinalityModes, ForkDigests,
+	FulfillThreshold, FunctionIds, FunctionInput, FunctionOutput, FunctionProof, GenesisTimestamp,
+	Head, Headers, MessageAbiVersions, MessagePreimages, MessageStatus, Operators, OrderedDelivery,
+	PendingVerificationKeys, PermissionedRelaying, ProverBond, QueuedMessages, Relayers,
+	RotateVerificationKey, SecondsPerSlot, SlotLayouts, SlotSubmitters, SlotTimings,
+	SourceChainFrozen, StepVerificationKey, SyncCommitteePoseidons, Timestamps, UnbondingUntil,
+	Updater, ValidProof, VerificationKeyVersions, WhitelistedDomains, MAX_INVALIDATED_SLOTS,
 };
 use avail_core::data_proof::Message::FungibleToken;
 use avail_core::data_proof::{tx_uid, AddressedMessage, Message};
+use codec::Encode;
 
 use frame_support::{
 	assert_err, assert_ok,
-	traits::{fungible::Inspect, DefensiveTruncateFrom},
+	dispatch::DispatchResultWithPostInfo,
+	traits::{fungible::Inspect, DefensiveTruncateFrom, EnsureOrigin, Get},
 	BoundedVec,
 };
 use frame_system::RawOrigin;
@@ -184,13 +200,17 @@ fn test_fulfill_step_call_proof_not_valid() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
 		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -207,15 +227,19 @@ fn test_fulfill_step_call_not_valid_function_id() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
 		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 		let invalid_function_id: H256 = H256(hex!(
 			"bf44af6890508b3b7f6910d4a4570a0d524769a23ce340b2c7400e140ad168ab"
 		));
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
 			invalid_function_id,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -233,18 +257,23 @@ fn test_fulfill_step_call_finality_not_met() {
 		let slot = 7634942;
 		Updater::<Test>::set(H256(TEST_SENDER_VEC));
 		SyncCommitteePoseidons::<Test>::insert(
+			2,
 			931,
 			U256::from(hex!(
 				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 512, // max finality
-		});
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 512, // max finality
+			},
+		);
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -261,10 +290,13 @@ fn test_fulfill_step_call_wrong_updater_address() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
 		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 		let invalid_function_id: H256 = H256(hex!(
 			"bf44af6890508b3b7f6910d4a4570a0d524769a23ce340b2c7400e140ad168ab"
 		));
@@ -273,6 +305,7 @@ fn test_fulfill_step_call_wrong_updater_address() {
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(wrong_updater),
+			2,
 			invalid_function_id,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -297,6 +330,7 @@ fn test_execute_fungible_token_via_storage() {
 
 		let slot = 8581263;
 		ExecutionStateRoots::<Test>::set(
+			2,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -348,6 +382,7 @@ fn test_execute_fungible_token_via_storage_with_trimmed_storage_value() {
 
 		let slot = 4965568;
 		ExecutionStateRoots::<Test>::set(
+			2,
 			slot,
 			H256(hex!(
 				"5e3fe0dd03c7ce3f89524cfa65545232bbf52645b52ac0a3939f766540a6ed69"
@@ -414,6 +449,7 @@ fn test_execute_message_with_frozen_chain() {
 
 		let slot = 8581263;
 		ExecutionStateRoots::<Test>::set(
+			2,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -438,937 +474,4917 @@ fn test_execute_message_with_frozen_chain() {
 	});
 }
 
+fn setup_failed_fungible_token_message() -> (AddressedMessage, H256) {
+	use frame_support::traits::Currency;
+
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+		)),
+	);
+
+	let slot = 8581263;
+	ExecutionStateRoots::<Test>::set(
+		2,
+		slot,
+		H256(hex!(
+			"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+		)),
+	);
+
+	// The bridge cannot pay out the transferred amount.
+	Balances::make_free_balance_be(&Bridge::account_id(), 0);
+
+	let message = get_valid_message();
+	let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+	let ok = Bridge::execute(
+		RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+		slot,
+		message.clone(),
+		get_valid_account_proof(),
+		get_valid_storage_proof(),
+	);
+	assert_ok!(ok);
+
+	(message, message_root)
+}
+
 #[test]
-fn test_execute_message_with_faulty_account_proof() {
+fn execute_records_transient_failure() {
 	new_test_ext().execute_with(|| {
-		Broadcasters::<Test>::set(
-			2,
-			H256(hex!(
-				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
-			)),
-		);
+		let (message, message_root) = setup_failed_fungible_token_message();
 
-		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
-			slot,
-			H256(hex!(
-				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
-			)),
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::TransferFailed)
 		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageExecutionFailed {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+			reason: ExecutionFailureReason::TransferFailed,
+		}));
+	});
+}
 
-		let account_proof = get_invalid_account_proof();
-		let storage_proof = get_valid_storage_proof();
-		let message = get_valid_message();
+#[test]
+fn retry_execute_works() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Currency;
 
-		let fail = Bridge::execute(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			slot,
-			message,
-			account_proof,
-			storage_proof,
+		let (message, message_root) = setup_failed_fungible_token_message();
+		Balances::make_free_balance_be(&Bridge::account_id(), 2_000 * 1000000000000000000);
+
+		let ok = Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message.clone());
+		assert_ok!(ok);
+
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
 		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+			origin_domain: message.origin_domain,
+			destination_domain: message.destination_domain,
+			result: Ok(()),
+		}));
+
+		// A succeeded message cannot be executed again.
+		let err = Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message);
+		assert_err!(err, Error::<Test>::MessageNotFailed);
+	});
+}
 
-		// invalid proof should return error
-		assert_err!(fail, Error::<Test>::CannotGetStorageRoot);
+#[test]
+fn set_asset_rate_limit_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let limit = Some(RateLimit {
+			max_amount: 1_000,
+			window: 100,
+		});
+		let ok = Bridge::set_asset_rate_limit(RawOrigin::Root.into(), H256::zero(), limit);
+		assert_ok!(ok);
+		assert_eq!(AssetRateLimits::<Test>::get(H256::zero()), limit);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::AssetRateLimitUpdated {
+			asset_id: H256::zero(),
+			limit,
+		}));
+
+		let ok = Bridge::set_asset_rate_limit(RawOrigin::Root.into(), H256::zero(), None);
+		assert_ok!(ok);
+		assert_eq!(AssetRateLimits::<Test>::get(H256::zero()), None);
 	});
 }
 
 #[test]
-fn test_execute_message_with_faulty_storage_proof() {
+fn set_asset_rate_limit_does_not_work_with_non_root() {
 	new_test_ext().execute_with(|| {
-		Broadcasters::<Test>::set(
-			2,
-			H256(hex!(
-				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
-			)),
-		);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_asset_rate_limit(origin, H256::zero(), None);
+		assert_err!(err, BadOrigin);
+	});
+}
 
-		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
-			slot,
-			H256(hex!(
-				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
-			)),
+fn execute_valid_message(slot: u64) -> DispatchResultWithPostInfo {
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+		)),
+	);
+	ExecutionStateRoots::<Test>::set(
+		2,
+		slot,
+		H256(hex!(
+			"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+		)),
+	);
+
+	Bridge::execute(
+		RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+		slot,
+		get_valid_message(),
+		get_valid_account_proof(),
+		get_valid_storage_proof(),
+	)
+}
+
+#[test]
+fn message_status_and_executed_in_range_work() {
+	new_test_ext().execute_with(|| {
+		let message_root = H256(keccak_256(get_valid_message().abi_encode().as_slice()));
+		assert_eq!(
+			Bridge::message_status(message_root),
+			MessageStatusEnum::NotExecuted
 		);
-		let account_proof = get_valid_account_proof();
-		let storage_proof = get_invalid_storage_proof();
-		let message = get_valid_message();
 
-		let fail = Bridge::execute(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			slot,
-			message,
-			account_proof,
-			storage_proof,
+		System::set_block_number(3);
+		assert_ok!(execute_valid_message(8581263));
+
+		assert_eq!(
+			Bridge::message_status(message_root),
+			MessageStatusEnum::ExecutionSucceeded
 		);
+		assert_eq!(Bridge::executed_in_range(1, 5), vec![message_root]);
+		assert_eq!(Bridge::executed_in_range(3, 3), vec![message_root]);
+		assert!(Bridge::executed_in_range(4, 5).is_empty());
+		assert!(Bridge::executed_in_range(5, 1).is_empty());
+	});
+}
 
-		// invalid storage proof should return error
-		assert_err!(fail, Error::<Test>::CannotGetStorageValue);
+#[test]
+fn executed_transfers_in_range_works() {
+	new_test_ext().execute_with(|| {
+		let message = get_valid_message();
+		System::set_block_number(3);
+		assert_ok!(execute_valid_message(8581263));
+
+		let transfer = (message.id, 2, H256::zero(), 1_000_000_000_000_000_000u128);
+		assert_eq!(Bridge::executed_transfers_in_range(1, 5), vec![transfer]);
+		assert!(Bridge::executed_transfers_in_range(4, 5).is_empty());
 	});
 }
 
 #[test]
-fn test_execute_message_with_already_executed_message() {
+fn set_asset_info_works_with_root() {
 	new_test_ext().execute_with(|| {
-		let balance_before_transfer = Balances::balance(&Bridge::account_id());
+		let asset_id = H256::repeat_byte(1);
+		let info = Some(AssetInfo {
+			kind: AssetKind::Local(1),
+			decimals: 6,
+			min_amount: 10,
+		});
+		let ok = Bridge::set_asset_info(RawOrigin::Root.into(), asset_id, info);
+		assert_ok!(ok);
+		assert_eq!(AssetRegistry::<Test>::get(asset_id), info);
 
-		Broadcasters::<Test>::set(
-			2,
-			H256(hex!(
-				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
-			)),
-		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::AssetInfoUpdated {
+			asset_id,
+			info,
+		}));
 
-		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
-			slot,
-			H256(hex!(
-				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
-			)),
-		);
+		let ok = Bridge::set_asset_info(RawOrigin::Root.into(), asset_id, None);
+		assert_ok!(ok);
+		assert_eq!(AssetRegistry::<Test>::get(asset_id), None);
+	});
+}
+
+#[test]
+fn set_asset_info_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_asset_info(origin, H256::zero(), None);
+		assert_err!(err, BadOrigin);
+	});
+}
 
+#[test]
+fn execute_unregistered_asset_fails() {
+	new_test_ext().execute_with(|| {
 		let message = get_valid_message();
-		let account_proof = get_valid_account_proof();
-		let storage_proof = get_valid_storage_proof();
-		let account: AccountId32 = AccountId32::from_slice(message.to.as_bytes()).unwrap();
-		let account_balance_before = Balances::balance(&account);
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		AssetRegistry::<Test>::remove(H256::zero());
 
-		let ok = Bridge::execute(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			slot,
-			message.clone(),
-			account_proof.clone(),
-			storage_proof.clone(),
+		assert_ok!(execute_valid_message(8581263));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::AssetNotSupported)
 		);
 
+		// The message goes through once the asset is registered.
+		AssetRegistry::<Test>::insert(H256::zero(), AssetInfo::native());
+		let ok = Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message);
 		assert_ok!(ok);
-		let balance_after_transfer = Balances::balance(&Bridge::account_id());
-		let expected_transfered_value = 1000000000000000000u128;
-		assert_eq!(
-			balance_before_transfer,
-			balance_after_transfer.saturating_add(expected_transfered_value)
-		);
-		let account_balance = Balances::balance(&account);
-		assert_eq!(account_balance_before, 0);
-		assert_eq!(account_balance, expected_transfered_value);
+	});
+}
 
-		let fail = Bridge::execute(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			slot,
-			message,
-			account_proof,
-			storage_proof,
+#[test]
+fn execute_below_min_amount_fails() {
+	new_test_ext().execute_with(|| {
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		AssetRegistry::<Test>::insert(
+			H256::zero(),
+			AssetInfo {
+				min_amount: 2_000_000_000_000_000_000,
+				..AssetInfo::native()
+			},
 		);
 
-		assert_err!(fail, Error::<Test>::MessageAlreadyExecuted);
+		assert_ok!(execute_valid_message(8581263));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::AmountBelowMinimum)
+		);
 	});
 }
 
 #[test]
-fn test_execute_message_with_unsupported_domain() {
+fn set_execution_fee_works_with_root() {
 	new_test_ext().execute_with(|| {
-		Broadcasters::<Test>::set(
-			2,
-			H256(hex!(
-				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
-			)),
-		);
+		let ok = Bridge::set_execution_fee(RawOrigin::Root.into(), H256::zero(), Some(1_000));
+		assert_ok!(ok);
+		assert_eq!(ExecutionFees::<Test>::get(H256::zero()), Some(1_000));
 
-		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
-			slot,
-			H256(hex!(
-				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
-			)),
-		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ExecutionFeeUpdated {
+			asset_id: H256::zero(),
+			fee: Some(1_000),
+		}));
+	});
+}
 
-		let mut message = get_valid_message();
-		// alter message
-		message.origin_domain = 4;
+#[test]
+fn set_execution_fee_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_execution_fee(origin, H256::zero(), Some(1_000));
+		assert_err!(err, BadOrigin);
+	});
+}
 
-		let account_proof = get_valid_account_proof();
-		let storage_proof = get_valid_storage_proof();
+#[test]
+fn execute_pays_execution_fee_to_relayer() {
+	new_test_ext().execute_with(|| {
+		let message = get_valid_message();
+		let recipient = AccountId32::new(message.to.0);
+		let fee = 1_000_000_000_000_000;
+		ExecutionFees::<Test>::insert(H256::zero(), fee);
 
-		let fail = Bridge::execute(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			slot,
-			message,
-			account_proof,
-			storage_proof,
-		);
+		assert_ok!(execute_valid_message(8581263));
 
-		assert_err!(fail, Error::<Test>::UnsupportedOriginChain);
+		assert_eq!(
+			Balances::balance(&recipient),
+			1_000_000_000_000_000_000 - fee
+		);
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), fee);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::ExecutionFeePaid {
+			relayer: TEST_SENDER_ACCOUNT,
+			asset_id: H256::zero(),
+			amount: fee,
+			message_id: message.id,
+		}));
 	});
 }
 
 #[test]
-fn test_fulfill_step_call() {
+fn bind_address_works_with_bridged_origin() {
 	new_test_ext().execute_with(|| {
-		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		let address = H256::repeat_byte(1);
+		let origin = RuntimeOrigin::from(BridgedOrigin(2, address));
 
-		SyncCommitteePoseidons::<Test>::insert(
-			931,
-			U256::from(hex!(
-				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
-			)),
+		let ok = Bridge::bind_address(origin, Some(TEST_SENDER_ACCOUNT));
+		assert_ok!(ok);
+		assert_eq!(
+			AddressBindings::<Test>::get(address),
+			Some(TEST_SENDER_ACCOUNT)
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		System::assert_last_event(RuntimeEvent::Bridge(Event::AddressBound {
+			address,
+			account: Some(TEST_SENDER_ACCOUNT),
+		}));
+	});
+}
 
-		let result = Bridge::fulfill_call(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			STEP_FUNCTION_ID,
-			get_valid_step_input(),
-			get_valid_step_output(),
-			get_valid_step_proof(),
-			slot,
+#[test]
+fn bind_address_does_not_work_with_signed_origin() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::bind_address(origin, Some(TEST_SENDER_ACCOUNT));
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_transfers_to_bound_address() {
+	new_test_ext().execute_with(|| {
+		let message = get_valid_message();
+		AddressBindings::<Test>::insert(message.to, TEST_SENDER_ACCOUNT);
+
+		assert_ok!(execute_valid_message(8581263));
+
+		assert_eq!(
+			Balances::balance(&TEST_SENDER_ACCOUNT),
+			1_000_000_000_000_000_000
 		);
+		assert_eq!(Balances::balance(&AccountId32::new(message.to.0)), 0);
+	});
+}
 
-		assert_ok!(result);
-		let finalized_slot = 7634848;
-		// ensure that event is fired
-		let expected_event = RuntimeEvent::Bridge(Event::HeadUpdated {
-			slot: finalized_slot,
-			finalization_root: H256(hex!(
-				"e4566e0cf4edb171a3eedd59f9943bbcd0b1f6b648f1a6e26d5264b668ab41ec"
-			)),
-			execution_state_root: H256(hex!(
-				"51e76629b32b943497207e7b7ccff8fbc12e9e6d758cc7eed972422c4cad02b9"
-			)),
-		});
+#[test]
+fn execute_fails_with_amount_below_fee() {
+	new_test_ext().execute_with(|| {
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		ExecutionFees::<Test>::insert(H256::zero(), 1_000_000_000_000_000_000);
 
-		let finalized_slot = 7634848;
+		assert_ok!(execute_valid_message(8581263));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::AmountBelowFee)
+		);
+	});
+}
+
+#[test]
+fn send_message_fungible_token_does_not_accept_amount_below_minimum() {
+	new_test_ext().execute_with(|| {
+		AssetRegistry::<Test>::insert(
+			H256::zero(),
+			AssetInfo {
+				min_amount: 1_000,
+				..AssetInfo::native()
+			},
+		);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 100,
+		};
+
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(err, Error::<Test>::AmountBelowMinimum);
+	});
+}
+
+#[test]
+fn execute_local_asset_mints() {
+	new_test_ext().execute_with(|| {
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		let balance_before = Balances::balance(&Bridge::account_id());
+		AssetRegistry::<Test>::insert(
+			H256::zero(),
+			AssetInfo {
+				kind: AssetKind::Local(7),
+				decimals: 18,
+				min_amount: 0,
+			},
+		);
 
-		let header = Headers::<Test>::get(finalized_slot);
-		let head = Head::<Test>::get();
-		let ex_state_root = ExecutionStateRoots::<Test>::get(finalized_slot);
+		assert_ok!(execute_valid_message(8581263));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
 
+		let to = AccountId32::new(message.to.0);
 		assert_eq!(
-			header,
-			H256(hex!(
-				"e4566e0cf4edb171a3eedd59f9943bbcd0b1f6b648f1a6e26d5264b668ab41ec"
-			))
+			AssetBalances::get(),
+			vec![(7, to, 1_000_000_000_000_000_000)]
 		);
 		assert_eq!(
-			ex_state_root,
-			H256(hex!(
-				"51e76629b32b943497207e7b7ccff8fbc12e9e6d758cc7eed972422c4cad02b9"
-			))
+			BridgedSupply::<Test>::get(H256::zero()),
+			1_000_000_000_000_000_000
 		);
-		assert_eq!(head, finalized_slot);
-		assert_eq!(expected_event, System::events()[0].event);
+		assert_eq!(Balances::balance(&Bridge::account_id()), balance_before);
 	});
 }
 
 #[test]
-fn test_fulfill_step_call_wrong_poseidon() {
+fn send_message_local_asset_burns() {
 	new_test_ext().execute_with(|| {
-		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		let asset_id = H256::repeat_byte(1);
+		let sender = AccountId32::new(TEST_SENDER_VEC);
+		AssetRegistry::<Test>::insert(
+			asset_id,
+			AssetInfo {
+				kind: AssetKind::Local(7),
+				decimals: 18,
+				min_amount: 0,
+			},
+		);
+		AssetBalances::set(vec![(7, sender.clone(), 300)]);
+		BridgedSupply::<Test>::insert(asset_id, 300);
 
-		// current poseidon is not the same as the one in the valid proof
-		SyncCommitteePoseidons::<Test>::insert(
-			931,
-			U256::from(hex!(
-				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332da"
-			)),
+		let message = Message::FungibleToken {
+			asset_id,
+			amount: 100,
+		};
+		let ok = Bridge::send_message(
+			RuntimeOrigin::signed(sender.clone()),
+			message,
+			ROTATE_FUNCTION_ID,
+			2,
 		);
+		assert_ok!(ok);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		assert_eq!(AssetBalances::get(), vec![(7, sender.clone(), 200)]);
+		assert_eq!(BridgedSupply::<Test>::get(asset_id), 200);
 
-		let result = Bridge::fulfill_call(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			STEP_FUNCTION_ID,
-			get_valid_step_input(),
-			get_valid_step_output(),
-			get_valid_step_proof(),
-			slot,
+		// Cannot burn more than the sender holds.
+		let message = Message::FungibleToken {
+			asset_id,
+			amount: 300,
+		};
+		let err = Bridge::send_message(
+			RuntimeOrigin::signed(sender),
+			message,
+			ROTATE_FUNCTION_ID,
+			2,
 		);
+		assert_err!(err, sp_runtime::DispatchError::Other("InsufficientBalance"));
+		assert_eq!(BridgedSupply::<Test>::get(asset_id), 200);
+	});
+}
 
-		assert_err!(result, Error::<Test>::StepVerificationError);
+#[test]
+fn execute_throttled_by_rate_limit() {
+	new_test_ext().execute_with(|| {
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		AssetRateLimits::<Test>::insert(
+			H256::zero(),
+			RateLimit {
+				max_amount: 1_500_000_000_000_000_000,
+				window: 100,
+			},
+		);
+		BridgedVolumes::<Test>::insert(
+			H256::zero(),
+			BridgedVolume {
+				window_start: 1,
+				amount: 1_000_000_000_000_000_000,
+			},
+		);
+
+		assert_ok!(execute_valid_message(8581263));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::RateLimited)
+		);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::TransferThrottled {
+			asset_id: H256::zero(),
+			amount: 1_000_000_000_000_000_000,
+			message_id: message.id,
+		}));
+
+		// Still throttled within the window.
+		System::set_block_number(100);
+		let err =
+			Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message.clone());
+		assert_err!(err, Error::<Test>::ExecutionStillFailing);
 	});
 }
 
 #[test]
-fn test_fulfill_step_call_slot_behind_head() {
+fn rate_limit_window_resets() {
 	new_test_ext().execute_with(|| {
-		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		SyncCommitteePoseidons::<Test>::insert(
-			931,
-			U256::from(hex!(
-				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
-			)),
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		AssetRateLimits::<Test>::insert(
+			H256::zero(),
+			RateLimit {
+				max_amount: 1_500_000_000_000_000_000,
+				window: 100,
+			},
+		);
+		BridgedVolumes::<Test>::insert(
+			H256::zero(),
+			BridgedVolume {
+				window_start: 1,
+				amount: 1_000_000_000_000_000_000,
+			},
 		);
+		assert_ok!(execute_valid_message(8581263));
 
-		// move head forward
-		Head::<Test>::set(8634942);
+		System::set_block_number(101);
+		let ok = Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message);
+		assert_ok!(ok);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		assert_eq!(
+			BridgedVolumes::<Test>::get(H256::zero()),
+			BridgedVolume {
+				window_start: 101,
+				amount: 1_000_000_000_000_000_000,
+			}
+		);
+	});
+}
 
-		let result = Bridge::fulfill_call(
+#[test]
+fn retry_execute_fails_if_not_failed() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::retry_execute(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			STEP_FUNCTION_ID,
-			get_valid_step_input(),
-			get_valid_step_output(),
-			get_valid_step_proof(),
-			slot,
+			get_valid_message(),
 		);
-
-		assert_err!(result, Error::<Test>::SlotBehindHead);
+		assert_err!(err, Error::<Test>::MessageNotFailed);
 	});
 }
 
 #[test]
-fn test_fulfill_rotate_call() {
+fn retry_execute_fails_if_still_failing() {
 	new_test_ext().execute_with(|| {
-		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		let (message, message_root) = setup_failed_fungible_token_message();
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 342,
-		});
+		let err = Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message);
+		assert_err!(err, Error::<Test>::ExecutionStillFailing);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::TransferFailed)
+		);
+	});
+}
 
-		Headers::<Test>::set(
+#[test]
+fn test_execute_message_with_faulty_account_proof() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
 			slot,
 			H256(hex!(
-				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
 			)),
 		);
 
-		let result = Bridge::fulfill_call(
+		let account_proof = get_invalid_account_proof();
+		let storage_proof = get_valid_storage_proof();
+		let message = get_valid_message();
+
+		let fail = Bridge::execute(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			ROTATE_FUNCTION_ID,
-			get_valid_rotate_input(),
-			get_valid_rotate_output(),
-			get_valid_rotate_proof(),
 			slot,
+			message,
+			account_proof,
+			storage_proof,
 		);
 
-		assert_ok!(result);
-		// ensure that event is fired
-		let expected_poseidon = U256::from_dec_str(
-			"16399439943012933445970260519503780180385945954293268151243539801891563949197",
-		)
-		.unwrap();
+		// invalid proof should return error
+		assert_err!(fail, Error::<Test>::CannotGetStorageRoot);
+	});
+}
 
-		let current_period = 931;
-		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeUpdated {
-			period: current_period + 1,
-			root: expected_poseidon,
-		});
+#[test]
+fn test_execute_message_with_faulty_storage_proof() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
 
-		let poseidon = SyncCommitteePoseidons::<Test>::get(current_period + 1);
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		let account_proof = get_valid_account_proof();
+		let storage_proof = get_invalid_storage_proof();
+		let message = get_valid_message();
 
-		assert_eq!(expected_event, System::events()[0].event);
-		assert_eq!(poseidon, expected_poseidon);
+		let fail = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		// invalid storage proof should return error
+		assert_err!(fail, Error::<Test>::CannotGetStorageValue);
 	});
 }
 
 #[test]
-fn test_fulfill_rotate_call_wrong_header() {
+fn test_execute_message_with_already_executed_message() {
 	new_test_ext().execute_with(|| {
-		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		let balance_before_transfer = Balances::balance(&Bridge::account_id());
 
-		ConfigurationStorage::<Test>::set(Configuration {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_message();
+		let account_proof = get_valid_account_proof();
+		let storage_proof = get_valid_storage_proof();
+		let account: AccountId32 = AccountId32::from_slice(message.to.as_bytes()).unwrap();
+		let account_balance_before = Balances::balance(&account);
+
+		let ok = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+
+		assert_ok!(ok);
+		let balance_after_transfer = Balances::balance(&Bridge::account_id());
+		let expected_transfered_value = 1000000000000000000u128;
+		assert_eq!(
+			balance_before_transfer,
+			balance_after_transfer.saturating_add(expected_transfered_value)
+		);
+		let account_balance = Balances::balance(&account);
+		assert_eq!(account_balance_before, 0);
+		assert_eq!(account_balance, expected_transfered_value);
+
+		let fail = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(fail, Error::<Test>::MessageAlreadyExecuted);
+	});
+}
+
+#[test]
+fn test_execute_message_with_unsupported_domain() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let mut message = get_valid_message();
+		// alter message
+		message.origin_domain = 4;
+
+		let account_proof = get_valid_account_proof();
+		let storage_proof = get_valid_storage_proof();
+
+		let fail = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(fail, Error::<Test>::UnsupportedOriginChain);
+	});
+}
+
+#[test]
+fn test_fulfill_step_call() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		SyncCommitteePoseidons::<Test>::insert(
+			2,
+			931,
+			U256::from(hex!(
+				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+			)),
+		);
+
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+
+		assert_ok!(result);
+		let finalized_slot = 7634848;
+		// ensure that event is fired
+		let expected_event = RuntimeEvent::Bridge(Event::HeadUpdated {
+			domain: 2,
+			slot: finalized_slot,
+			finalization_root: H256(hex!(
+				"e4566e0cf4edb171a3eedd59f9943bbcd0b1f6b648f1a6e26d5264b668ab41ec"
+			)),
+			execution_state_root: H256(hex!(
+				"51e76629b32b943497207e7b7ccff8fbc12e9e6d758cc7eed972422c4cad02b9"
+			)),
+		});
+
+		let finalized_slot = 7634848;
+
+		let header = Headers::<Test>::get(2, finalized_slot);
+		let head = Head::<Test>::get(2);
+		let ex_state_root = ExecutionStateRoots::<Test>::get(2, finalized_slot);
+
+		assert_eq!(
+			header,
+			H256(hex!(
+				"e4566e0cf4edb171a3eedd59f9943bbcd0b1f6b648f1a6e26d5264b668ab41ec"
+			))
+		);
+		assert_eq!(
+			ex_state_root,
+			H256(hex!(
+				"51e76629b32b943497207e7b7ccff8fbc12e9e6d758cc7eed972422c4cad02b9"
+			))
+		);
+		assert_eq!(head, finalized_slot);
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn test_fulfill_step_call_updates_only_its_domain() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		let domain = 3;
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		SyncCommitteePoseidons::<Test>::insert(
+			domain,
+			931,
+			U256::from(hex!(
+				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+			)),
+		);
+		ConfigurationStorage::<Test>::insert(
+			domain,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			domain,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+		assert_ok!(result);
+
+		let finalized_slot = 7634848;
+		assert_eq!(Head::<Test>::get(domain), finalized_slot);
+		assert_eq!(Head::<Test>::get(2), 0);
+		assert_eq!(Headers::<Test>::get(2, finalized_slot), H256::zero());
+		assert_eq!(
+			ExecutionStateRoots::<Test>::get(2, finalized_slot),
+			H256::zero()
+		);
+	});
+}
+
+#[test]
+fn test_fulfill_step_call_without_domain_configuration() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			3,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+		assert_err!(result, Error::<Test>::ConfigurationNotSet);
+	});
+}
+
+fn setup_step() {
+	Updater::<Test>::set(H256(TEST_SENDER_VEC));
+	SyncCommitteePoseidons::<Test>::insert(
+		2,
+		931,
+		U256::from(hex!(
+			"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+		)),
+	);
+	ConfigurationStorage::<Test>::insert(
+		2,
+		Configuration {
 			slots_per_period: 8192,
-			finality_threshold: 342,
+			finality_threshold: 461,
+		},
+	);
+}
+
+fn invalid_execution_state_root_branch(fork_digest: [u8; 4]) -> ExecutionStateRootBranch {
+	ExecutionStateRootBranch {
+		fork_digest,
+		body_root: H256::repeat_byte(1),
+		body_root_branch: BoundedVec::truncate_from(vec![H256::repeat_byte(2); 3]),
+		execution_state_root_branch: BoundedVec::truncate_from(vec![H256::repeat_byte(3); 8]),
+	}
+}
+
+#[test]
+fn fulfill_step_call_fails_with_unknown_fork_digest() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_step();
+
+		let result = Bridge::fulfill_step_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+			invalid_execution_state_root_branch([1, 2, 3, 4]),
+		);
+
+		assert_err!(result, Error::<Test>::UnknownForkDigest);
+	});
+}
+
+#[test]
+fn fulfill_step_call_fails_with_invalid_branch() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_step();
+		ForkDigests::<Test>::insert(2, [1, 2, 3, 4], ConsensusFork::Capella);
+
+		let result = Bridge::fulfill_step_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+			invalid_execution_state_root_branch([1, 2, 3, 4]),
+		);
+
+		assert_err!(result, Error::<Test>::InvalidExecutionStateRootBranch);
+		assert_eq!(Head::<Test>::get(2), 0);
+	});
+}
+
+#[test]
+fn set_fork_version_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let fork_version = [4, 0, 0, 0];
+		let ok = Bridge::set_fork_version(
+			RawOrigin::Root.into(),
+			2,
+			fork_version,
+			Some(ConsensusFork::Deneb),
+		);
+		assert_ok!(ok);
+
+		let fork_digest = crate::ssz::compute_fork_digest(
+			fork_version,
+			crate::GenesisValidatorRoot::<Test>::get(),
+		);
+		assert_eq!(
+			ForkDigests::<Test>::get(2, fork_digest),
+			Some(ConsensusFork::Deneb)
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ForkVersionUpdated {
+			domain: 2,
+			fork_version,
+			fork_digest,
+			fork: Some(ConsensusFork::Deneb),
+		}));
+
+		let ok = Bridge::set_fork_version(RawOrigin::Root.into(), 2, fork_version, None);
+		assert_ok!(ok);
+		assert_eq!(ForkDigests::<Test>::get(2, fork_digest), None);
+	});
+}
+
+#[test]
+fn set_fork_version_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_fork_version(origin, 2, [4, 0, 0, 0], Some(ConsensusFork::Deneb));
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+fn hash_pair(left: H256, right: H256) -> H256 {
+	let mut buf = [0u8; 64];
+	buf[..32].copy_from_slice(left.as_bytes());
+	buf[32..].copy_from_slice(right.as_bytes());
+	H256(sp_io::hashing::sha2_256(&buf))
+}
+
+fn ssz_root(leaf: H256, branch: &[H256], gindex: u64) -> H256 {
+	branch.iter().enumerate().fold(leaf, |value, (i, node)| {
+		if (gindex >> i) & 1 == 1 {
+			hash_pair(*node, value)
+		} else {
+			hash_pair(value, *node)
+		}
+	})
+}
+
+/// Builds a receipt proof of the `MessageSent` log with `topics` and stores the header root the
+/// proof commits to at `slot`.
+fn setup_receipt_proof(slot: u64, topics: &[H256]) -> ReceiptProof {
+	let fork_digest = [1, 2, 3, 4];
+	ForkDigests::<Test>::insert(2, fork_digest, ConsensusFork::Deneb);
+	ExecutionModes::<Test>::insert(2, ExecutionMode::ReceiptProof);
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+		)),
+	);
+
+	let mut log = rlp::RlpStream::new_list(3);
+	log.append(&hex!("DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95").to_vec());
+	log.begin_list(topics.len());
+	for topic in topics {
+		log.append(&topic.as_bytes().to_vec());
+	}
+	log.append(&Vec::<u8>::new());
+	let mut receipt = rlp::RlpStream::new_list(4);
+	receipt.append(&1u8);
+	receipt.append(&21_000u64);
+	receipt.append(&vec![0u8; 256]);
+	receipt.begin_list(1);
+	receipt.append_raw(&log.out(), 1);
+
+	let mut path = vec![0x20];
+	path.extend(rlp::encode(&0u64).to_vec());
+	let mut leaf = rlp::RlpStream::new_list(2);
+	leaf.append(&path);
+	leaf.append(&receipt.out().to_vec());
+	let leaf = leaf.out().to_vec();
+	let receipts_root = H256(keccak_256(&leaf));
+
+	let receipts_root_branch = vec![H256::repeat_byte(3); 9];
+	let body_root = ssz_root(receipts_root, &receipts_root_branch, 803);
+	let body_root_branch = vec![H256::repeat_byte(2); 3];
+	Headers::<Test>::insert(
+		2,
+		slot,
+		ssz_root(body_root, &body_root_branch, crate::ssz::BODY_ROOT_GINDEX),
+	);
+
+	ReceiptProof {
+		fork_digest,
+		body_root,
+		body_root_branch: BoundedVec::truncate_from(body_root_branch),
+		receipts_root,
+		receipts_root_branch: BoundedVec::truncate_from(receipts_root_branch),
+		tx_index: 0,
+		log_index: 0,
+		receipt_proof: BoundedVec::truncate_from(vec![BoundedVec::truncate_from(leaf)]),
+	}
+}
+
+fn message_sent_topics(message: &AddressedMessage) -> [H256; 3] {
+	let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+	let mut id = [0u8; 32];
+	U256::from(message.id).to_big_endian(&mut id);
+	[
+		H256(keccak_256(crate::MESSAGE_SENT_EVENT_SIGNATURE)),
+		H256(id),
+		message_root,
+	]
+}
+
+fn outbound_message_hash(message: Message, to: H256, domain: u32, id: u64) -> H256 {
+	let message = AddressedMessage::new(message, H256(TEST_SENDER_VEC), to, 1, domain, id);
+	H256(keccak_256(message.abi_encode().as_slice()))
+}
+
+fn bridged_call_message(call: RuntimeCall) -> AddressedMessage {
+	let from = hex!("681257BED628425a28B469114Dc21A7c30205cFD000000000000000000000000");
+	let to: [u8; 32] = Bridge::account_id().into();
+
+	AddressedMessage {
+		message: Message::ArbitraryMessage(BoundedVec::defensive_truncate_from(call.encode())),
+		from: from.into(),
+		to: to.into(),
+		origin_domain: 2,
+		destination_domain: 1,
+		id: 5,
+	}
+}
+
+#[test]
+fn execute_bridged_call_works() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = bridged_call_message(RuntimeCall::System(frame_system::Call::remark {
+			remark: b"bridged".to_vec(),
+		}));
+		let proof = setup_receipt_proof(slot, &message_sent_topics(&message));
+		let message_root = message_sent_topics(&message)[2];
+
+		assert_ok!(Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			proof,
+		));
+
+		System::assert_has_event(RuntimeEvent::Bridge(Event::BridgedCallDispatched {
+			origin_domain: 2,
+			sender: message.from,
+			message_id: 5,
+			result: Ok(()),
+		}));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		assert!(HandledMessages::get().is_empty());
+	});
+}
+
+#[test]
+fn execute_bridged_call_consumes_message_when_call_fails() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message =
+			bridged_call_message(RuntimeCall::System(frame_system::Call::set_heap_pages {
+				pages: 1,
+			}));
+		let proof = setup_receipt_proof(slot, &message_sent_topics(&message));
+		let message_root = message_sent_topics(&message)[2];
+
+		assert_ok!(Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			proof,
+		));
+
+		System::assert_has_event(RuntimeEvent::Bridge(Event::BridgedCallDispatched {
+			origin_domain: 2,
+			sender: message.from,
+			message_id: 5,
+			result: Err(BadOrigin.into()),
+		}));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: message.from,
+			to: message.to,
+			message_id: 5,
+			message_root,
+			origin_domain: 2,
+			destination_domain: 1,
+			result: Err(BadOrigin.into()),
+		}));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+	});
+}
+
+#[test]
+fn ensure_bridged_origin_works() {
+	new_test_ext().execute_with(|| {
+		let sender = H256::repeat_byte(1);
+		let origin = RuntimeOrigin::from(BridgedOrigin(2, sender));
+
+		assert_eq!(
+			EnsureBridgedOrigin::try_origin(origin).ok(),
+			Some(BridgedOrigin(2, sender))
+		);
+		assert!(EnsureBridgedOrigin::try_origin(RuntimeOrigin::root()).is_err());
+	});
+}
+
+#[test]
+fn execute_with_receipt_works() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let proof = setup_receipt_proof(slot, &message_sent_topics(&message));
+		let message_root = message_sent_topics(&message)[2];
+		let balance_before = Balances::balance(&Bridge::account_id());
+
+		let ok = Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			proof,
+		);
+		assert_ok!(ok);
+
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		assert_eq!(
+			Balances::balance(&Bridge::account_id()),
+			balance_before.saturating_sub(1_000_000_000_000_000_000u128)
+		);
+	});
+}
+
+#[test]
+fn execute_with_receipt_refunds_unused_proof_weight() {
+	new_test_ext().execute_with(|| {
+		use crate::{weight_helper, MAX_PROOF_BYTES};
+
+		let slot = 8581263;
+		let message = get_valid_message();
+		let proof = setup_receipt_proof(slot, &message_sent_topics(&message));
+		let proof_bytes: u32 = proof
+			.receipt_proof
+			.iter()
+			.map(|node| node.len() as u32)
+			.sum();
+		let declared = weight_helper::execute_with_receipt::<Test>(&message)
+			.saturating_add(weight_helper::proof_bytes::<Test>(MAX_PROOF_BYTES));
+		let actual = weight_helper::execute_with_receipt::<Test>(&message)
+			.saturating_add(weight_helper::proof_bytes::<Test>(proof_bytes));
+
+		let info = Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			proof,
+		)
+		.unwrap();
+		assert_eq!(info.actual_weight, Some(actual));
+		assert!(actual.all_lte(declared));
+	});
+}
+
+#[test]
+fn execute_with_receipt_fails_with_storage_proof_mode() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let proof = setup_receipt_proof(slot, &message_sent_topics(&message));
+		ExecutionModes::<Test>::insert(2, ExecutionMode::StorageProof);
+
+		let err = Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			proof,
+		);
+		assert_err!(err, Error::<Test>::ExecutionModeMismatch);
+	});
+}
+
+#[test]
+fn execute_with_receipt_fails_with_invalid_branch() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let mut proof = setup_receipt_proof(slot, &message_sent_topics(&message));
+		proof.receipts_root = H256::repeat_byte(4);
+
+		let err = Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			proof,
+		);
+		assert_err!(err, Error::<Test>::InvalidReceiptsRootBranch);
+	});
+}
+
+#[test]
+fn execute_with_receipt_fails_with_wrong_log() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let mut topics = message_sent_topics(&message);
+		topics[2] = H256::repeat_byte(4);
+		let proof = setup_receipt_proof(slot, &topics);
+
+		let err = Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			proof,
+		);
+		assert_err!(err, Error::<Test>::InvalidMessageLog);
+	});
+}
+
+#[test]
+fn execute_fails_with_receipt_proof_mode() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		ExecutionModes::<Test>::insert(2, ExecutionMode::ReceiptProof);
+
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_message(),
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::ExecutionModeMismatch);
+	});
+}
+
+#[test]
+fn set_execution_mode_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_execution_mode(RawOrigin::Root.into(), 2, ExecutionMode::ReceiptProof);
+		assert_ok!(ok);
+		assert_eq!(ExecutionModes::<Test>::get(2), ExecutionMode::ReceiptProof);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ExecutionModeUpdated {
+			domain: 2,
+			mode: ExecutionMode::ReceiptProof,
+		}));
+	});
+}
+
+#[test]
+fn set_execution_mode_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_execution_mode(origin, 2, ExecutionMode::ReceiptProof);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn set_slot_layout_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let layout = SlotLayout { message_mapping: 3 };
+		let ok = Bridge::set_slot_layout(RawOrigin::Root.into(), 2, Some(layout));
+		assert_ok!(ok);
+		assert_eq!(SlotLayouts::<Test>::get(2), Some(layout));
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::SlotLayoutUpdated {
+			domain: 2,
+			layout: Some(layout),
+		}));
+	});
+}
+
+#[test]
+fn set_slot_layout_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_slot_layout(origin, 2, Some(SlotLayout { message_mapping: 3 }));
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn set_slot_timing_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let timing = SlotTiming {
+			genesis_time: 1606824023,
+			seconds_per_slot: 12,
+		};
+		let ok = Bridge::set_slot_timing(RawOrigin::Root.into(), 2, Some(timing));
+		assert_ok!(ok);
+		assert_eq!(SlotTimings::<Test>::get(2), Some(timing));
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::SlotTimingUpdated {
+			domain: 2,
+			timing: Some(timing),
+		}));
+	});
+}
+
+#[test]
+fn set_slot_timing_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_slot_timing(origin, 2, None);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_works_with_slot_layout() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		SlotLayouts::<Test>::insert(2, SlotLayout { message_mapping: 3 });
+		let err = execute_valid_message(slot);
+		assert_err!(err, Error::<Test>::CannotGetStorageValue);
+
+		// The broadcaster of the valid message keeps its messages at slot 1.
+		SlotLayouts::<Test>::insert(2, SlotLayout { message_mapping: 1 });
+		assert_ok!(execute_valid_message(slot));
+	});
+}
+
+#[test]
+fn set_message_abi_version_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_message_abi_version(RawOrigin::Root.into(), 2, MessageAbiVersion::V1);
+		assert_ok!(ok);
+		assert_eq!(MessageAbiVersions::<Test>::get(2), MessageAbiVersion::V1);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageAbiVersionUpdated {
+			domain: 2,
+			version: MessageAbiVersion::V1,
+		}));
+	});
+}
+
+#[test]
+fn set_message_abi_version_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_message_abi_version(origin, 2, MessageAbiVersion::V1);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_works_with_message_abi_version() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let mut v1_encoding = vec![1u8];
+		v1_encoding.extend(message.clone().abi_encode());
+		assert_eq!(MessageAbiVersion::V1.encode_message(&message), v1_encoding);
+
+		// The broadcaster of the valid message uses the version 0 encoding.
+		MessageAbiVersions::<Test>::insert(2, MessageAbiVersion::V1);
+		let err = execute_valid_message(slot);
+		assert_err!(err, Error::<Test>::InvalidMessageHash);
+
+		MessageAbiVersions::<Test>::insert(2, MessageAbiVersion::V0);
+		assert_ok!(execute_valid_message(slot));
+	});
+}
+
+#[test]
+fn invalidate_execution_state_roots_works() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message_root = H256(keccak_256(get_valid_message().abi_encode().as_slice()));
+		assert_ok!(execute_valid_message(slot));
+		ExecutionStateRoots::<Test>::set(2, slot + 1, H256::repeat_byte(1));
+
+		let ok =
+			Bridge::invalidate_execution_state_roots(RawOrigin::Root.into(), 2, slot, slot + 1, 10);
+		assert_ok!(ok);
+
+		assert_eq!(ExecutionStateRoots::<Test>::get(2, slot), H256::zero());
+		assert_eq!(ExecutionStateRoots::<Test>::get(2, slot + 1), H256::zero());
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::Invalidated
+		);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::MessageInvalidated {
+			domain: 2,
+			slot,
+			message_root,
+			previous: MessageStatusEnum::ExecutionSucceeded,
+		}));
+		System::assert_last_event(RuntimeEvent::Bridge(
+			Event::ExecutionStateRootsInvalidated {
+				domain: 2,
+				from_slot: slot,
+				to_slot: slot + 1,
+				roots: 2,
+				executed: 1,
+				pending: 0,
+			},
+		));
+
+		// An invalidated message cannot be executed again.
+		assert!(execute_valid_message(slot + 1).is_err());
+	});
+}
+
+#[test]
+fn invalidate_execution_state_roots_does_not_work_with_non_rollback_origin() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::invalidate_execution_state_roots(origin, 2, 1, 2, 10);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn invalidate_execution_state_roots_fails_with_invalid_range() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::invalidate_execution_state_roots(RawOrigin::Root.into(), 2, 2, 1, 10);
+		assert_err!(err, Error::<Test>::InvalidSlotRange);
+
+		let to_slot = MAX_INVALIDATED_SLOTS as u64;
+		let err =
+			Bridge::invalidate_execution_state_roots(RawOrigin::Root.into(), 2, 0, to_slot, 10);
+		assert_err!(err, Error::<Test>::InvalidSlotRange);
+	});
+}
+
+#[test]
+fn invalidate_execution_state_roots_fails_with_too_many_messages() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let (_, message_root) = setup_failed_fungible_token_message();
+
+		let err =
+			Bridge::invalidate_execution_state_roots(RawOrigin::Root.into(), 2, slot, slot, 0);
+		assert_err!(err, Error::<Test>::TooManyInvalidatedMessages);
+
+		let ok = Bridge::invalidate_execution_state_roots(RawOrigin::Root.into(), 2, slot, slot, 1);
+		assert_ok!(ok);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::Invalidated
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(
+			Event::ExecutionStateRootsInvalidated {
+				domain: 2,
+				from_slot: slot,
+				to_slot: slot,
+				roots: 1,
+				executed: 0,
+				pending: 1,
+			},
+		));
+	});
+}
+
+fn setup_zk_finality() {
+	Updater::<Test>::set(H256(TEST_SENDER_VEC));
+	FinalityModes::<Test>::insert(2, FinalityMode::ZkFinality);
+	Head::<Test>::insert(2, 7634848);
+	Headers::<Test>::insert(2, 7634848, H256::repeat_byte(1));
+}
+
+#[test]
+fn fulfill_finality_fails_with_sync_committee_mode() {
+	new_test_ext().execute_with(|| {
+		setup_zk_finality();
+		FinalityModes::<Test>::remove(2);
+
+		let err = Bridge::fulfill_finality(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			get_valid_step_output(),
+			get_valid_step_proof(),
+		);
+		assert_err!(err, Error::<Test>::FinalityModeMismatch);
+	});
+}
+
+#[test]
+fn fulfill_finality_fails_without_verification_key() {
+	new_test_ext().execute_with(|| {
+		setup_zk_finality();
+
+		let err = Bridge::fulfill_finality(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			get_valid_step_output(),
+			get_valid_step_proof(),
+		);
+		assert_err!(err, Error::<Test>::VerificationKeyIsNotSet);
+	});
+}
+
+#[test]
+fn fulfill_finality_fails_with_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		setup_zk_finality();
+		FinalityVerificationKey::<Test>::set(Some(
+			BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap(),
+		));
+
+		// The step proof is not bound to the current head.
+		let err = Bridge::fulfill_finality(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			get_valid_step_output(),
+			get_valid_step_proof(),
+		);
+		assert_err!(err, Error::<Test>::VerificationFailed);
+		assert_eq!(Head::<Test>::get(2), 7634848);
+	});
+}
+
+#[test]
+fn fulfill_call_step_fails_with_zk_finality_mode() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_step();
+		FinalityModes::<Test>::insert(2, FinalityMode::ZkFinality);
+
+		let err = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+		assert_err!(err, Error::<Test>::FinalityModeMismatch);
+	});
+}
+
+#[test]
+fn set_finality_verification_key_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let value = Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap());
+		let ok = Bridge::set_finality_verification_key(RawOrigin::Root.into(), value.clone());
+		assert_ok!(ok);
+		assert_eq!(FinalityVerificationKey::<Test>::get(), None);
+
+		System::set_block_number(6);
+		Bridge::on_initialize(6);
+		assert_eq!(FinalityVerificationKey::<Test>::get(), value);
+
+		System::assert_has_event(RuntimeEvent::Bridge(
+			Event::FinalityVerificationKeyUpdated { value },
+		));
+	});
+}
+
+#[test]
+fn set_finality_verification_key_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let value = Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap());
+		let err = Bridge::set_finality_verification_key(origin, value);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn set_finality_verification_key_fails_with_malformed_key() {
+	new_test_ext().execute_with(|| {
+		let value = Some(BoundedVec::try_from(b"not a key".to_vec()).unwrap());
+		let err = Bridge::set_finality_verification_key(RawOrigin::Root.into(), value);
+		assert_err!(err, Error::<Test>::MalformedVerificationKey);
+	});
+}
+
+#[test]
+fn set_finality_mode_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_finality_mode(RawOrigin::Root.into(), 2, FinalityMode::ZkFinality);
+		assert_ok!(ok);
+		assert_eq!(FinalityModes::<Test>::get(2), FinalityMode::ZkFinality);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::FinalityModeUpdated {
+			domain: 2,
+			mode: FinalityMode::ZkFinality,
+		}));
+	});
+}
+
+#[test]
+fn set_finality_mode_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_finality_mode(origin, 2, FinalityMode::ZkFinality);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn test_fulfill_step_call_wrong_poseidon() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		// current poseidon is not the same as the one in the valid proof
+		SyncCommitteePoseidons::<Test>::insert(
+			2,
+			931,
+			U256::from(hex!(
+				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332da"
+			)),
+		);
+
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+
+		assert_err!(result, Error::<Test>::StepVerificationError);
+	});
+}
+
+#[test]
+fn test_fulfill_step_call_slot_behind_head() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		SyncCommitteePoseidons::<Test>::insert(
+			2,
+			931,
+			U256::from(hex!(
+				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+			)),
+		);
+
+		// move head forward
+		Head::<Test>::insert(2, 8634942);
+
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+
+		assert_err!(result, Error::<Test>::SlotBehindHead);
+	});
+}
+
+#[test]
+fn test_fulfill_rotate_call() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
+
+		Headers::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
+			)),
+		);
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			ROTATE_FUNCTION_ID,
+			get_valid_rotate_input(),
+			get_valid_rotate_output(),
+			get_valid_rotate_proof(),
+			slot,
+		);
+
+		assert_ok!(result);
+		// ensure that event is fired
+		let expected_poseidon = U256::from_dec_str(
+			"16399439943012933445970260519503780180385945954293268151243539801891563949197",
+		)
+		.unwrap();
+
+		let current_period = 931;
+		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeUpdated {
+			domain: 2,
+			period: current_period + 1,
+			root: expected_poseidon,
+		});
+
+		let poseidon = SyncCommitteePoseidons::<Test>::get(2, current_period + 1);
+
+		assert_eq!(expected_event, System::events()[0].event);
+		assert_eq!(poseidon, expected_poseidon);
+	});
+}
+
+fn setup_rotate(slot: u64) {
+	Updater::<Test>::set(H256(TEST_SENDER_VEC));
+	ConfigurationStorage::<Test>::insert(
+		2,
+		Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 342,
+		},
+	);
+	Headers::<Test>::set(
+		2,
+		slot,
+		H256(hex!(
+			"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
+		)),
+	);
+}
+
+fn invalid_sync_committee_branch() -> SyncCommitteeBranch {
+	SyncCommitteeBranch {
+		state_root: H256::repeat_byte(1),
+		state_root_branch: BoundedVec::truncate_from(vec![H256::repeat_byte(2); 3]),
+		sync_committee_root: H256::repeat_byte(3),
+		sync_committee_branch: BoundedVec::truncate_from(vec![H256::repeat_byte(4); 5]),
+	}
+}
+
+#[test]
+fn fulfill_call_rotate_requires_branch_with_consensus_fork() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_rotate(slot);
+		ConsensusForks::<Test>::insert(2, ConsensusFork::Deneb);
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			ROTATE_FUNCTION_ID,
+			get_valid_rotate_input(),
+			get_valid_rotate_output(),
+			get_valid_rotate_proof(),
+			slot,
+		);
+
+		assert_err!(result, Error::<Test>::SyncCommitteeBranchRequired);
+		assert_eq!(SyncCommitteePoseidons::<Test>::get(2, 932), U256::zero());
+	});
+}
+
+#[test]
+fn fulfill_rotate_call_fails_without_consensus_fork() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_rotate(slot);
+
+		let result = Bridge::fulfill_rotate_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			get_valid_rotate_input(),
+			get_valid_rotate_output(),
+			get_valid_rotate_proof(),
+			slot,
+			invalid_sync_committee_branch(),
+		);
+
+		assert_err!(result, Error::<Test>::ConsensusForkNotSet);
+	});
+}
+
+#[test]
+fn fulfill_rotate_call_fails_with_invalid_branch() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_rotate(slot);
+		ConsensusForks::<Test>::insert(2, ConsensusFork::Deneb);
+
+		let result = Bridge::fulfill_rotate_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			get_valid_rotate_input(),
+			get_valid_rotate_output(),
+			get_valid_rotate_proof(),
+			slot,
+			invalid_sync_committee_branch(),
+		);
+
+		assert_err!(result, Error::<Test>::InvalidSyncCommitteeBranch);
+		assert_eq!(SyncCommitteePoseidons::<Test>::get(2, 932), U256::zero());
+	});
+}
+
+#[test]
+fn fulfill_rotate_call_does_not_work_with_non_updater() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_rotate(slot);
+		ConsensusForks::<Test>::insert(2, ConsensusFork::Deneb);
+
+		let result = Bridge::fulfill_rotate_call(
+			RuntimeOrigin::signed(AccountId32::new([9u8; 32])),
+			2,
+			get_valid_rotate_input(),
+			get_valid_rotate_output(),
+			get_valid_rotate_proof(),
+			slot,
+			invalid_sync_committee_branch(),
+		);
+
+		assert_err!(result, Error::<Test>::UpdaterMisMatch);
+	});
+}
+
+#[test]
+fn set_consensus_fork_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok =
+			Bridge::set_consensus_fork(RawOrigin::Root.into(), 2, Some(ConsensusFork::Electra));
+		assert_ok!(ok);
+		assert_eq!(ConsensusForks::<Test>::get(2), Some(ConsensusFork::Electra));
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ConsensusForkUpdated {
+			domain: 2,
+			fork: Some(ConsensusFork::Electra),
+		}));
+
+		let ok = Bridge::set_consensus_fork(RawOrigin::Root.into(), 2, None);
+		assert_ok!(ok);
+		assert_eq!(ConsensusForks::<Test>::get(2), None);
+	});
+}
+
+#[test]
+fn set_consensus_fork_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_consensus_fork(origin, 2, Some(ConsensusFork::Deneb));
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn test_fulfill_rotate_call_wrong_header() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
+		// set current wrong header for valid rotate call
+		Headers::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57855"
+			)),
+		);
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			ROTATE_FUNCTION_ID,
+			get_valid_rotate_input(),
+			get_valid_rotate_output(),
+			get_valid_rotate_proof(),
+			slot,
+		);
+
+		assert_err!(result, Error::<Test>::RotateVerificationError);
+	});
+}
+
+#[test]
+fn test_fulfill_call_function_ids_not_set() {
+	new_test_ext().execute_with(|| {
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		Bridge::set_function_ids(RawOrigin::Root.into(), None).unwrap();
+		let slot = 7634942;
+		let err = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+		assert_err!(err, Error::<Test>::FunctionIdsAreNotSet);
+	});
+}
+
+#[test]
+fn test_fulfill_step_call_verification_key_is_not_set() {
+	new_test_ext().execute_with(|| {
+		Bridge::set_step_verification_key(RawOrigin::Root.into(), None).unwrap();
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		let slot = 7634942;
+
+		SyncCommitteePoseidons::<Test>::insert(
+			2,
+			931,
+			U256::from(hex!(
+				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+			)),
+		);
+
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
+
+		let err = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+		assert_err!(err, Error::<Test>::VerificationKeyIsNotSet);
+	});
+}
+
+#[test]
+fn test_fulfill_rotate_call_verification_key_is_not_set() {
+	new_test_ext().execute_with(|| {
+		Bridge::set_rotate_verification_key(RawOrigin::Root.into(), None).unwrap();
+		let slot = 7634942;
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		ConfigurationStorage::<Test>::insert(
+			2,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
+
+		Headers::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
+			)),
+		);
+
+		let err = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			ROTATE_FUNCTION_ID,
+			get_valid_rotate_input(),
+			get_valid_rotate_output(),
+			get_valid_rotate_proof(),
+			slot,
+		);
+		assert_err!(err, Error::<Test>::VerificationKeyIsNotSet);
+	});
+}
+
+#[test]
+fn set_whitelisted_domains_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let domains = BoundedVec::try_from([0, 1, 2, 3].to_vec()).unwrap();
+		assert_ne!(WhitelistedDomains::<Test>::get(), domains);
+
+		let ok = Bridge::set_whitelisted_domains(RawOrigin::Root.into(), domains.clone());
+		assert_ok!(ok);
+		assert_eq!(WhitelistedDomains::<Test>::get(), domains);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::WhitelistedDomainsUpdated));
+	});
+}
+
+#[test]
+fn set_whitelisted_domains_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let domains = BoundedVec::try_from([0, 1, 2, 3].to_vec()).unwrap();
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_whitelisted_domains(origin, domains.clone());
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn set_whitelisted_domains_sorts_and_deduplicates() {
+	new_test_ext().execute_with(|| {
+		let domains = BoundedVec::try_from([3, 1, 2, 1].to_vec()).unwrap();
+
+		let ok = Bridge::set_whitelisted_domains(RawOrigin::Root.into(), domains);
+		assert_ok!(ok);
+		assert_eq!(WhitelistedDomains::<Test>::get().to_vec(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn add_whitelisted_domain_works() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::add_whitelisted_domain(RawOrigin::Root.into(), 1);
+		assert_ok!(ok);
+		assert_eq!(WhitelistedDomains::<Test>::get().to_vec(), vec![1, 2]);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::WhitelistedDomainAdded {
+			domain: 1,
+		}));
+	});
+}
+
+#[test]
+fn add_whitelisted_domain_does_not_work_with_non_whitelist_origin() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::add_whitelisted_domain(origin, 1);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn add_whitelisted_domain_fails_if_already_whitelisted() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::add_whitelisted_domain(RawOrigin::Root.into(), 2);
+		assert_err!(ok, Error::<Test>::DomainAlreadyWhitelisted);
+	});
+}
+
+#[test]
+fn remove_whitelisted_domain_works() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::remove_whitelisted_domain(RawOrigin::Root.into(), 2);
+		assert_ok!(ok);
+		assert!(WhitelistedDomains::<Test>::get().is_empty());
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::WhitelistedDomainRemoved {
+			domain: 2,
+		}));
+	});
+}
+
+#[test]
+fn remove_whitelisted_domain_fails_if_not_whitelisted() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::remove_whitelisted_domain(RawOrigin::Root.into(), 1);
+		assert_err!(ok, Error::<Test>::DomainNotWhitelisted);
+	});
+}
+
+#[test]
+fn set_configuration_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let conf = Configuration {
+			slots_per_period: 1,
+			finality_threshold: 69,
+		};
+		assert_ne!(ConfigurationStorage::<Test>::get(2), conf);
+
+		let ok = Bridge::set_configuration(RawOrigin::Root.into(), 2, conf);
+		assert_ok!(ok);
+		assert_eq!(ConfigurationStorage::<Test>::get(2), conf);
+
+		let expected_event = RuntimeEvent::Bridge(Event::ConfigurationUpdated {
+			domain: 2,
+			slots_per_period: conf.slots_per_period,
+			finality_threshold: conf.finality_threshold,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_configuration_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let conf = Configuration {
+			slots_per_period: 1,
+			finality_threshold: 69,
+		};
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_configuration(origin, 2, conf);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn set_broadcaster_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let domain = 2;
+		let old = Broadcasters::<Test>::get(domain);
+		assert_ne!(old, STEP_FUNCTION_ID);
+
+		let ok = Bridge::set_broadcaster(RawOrigin::Root.into(), domain, STEP_FUNCTION_ID);
+		assert_ok!(ok);
+		assert_eq!(Broadcasters::<Test>::get(domain), STEP_FUNCTION_ID);
+
+		let expected_event = RuntimeEvent::Bridge(Event::BroadcasterUpdated {
+			old,
+			new: STEP_FUNCTION_ID,
+			domain,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_broadcaster_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_broadcaster(origin, 2, STEP_FUNCTION_ID);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn set_poseidon_hash_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let period = 2;
+		let poseidon_hash = BoundedVec::try_from(
+			[
+				0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+				23, 24, 25, 26, 27, 28, 29, 30, 31,
+			]
+			.to_vec(),
+		)
+		.unwrap();
+		let root = U256::from_dec_str(
+			"1780731860627700044960722568376592200742329637303199754547598369979440671",
+		)
+		.unwrap();
+		assert_ne!(SyncCommitteePoseidons::<Test>::get(2, period), root);
+
+		let ok = Bridge::set_poseidon_hash(RawOrigin::Root.into(), 2, period, poseidon_hash);
+		assert_ok!(ok);
+		assert_eq!(SyncCommitteePoseidons::<Test>::get(2, period), root);
+
+		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeUpdated {
+			domain: 2,
+			period,
+			root,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_poseidon_hash_wrong_hash_length() {
+	new_test_ext().execute_with(|| {
+		let period = 2;
+		let poseidon_hash = BoundedVec::try_from(
+			[
+				0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+				23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+			]
+			.to_vec(),
+		)
+		.unwrap();
+
+		let error = Bridge::set_poseidon_hash(RawOrigin::Root.into(), 2, period, poseidon_hash);
+		assert_err!(error, Error::<Test>::CannotParseOutputData);
+		assert_eq!(SyncCommitteePoseidons::<Test>::get(2, period), U256::zero());
+	});
+}
+
+#[test]
+fn set_poseidon_hash_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let root = BoundedVec::try_from([0, 1, 2, 3, 4].to_vec()).unwrap();
+
+		let ok = Bridge::set_poseidon_hash(origin, 2, 2, root);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn source_chain_froze_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let source_chain_id = 2;
+		let frozen = true;
+		assert_ne!(SourceChainFrozen::<Test>::get(source_chain_id), frozen);
+
+		let ok = Bridge::source_chain_froze(RawOrigin::Root.into(), source_chain_id, frozen);
+		assert_ok!(ok);
+		assert_eq!(SourceChainFrozen::<Test>::get(source_chain_id), frozen);
+
+		let expected_event = RuntimeEvent::Bridge(Event::SourceChainFrozen {
+			source_chain_id,
+			frozen,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn source_chain_froze_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let ok = Bridge::source_chain_froze(origin, 2, true);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn send_message_arbitrary_message_works() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+		let to = ROTATE_FUNCTION_ID;
+		let domain = 2;
+
+		let event = Event::MessageSubmitted {
+			from: TEST_SENDER_VEC.into(),
+			to,
+			message_type: message.r#type(),
+			origin_domain: 1,
+			destination_domain: domain,
+			message_id: tx_uid(1, 0),
+			message_hash: outbound_message_hash(message.clone(), to, domain, tx_uid(1, 0)),
+			leaf_index: 0,
+		};
+		let ok = Bridge::send_message(origin, message, to, domain);
+		assert_ok!(ok);
+		System::assert_last_event(RuntimeEvent::Bridge(event));
+	});
+}
+
+#[test]
+fn send_message_stores_preimage() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+		let to = ROTATE_FUNCTION_ID;
+
+		assert_ok!(Bridge::send_message(origin, message.clone(), to, 2));
+
+		let expected =
+			AddressedMessage::new(message, H256(TEST_SENDER_VEC), to, 1, 2, tx_uid(1, 0));
+		assert_eq!(Bridge::message_preimage(tx_uid(1, 0)), Some(expected));
+		assert_eq!(Bridge::message_preimage(tx_uid(1, 1)), None);
+	});
+}
+
+#[test]
+fn message_preimages_are_pruned_after_retention() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+		assert_ok!(Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2));
+
+		// The preimage is kept for `MessagePreimageRetention` blocks.
+		System::set_block_number(10);
+		Bridge::on_initialize(10);
+		assert!(Bridge::message_preimage(tx_uid(1, 0)).is_some());
+
+		System::set_block_number(11);
+		Bridge::on_initialize(11);
+		assert_eq!(Bridge::message_preimage(tx_uid(1, 0)), None);
+		assert_eq!(MessagePreimages::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn send_message_arbitrary_message_doesnt_accept_empty_data() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from(vec![]));
+
+		let ok = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(ok, Error::<Test>::InvalidBridgeInputs);
+	});
+}
+
+#[test]
+fn send_messages_works() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Currency;
+
+		Balances::make_free_balance_be(&TEST_SENDER_ACCOUNT, 1_000_000);
+		MessageFee::<Test>::set(1_000);
+		let bridge_balance = Balances::balance(&Bridge::account_id());
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let messages = BoundedVec::truncate_from(vec![
+			(
+				Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec())),
+				ROTATE_FUNCTION_ID,
+			),
+			(
+				Message::FungibleToken {
+					asset_id: H256::zero(),
+					amount: 100,
+				},
+				STEP_FUNCTION_ID,
+			),
+		]);
+		let message_ids = vec![tx_uid(1, 0), tx_uid(1, 1 << 16)];
+		let message_hashes = messages
+			.iter()
+			.zip(&message_ids)
+			.map(|((message, to), id)| outbound_message_hash(message.clone(), *to, 2, *id))
+			.collect();
+		let ok = Bridge::send_messages(origin.clone(), messages.clone(), 2);
+		assert_ok!(ok);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessagesSubmitted {
+			from: TEST_SENDER_VEC.into(),
+			origin_domain: 1,
+			destination_domain: 2,
+			message_ids,
+			message_hashes,
+			leaf_index: 0,
+		}));
+		// The message fee is only paid once for the batch.
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), 998_900);
+		assert_eq!(Balances::balance(&Bridge::reward_pot_account_id()), 1_000);
+		assert_eq!(
+			Balances::balance(&Bridge::account_id()),
+			bridge_balance + 100
+		);
+
+		// The next messages of the block follow in the bridge root.
+		let ok = Bridge::send_messages(origin, messages, 2);
+		assert_ok!(ok);
+		assert!(matches!(
+			System::events().last().map(|record| &record.event),
+			Some(RuntimeEvent::Bridge(Event::MessagesSubmitted {
+				leaf_index: 2,
+				..
+			}))
+		));
+	});
+}
+
+#[test]
+fn send_messages_fails_with_empty_batch() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::send_messages(origin, BoundedVec::new(), 2);
+		assert_err!(err, Error::<Test>::EmptySendBatch);
+	});
+}
+
+#[test]
+fn send_messages_fails_with_invalid_message() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let messages = BoundedVec::truncate_from(vec![
+			(
+				Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec())),
+				ROTATE_FUNCTION_ID,
+			),
+			(
+				Message::ArbitraryMessage(BoundedVec::truncate_from(vec![])),
+				ROTATE_FUNCTION_ID,
+			),
+		]);
+		let err = Bridge::send_messages(origin, messages, 2);
+		assert_err!(err, Error::<Test>::InvalidBridgeInputs);
+	});
+}
+
+#[test]
+fn send_message_fungible_token_works() {
+	new_test_ext().execute_with(|| {
+		use crate::BalanceOf;
+		use frame_support::traits::Currency;
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 100,
+		};
+		let to = ROTATE_FUNCTION_ID;
+		let domain = 2;
+
+		Balances::make_free_balance_be(
+			&TEST_SENDER_VEC.into(),
+			BalanceOf::<Test>::max_value() / 2u128,
+		);
+
+		let event = Event::MessageSubmitted {
+			from: TEST_SENDER_VEC.into(),
+			to,
+			message_type: message.r#type(),
+			origin_domain: 1,
+			destination_domain: domain,
+			message_id: tx_uid(1, 0),
+			message_hash: outbound_message_hash(message.clone(), to, domain, tx_uid(1, 0)),
+			leaf_index: 0,
+		};
+		let ok = Bridge::send_message(origin, message, to, domain);
+		assert_ok!(ok);
+		System::assert_last_event(RuntimeEvent::Bridge(event));
+	});
+}
+
+#[test]
+fn send_message_fungible_token_does_not_accept_zero_amount() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 0,
+		};
+		let to = ROTATE_FUNCTION_ID;
+		let domain = 2;
+
+		let err = Bridge::send_message(origin, message, to, domain);
+		assert_err!(err, Error::<Test>::InvalidBridgeInputs);
+	});
+}
+
+#[test]
+fn execute_arbitrary_message_works() {
+	new_test_ext().execute_with(|| {
+		use crate::BalanceOf;
+		use frame_support::traits::Currency;
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		Balances::make_free_balance_be(
+			&TEST_SENDER_VEC.into(),
+			BalanceOf::<Test>::max_value() / 2u128,
+		);
+
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_amb_message();
+		let account_proof = get_valid_amb_account_proof();
+		let storage_proof = get_valid_amb_storage_proof();
+
+		let ok = Bridge::execute(
+			origin,
+			slot,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+		assert_ok!(ok);
+		let encoded_data = message.clone().abi_encode();
+		let message_root = H256(keccak_256(encoded_data.as_slice()));
+
+		let expected_event = RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+			origin_domain: message.origin_domain,
+			destination_domain: message.destination_domain,
+			result: Ok(()),
+		});
+		System::assert_last_event(expected_event);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		)
+	});
+}
+
+#[test]
+fn execute_arbitrary_message_dispatches_to_handler() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_amb_message();
+		let ok = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_ok!(ok);
+
+		let expected = vec![(
+			message.origin_domain,
+			message.from,
+			message.to,
+			"Hello, World!".as_bytes().to_vec(),
+		)];
+		assert_eq!(HandledMessages::get(), expected);
+	});
+}
+
+#[test]
+fn execute_arbitrary_message_reverts_when_handler_fails() {
+	new_test_ext().execute_with(|| {
+		MessageHandlerFails::set(true);
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_amb_message();
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_err!(
+			err,
+			sp_runtime::DispatchError::Other("MessageHandlerFailed")
+		);
+
+		let message_root = H256(keccak_256(message.abi_encode().as_slice()));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::NotExecuted
+		);
+		assert!(HandledMessages::get().is_empty());
+	});
+}
+
+#[test]
+fn test_double_execute_arbitrary_message() {
+	new_test_ext().execute_with(|| {
+		use crate::BalanceOf;
+		use frame_support::traits::Currency;
+
+		let origin1 = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let origin2 = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		Balances::make_free_balance_be(
+			&TEST_SENDER_VEC.into(),
+			BalanceOf::<Test>::max_value() / 2u128,
+		);
+
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_amb_message();
+
+		let account_proof = get_valid_amb_account_proof();
+		let storage_proof = get_valid_amb_storage_proof();
+
+		let ok = Bridge::execute(
+			origin1,
+			slot,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+		assert_ok!(ok);
+
+		let err = Bridge::execute(
+			origin2,
+			slot,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+		assert_err!(err, Error::<Test>::MessageAlreadyExecuted);
+
+		let encoded_data = message.clone().abi_encode();
+		let message_root = H256(keccak_256(encoded_data.as_slice()));
+
+		let expected_event = RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+			origin_domain: message.origin_domain,
+			destination_domain: message.destination_domain,
+			result: Ok(()),
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_function_ids_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_function_ids(RawOrigin::Root.into(), None);
+		assert_ok!(ok);
+		assert_eq!(FunctionIds::<Test>::get(), None);
+
+		let value = Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID));
+		let ok = Bridge::set_function_ids(RawOrigin::Root.into(), value);
+		assert_ok!(ok);
+		assert_eq!(FunctionIds::<Test>::get(), value);
+
+		let expected_event = RuntimeEvent::Bridge(Event::FunctionIdsUpdated { value });
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_function_ids_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_function_ids(origin, None);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn set_step_verification_key_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_step_verification_key(RawOrigin::Root.into(), None);
+		assert_ok!(ok);
+		assert_eq!(StepVerificationKey::<Test>::get(), None);
+
+		let value = Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap());
+		let ok = Bridge::set_step_verification_key(RawOrigin::Root.into(), value.clone());
+		assert_ok!(ok);
+		assert_eq!(StepVerificationKey::<Test>::get(), None);
+
+		System::set_block_number(6);
+		Bridge::on_initialize(6);
+		assert_eq!(StepVerificationKey::<Test>::get(), value.clone());
+
+		let expected_event = RuntimeEvent::Bridge(Event::StepVerificationKeyUpdated { value });
+		System::assert_has_event(expected_event);
+	});
+}
+
+#[test]
+fn set_step_verification_key_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_step_verification_key(origin, None);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn set_rotate_verification_key_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_rotate_verification_key(RawOrigin::Root.into(), None);
+		assert_ok!(ok);
+		assert_eq!(RotateVerificationKey::<Test>::get(), None);
+
+		let value = Some(BoundedVec::try_from(ROTATE_VK.as_bytes().to_vec()).unwrap());
+		let ok = Bridge::set_rotate_verification_key(RawOrigin::Root.into(), value.clone());
+		assert_ok!(ok);
+		assert_eq!(RotateVerificationKey::<Test>::get(), None);
+
+		System::set_block_number(6);
+		Bridge::on_initialize(6);
+		assert_eq!(RotateVerificationKey::<Test>::get(), value.clone());
+
+		let expected_event = RuntimeEvent::Bridge(Event::RotateVerificationKeyUpdated { value });
+		System::assert_has_event(expected_event);
+	});
+}
+
+#[test]
+fn set_rotate_verification_key_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_rotate_verification_key(origin, None);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn update_updater() {
+	new_test_ext().execute_with(|| {
+		let old_updater = H256(TEST_SENDER_VEC);
+		Updater::<Test>::set(old_updater);
+
+		let new_updater = H256([2u8; 32]);
+		let ok = Bridge::set_updater(RawOrigin::Root.into(), new_updater);
+		assert_ok!(ok);
+
+		let expected_event = RuntimeEvent::Bridge(Event::NewUpdater {
+			old: old_updater,
+			new: new_updater,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn update_updater_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let old_updater = H256(TEST_SENDER_VEC);
+		Updater::<Test>::set(old_updater);
+		let new_updater = H256([2u8; 32]);
+
+		let err = Bridge::set_updater(origin, new_updater);
+		assert_err!(err, BadOrigin);
+		assert_eq!(old_updater, Updater::<Test>::get());
+	});
+}
+
+#[test]
+fn add_operator_works() {
+	new_test_ext().execute_with(|| {
+		let operator = H256([2u8; 32]);
+		let ok = Bridge::add_operator(RawOrigin::Root.into(), operator);
+		assert_ok!(ok);
+		assert_eq!(Operators::<Test>::get().to_vec(), vec![operator]);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::OperatorAdded { operator }));
+	});
+}
+
+#[test]
+fn add_operator_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::add_operator(origin, H256([2u8; 32]));
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn add_operator_fails_if_already_operator() {
+	new_test_ext().execute_with(|| {
+		let operator = H256([2u8; 32]);
+		assert_ok!(Bridge::add_operator(RawOrigin::Root.into(), operator));
+
+		let err = Bridge::add_operator(RawOrigin::Root.into(), operator);
+		assert_err!(err, Error::<Test>::AlreadyOperator);
+	});
+}
+
+#[test]
+fn remove_operator_works() {
+	new_test_ext().execute_with(|| {
+		let operator = H256([2u8; 32]);
+		Operators::<Test>::put(BoundedVec::truncate_from(vec![operator]));
+
+		let ok = Bridge::remove_operator(RawOrigin::Root.into(), operator);
+		assert_ok!(ok);
+		assert!(Operators::<Test>::get().is_empty());
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::OperatorRemoved { operator }));
+	});
+}
+
+#[test]
+fn remove_operator_fails_if_not_operator() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::remove_operator(RawOrigin::Root.into(), H256([2u8; 32]));
+		assert_err!(err, Error::<Test>::NotOperator);
+	});
+}
+
+#[test]
+fn rotate_operator_works() {
+	new_test_ext().execute_with(|| {
+		let old = H256([2u8; 32]);
+		let new = H256([3u8; 32]);
+		Operators::<Test>::put(BoundedVec::truncate_from(vec![old]));
+
+		let ok = Bridge::rotate_operator(RawOrigin::Root.into(), old, new);
+		assert_ok!(ok);
+		assert_eq!(Operators::<Test>::get().to_vec(), vec![new]);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::OperatorRotated { old, new }));
+	});
+}
+
+#[test]
+fn rotate_operator_fails_if_not_operator() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::rotate_operator(RawOrigin::Root.into(), H256([2u8; 32]), H256([3u8; 32]));
+		assert_err!(err, Error::<Test>::NotOperator);
+	});
+}
+
+#[test]
+fn set_fulfill_threshold_works_with_root() {
+	new_test_ext().execute_with(|| {
+		Operators::<Test>::put(BoundedVec::truncate_from(vec![H256([2u8; 32])]));
+
+		let ok = Bridge::set_fulfill_threshold(RawOrigin::Root.into(), 2);
+		assert_ok!(ok);
+		assert_eq!(FulfillThreshold::<Test>::get(), 2);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::FulfillThresholdUpdated {
+			threshold: 2,
+		}));
+	});
+}
+
+#[test]
+fn set_fulfill_threshold_fails_if_too_high() {
+	new_test_ext().execute_with(|| {
+		Operators::<Test>::put(BoundedVec::truncate_from(vec![H256([2u8; 32])]));
+
+		let err = Bridge::set_fulfill_threshold(RawOrigin::Root.into(), 3);
+		assert_err!(err, Error::<Test>::FulfillThresholdTooHigh);
+	});
+}
+
+fn fulfill_valid_step(who: AccountId32) -> DispatchResultWithPostInfo {
+	SyncCommitteePoseidons::<Test>::insert(
+		2,
+		931,
+		U256::from(hex!(
+			"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+		)),
+	);
+	ConfigurationStorage::<Test>::insert(
+		2,
+		Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		},
+	);
+
+	Bridge::fulfill_call(
+		RuntimeOrigin::signed(who),
+		2,
+		STEP_FUNCTION_ID,
+		get_valid_step_input(),
+		get_valid_step_output(),
+		get_valid_step_proof(),
+		7634942,
+	)
+}
+
+#[test]
+fn fulfill_call_works_with_operator() {
+	new_test_ext().execute_with(|| {
+		Operators::<Test>::put(BoundedVec::truncate_from(vec![H256(TEST_SENDER_VEC)]));
+
+		assert_ok!(fulfill_valid_step(TEST_SENDER_ACCOUNT));
+		assert_eq!(Head::<Test>::get(2), 7634848);
+	});
+}
+
+#[test]
+fn fulfill_call_waits_for_threshold() {
+	new_test_ext().execute_with(|| {
+		let operator = AccountId32::new([2u8; 32]);
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Operators::<Test>::put(BoundedVec::truncate_from(vec![H256([2u8; 32])]));
+		FulfillThreshold::<Test>::put(2);
+
+		assert_ok!(fulfill_valid_step(TEST_SENDER_ACCOUNT));
+		assert_eq!(Head::<Test>::get(2), 0);
+		assert!(matches!(
+			System::events().last().map(|record| &record.event),
+			Some(RuntimeEvent::Bridge(Event::FulfillApproved {
+				approvals: 1,
+				..
+			}))
+		));
+
+		// The same operator cannot approve twice.
+		let err = fulfill_valid_step(TEST_SENDER_ACCOUNT);
+		assert_err!(err, Error::<Test>::AlreadyApproved);
+
+		assert_ok!(fulfill_valid_step(operator));
+		assert_eq!(Head::<Test>::get(2), 7634848);
+	});
+}
+
+fn wait_for_threshold() -> AccountId32 {
+	Operators::<Test>::put(BoundedVec::truncate_from(vec![H256([2u8; 32])]));
+	FulfillThreshold::<Test>::put(2);
+	AccountId32::new([2u8; 32])
+}
+
+#[test]
+fn fulfill_rotate_call_waits_for_threshold() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_rotate(slot);
+		let operator = wait_for_threshold();
+		let fulfill = |who| {
+			Bridge::fulfill_rotate_call(
+				RuntimeOrigin::signed(who),
+				2,
+				get_valid_rotate_input(),
+				get_valid_rotate_output(),
+				get_valid_rotate_proof(),
+				slot,
+				invalid_sync_committee_branch(),
+			)
+		};
+
+		// The call is only checked once the threshold of operators approved it.
+		assert_ok!(fulfill(TEST_SENDER_ACCOUNT));
+		assert!(matches!(
+			System::events().last().map(|record| &record.event),
+			Some(RuntimeEvent::Bridge(Event::FulfillApproved {
+				approvals: 1,
+				..
+			}))
+		));
+		assert_err!(fulfill(operator), Error::<Test>::ConsensusForkNotSet);
+	});
+}
+
+#[test]
+fn fulfill_step_call_waits_for_threshold() {
+	new_test_ext().execute_with(|| {
+		let slot = 7634942;
+		setup_step();
+		let operator = wait_for_threshold();
+		let fulfill = |who| {
+			Bridge::fulfill_step_call(
+				RuntimeOrigin::signed(who),
+				2,
+				get_valid_step_input(),
+				get_valid_step_output(),
+				get_valid_step_proof(),
+				slot,
+				invalid_execution_state_root_branch([1, 2, 3, 4]),
+			)
+		};
+
+		assert_ok!(fulfill(TEST_SENDER_ACCOUNT));
+		assert_err!(fulfill(TEST_SENDER_ACCOUNT), Error::<Test>::AlreadyApproved);
+		assert_err!(fulfill(operator), Error::<Test>::UnknownForkDigest);
+	});
+}
+
+#[test]
+fn fulfill_finality_waits_for_threshold() {
+	new_test_ext().execute_with(|| {
+		setup_zk_finality();
+		let operator = wait_for_threshold();
+		let fulfill = |who| {
+			Bridge::fulfill_finality(
+				RuntimeOrigin::signed(who),
+				2,
+				get_valid_step_output(),
+				get_valid_step_proof(),
+			)
+		};
+
+		assert_ok!(fulfill(TEST_SENDER_ACCOUNT));
+		assert_eq!(Head::<Test>::get(2), 7634848);
+		assert_err!(fulfill(operator), Error::<Test>::VerificationKeyIsNotSet);
+	});
+}
+
+#[test]
+fn set_prover_bond_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_prover_bond(RawOrigin::Root.into(), 1_000);
+		assert_ok!(ok);
+		assert_eq!(ProverBond::<Test>::get(), 1_000);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ProverBondUpdated {
+			value: 1_000,
+		}));
+	});
+}
+
+#[test]
+fn set_prover_bond_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_prover_bond(origin, 1_000);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+fn bond_test_sender() {
+	use frame_support::traits::Currency;
+	Balances::make_free_balance_be(&TEST_SENDER_ACCOUNT, 5_000);
+	ProverBond::<Test>::put(1_000);
+	assert_ok!(Bridge::bond(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT)));
+}
+
+#[test]
+fn bond_works() {
+	new_test_ext().execute_with(|| {
+		bond_test_sender();
+
+		assert_eq!(Bonds::<Test>::get(TEST_SENDER_ACCOUNT), 1_000);
+		assert_eq!(Balances::usable_balance(&TEST_SENDER_ACCOUNT), 4_000);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::Bonded {
+			who: TEST_SENDER_ACCOUNT,
+			amount: 1_000,
+		}));
+	});
+}
+
+#[test]
+fn bond_fails_if_already_bonded() {
+	new_test_ext().execute_with(|| {
+		bond_test_sender();
+
+		let err = Bridge::bond(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT));
+		assert_err!(err, Error::<Test>::AlreadyBonded);
+	});
+}
+
+#[test]
+fn fulfill_call_fails_without_bond() {
+	new_test_ext().execute_with(|| {
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		ProverBond::<Test>::put(1_000);
+
+		let err = fulfill_valid_step(TEST_SENDER_ACCOUNT);
+		assert_err!(err, Error::<Test>::NotBonded);
+
+		bond_test_sender();
+		assert_ok!(fulfill_valid_step(TEST_SENDER_ACCOUNT));
+		assert_eq!(
+			SlotSubmitters::<Test>::get(2, 7634848),
+			Some(TEST_SENDER_ACCOUNT)
+		);
+	});
+}
+
+#[test]
+fn unbond_works() {
+	new_test_ext().execute_with(|| {
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		bond_test_sender();
+
+		assert_ok!(Bridge::unbond(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT)));
+		assert_eq!(UnbondingUntil::<Test>::get(TEST_SENDER_ACCOUNT), Some(11));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::Unbonding {
+			who: TEST_SENDER_ACCOUNT,
+			until: 11,
+		}));
+
+		// An unbonding prover cannot submit updates anymore.
+		let err = fulfill_valid_step(TEST_SENDER_ACCOUNT);
+		assert_err!(err, Error::<Test>::NotBonded);
+	});
+}
+
+#[test]
+fn withdraw_bond_works() {
+	new_test_ext().execute_with(|| {
+		bond_test_sender();
+		assert_ok!(Bridge::unbond(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT)));
+
+		System::set_block_number(12);
+		assert_ok!(Bridge::withdraw_bond(RuntimeOrigin::signed(
+			TEST_SENDER_ACCOUNT
+		)));
+		assert_eq!(Bonds::<Test>::get(TEST_SENDER_ACCOUNT), 0);
+		assert_eq!(Balances::usable_balance(&TEST_SENDER_ACCOUNT), 5_000);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::BondWithdrawn {
+			who: TEST_SENDER_ACCOUNT,
+			amount: 1_000,
+		}));
+	});
+}
+
+#[test]
+fn withdraw_bond_fails_while_unbonding() {
+	new_test_ext().execute_with(|| {
+		bond_test_sender();
+
+		let err = Bridge::withdraw_bond(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT));
+		assert_err!(err, Error::<Test>::NotUnbonding);
+
+		assert_ok!(Bridge::unbond(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT)));
+		System::set_block_number(11);
+		let err = Bridge::withdraw_bond(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT));
+		assert_err!(err, Error::<Test>::BondStillUnbonding);
+	});
+}
+
+#[test]
+fn slash_submission_works() {
+	new_test_ext().execute_with(|| {
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		bond_test_sender();
+		assert_ok!(fulfill_valid_step(TEST_SENDER_ACCOUNT));
+
+		let pot_before = Balances::balance(&Bridge::reward_pot_account_id());
+		let ok = Bridge::slash_submission(RawOrigin::Root.into(), 2, 7634848);
+		assert_ok!(ok);
+
+		assert_eq!(Bonds::<Test>::get(TEST_SENDER_ACCOUNT), 0);
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), 4_000);
+		assert_eq!(
+			Balances::balance(&Bridge::reward_pot_account_id()),
+			pot_before + 1_000
+		);
+		assert_eq!(SlotSubmitters::<Test>::get(2, 7634848), None);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ProverSlashed {
+			who: TEST_SENDER_ACCOUNT,
+			domain: 2,
+			slot: 7634848,
+			amount: 1_000,
+		}));
+	});
+}
+
+#[test]
+fn slash_submission_fails_without_submitter() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::slash_submission(RawOrigin::Root.into(), 2, 7634848);
+		assert_err!(err, Error::<Test>::SubmissionNotFound);
+	});
+}
+
+#[test]
+fn set_broadcaster_code_hash_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let domain = 2;
+		let code_hash = Some(H256([1u8; 32]));
+		assert_eq!(BroadcasterCodeHashes::<Test>::get(domain), None);
+
+		let ok = Bridge::set_broadcaster_code_hash(RawOrigin::Root.into(), domain, code_hash);
+		assert_ok!(ok);
+		assert_eq!(BroadcasterCodeHashes::<Test>::get(domain), code_hash);
+
+		let expected_event =
+			RuntimeEvent::Bridge(Event::BroadcasterCodeHashUpdated { domain, code_hash });
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_broadcaster_code_hash_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_broadcaster_code_hash(origin, 2, Some(H256([1u8; 32])));
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn test_execute_fungible_token_with_approved_code_hash() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		BroadcasterCodeHashes::<Test>::insert(
+			2,
+			H256(hex!(
+				"54ccfa149cf4cf471e53bc5dfbfe8ae5c27fa96b0599f2747ffe6e1fb8bd4396"
+			)),
+		);
+
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_message(),
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		);
+		assert_ok!(result);
+	});
+}
+
+#[test]
+fn test_execute_fungible_token_with_code_hash_mismatch() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		BroadcasterCodeHashes::<Test>::insert(2, H256([1u8; 32]));
+
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		);
+		assert_err!(result, Error::<Test>::BroadcasterCodeHashMismatch);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::NotExecuted
+		);
+	});
+}
+
+#[test]
+fn execute_batch_works() {
+	new_test_ext().execute_with(|| {
+		let balance_before = Balances::balance(&Bridge::account_id());
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		let messages = ExecuteBatch::truncate_from(vec![(message, get_valid_storage_proof())]);
+
+		let ok = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			messages,
+			get_valid_account_proof(),
+		);
+		assert_ok!(ok);
+
+		assert_eq!(
+			balance_before.saturating_sub(1000000000000000000u128),
+			Balances::balance(&Bridge::account_id())
+		);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+	});
+}
+
+#[test]
+fn execute_batch_fails_with_duplicated_message() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_message();
+		let messages = ExecuteBatch::truncate_from(vec![
+			(message.clone(), get_valid_storage_proof()),
+			(message, get_valid_storage_proof()),
+		]);
+
+		let err = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			messages,
+			get_valid_account_proof(),
+		);
+		assert_err!(err, Error::<Test>::MessageAlreadyExecuted);
+	});
+}
+
+#[test]
+fn execute_batch_fails_with_empty_batch() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8581263,
+			ExecuteBatch::default(),
+			get_valid_account_proof(),
+		);
+		assert_err!(err, Error::<Test>::EmptyBatch);
+	});
+}
+
+#[test]
+fn execute_batch_fails_with_mixed_origin_domains() {
+	new_test_ext().execute_with(|| {
+		let message = get_valid_message();
+		let mut other_message = get_valid_message();
+		other_message.origin_domain = 3;
+		let messages = ExecuteBatch::truncate_from(vec![
+			(message, get_valid_storage_proof()),
+			(other_message, get_valid_storage_proof()),
+		]);
+
+		let err = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8581263,
+			messages,
+			get_valid_account_proof(),
+		);
+		assert_err!(err, Error::<Test>::BatchOriginDomainMismatch);
+	});
+}
+
+fn setup_expirable_amb_message(slot: u64, ttl: u64, elapsed: u64) {
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+		)),
+	);
+	ExecutionStateRoots::<Test>::set(
+		2,
+		slot,
+		H256(hex!(
+			"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+		)),
+	);
+
+	let genesis_time = 1606824023;
+	GenesisTimestamp::<Test>::set(genesis_time);
+	SecondsPerSlot::<Test>::set(12);
+	MessageTtl::<Test>::set(Some(ttl));
+
+	let slot_timestamp = genesis_time + slot * 12;
+	Timestamp::set_timestamp((slot_timestamp + elapsed) * 1000);
+}
+
+#[test]
+fn set_message_ttl_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let value = Some(86400);
+		let ok = Bridge::set_message_ttl(RawOrigin::Root.into(), value);
+		assert_ok!(ok);
+		assert_eq!(MessageTtl::<Test>::get(), value);
+
+		let expected_event = RuntimeEvent::Bridge(Event::MessageTtlUpdated { value });
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_message_ttl_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_message_ttl(origin, Some(86400));
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_fails_with_expired_message() {
+	new_test_ext().execute_with(|| {
+		let slot = 5085118;
+		setup_expirable_amb_message(slot, 86400, 86400);
+
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_amb_message(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::MessageExpired);
+	});
+}
+
+#[test]
+fn execute_works_with_message_within_ttl() {
+	new_test_ext().execute_with(|| {
+		let slot = 5085118;
+		setup_expirable_amb_message(slot, 86400, 86399);
+
+		let ok = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_amb_message(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_ok!(ok);
+	});
+}
+
+#[test]
+fn execute_expires_with_slot_timing_of_origin_domain() {
+	new_test_ext().execute_with(|| {
+		let slot = 5085118;
+		setup_expirable_amb_message(slot, 86400, 86399);
+		// The origin domain has slots half as long, so the slot is older than on the broadcaster
+		// domain.
+		SlotTimings::<Test>::insert(
+			2,
+			SlotTiming {
+				genesis_time: 1606824023,
+				seconds_per_slot: 6,
+			},
+		);
+
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_amb_message(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::MessageExpired);
+	});
+}
+
+#[test]
+fn mark_message_refundable_works() {
+	new_test_ext().execute_with(|| {
+		let slot = 5085118;
+		setup_expirable_amb_message(slot, 86400, 86400);
+
+		let message = get_valid_amb_message();
+		let ok = Bridge::mark_message_refundable(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_ok!(ok);
+
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::Refundable
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::MessageRefundable {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+		});
+		System::assert_last_event(expected_event);
+
+		// Refundable messages can never be executed, even if the TTL is lifted.
+		MessageTtl::<Test>::set(None);
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::MessageAlreadyExecuted);
+	});
+}
+
+#[test]
+fn mark_message_refundable_fails_when_not_expired() {
+	new_test_ext().execute_with(|| {
+		let slot = 5085118;
+		setup_expirable_amb_message(slot, 86400, 0);
+
+		let err = Bridge::mark_message_refundable(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_amb_message(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::MessageNotExpired);
+	});
+}
+
+#[test]
+fn pause_domain_works() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::pause_domain(RawOrigin::Root.into(), 2);
+		assert_ok!(ok);
+		assert!(PausedDomains::<Test>::get(2));
+
+		let expected_event = RuntimeEvent::Bridge(Event::DomainPaused { domain: 2 });
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn pause_domain_does_not_work_with_non_pause_origin() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::pause_domain(origin.clone(), 2);
+		assert_err!(err, BadOrigin);
+
+		let err = Bridge::unpause_domain(origin, 2);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn unpause_domain_works() {
+	new_test_ext().execute_with(|| {
+		PausedDomains::<Test>::set(2, true);
+
+		let ok = Bridge::unpause_domain(RawOrigin::Root.into(), 2);
+		assert_ok!(ok);
+		assert!(!PausedDomains::<Test>::get(2));
+
+		let expected_event = RuntimeEvent::Bridge(Event::DomainUnpaused { domain: 2 });
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_confirmation_depth_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_confirmation_depth(RawOrigin::Root.into(), 2, 64);
+		assert_ok!(ok);
+		assert_eq!(ConfirmationDepths::<Test>::get(2), 64);
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ConfirmationDepthUpdated {
+			domain: 2,
+			depth: 64,
+		}));
+	});
+}
+
+#[test]
+fn set_confirmation_depth_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_confirmation_depth(origin, 2, 64);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_fails_with_unconfirmed_slot() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		ConfirmationDepths::<Test>::insert(2, 64);
+		Head::<Test>::insert(2, slot + 63);
+
+		let err = execute_valid_message(slot);
+		assert_err!(err, Error::<Test>::SlotNotConfirmed);
+
+		Head::<Test>::insert(2, slot + 64);
+		assert_ok!(execute_valid_message(slot));
+	});
+}
+
+#[test]
+fn set_ordered_delivery_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let sender = H256::repeat_byte(1);
+		let ok = Bridge::set_ordered_delivery(RawOrigin::Root.into(), 2, sender, Some(3));
+		assert_ok!(ok);
+		assert_eq!(OrderedDelivery::<Test>::get(2, sender), Some(3));
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::OrderedDeliveryUpdated {
+			domain: 2,
+			sender,
+			next_id: Some(3),
+		}));
+
+		let ok = Bridge::set_ordered_delivery(RawOrigin::Root.into(), 2, sender, None);
+		assert_ok!(ok);
+		assert_eq!(OrderedDelivery::<Test>::get(2, sender), None);
+	});
+}
+
+#[test]
+fn set_ordered_delivery_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_ordered_delivery(origin, 2, H256::repeat_byte(1), Some(3));
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_queues_out_of_order_message() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let mut message = get_valid_message();
+		message.id = 1;
+		OrderedDelivery::<Test>::insert(2, message.from, 0);
+		let proof = setup_receipt_proof(slot, &message_sent_topics(&message));
+		let message_root = message_sent_topics(&message)[2];
+
+		let ok = Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			proof,
+		);
+		assert_ok!(ok);
+
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionFailed(ExecutionFailureReason::OutOfOrder)
+		);
+		assert_eq!(
+			QueuedMessages::<Test>::get((2, message.from, 1)),
+			Some(message_root)
+		);
+		assert_eq!(OrderedDelivery::<Test>::get(2, message.from), Some(0));
+
+		// The queued message stays queued until the preceding message is executed.
+		let err = Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message);
+		assert_err!(err, Error::<Test>::ExecutionStillFailing);
+	});
+}
+
+#[test]
+fn retry_execute_works_with_queued_message() {
+	new_test_ext().execute_with(|| {
+		let first = get_valid_message();
+		let mut second = get_valid_message();
+		second.id = 1;
+		OrderedDelivery::<Test>::insert(2, first.from, 0);
+
+		let proof = setup_receipt_proof(8581263, &message_sent_topics(&second));
+		let ok = Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8581263,
+			second.clone(),
+			proof,
+		);
+		assert_ok!(ok);
+
+		let proof = setup_receipt_proof(8581264, &message_sent_topics(&first));
+		let ok = Bridge::execute_with_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8581264,
+			first,
+			proof,
+		);
+		assert_ok!(ok);
+		assert_eq!(OrderedDelivery::<Test>::get(2, second.from), Some(1));
+
+		let ok = Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), second.clone());
+		assert_ok!(ok);
+
+		assert_eq!(
+			MessageStatus::<Test>::get(message_sent_topics(&second)[2]),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		assert_eq!(QueuedMessages::<Test>::get((2, second.from, 1)), None);
+		assert_eq!(OrderedDelivery::<Test>::get(2, second.from), Some(2));
+	});
+}
+
+#[test]
+fn emergency_pause_works() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::emergency_pause(RawOrigin::Root.into(), 10);
+		assert_ok!(ok);
+		assert_eq!(EmergencyPauseExpiry::<Test>::get(), Some(11));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::EmergencyPaused { until: 11 }));
+
+		let err = execute_valid_message(8581263);
+		assert_err!(err, Error::<Test>::EmergencyPaused);
+
+		let message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 100,
+		};
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(err, Error::<Test>::EmergencyPaused);
+	});
+}
+
+#[test]
+fn emergency_pause_does_not_work_with_non_pause_origin() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::emergency_pause(origin.clone(), 10);
+		assert_err!(err, BadOrigin);
+
+		let err = Bridge::emergency_unpause(origin);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn emergency_pause_fails_if_too_long() {
+	new_test_ext().execute_with(|| {
+		let max = <Test as crate::Config>::MaxEmergencyPauseDuration::get();
+		let err = Bridge::emergency_pause(RawOrigin::Root.into(), max + 1);
+		assert_err!(err, Error::<Test>::EmergencyPauseTooLong);
+	});
+}
+
+#[test]
+fn emergency_pause_expires() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Hooks;
+
+		assert_ok!(Bridge::emergency_pause(RawOrigin::Root.into(), 10));
+
+		System::set_block_number(10);
+		Bridge::on_initialize(10);
+		assert_eq!(EmergencyPauseExpiry::<Test>::get(), Some(11));
+
+		System::set_block_number(11);
+		Bridge::on_initialize(11);
+		assert_eq!(EmergencyPauseExpiry::<Test>::get(), None);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::EmergencyPauseExpired));
+
+		assert_ok!(execute_valid_message(8581263));
+	});
+}
+
+#[test]
+fn emergency_unpause_works() {
+	new_test_ext().execute_with(|| {
+		EmergencyPauseExpiry::<Test>::put(100);
+
+		let ok = Bridge::emergency_unpause(RawOrigin::Root.into());
+		assert_ok!(ok);
+		assert_eq!(EmergencyPauseExpiry::<Test>::get(), None);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::EmergencyUnpaused));
+
+		assert_ok!(execute_valid_message(8581263));
+	});
+}
+
+#[test]
+fn execute_fails_with_paused_domain() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		PausedDomains::<Test>::set(2, true);
+
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_amb_message(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::DomainPaused);
+
+		// Messages can be executed again once the domain is unpaused.
+		PausedDomains::<Test>::remove(2);
+		let ok = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_amb_message(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_ok!(ok);
+	});
+}
+
+#[test]
+fn send_message_fails_with_paused_domain() {
+	new_test_ext().execute_with(|| {
+		PausedDomains::<Test>::set(2, true);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(err, Error::<Test>::DomainPaused);
+	});
+}
+
+#[test]
+fn send_message_increases_message_volume() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+
+		let ok = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_ok!(ok);
+		assert_eq!(MessageVolume::<Test>::get(2), 1);
+	});
+}
+
+#[test]
+fn fee_multiplier_rises_with_congestion_and_decays() {
+	new_test_ext().execute_with(|| {
+		use crate::weight_helper::fee_modifier;
+		use frame_support::traits::Hooks;
+
+		let target = <Test as crate::Config>::MessageVolumeTarget::get();
+		assert_eq!(fee_modifier::<Test>(2).weight_fee_multiplier, None);
+
+		MessageVolume::<Test>::insert(2, target + 1);
+		Bridge::on_initialize(2);
+		assert_eq!(FeeMultipliers::<Test>::get(2), Some(2));
+		assert_eq!(MessageVolume::<Test>::get(2), 0);
+
+		MessageVolume::<Test>::insert(2, target + 1);
+		Bridge::on_initialize(3);
+		assert_eq!(FeeMultipliers::<Test>::get(2), Some(3));
+		assert_eq!(fee_modifier::<Test>(2).weight_fee_multiplier, Some(3));
+
+		// Volume at the target does not count as congestion.
+		MessageVolume::<Test>::insert(2, target);
+		Bridge::on_initialize(4);
+		assert_eq!(FeeMultipliers::<Test>::get(2), Some(2));
+
+		Bridge::on_initialize(5);
+		assert_eq!(FeeMultipliers::<Test>::get(2), None);
+		assert_eq!(fee_modifier::<Test>(2).weight_fee_multiplier, None);
+	});
+}
+
+#[test]
+fn fee_multiplier_is_capped() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Hooks;
+
+		let target = <Test as crate::Config>::MessageVolumeTarget::get();
+		let max = <Test as crate::Config>::MaxFeeMultiplier::get();
+		FeeMultipliers::<Test>::insert(2, max);
+
+		MessageVolume::<Test>::insert(2, target + 1);
+		Bridge::on_initialize(2);
+		assert_eq!(FeeMultipliers::<Test>::get(2), Some(max));
+	});
+}
+
+#[test]
+fn set_relayer_reward_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let value = RelayerReward {
+			flat: 1_000,
+			per_proof_byte: 1,
+		};
+		let ok = Bridge::set_relayer_reward(RawOrigin::Root.into(), value);
+		assert_ok!(ok);
+		assert_eq!(RelayerRewards::<Test>::get(), value);
+
+		let expected_event = RuntimeEvent::Bridge(Event::RelayerRewardUpdated { value });
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_relayer_reward_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_relayer_reward(origin, RelayerReward::default());
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn set_message_fee_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_message_fee(RawOrigin::Root.into(), 1_000);
+		assert_ok!(ok);
+		assert_eq!(MessageFee::<Test>::get(), 1_000);
+
+		let expected_event = RuntimeEvent::Bridge(Event::MessageFeeUpdated { value: 1_000 });
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_message_fee_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let ok = Bridge::set_message_fee(origin, 1_000);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn send_message_charges_message_fee() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Currency;
+
+		Balances::make_free_balance_be(&TEST_SENDER_ACCOUNT, 1_000_000);
+		MessageFee::<Test>::set(1_000);
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+		let ok = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_ok!(ok);
+
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), 999_000);
+		assert_eq!(Balances::balance(&Bridge::reward_pot_account_id()), 1_000);
+	});
+}
+
+#[test]
+fn send_message_charges_arbitrary_message_by_length() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Currency;
+
+		Balances::make_free_balance_be(&TEST_SENDER_ACCOUNT, 1_000_000);
+		MessageFee::<Test>::set(1_000);
+		ArbitraryMessageByteFee::set(100);
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+		let ok = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_ok!(ok);
+
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), 998_600);
+		assert_eq!(Balances::balance(&Bridge::reward_pot_account_id()), 1_400);
+	});
+}
+
+#[test]
+fn send_messages_charges_arbitrary_messages_by_length() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Currency;
+
+		Balances::make_free_balance_be(&TEST_SENDER_ACCOUNT, 1_000_000);
+		MessageFee::<Test>::set(1_000);
+		ArbitraryMessageByteFee::set(100);
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let messages = BoundedVec::truncate_from(vec![
+			(
+				Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec())),
+				ROTATE_FUNCTION_ID,
+			),
+			(
+				Message::ArbitraryMessage(BoundedVec::truncate_from([4, 5].to_vec())),
+				ROTATE_FUNCTION_ID,
+			),
+		]);
+		let ok = Bridge::send_messages(origin, messages, 2);
+		assert_ok!(ok);
+
+		assert_eq!(Bridge::message_fee(6), 1_600);
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), 998_400);
+		assert_eq!(Balances::balance(&Bridge::reward_pot_account_id()), 1_600);
+	});
+}
+
+#[test]
+fn execute_rewards_relayer() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Currency;
+
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		RelayerRewards::<Test>::set(RelayerReward {
+			flat: 1_000,
+			per_proof_byte: 1,
 		});
-		// set current wrong header for valid rotate call
-		Headers::<Test>::set(
+		Balances::make_free_balance_be(&Bridge::reward_pot_account_id(), 1_000_000);
+
+		let account_proof = get_valid_amb_account_proof();
+		let storage_proof = get_valid_amb_storage_proof();
+		let proof_bytes: u128 = account_proof
+			.iter()
+			.chain(storage_proof.iter())
+			.map(|node| node.len() as u128)
+			.sum();
+
+		let ok = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_amb_message(),
+			account_proof,
+			storage_proof,
+		);
+		assert_ok!(ok);
+
+		let amount = 1_000 + proof_bytes;
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), amount);
+		let expected_event = RuntimeEvent::Bridge(Event::RelayerRewarded {
+			relayer: TEST_SENDER_ACCOUNT,
+			amount,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn execute_reward_is_capped_by_reward_pot() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Currency;
+
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			2,
 			slot,
 			H256(hex!(
-				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57855"
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
 			)),
 		);
+		RelayerRewards::<Test>::set(RelayerReward {
+			flat: 1_000_000,
+			per_proof_byte: 0,
+		});
+		Balances::make_free_balance_be(&Bridge::reward_pot_account_id(), 500);
 
-		let result = Bridge::fulfill_call(
+		let ok = Bridge::execute(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			ROTATE_FUNCTION_ID,
-			get_valid_rotate_input(),
-			get_valid_rotate_output(),
-			get_valid_rotate_proof(),
 			slot,
+			get_valid_amb_message(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
 		);
+		assert_ok!(ok);
 
-		assert_err!(result, Error::<Test>::RotateVerificationError);
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), 500);
+		assert_eq!(Balances::balance(&Bridge::reward_pot_account_id()), 0);
 	});
 }
 
 #[test]
-fn test_fulfill_call_function_ids_not_set() {
+fn migrate_to_v1_moves_light_client_state_to_domain() {
 	new_test_ext().execute_with(|| {
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		use crate::migrations::v1::MigrateToV1;
+		use codec::Encode;
+		use frame_support::{
+			storage::unhashed,
+			traits::{ConstU32, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		};
+		use sp_io::hashing::twox_128;
+
+		fn v0_key(item: &[u8], slot: Option<u64>) -> Vec<u8> {
+			let mut key = twox_128(b"Bridge").to_vec();
+			key.extend(twox_128(item));
+			if let Some(slot) = slot {
+				key.extend(slot.encode());
+			}
+			key
+		}
+
+		let _ = ConfigurationStorage::<Test>::clear(u32::MAX, None);
+		let _ = SyncCommitteePoseidons::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<Bridge>();
+
+		let slot = 8581263u64;
+		let root = H256([1u8; 32]);
+		let config = Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		};
+		unhashed::put(&v0_key(b"Head", None), &slot);
+		unhashed::put(&v0_key(b"Headers", Some(slot)), &root);
+		unhashed::put(&v0_key(b"ExecutionStateRoots", Some(slot)), &root);
+		unhashed::put(&v0_key(b"Timestamps", Some(slot)), &1u64);
+		unhashed::put(
+			&v0_key(b"SyncCommitteePoseidons", Some(1047)),
+			&U256::from(7u64),
+		);
+		unhashed::put(&v0_key(b"ConfigurationStorage", None), &config);
 
-		Bridge::set_function_ids(RawOrigin::Root.into(), None).unwrap();
-		let slot = 7634942;
-		let err = Bridge::fulfill_call(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			STEP_FUNCTION_ID,
-			get_valid_step_input(),
-			get_valid_step_output(),
-			get_valid_step_proof(),
-			slot,
+		MigrateToV1::<Test, ConstU32<2>>::on_runtime_upgrade();
+
+		assert_eq!(Head::<Test>::get(2), slot);
+		assert_eq!(Headers::<Test>::get(2, slot), root);
+		assert_eq!(ExecutionStateRoots::<Test>::get(2, slot), root);
+		assert_eq!(crate::Timestamps::<Test>::get(2, slot), 1);
+		assert_eq!(
+			SyncCommitteePoseidons::<Test>::get(2, 1047),
+			U256::from(7u64)
 		);
-		assert_err!(err, Error::<Test>::FunctionIdsAreNotSet);
+		assert_eq!(ConfigurationStorage::<Test>::get(2), config);
+		assert_eq!(Bridge::on_chain_storage_version(), 1);
 	});
 }
 
 #[test]
-fn test_fulfill_step_call_verification_key_is_not_set() {
+fn migrate_to_v1_is_skipped_above_its_bound() {
 	new_test_ext().execute_with(|| {
-		Bridge::set_step_verification_key(RawOrigin::Root.into(), None).unwrap();
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		use crate::migrations::v1::{MigrateToV1, MAX_MIGRATED_ENTRIES};
+		use codec::Encode;
+		use frame_support::{
+			storage::unhashed,
+			traits::{ConstU32, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		};
+		use sp_io::hashing::twox_128;
 
-		let slot = 7634942;
+		let _ = ConfigurationStorage::<Test>::clear(u32::MAX, None);
+		let _ = SyncCommitteePoseidons::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<Bridge>();
 
-		SyncCommitteePoseidons::<Test>::insert(
-			931,
-			U256::from(hex!(
-				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
-			)),
-		);
+		let prefix = [twox_128(b"Bridge"), twox_128(b"Timestamps")].concat();
+		for slot in 0..=MAX_MIGRATED_ENTRIES {
+			unhashed::put(&[prefix.clone(), slot.encode()].concat(), &1u64);
+		}
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		MigrateToV1::<Test, ConstU32<2>>::on_runtime_upgrade();
 
-		let err = Bridge::fulfill_call(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			STEP_FUNCTION_ID,
-			get_valid_step_input(),
-			get_valid_step_output(),
-			get_valid_step_proof(),
-			slot,
+		assert_eq!(crate::Timestamps::<Test>::get(2, 0), 0);
+		assert_eq!(
+			unhashed::get::<u64>(&[prefix, 0u64.encode()].concat()),
+			Some(1)
 		);
-		assert_err!(err, Error::<Test>::VerificationKeyIsNotSet);
+		assert_eq!(Bridge::on_chain_storage_version(), 0);
 	});
 }
 
 #[test]
-fn test_fulfill_rotate_call_verification_key_is_not_set() {
+fn migrate_to_v2_sorts_whitelisted_domains() {
 	new_test_ext().execute_with(|| {
-		Bridge::set_rotate_verification_key(RawOrigin::Root.into(), None).unwrap();
-		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		use crate::migrations::v2::MigrateToV2;
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 342,
-		});
+		StorageVersion::new(1).put::<Bridge>();
+		WhitelistedDomains::<Test>::put(BoundedVec::try_from(vec![3, 1, 3, 2]).unwrap());
 
-		Headers::<Test>::set(
-			slot,
-			H256(hex!(
-				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
-			)),
-		);
+		MigrateToV2::<Test>::on_runtime_upgrade();
 
-		let err = Bridge::fulfill_call(
-			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
-			ROTATE_FUNCTION_ID,
-			get_valid_rotate_input(),
-			get_valid_rotate_output(),
-			get_valid_rotate_proof(),
-			slot,
-		);
-		assert_err!(err, Error::<Test>::VerificationKeyIsNotSet);
+		assert_eq!(WhitelistedDomains::<Test>::get().to_vec(), vec![1, 2, 3]);
+		assert_eq!(Bridge::on_chain_storage_version(), 2);
 	});
 }
 
 #[test]
-fn set_whitelisted_domains_works_with_root() {
+fn migrate_to_v3_registers_native_asset() {
 	new_test_ext().execute_with(|| {
-		let domains = BoundedVec::try_from([0, 1, 2, 3].to_vec()).unwrap();
-		assert_ne!(WhitelistedDomains::<Test>::get(), domains);
+		use crate::migrations::v3::MigrateToV3;
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
 
-		let ok = Bridge::set_whitelisted_domains(RawOrigin::Root.into(), domains.clone());
-		assert_ok!(ok);
-		assert_eq!(WhitelistedDomains::<Test>::get(), domains);
+		StorageVersion::new(2).put::<Bridge>();
+		AssetRegistry::<Test>::remove(H256::zero());
 
-		System::assert_last_event(RuntimeEvent::Bridge(Event::WhitelistedDomainsUpdated));
-	});
-}
+		MigrateToV3::<Test>::on_runtime_upgrade();
 
-#[test]
-fn set_whitelisted_domains_does_not_work_with_non_root() {
-	new_test_ext().execute_with(|| {
-		let domains = BoundedVec::try_from([0, 1, 2, 3].to_vec()).unwrap();
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_whitelisted_domains(origin, domains.clone());
-		assert_err!(ok, BadOrigin);
+		assert_eq!(
+			AssetRegistry::<Test>::get(H256::zero()),
+			Some(AssetInfo::native())
+		);
+		assert_eq!(Bridge::on_chain_storage_version(), 3);
 	});
 }
 
 #[test]
-fn set_configuration_works_with_root() {
+fn set_permissioned_relaying_works_with_root() {
 	new_test_ext().execute_with(|| {
-		let conf = Configuration {
-			slots_per_period: 1,
-			finality_threshold: 69,
-		};
-		assert_ne!(ConfigurationStorage::<Test>::get(), conf);
-
-		let ok = Bridge::set_configuration(RawOrigin::Root.into(), conf);
+		let ok = Bridge::set_permissioned_relaying(RawOrigin::Root.into(), true);
 		assert_ok!(ok);
-		assert_eq!(ConfigurationStorage::<Test>::get(), conf);
+		assert!(PermissionedRelaying::<Test>::get());
 
-		let expected_event = RuntimeEvent::Bridge(Event::ConfigurationUpdated {
-			slots_per_period: conf.slots_per_period,
-			finality_threshold: conf.finality_threshold,
-		});
-		System::assert_last_event(expected_event);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::PermissionedRelayingUpdated {
+			enabled: true,
+		}));
 	});
 }
 
 #[test]
-fn set_configuration_does_not_work_with_non_root() {
+fn set_permissioned_relaying_does_not_work_with_non_root() {
 	new_test_ext().execute_with(|| {
-		let conf = Configuration {
-			slots_per_period: 1,
-			finality_threshold: 69,
-		};
-
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_configuration(origin, conf);
-		assert_err!(ok, BadOrigin);
+		let err = Bridge::set_permissioned_relaying(origin, true);
+		assert_err!(err, BadOrigin);
 	});
 }
 
 #[test]
-fn set_broadcaster_works_with_root() {
+fn add_relayer_works() {
 	new_test_ext().execute_with(|| {
-		let domain = 2;
-		let old = Broadcasters::<Test>::get(domain);
-		assert_ne!(old, STEP_FUNCTION_ID);
-
-		let ok = Bridge::set_broadcaster(RawOrigin::Root.into(), domain, STEP_FUNCTION_ID);
+		let ok = Bridge::add_relayer(RawOrigin::Root.into(), TEST_SENDER_ACCOUNT);
 		assert_ok!(ok);
-		assert_eq!(Broadcasters::<Test>::get(domain), STEP_FUNCTION_ID);
+		assert!(Relayers::<Test>::contains_key(TEST_SENDER_ACCOUNT));
 
-		let expected_event = RuntimeEvent::Bridge(Event::BroadcasterUpdated {
-			old,
-			new: STEP_FUNCTION_ID,
-			domain,
-		});
-		System::assert_last_event(expected_event);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::RelayerAdded {
+			relayer: TEST_SENDER_ACCOUNT,
+		}));
 	});
 }
 
 #[test]
-fn set_broadcaster_does_not_work_with_non_root() {
+fn add_relayer_does_not_work_with_non_root() {
 	new_test_ext().execute_with(|| {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_broadcaster(origin, 2, STEP_FUNCTION_ID);
-		assert_err!(ok, BadOrigin);
+		let err = Bridge::add_relayer(origin, TEST_SENDER_ACCOUNT);
+		assert_err!(err, BadOrigin);
 	});
 }
 
 #[test]
-fn set_poseidon_hash_works_with_root() {
+fn add_relayer_fails_if_already_relayer() {
 	new_test_ext().execute_with(|| {
-		let period = 2;
-		let poseidon_hash = BoundedVec::try_from(
-			[
-				0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
-				23, 24, 25, 26, 27, 28, 29, 30, 31,
-			]
-			.to_vec(),
-		)
-		.unwrap();
-		let root = U256::from_dec_str(
-			"1780731860627700044960722568376592200742329637303199754547598369979440671",
-		)
-		.unwrap();
-		assert_ne!(SyncCommitteePoseidons::<Test>::get(period), root);
-
-		let ok = Bridge::set_poseidon_hash(RawOrigin::Root.into(), period, poseidon_hash);
-		assert_ok!(ok);
-		assert_eq!(SyncCommitteePoseidons::<Test>::get(period), root);
+		assert_ok!(Bridge::add_relayer(
+			RawOrigin::Root.into(),
+			TEST_SENDER_ACCOUNT
+		));
 
-		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeUpdated { period, root });
-		System::assert_last_event(expected_event);
+		let err = Bridge::add_relayer(RawOrigin::Root.into(), TEST_SENDER_ACCOUNT);
+		assert_err!(err, Error::<Test>::AlreadyRelayer);
 	});
 }
 
 #[test]
-fn set_poseidon_hash_wrong_hash_length() {
+fn remove_relayer_works() {
 	new_test_ext().execute_with(|| {
-		let period = 2;
-		let poseidon_hash = BoundedVec::try_from(
-			[
-				0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
-				23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
-			]
-			.to_vec(),
-		)
-		.unwrap();
+		Relayers::<Test>::insert(TEST_SENDER_ACCOUNT, ());
 
-		let error = Bridge::set_poseidon_hash(RawOrigin::Root.into(), period, poseidon_hash);
-		assert_err!(error, Error::<Test>::CannotParseOutputData);
-		assert_eq!(SyncCommitteePoseidons::<Test>::get(period), U256::zero());
+		let ok = Bridge::remove_relayer(RawOrigin::Root.into(), TEST_SENDER_ACCOUNT);
+		assert_ok!(ok);
+		assert!(!Relayers::<Test>::contains_key(TEST_SENDER_ACCOUNT));
+
+		System::assert_last_event(RuntimeEvent::Bridge(Event::RelayerRemoved {
+			relayer: TEST_SENDER_ACCOUNT,
+		}));
 	});
 }
 
 #[test]
-fn set_poseidon_hash_does_not_work_with_non_root() {
+fn remove_relayer_fails_if_not_relayer() {
 	new_test_ext().execute_with(|| {
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let root = BoundedVec::try_from([0, 1, 2, 3, 4].to_vec()).unwrap();
-
-		let ok = Bridge::set_poseidon_hash(origin, 2, root);
-		assert_err!(ok, BadOrigin);
+		let err = Bridge::remove_relayer(RawOrigin::Root.into(), TEST_SENDER_ACCOUNT);
+		assert_err!(err, Error::<Test>::NotRelayer);
 	});
 }
 
 #[test]
-fn source_chain_froze_works_with_root() {
+fn execute_fails_with_unregistered_relayer() {
 	new_test_ext().execute_with(|| {
-		let source_chain_id = 2;
-		let frozen = true;
-		assert_ne!(SourceChainFrozen::<Test>::get(source_chain_id), frozen);
+		let slot = 8581263;
+		PermissionedRelaying::<Test>::put(true);
 
-		let ok = Bridge::source_chain_froze(RawOrigin::Root.into(), source_chain_id, frozen);
-		assert_ok!(ok);
-		assert_eq!(SourceChainFrozen::<Test>::get(source_chain_id), frozen);
+		let err = execute_valid_message(slot);
+		assert_err!(err, Error::<Test>::RelayerNotAllowed);
 
-		let expected_event = RuntimeEvent::Bridge(Event::SourceChainFrozen {
-			source_chain_id,
-			frozen,
-		});
-		System::assert_last_event(expected_event);
+		Relayers::<Test>::insert(TEST_SENDER_ACCOUNT, ());
+		assert_ok!(execute_valid_message(slot));
 	});
 }
 
 #[test]
-fn source_chain_froze_does_not_work_with_non_root() {
+fn freeze_source_chain_works_with_root() {
 	new_test_ext().execute_with(|| {
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		System::set_block_number(5);
+		let ok = Bridge::freeze_source_chain(RawOrigin::Root.into(), 2, FreezeReason::Maintenance);
+		assert_ok!(ok);
 
-		let ok = Bridge::source_chain_froze(origin, 2, true);
-		assert_err!(ok, BadOrigin);
+		assert!(SourceChainFrozen::<Test>::get(2));
+		assert_eq!(
+			Bridge::freeze_status(2),
+			Some(FreezeStatus {
+				reason: FreezeReason::Maintenance,
+				frozen_by: FreezeOrigin::Root,
+				frozen_at: 5,
+			})
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::SourceChainFreezeStarted {
+			domain: 2,
+			reason: FreezeReason::Maintenance,
+			frozen_by: FreezeOrigin::Root,
+		}));
 	});
 }
 
 #[test]
-fn send_message_arbitrary_message_works() {
+fn freeze_source_chain_does_not_work_with_signed_origin() {
 	new_test_ext().execute_with(|| {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
-		let to = ROTATE_FUNCTION_ID;
-		let domain = 2;
-
-		let event = Event::MessageSubmitted {
-			from: TEST_SENDER_VEC.into(),
-			to,
-			message_type: message.r#type(),
-			destination_domain: domain,
-			message_id: tx_uid(1, 0),
-		};
-		let ok = Bridge::send_message(origin, message, to, domain);
-		assert_ok!(ok);
-		System::assert_last_event(RuntimeEvent::Bridge(event));
+		let err = Bridge::freeze_source_chain(origin, 2, FreezeReason::Maintenance);
+		assert_err!(err, BadOrigin);
 	});
 }
 
 #[test]
-fn send_message_arbitrary_message_doesnt_accept_empty_data() {
+fn source_chain_froze_records_freeze_status() {
 	new_test_ext().execute_with(|| {
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let message = Message::ArbitraryMessage(BoundedVec::truncate_from(vec![]));
+		assert_ok!(Bridge::source_chain_froze(RawOrigin::Root.into(), 2, true));
+		assert_eq!(
+			Bridge::freeze_status(2).map(|status| status.reason),
+			Some(FreezeReason::Unspecified)
+		);
 
-		let ok = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
-		assert_err!(ok, Error::<Test>::InvalidBridgeInputs);
+		assert_ok!(Bridge::source_chain_froze(RawOrigin::Root.into(), 2, false));
+		assert_eq!(Bridge::freeze_status(2), None);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::SourceChainFreezeLifted {
+			domain: 2,
+		}));
 	});
 }
 
 #[test]
-fn send_message_fungible_token_works() {
+fn unfreeze_source_chain_works() {
 	new_test_ext().execute_with(|| {
-		use crate::BalanceOf;
-		use frame_support::traits::Currency;
-
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let message = Message::FungibleToken {
-			asset_id: H256::zero(),
-			amount: 100,
-		};
-		let to = ROTATE_FUNCTION_ID;
-		let domain = 2;
-
-		Balances::make_free_balance_be(
-			&TEST_SENDER_VEC.into(),
-			BalanceOf::<Test>::max_value() / 2u128,
-		);
+		let reason = FreezeReason::FaultyStateRoot;
+		assert_ok!(Bridge::freeze_source_chain(
+			RawOrigin::Root.into(),
+			2,
+			reason
+		));
 
-		let event = Event::MessageSubmitted {
-			from: TEST_SENDER_VEC.into(),
-			to,
-			message_type: message.r#type(),
-			destination_domain: domain,
-			message_id: tx_uid(1, 0),
-		};
-		let ok = Bridge::send_message(origin, message, to, domain);
+		let ok = Bridge::unfreeze_source_chain(RawOrigin::Root.into(), 2);
 		assert_ok!(ok);
-		System::assert_last_event(RuntimeEvent::Bridge(event));
+
+		assert!(!SourceChainFrozen::<Test>::get(2));
+		assert_eq!(Bridge::freeze_status(2), None);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::SourceChainFreezeLifted {
+			domain: 2,
+		}));
 	});
 }
 
 #[test]
-fn send_message_fungible_token_does_not_accept_zero_amount() {
+fn unfreeze_source_chain_fails_if_not_frozen() {
 	new_test_ext().execute_with(|| {
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let message = Message::FungibleToken {
-			asset_id: H256::zero(),
-			amount: 0,
-		};
-		let to = ROTATE_FUNCTION_ID;
-		let domain = 2;
-
-		let err = Bridge::send_message(origin, message, to, domain);
-		assert_err!(err, Error::<Test>::InvalidBridgeInputs);
+		let err = Bridge::unfreeze_source_chain(RawOrigin::Root.into(), 2);
+		assert_err!(err, Error::<Test>::SourceChainNotFrozen);
 	});
 }
 
+fn fulfill_and_execute_valid_message(messages: ExecuteBatch) -> DispatchResultWithPostInfo {
+	Updater::<Test>::set(H256(TEST_SENDER_VEC));
+	SyncCommitteePoseidons::<Test>::insert(
+		2,
+		931,
+		U256::from(hex!(
+			"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+		)),
+	);
+	ConfigurationStorage::<Test>::insert(
+		2,
+		Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		},
+	);
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+		)),
+	);
+
+	Bridge::fulfill_and_execute(
+		RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+		2,
+		STEP_FUNCTION_ID,
+		get_valid_step_input(),
+		get_valid_step_output(),
+		get_valid_step_proof(),
+		7634942,
+		8581263,
+		messages,
+		get_valid_account_proof(),
+	)
+}
+
 #[test]
-fn execute_arbitrary_message_works() {
+fn fulfill_and_execute_works() {
 	new_test_ext().execute_with(|| {
-		use crate::BalanceOf;
-		use frame_support::traits::Currency;
-
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		Balances::make_free_balance_be(
-			&TEST_SENDER_VEC.into(),
-			BalanceOf::<Test>::max_value() / 2u128,
-		);
-
-		Broadcasters::<Test>::set(
-			2,
-			H256(hex!(
-				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
-			)),
-		);
-
-		let slot = 5085118;
 		ExecutionStateRoots::<Test>::set(
-			slot,
+			2,
+			8581263,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
 			)),
 		);
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		let messages = ExecuteBatch::truncate_from(vec![(message, get_valid_storage_proof())]);
 
-		let message = get_valid_amb_message();
-		let account_proof = get_valid_amb_account_proof();
-		let storage_proof = get_valid_amb_storage_proof();
-
-		let ok = Bridge::execute(
-			origin,
-			slot,
-			message.clone(),
-			account_proof.clone(),
-			storage_proof.clone(),
-		);
-		assert_ok!(ok);
-		let encoded_data = message.clone().abi_encode();
-		let message_root = H256(keccak_256(encoded_data.as_slice()));
+		assert_ok!(fulfill_and_execute_valid_message(messages));
 
-		let expected_event = RuntimeEvent::Bridge(Event::MessageExecuted {
-			from: message.from,
-			to: message.to,
-			message_id: message.id,
-			message_root,
-		});
-		System::assert_last_event(expected_event);
+		assert_eq!(Head::<Test>::get(2), 7634848);
 		assert_eq!(
 			MessageStatus::<Test>::get(message_root),
 			MessageStatusEnum::ExecutionSucceeded
-		)
+		);
 	});
 }
 
 #[test]
-fn test_double_execute_arbitrary_message() {
+fn fulfill_and_execute_reverts_update_on_failed_execution() {
 	new_test_ext().execute_with(|| {
-		use crate::BalanceOf;
-		use frame_support::traits::Currency;
+		// No execution state root is known for the execution slot.
+		let messages =
+			ExecuteBatch::truncate_from(vec![(get_valid_message(), get_valid_storage_proof())]);
 
-		let origin1 = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let origin2 = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		Balances::make_free_balance_be(
-			&TEST_SENDER_VEC.into(),
-			BalanceOf::<Test>::max_value() / 2u128,
-		);
+		assert!(fulfill_and_execute_valid_message(messages).is_err());
 
-		Broadcasters::<Test>::set(
+		assert_eq!(Head::<Test>::get(2), 0);
+		assert_eq!(Headers::<Test>::get(2, 7634848), H256::zero());
+	});
+}
+
+#[test]
+fn fulfill_and_execute_fails_with_other_origin_domain() {
+	new_test_ext().execute_with(|| {
+		let mut message = get_valid_message();
+		message.origin_domain = 3;
+		let messages = ExecuteBatch::truncate_from(vec![(message, get_valid_storage_proof())]);
+
+		let err = fulfill_and_execute_valid_message(messages);
+		assert_err!(err, Error::<Test>::BatchOriginDomainMismatch);
+	});
+}
+
+#[test]
+fn fulfill_call_step_notifies_new_verified_head() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Bridge::latest_verified_head(2), None);
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		GenesisTimestamp::<Test>::set(1_606_824_023);
+		SecondsPerSlot::<Test>::set(12);
+		SyncCommitteePoseidons::<Test>::insert(
 			2,
-			H256(hex!(
-				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			931,
+			U256::from(hex!(
+				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
 			)),
 		);
 
-		let slot = 5085118;
-		ExecutionStateRoots::<Test>::set(
-			slot,
-			H256(hex!(
-				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+		assert_ok!(Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			7634942,
+		));
+
+		let head = VerifiedHead {
+			slot: 7634848,
+			timestamp: 1_606_824_023 + 7634848 * 12,
+			header_root: H256(hex!(
+				"e4566e0cf4edb171a3eedd59f9943bbcd0b1f6b648f1a6e26d5264b668ab41ec"
 			)),
-		);
+			execution_state_root: H256(hex!(
+				"51e76629b32b943497207e7b7ccff8fbc12e9e6d758cc7eed972422c4cad02b9"
+			)),
+		};
+		assert_eq!(Bridge::latest_verified_head(2), Some(head));
+		assert_eq!(VerifiedHeads::get(), vec![(2, head)]);
+	});
+}
 
-		let message = get_valid_amb_message();
+#[test]
+fn verification_key_activates_after_delay() {
+	new_test_ext().execute_with(|| {
+		let key = BoundedVec::try_from(ROTATE_VK.as_bytes().to_vec()).unwrap();
+		assert_ok!(Bridge::set_step_verification_key(
+			RawOrigin::Root.into(),
+			Some(key.clone())
+		));
 
-		let account_proof = get_valid_amb_account_proof();
-		let storage_proof = get_valid_amb_storage_proof();
+		System::assert_last_event(RuntimeEvent::Bridge(Event::VerificationKeyScheduled {
+			kind: VerificationKeyKind::Step,
+			version: 2,
+			key_hash: H256(keccak_256(key.as_slice())),
+			activation: 6,
+		}));
 
-		let ok = Bridge::execute(
-			origin1,
-			slot,
-			message.clone(),
-			account_proof.clone(),
-			storage_proof.clone(),
+		System::set_block_number(5);
+		Bridge::on_initialize(5);
+		assert_eq!(
+			StepVerificationKey::<Test>::get(),
+			Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap())
 		);
-		assert_ok!(ok);
-
-		let err = Bridge::execute(
-			origin2,
-			slot,
-			message.clone(),
-			account_proof.clone(),
-			storage_proof.clone(),
+		assert_eq!(
+			VerificationKeyVersions::<Test>::get(VerificationKeyKind::Step),
+			1
 		);
-		assert_err!(err, Error::<Test>::MessageAlreadyExecuted);
-
-		let encoded_data = message.clone().abi_encode();
-		let message_root = H256(keccak_256(encoded_data.as_slice()));
 
-		let expected_event = RuntimeEvent::Bridge(Event::MessageExecuted {
-			from: message.from,
-			to: message.to,
-			message_id: message.id,
-			message_root,
-		});
-		System::assert_last_event(expected_event);
+		System::set_block_number(6);
+		Bridge::on_initialize(6);
+		assert_eq!(StepVerificationKey::<Test>::get(), Some(key));
+		assert_eq!(
+			VerificationKeyVersions::<Test>::get(VerificationKeyKind::Step),
+			2
+		);
+		assert!(PendingVerificationKeys::<Test>::get(VerificationKeyKind::Step).is_none());
+		System::assert_last_event(RuntimeEvent::Bridge(Event::VerificationKeyActivated {
+			kind: VerificationKeyKind::Step,
+			version: 2,
+		}));
 	});
 }
 
 #[test]
-fn set_function_ids_works_with_root() {
+fn cancel_verification_key_works() {
 	new_test_ext().execute_with(|| {
-		let ok = Bridge::set_function_ids(RawOrigin::Root.into(), None);
-		assert_ok!(ok);
-		assert_eq!(FunctionIds::<Test>::get(), None);
+		let key = BoundedVec::try_from(ROTATE_VK.as_bytes().to_vec()).unwrap();
+		assert_ok!(Bridge::set_step_verification_key(
+			RawOrigin::Root.into(),
+			Some(key)
+		));
 
-		let value = Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID));
-		let ok = Bridge::set_function_ids(RawOrigin::Root.into(), value);
-		assert_ok!(ok);
-		assert_eq!(FunctionIds::<Test>::get(), value);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::cancel_verification_key(origin, VerificationKeyKind::Step);
+		assert_err!(err, BadOrigin);
 
-		let expected_event = RuntimeEvent::Bridge(Event::FunctionIdsUpdated { value });
-		System::assert_last_event(expected_event);
+		assert_ok!(Bridge::cancel_verification_key(
+			RawOrigin::Root.into(),
+			VerificationKeyKind::Step
+		));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::VerificationKeyCancelled {
+			kind: VerificationKeyKind::Step,
+			version: 2,
+		}));
+
+		System::set_block_number(6);
+		Bridge::on_initialize(6);
+		assert_eq!(
+			StepVerificationKey::<Test>::get(),
+			Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap())
+		);
+		assert_eq!(
+			VerificationKeyVersions::<Test>::get(VerificationKeyKind::Step),
+			1
+		);
 	});
 }
 
 #[test]
-fn set_function_ids_does_not_work_with_non_root() {
+fn cancel_verification_key_fails_without_pending_key() {
 	new_test_ext().execute_with(|| {
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_function_ids(origin, None);
-		assert_err!(ok, BadOrigin);
+		let err =
+			Bridge::cancel_verification_key(RawOrigin::Root.into(), VerificationKeyKind::Rotate);
+		assert_err!(err, Error::<Test>::NoPendingVerificationKey);
 	});
 }
 
 #[test]
-fn set_step_verification_key_works_with_root() {
-	new_test_ext().execute_with(|| {
-		let ok = Bridge::set_step_verification_key(RawOrigin::Root.into(), None);
-		assert_ok!(ok);
-		assert_eq!(StepVerificationKey::<Test>::get(), None);
+fn offchain_worker_prevalidates_queued_proofs() {
+	use crate::offchain::{ProofMetrics, QueuedProof, PROOF_METRICS_KEY, PROOF_QUEUE_KEY};
+	use sp_core::offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt};
+	use sp_runtime::offchain::storage::StorageValueRef;
+
+	let mut ext = new_test_ext();
+	let (offchain, _state) = TestOffchainExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+
+	ext.execute_with(|| {
+		let valid = QueuedProof {
+			function_id: STEP_FUNCTION_ID,
+			input: get_valid_step_input(),
+			output: get_valid_step_output(),
+			proof: get_valid_step_proof(),
+		};
+		let malformed = QueuedProof {
+			proof: get_invalid_proof(),
+			..valid.clone()
+		};
+		StorageValueRef::persistent(PROOF_QUEUE_KEY).set(&vec![valid, malformed.clone()]);
 
-		let value = Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap());
-		let ok = Bridge::set_step_verification_key(RawOrigin::Root.into(), value.clone());
-		assert_ok!(ok);
-		assert_eq!(StepVerificationKey::<Test>::get(), value.clone());
+		Bridge::offchain_worker(1);
 
-		let expected_event = RuntimeEvent::Bridge(Event::StepVerificationKeyUpdated { value });
-		System::assert_last_event(expected_event);
+		let queue = StorageValueRef::persistent(PROOF_QUEUE_KEY).get::<Vec<QueuedProof>>();
+		assert_eq!(queue, Ok(Some(vec![])));
+		let metrics = StorageValueRef::persistent(PROOF_METRICS_KEY).get::<ProofMetrics>();
+		assert_eq!(
+			metrics,
+			Ok(Some(ProofMetrics {
+				checked: 2,
+				malformed: 1,
+				last_malformed: Some(H256(sp_io::hashing::blake2_256(&malformed.encode()))),
+			}))
+		);
 	});
 }
 
 #[test]
-fn set_step_verification_key_does_not_work_with_non_root() {
+fn on_message_executed_is_notified_of_outcomes() {
 	new_test_ext().execute_with(|| {
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_step_verification_key(origin, None);
-		assert_err!(ok, BadOrigin);
+		use frame_support::traits::Currency;
+
+		let (message, _) = setup_failed_fungible_token_message();
+		assert_eq!(
+			MessageOutcomes::get(),
+			vec![(
+				message.id,
+				MessageOutcome::Failed(ExecutionFailureReason::TransferFailed)
+			)]
+		);
+
+		Balances::make_free_balance_be(&Bridge::account_id(), 2_000 * 1000000000000000000);
+		let ok = Bridge::retry_execute(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message.clone());
+		assert_ok!(ok);
+		assert_eq!(
+			MessageOutcomes::get().last(),
+			Some(&(message.id, MessageOutcome::Executed(Ok(()))))
+		);
 	});
 }
 
 #[test]
-fn set_rotate_verification_key_works_with_root() {
+fn send_message_records_withdrawal_receipt() {
 	new_test_ext().execute_with(|| {
-		let ok = Bridge::set_rotate_verification_key(RawOrigin::Root.into(), None);
-		assert_ok!(ok);
-		assert_eq!(RotateVerificationKey::<Test>::get(), None);
+		use frame_support::traits::Currency;
 
-		let value = Some(BoundedVec::try_from(ROTATE_VK.as_bytes().to_vec()).unwrap());
-		let ok = Bridge::set_rotate_verification_key(RawOrigin::Root.into(), value.clone());
-		assert_ok!(ok);
-		assert_eq!(RotateVerificationKey::<Test>::get(), value.clone());
+		Balances::make_free_balance_be(&TEST_SENDER_ACCOUNT, 1_000_000);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+		assert_ok!(Bridge::send_message(
+			origin.clone(),
+			message,
+			ROTATE_FUNCTION_ID,
+			2
+		));
+		assert_eq!(Bridge::withdrawal_receipt(tx_uid(1, 0)), None);
 
-		let expected_event = RuntimeEvent::Bridge(Event::RotateVerificationKeyUpdated { value });
-		System::assert_last_event(expected_event);
+		System::set_extrinsic_index(1);
+		let message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 100,
+		};
+		assert_ok!(Bridge::send_message(origin, message, STEP_FUNCTION_ID, 2));
+
+		let receipt = WithdrawalReceipt {
+			tx_index: 1,
+			leaf_index: 1,
+			asset_id: H256::zero(),
+			amount: 100,
+			recipient: STEP_FUNCTION_ID,
+			destination_domain: 2,
+		};
+		assert_eq!(Bridge::withdrawal_receipt(tx_uid(1, 1)), Some((1, receipt)));
+
+		// Receipts are pruned with the message preimages.
+		Bridge::on_initialize(11);
+		assert_eq!(Bridge::withdrawal_receipt(tx_uid(1, 1)), None);
 	});
 }
 
 #[test]
-fn set_rotate_verification_key_does_not_work_with_non_root() {
+fn genesis_config_sets_up_further_domains() {
+	use sp_runtime::BuildStorage;
+
+	let domain = DomainGenesisConfig {
+		domain: 3,
+		broadcaster: H256::repeat_byte(3),
+		slots_per_period: 8192,
+		finality_threshold: 342,
+		period: 931,
+		sync_committee_poseidon: U256::from(7),
+	};
+	let storage = crate::GenesisConfig::<Test> {
+		broadcaster_domain: 2,
+		..Default::default()
+	}
+	.with_whitelisted_domains(vec![2])
+	.with_domain(domain)
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		assert_eq!(WhitelistedDomains::<Test>::get().to_vec(), vec![2, 3]);
+		assert_eq!(Broadcasters::<Test>::get(3), H256::repeat_byte(3));
+		assert_eq!(
+			ConfigurationStorage::<Test>::get(3),
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			}
+		);
+		assert_eq!(SyncCommitteePoseidons::<Test>::get(3, 931), U256::from(7));
+	});
+}
+
+#[test]
+fn execute_refunds_unused_proof_weight() {
 	new_test_ext().execute_with(|| {
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_rotate_verification_key(origin, None);
-		assert_err!(ok, BadOrigin);
+		use crate::{weight_helper, MAX_PROOF_BYTES};
+
+		let proof_bytes: u32 = get_valid_account_proof()
+			.iter()
+			.chain(get_valid_storage_proof().iter())
+			.map(|node| node.len() as u32)
+			.sum();
+		let declared = weight_helper::execute::<Test>(&get_valid_message())
+			.saturating_add(weight_helper::proof_bytes::<Test>(2 * MAX_PROOF_BYTES));
+		let actual = weight_helper::execute::<Test>(&get_valid_message())
+			.saturating_add(weight_helper::proof_bytes::<Test>(proof_bytes));
+
+		let info = execute_valid_message(8581263).unwrap();
+		assert_eq!(info.actual_weight, Some(actual));
+		assert!(actual.all_lte(declared));
 	});
 }
 
 #[test]
-fn update_updater() {
+fn execute_batch_refunds_unused_proof_weight() {
 	new_test_ext().execute_with(|| {
-		let old_updater = H256(TEST_SENDER_VEC);
-		Updater::<Test>::set(old_updater);
+		use crate::weight_helper;
 
-		let new_updater = H256([2u8; 32]);
-		let ok = Bridge::set_updater(RawOrigin::Root.into(), new_updater);
-		assert_ok!(ok);
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			2,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
 
-		let expected_event = RuntimeEvent::Bridge(Event::NewUpdater {
-			old: old_updater,
-			new: new_updater,
-		});
-		System::assert_last_event(expected_event);
+		let proof_bytes: u32 = get_valid_account_proof()
+			.iter()
+			.chain(get_valid_storage_proof().iter())
+			.map(|node| node.len() as u32)
+			.sum();
+		let messages =
+			ExecuteBatch::truncate_from(vec![(get_valid_message(), get_valid_storage_proof())]);
+		let declared = weight_helper::execute_batch::<Test>(&messages).saturating_add(
+			weight_helper::proof_bytes::<Test>(weight_helper::max_batch_proof_bytes(&messages)),
+		);
+		let actual = weight_helper::execute_batch::<Test>(&messages)
+			.saturating_add(weight_helper::proof_bytes::<Test>(proof_bytes));
+
+		let info = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			messages,
+			get_valid_account_proof(),
+		)
+		.unwrap();
+		assert_eq!(info.actual_weight, Some(actual));
+		assert!(actual.all_lte(declared));
 	});
 }
 
 #[test]
-fn update_updater_non_root() {
+fn try_state_checks_invariants() {
 	new_test_ext().execute_with(|| {
-		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let old_updater = H256(TEST_SENDER_VEC);
-		Updater::<Test>::set(old_updater);
-		let new_updater = H256([2u8; 32]);
-
-		let err = Bridge::set_updater(origin, new_updater);
-		assert_err!(err, BadOrigin);
-		assert_eq!(old_updater, Updater::<Test>::get());
+		Head::<Test>::insert(2, 8581263);
+		assert_ok!(execute_valid_message(8581263));
+		Timestamps::<Test>::insert(2, 8581000, 10);
+		Timestamps::<Test>::insert(2, 8581263, 20);
+		assert_ok!(Bridge::do_try_state());
+
+		Timestamps::<Test>::insert(2, 8581300, 15);
+		assert!(Bridge::do_try_state().is_err());
+		Timestamps::<Test>::remove(2, 8581300);
+
+		ExecutionStateRoots::<Test>::insert(2, 8581264, H256::repeat_byte(1));
+		assert!(Bridge::do_try_state().is_err());
+		ExecutionStateRoots::<Test>::remove(2, 8581264);
+
+		let message_root = H256(keccak_256(get_valid_message().abi_encode().as_slice()));
+		MessageStatus::<Test>::remove(message_root);
+		assert!(Bridge::do_try_state().is_err());
 	});
 }
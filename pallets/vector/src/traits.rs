@@ -0,0 +1,224 @@
+use crate::{MessageOutcome, VerifiedHead};
+use avail_core::data_proof::AddressedMessage;
+use codec::{Decode, Encode};
+use ethabi::{ParamType, Token};
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::tokens::{fungibles, Fortitude, Precision},
+	weights::Weight,
+};
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_runtime::{DispatchError, SaturatedConversion};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Handles `ArbitraryMessage`s once their inclusion on the origin domain has been proven.
+pub trait MessageHandler {
+	/// Handles the ABI encoded `data` sent by `from` on `origin_domain` to the recipient `to`.
+	/// Returning an error reverts the execution and leaves the message executable.
+	fn handle(origin_domain: u32, from: H256, to: H256, data: &[u8]) -> DispatchResult;
+
+	/// Maximum weight consumed by `handle` for a payload of `len` bytes.
+	fn weight(len: u32) -> Weight;
+}
+
+impl MessageHandler for () {
+	fn handle(_: u32, _: H256, _: H256, _: &[u8]) -> DispatchResult {
+		Ok(())
+	}
+
+	fn weight(_: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Notified when the light client of a domain verifies a new head, so other pallets can react
+/// to the advancement of the bridge.
+pub trait OnNewVerifiedHead {
+	/// Called once `head` of `domain` is verified.
+	fn on_new_verified_head(domain: u32, head: &VerifiedHead);
+
+	/// Maximum weight consumed by `on_new_verified_head`.
+	fn weight() -> Weight;
+}
+
+impl OnNewVerifiedHead for () {
+	fn on_new_verified_head(_: u32, _: &VerifiedHead) {}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Notified when a proven message is executed or fails to execute, so other pallets can react
+/// to bridged transfers without scanning events.
+pub trait OnMessageExecuted {
+	/// Called once `message` is processed with `outcome`.
+	fn on_message_executed(message: &AddressedMessage, outcome: &MessageOutcome);
+
+	/// Maximum weight consumed by `on_message_executed`.
+	fn weight() -> Weight;
+}
+
+impl OnMessageExecuted for () {
+	fn on_message_executed(_: &AddressedMessage, _: &MessageOutcome) {}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Mints and burns the local representation of bridged non-native assets.
+pub trait BridgedAssets<AccountId> {
+	/// Mints `amount` of the local asset `asset` into `who` once a transfer to `who` is executed.
+	fn mint(asset: u32, who: &AccountId, amount: u128) -> DispatchResult;
+
+	/// Burns `amount` of the local asset `asset` of `who` who sends it back to the origin domain.
+	fn burn(asset: u32, who: &AccountId, amount: u128) -> DispatchResult;
+}
+
+impl<AccountId> BridgedAssets<AccountId> for () {
+	fn mint(_: u32, _: &AccountId, _: u128) -> DispatchResult {
+		Err(DispatchError::Other("Local assets are not supported"))
+	}
+
+	fn burn(_: u32, _: &AccountId, _: u128) -> DispatchResult {
+		Err(DispatchError::Other("Local assets are not supported"))
+	}
+}
+
+/// Maps the `H256` recipient of a bridged transfer to a local account. Addresses bound in the
+/// `AddressBindings` registry take precedence over the mapping.
+pub trait AddressMapping<AccountId> {
+	/// Local account of `address`, or `None` if the address has no account.
+	fn into_account_id(address: H256) -> Option<AccountId>;
+}
+
+/// Maps only the addresses bound in the registry.
+impl<AccountId> AddressMapping<AccountId> for () {
+	fn into_account_id(_: H256) -> Option<AccountId> {
+		None
+	}
+}
+
+/// Maps a local account to the `H256` address that identifies it on the other domains, such as
+/// the sender of its outbound messages.
+pub trait AccountMapping<AccountId> {
+	/// Address of `who`.
+	fn into_address(who: &AccountId) -> H256;
+}
+
+/// Decodes the account from the leading bytes of the address, so the account of a 32 byte
+/// address is the address itself. Accounts map back to their zero padded encoding.
+pub struct TruncatedAddress;
+
+impl<AccountId: Decode> AddressMapping<AccountId> for TruncatedAddress {
+	fn into_account_id(address: H256) -> Option<AccountId> {
+		AccountId::decode(&mut address.as_bytes()).ok()
+	}
+}
+
+impl<AccountId: Encode> AccountMapping<AccountId> for TruncatedAddress {
+	fn into_address(who: &AccountId) -> H256 {
+		who.using_encoded(|account| {
+			let mut address = H256::zero();
+			let len = account.len().min(address.0.len());
+			address.0[..len].copy_from_slice(&account[..len]);
+			address
+		})
+	}
+}
+
+/// Derives the account from the hash of the address, so addresses of other formats such as
+/// zero padded Ethereum addresses never map onto an existing account.
+pub struct HashedAddress;
+
+impl<AccountId: Decode> AddressMapping<AccountId> for HashedAddress {
+	fn into_account_id(address: H256) -> Option<AccountId> {
+		let hash = blake2_256(&[b"vector:address".as_slice(), address.as_bytes()].concat());
+		AccountId::decode(&mut &hash[..]).ok()
+	}
+}
+
+/// Bridges the assets of a `fungibles` implementation such as `pallet-assets`.
+pub struct FungiblesAdapter<F>(PhantomData<F>);
+
+impl<AccountId, F> BridgedAssets<AccountId> for FungiblesAdapter<F>
+where
+	F: fungibles::Mutate<AccountId>,
+	F::AssetId: From<u32>,
+{
+	fn mint(asset: u32, who: &AccountId, amount: u128) -> DispatchResult {
+		F::mint_into(asset.into(), who, amount.saturated_into()).map(|_| ())
+	}
+
+	fn burn(asset: u32, who: &AccountId, amount: u128) -> DispatchResult {
+		F::burn_from(
+			asset.into(),
+			who,
+			amount.saturated_into(),
+			Precision::Exact,
+			Fortitude::Polite,
+		)
+		.map(|_| ())
+	}
+}
+
+/// Decodes the ABI encoded payload of an `ArbitraryMessage` into the expected `types`.
+pub fn decode_message_data(types: &[ParamType], data: &[u8]) -> Option<Vec<Token>> {
+	ethabi::decode(types, data).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use primitive_types::U256;
+
+	#[test]
+	fn decode_message_data_works() {
+		let tokens = vec![
+			Token::Uint(U256::from(42u64)),
+			Token::Bytes(b"Hello, World!".to_vec()),
+		];
+		let data = ethabi::encode(&tokens);
+
+		let decoded = decode_message_data(&[ParamType::Uint(256), ParamType::Bytes], &data);
+		assert_eq!(decoded, Some(tokens));
+	}
+
+	#[test]
+	fn decode_message_data_fails_on_malformed_data() {
+		let decoded = decode_message_data(&[ParamType::Uint(256)], &[1u8, 2, 3]);
+		assert_eq!(decoded, None);
+	}
+
+	#[test]
+	fn address_mappings_work() {
+		let address = H256::repeat_byte(1);
+
+		let truncated: Option<[u8; 32]> = TruncatedAddress::into_account_id(address);
+		assert_eq!(truncated, Some(address.0));
+
+		let hashed: Option<[u8; 32]> = HashedAddress::into_account_id(address);
+		assert!(hashed.is_some_and(|account| account != address.0));
+
+		let bound_only: Option<[u8; 32]> = <() as AddressMapping<_>>::into_account_id(address);
+		assert_eq!(bound_only, None);
+	}
+
+	#[test]
+	fn account_mapping_round_trips_shorter_accounts() {
+		let account = [7u8; 20];
+
+		let address = TruncatedAddress::into_address(&account);
+		assert_eq!(&address.0[..20], &account);
+		assert_eq!(&address.0[20..], &[0u8; 12]);
+
+		let mapped: Option<[u8; 20]> = TruncatedAddress::into_account_id(address);
+		assert_eq!(mapped, Some(account));
+		assert_eq!(
+			TruncatedAddress::into_address(&[1u8; 32]),
+			H256::repeat_byte(1)
+		);
+	}
+}
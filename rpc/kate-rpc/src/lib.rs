@@ -1,9 +1,10 @@
 use avail_base::metrics::avail::{MetricObserver, ObserveKind};
 use avail_core::{
-	data_proof::ProofResponse, header::HeaderExtension, traits::ExtendedHeader, OpaqueExtrinsic,
+	data_proof::ProofResponse, header::HeaderExtension, traits::ExtendedHeader, AppId,
+	OpaqueExtrinsic,
 };
 use da_runtime::apis::{DataAvailApi, KateApi as RTKateApi};
-use da_runtime::kate::{GDataProof, GRow};
+use da_runtime::kate::{DataLocation, GCellBlock, GDataProof, GMultiProof, GRow};
 use kate::com::Cell;
 
 use frame_support::BoundedVec;
@@ -47,6 +48,15 @@ where
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<Vec<GDataProof>>;
 
+	/// Returns the multiproof of the block of cells holding each of `cells`, so a single proof
+	/// covers all the cells of its block.
+	#[method(name = "kate_queryMultiProof")]
+	async fn query_multiproof(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<(GMultiProof, GCellBlock)>>;
+
 	#[method(name = "kate_blockLength")]
 	async fn query_block_length(&self, at: Option<HashOf<Block>>) -> RpcResult<BlockLength>;
 
@@ -56,6 +66,36 @@ where
 		transaction_index: u32,
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<ProofResponse>;
+
+	/// Returns the keccak256 Merkle proof of the leaf at `leaf_index` of the submitted data sub
+	/// trie of the data root, with the branch ordered like the Ethereum verifier expects it. The
+	/// leaves of an extrinsic are located by `kate_queryDataLocation`, one per entry of a batch.
+	#[method(name = "kate_queryLeafProof")]
+	async fn query_leaf_proof(
+		&self,
+		leaf_index: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<ProofResponse>;
+
+	#[method(name = "kate_queryDataLocation")]
+	async fn query_data_location(
+		&self,
+		transaction_index: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<DataLocation>;
+
+	#[method(name = "dataAvailability_appDataRanges")]
+	async fn query_app_data_ranges(
+		&self,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<(AppId, u32, u32)>>;
+
+	#[method(name = "dataAvailability_appSubtree")]
+	async fn query_app_subtree(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<AppId>>;
 }
 
 #[allow(clippy::type_complexity)]
@@ -240,6 +280,44 @@ where
 		Ok(proof)
 	}
 
+	async fn query_multiproof(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<(GMultiProof, GCellBlock)>> {
+		if cells.len() > self.max_cells_size {
+			return Err(
+				internal_err!(
+					"Cannot query ({}) more than {} amount of cells per request. Either increase the max cells size (--kate-max-cells-size) or query less amount of cells per request.",
+					cells.len(),
+					self.max_cells_size
+				)
+			);
+		}
+
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryMultiProof);
+
+		let (api, at, number, block_len, extrinsics, header) = self.scope(at)?;
+		match header.extension() {
+			HeaderExtension::V3(ext) => {
+				if ext.commitment.commitment.is_empty() {
+					return Err(internal_err!("Requested block {at} has empty commitments"));
+				}
+			},
+		};
+
+		let cells = cells
+			.into_iter()
+			.map(|cell| (cell.row.0, cell.col.0))
+			.collect::<Vec<_>>();
+		let multiproofs = api
+			.multiproof(at, number, extrinsics, block_len, cells)
+			.map_err(|kate_err| internal_err!("KateApi::multiproof failed: {kate_err:?}"))?
+			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?;
+
+		Ok(multiproofs)
+	}
+
 	async fn query_block_length(&self, at: Option<HashOf<Block>>) -> RpcResult<BlockLength> {
 		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryBlockLength);
 
@@ -270,4 +348,72 @@ where
 
 		Ok(proof)
 	}
+
+	async fn query_leaf_proof(
+		&self,
+		leaf_idx: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<ProofResponse> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryLeafProof);
+
+		let (api, at, number, _, extrinsics, _) = self.scope(at)?;
+		let proof = api
+			.leaf_proof(at, number, extrinsics, leaf_idx)
+			.map_err(|e| internal_err!("KateApi::leaf_proof failed: {e:?}"))?
+			.ok_or_else(|| {
+				internal_err!("Cannot fetch data leaf at index {leaf_idx:?} at block {at:?}")
+			})?;
+
+		Ok(proof)
+	}
+
+	async fn query_data_location(
+		&self,
+		tx_idx: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<DataLocation> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryDataLocation);
+
+		let (api, at, number, block_len, extrinsics, _) = self.scope(at)?;
+		let location = api
+			.data_location(at, number, extrinsics, block_len, tx_idx)
+			.map_err(|e| internal_err!("KateApi::data_location failed: {e:?}"))?
+			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?
+			.ok_or_else(|| {
+				internal_err!("Cannot fetch tx data at tx index {tx_idx:?} at block {at:?}")
+			})?;
+
+		Ok(location)
+	}
+
+	async fn query_app_data_ranges(
+		&self,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<(AppId, u32, u32)>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryAppDataRanges);
+
+		let (api, at, number, block_len, extrinsics, _) = self.scope(at)?;
+		let ranges = api
+			.app_data_ranges(at, number, extrinsics, block_len)
+			.map_err(|e| internal_err!("KateApi::app_data_ranges failed: {e:?}"))?
+			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?;
+
+		Ok(ranges)
+	}
+
+	async fn query_app_subtree(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<AppId>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryAppSubtree);
+
+		let at = self.at_or_best(at);
+		let api = self.client.runtime_api();
+		let subtree = api
+			.app_subtree(at, app_id)
+			.map_err(|e| internal_err!("DataAvailApi::app_subtree failed: {e:?}"))?;
+
+		Ok(subtree)
+	}
 }
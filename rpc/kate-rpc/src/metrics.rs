@@ -1,10 +1,10 @@
 use crate::{Cells, HashOf, Kate, KateApiServer, ProofResponse, Rows};
 
-use avail_core::{header::HeaderExtension, traits::ExtendedHeader, OpaqueExtrinsic};
+use avail_core::{header::HeaderExtension, traits::ExtendedHeader, AppId, OpaqueExtrinsic};
 use da_runtime::apis::DataAvailApi;
 
 use crate::RTKateApi;
-use da_runtime::kate::{GDataProof, GRow};
+use da_runtime::kate::{DataLocation, GCellBlock, GDataProof, GMultiProof, GRow};
 use frame_system::limits::BlockLength;
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
@@ -34,6 +34,13 @@ where
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<(Vec<GDataProof>, u128)>;
 
+	#[method(name = "kate_queryMultiProofMetrics")]
+	async fn query_multiproof_metrics(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<(GMultiProof, GCellBlock)>, u128)>;
+
 	#[method(name = "kate_blockLengthMetrics")]
 	async fn query_block_length_metrics(
 		&self,
@@ -46,6 +53,33 @@ where
 		transaction_index: u32,
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<(ProofResponse, u128)>;
+
+	#[method(name = "kate_queryLeafProofMetrics")]
+	async fn query_leaf_proof_metrics(
+		&self,
+		leaf_index: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(ProofResponse, u128)>;
+
+	#[method(name = "kate_queryDataLocationMetrics")]
+	async fn query_data_location_metrics(
+		&self,
+		transaction_index: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(DataLocation, u128)>;
+
+	#[method(name = "dataAvailability_appDataRangesMetrics")]
+	async fn query_app_data_ranges_metrics(
+		&self,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<(AppId, u32, u32)>, u128)>;
+
+	#[method(name = "dataAvailability_appSubtreeMetrics")]
+	async fn query_app_subtree_metrics(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<AppId>, u128)>;
 }
 
 #[async_trait]
@@ -81,6 +115,18 @@ where
 		result.map(|r| (r, elapsed.as_micros()))
 	}
 
+	async fn query_multiproof_metrics(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<(GMultiProof, GCellBlock)>, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_multiproof(cells, at).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r, elapsed.as_micros()))
+	}
+
 	async fn query_block_length_metrics(
 		&self,
 		at: Option<HashOf<Block>>,
@@ -103,4 +149,51 @@ where
 
 		result.map(|r| (r, elapsed.as_micros()))
 	}
+
+	async fn query_leaf_proof_metrics(
+		&self,
+		leaf_index: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(ProofResponse, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_leaf_proof(leaf_index, at).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r, elapsed.as_micros()))
+	}
+
+	async fn query_data_location_metrics(
+		&self,
+		transaction_index: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(DataLocation, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_data_location(transaction_index, at).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r, elapsed.as_micros()))
+	}
+
+	async fn query_app_data_ranges_metrics(
+		&self,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<(AppId, u32, u32)>, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_app_data_ranges(at).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r, elapsed.as_micros()))
+	}
+
+	async fn query_app_subtree_metrics(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<AppId>, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_app_subtree(app_id, at).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r, elapsed.as_micros()))
+	}
 }
@@ -17,5 +17,8 @@ fn commitment_builder_128() {
 fn commitment_builder_256() {
 	commitment_builder(BlockLengthColumns(256));
 }
+fn commitment_builder_512() {
+	commitment_builder(BlockLengthColumns(512));
+}
 
-iai::main! {commitment_builder_64, commitment_builder_128, commitment_builder_256 }
+iai::main! {commitment_builder_64, commitment_builder_128, commitment_builder_256, commitment_builder_512 }
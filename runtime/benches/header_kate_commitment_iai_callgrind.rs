@@ -12,6 +12,7 @@ fn setup(cols: u32) -> (Vec<AppExtrinsic>, BlockLength) {
 #[bench::columns_64(setup(64))]
 #[bench::columns_128(setup(128))]
 #[bench::columns_256(setup(256))]
+#[bench::columns_512(setup(512))]
 fn commitment_builder(input: (Vec<AppExtrinsic>, BlockLength)) {
 	commitment_builder_with(input.0, input.1);
 	black_box(());
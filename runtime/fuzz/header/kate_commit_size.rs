@@ -11,7 +11,9 @@ use std::{
 };
 
 use avail_core::header::HeaderExtension;
-use da_control::{pallet::Call as DaControlCall, AppDataFor, CheckAppId};
+use da_control::{
+	pallet::Call as DaControlCall, AppDataFor, CheckAppId, PrioritizedTransactionPayment,
+};
 use da_runtime::{
 	AppId, Executive, Header, Runtime, RuntimeCall, RuntimeGenesisConfig, SignedExtra,
 	SignedPayload, Timestamp, UncheckedExtrinsic, AVAIL,
@@ -25,7 +27,6 @@ use frame_system::{
 	CheckEra, CheckGenesis, CheckNonZeroSender, CheckNonce, CheckSpecVersion, CheckTxVersion,
 	CheckWeight,
 };
-use pallet_transaction_payment::ChargeTransactionPayment;
 use sp_core::{Pair, H256};
 use sp_io::TestExternalities;
 use sp_keyring::AccountKeyring::Alice;
@@ -80,7 +81,7 @@ fn submit_data(
 		CheckEra::<Runtime>::from(Era::Immortal),
 		CheckNonce::<Runtime>::from(nonce),
 		CheckWeight::<Runtime>::new(),
-		ChargeTransactionPayment::<Runtime>::from(0),
+		PrioritizedTransactionPayment::<Runtime>::from(0),
 		CheckAppId::<Runtime>::from(app_id),
 	);
 	let payload =
@@ -1,4 +1,4 @@
-use super::kate::{Error as RTKateError, GDataProof, GRow};
+use super::kate::{DataLocation, Error as RTKateError, GCellBlock, GDataProof, GMultiProof, GRow};
 use crate::{
 	constants, mmr, version::VERSION, AccountId, AuthorityDiscovery, Babe, Block, BlockNumber,
 	EpochDuration, Executive, Grandpa, Historical, Index, InherentDataExt, Mmr, NominationPools,
@@ -8,12 +8,13 @@ use crate::{
 use avail_base::{HeaderExtensionBuilderData, ProvidePostInherent};
 use avail_core::{
 	currency::Balance,
-	data_proof::{DataProof, ProofResponse, SubTrie},
+	data_proof::{AddressedMessage, DataProof, ProofResponse, SubTrie},
 	header::HeaderExtension,
-	OpaqueExtrinsic,
+	AppId, OpaqueExtrinsic,
 };
 
 use frame_system::limits::BlockLength;
+use pallet_vector::{FreezeStatus, MessageStatusEnum, VerifiedHead, WithdrawalReceipt};
 
 use frame_support::{
 	genesis_builder_helper::{build_config, create_default_config},
@@ -41,6 +42,12 @@ decl_runtime_apis! {
 	#[api_version(2)]
 	pub trait DataAvailApi {
 		fn block_length() -> BlockLength;
+		#[api_version(3)]
+		fn app_subtree(app_id: AppId) -> Vec<AppId>;
+		#[api_version(4)]
+		fn submit_data_fee(len: u32, app_id: AppId, nonce: u32) -> Option<Balance>;
+		#[api_version(5)]
+		fn grid_estimate(blobs: Vec<(AppId, u32)>) -> (da_control::GridEstimate, Balance);
 	}
 
 	pub trait ExtensionBuilder {
@@ -56,16 +63,49 @@ decl_runtime_apis! {
 		fn check_if_extrinsic_is_post_inherent(uxt: &<Block as BlockT>::Extrinsic) -> bool;
 	}
 
+	#[api_version(2)]
 	pub trait VectorApi {
+		fn sync_committee_poseidons(domain: u32, period: u64) -> U256;
+		fn head(domain: u32) -> u64;
+		fn headers(domain: u32, slot: u64) -> H256;
+		#[changed_in(2)]
 		fn sync_committee_poseidons(slot: u64) -> U256;
+		#[changed_in(2)]
 		fn head() -> u64;
+		#[changed_in(2)]
 		fn headers(slot: u64) -> H256;
+		#[api_version(3)]
+		fn message_status(message_root: H256) -> MessageStatusEnum;
+		#[api_version(3)]
+		fn executed_in_range(from: u32, to: u32) -> Vec<H256>;
+		#[api_version(4)]
+		fn estimate_execute(message: Vec<u8>, account_proof_len: u32, storage_proof_len: u32) -> Option<RuntimeDispatchInfo<Balance>>;
+		#[api_version(5)]
+		fn message_preimage(message_id: u64) -> Option<AddressedMessage>;
+		#[api_version(6)]
+		fn freeze_status(domain: u32) -> Option<FreezeStatus<BlockNumber>>;
+		#[api_version(7)]
+		fn latest_verified_head(domain: u32) -> Option<VerifiedHead>;
+		#[api_version(8)]
+		fn withdrawal_receipt(message_id: u64) -> Option<(BlockNumber, WithdrawalReceipt)>;
+		#[api_version(8)]
+		fn withdrawal_proof(message_id: u64, extrinsics: Vec<OpaqueExtrinsic>) -> Option<ProofResponse>;
+		#[api_version(9)]
+		fn executed_messages(from: u32, to: u32) -> Vec<(u64, u32, H256, u128)>;
 	}
 
 	pub trait KateApi {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse>;
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError >;
 		fn proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)> ) -> Result<Vec<GDataProof>, RTKateError>;
+		#[api_version(2)]
+		fn data_location(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, tx_idx: u32) -> Result<Option<DataLocation>, RTKateError>;
+		#[api_version(3)]
+		fn app_data_ranges(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength) -> Result<Vec<(AppId, u32, u32)>, RTKateError>;
+		#[api_version(4)]
+		fn multiproof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)>) -> Result<Vec<(GMultiProof, GCellBlock)>, RTKateError>;
+		#[api_version(5)]
+		fn leaf_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, leaf_idx: u32) -> Option<ProofResponse>;
 	}
 }
 
@@ -344,10 +384,23 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(5)]
 	impl crate::apis::DataAvailApi<Block> for Runtime {
 		fn block_length() -> frame_system::limits::BlockLength {
 			frame_system::Pallet::<Runtime>::block_length()
 		}
+
+		fn app_subtree(app_id: AppId) -> Vec<AppId> {
+			da_control::Pallet::<Runtime>::app_subtree(app_id)
+		}
+
+		fn submit_data_fee(len: u32, app_id: AppId, nonce: u32) -> Option<Balance> {
+			crate::impls::submit_data_fee(len, app_id, nonce)
+		}
+
+		fn grid_estimate(blobs: Vec<(AppId, u32)>) -> (da_control::GridEstimate, Balance) {
+			da_control::Pallet::<Runtime>::grid_estimate(&blobs)
+		}
 	}
 
 	#[api_version(4)]
@@ -386,20 +439,81 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(9)]
 	impl crate::apis::VectorApi<Block> for Runtime {
-		fn sync_committee_poseidons(slot: u64) -> U256 {
-			pallet_vector::Pallet::<Runtime>::sync_committee_poseidons(slot)
+		fn sync_committee_poseidons(domain: u32, period: u64) -> U256 {
+			pallet_vector::Pallet::<Runtime>::sync_committee_poseidons(domain, period)
+		}
+
+		fn head(domain: u32) -> u64 {
+			pallet_vector::Pallet::<Runtime>::head(domain)
+		}
+
+		fn headers(domain: u32, slot: u64) -> H256 {
+			pallet_vector::Pallet::<Runtime>::headers(domain, slot)
+		}
+
+		fn message_status(message_root: H256) -> MessageStatusEnum {
+			pallet_vector::Pallet::<Runtime>::message_status(message_root)
+		}
+
+		fn executed_in_range(from: u32, to: u32) -> Vec<H256> {
+			pallet_vector::Pallet::<Runtime>::executed_in_range(from, to)
+		}
+
+		fn estimate_execute(message: Vec<u8>, account_proof_len: u32, storage_proof_len: u32) -> Option<RuntimeDispatchInfo<Balance>> {
+			use codec::{Decode, Encode};
+			use frame_support::BoundedVec;
+
+			// Proofs are split into trie nodes of the maximum node size.
+			let proof_of_len = |len: u32| -> pallet_vector::ValidProof {
+				let nodes = sp_std::vec![0u8; len as usize]
+					.chunks(2048)
+					.map(|node| BoundedVec::truncate_from(node.to_vec()))
+					.collect::<Vec<_>>();
+				BoundedVec::truncate_from(nodes)
+			};
+
+			let addr_message = AddressedMessage::decode(&mut message.as_slice()).ok()?;
+			let call = RuntimeCall::Vector(pallet_vector::Call::execute {
+				slot: 0,
+				addr_message,
+				account_proof: proof_of_len(account_proof_len),
+				storage_proof: proof_of_len(storage_proof_len),
+			});
+			// The length fee covers the call only, not the signature and extensions.
+			let len = call.encoded_size() as u32;
+			Some(TransactionPayment::query_call_info(call, len))
+		}
+
+		fn message_preimage(message_id: u64) -> Option<AddressedMessage> {
+			pallet_vector::Pallet::<Runtime>::message_preimage(message_id)
 		}
 
-		fn head() -> u64 {
-			pallet_vector::Pallet::<Runtime>::head()
+		fn freeze_status(domain: u32) -> Option<FreezeStatus<BlockNumber>> {
+			pallet_vector::Pallet::<Runtime>::freeze_status(domain)
 		}
 
-		fn headers(slot: u64) -> H256 {
-			pallet_vector::Pallet::<Runtime>::headers(slot)
+		fn latest_verified_head(domain: u32) -> Option<VerifiedHead> {
+			pallet_vector::Pallet::<Runtime>::latest_verified_head(domain)
+		}
+
+		fn withdrawal_receipt(message_id: u64) -> Option<(BlockNumber, WithdrawalReceipt)> {
+			pallet_vector::Pallet::<Runtime>::withdrawal_receipt(message_id)
+		}
+
+		fn withdrawal_proof(message_id: u64, extrinsics: Vec<OpaqueExtrinsic>) -> Option<ProofResponse> {
+			// `extrinsics` are the ones of the block the withdrawal was sent in.
+			let (block, receipt) = pallet_vector::Pallet::<Runtime>::withdrawal_receipt(message_id)?;
+			<Runtime as crate::apis::KateApi<Block>>::data_proof(block, extrinsics, receipt.tx_index)
+		}
+
+		fn executed_messages(from: u32, to: u32) -> Vec<(u64, u32, H256, u128)> {
+			pallet_vector::Pallet::<Runtime>::executed_transfers_in_range(from, to)
 		}
 	}
 
+	#[api_version(5)]
 	impl crate::apis::KateApi<Block> for Runtime {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse> {
 			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
@@ -443,6 +557,53 @@ impl_runtime_apis! {
 			log::trace!(target: LOG_TARGET, "KateApi::proof: data_proofs={data_proofs:#?}");
 			Ok(data_proofs)
 		}
+
+		fn data_location(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, tx_idx: u32) -> Result<Option<DataLocation>, RTKateError> {
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let Some((leaf_idx, SubTrie::DataSubmit)) = data.leaf_idx(tx_idx) else {
+				return Ok(None);
+			};
+			let leaf_count = data.data_submissions.iter().filter(|s| s.tx_index == tx_idx).count();
+
+			// Each submitted data leaf adds one app extrinsic, in the block order.
+			let (start_row, end_row) = super::kate::data_rows::<Runtime>(data.to_app_extrinsics(), block_len, leaf_idx.try_into()?)?;
+			let location = DataLocation {
+				tx_index: tx_idx,
+				leaf_index: leaf_idx.try_into()?,
+				leaf_count: leaf_count.try_into()?,
+				start_row,
+				end_row,
+			};
+			log::trace!(target: LOG_TARGET, "KateApi::data_location: location={location:?}");
+			Ok(Some(location))
+		}
+
+		fn app_data_ranges(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength) -> Result<Vec<(AppId, u32, u32)>, RTKateError> {
+			let app_extrinsics = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics).to_app_extrinsics();
+			let ranges = super::kate::app_data_ranges::<Runtime>(app_extrinsics, block_len)?;
+			log::trace!(target: LOG_TARGET, "KateApi::app_data_ranges: ranges={ranges:?}");
+			Ok(ranges)
+		}
+
+		fn multiproof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)>) -> Result<Vec<(GMultiProof, GCellBlock)>, RTKateError> {
+			let app_extrinsics = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics).to_app_extrinsics();
+			let multiproofs = super::kate::multiproof::<Runtime>(app_extrinsics, block_len, cells)?;
+			log::trace!(target: LOG_TARGET, "KateApi::multiproof: multiproofs={multiproofs:#?}");
+			Ok(multiproofs)
+		}
+
+		fn leaf_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, leaf_idx: u32) -> Option<ProofResponse> {
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let sub_proof = data.submitted_proof_of(usize::try_from(leaf_idx).ok()?)?;
+
+			let data_proof = DataProof::new(SubTrie::DataSubmit, data.roots(), sub_proof);
+			let proof = ProofResponse::new(data_proof, None);
+			log::trace!(
+				target: LOG_TARGET,
+				"KateApi::leaf_proof: leaf_idx={leaf_idx:?} proof={proof:#?}");
+
+			Some(proof)
+		}
 	}
 
 	impl avail_base::PostInherentsProvider<Block> for Runtime {
@@ -344,18 +344,35 @@ pub mod preimage {
 }
 
 pub mod da {
-	use avail_core::{BlockLengthColumns, BlockLengthRows};
+	use avail_core::{currency::NANO_AVAIL, BlockLengthColumns, BlockLengthRows};
 
 	use super::*;
 
 	parameter_types! {
 		pub const MinBlockRows: BlockLengthRows = BlockLengthRows(32);
-		pub const MaxBlockRows: BlockLengthRows = BlockLengthRows(1024);
+		pub const MaxBlockRows: BlockLengthRows = BlockLengthRows(4096);
 		pub const MinBlockCols: BlockLengthColumns = BlockLengthColumns(64);
-		pub const MaxBlockCols: BlockLengthColumns = kate::config::MAX_BLOCK_COLUMNS;
+		pub const MaxBlockCols: BlockLengthColumns = BlockLengthColumns(512);
+		pub const AppQuotaEraLength: u32 =
+			staking::SessionsPerEra::get() * time::EpochDuration::get();
+		pub const MinDataBytePrice: Balance = 10 * NANO_AVAIL;
+		pub const AppKeyDeposit: Balance = 10 * AVAIL;
+		pub const TargetGridFill: Perbill = Perbill::from_percent(50);
+		pub const MaxReservedBlockSpace: Perbill = Perbill::from_percent(50);
+		pub const MaxRetentionPeriod: u32 = 30 * time::DAYS;
+		pub const MaxAppUsageHistory: u32 = 30;
+		pub const AppKeyRetirementPeriod: u32 = 7 * time::DAYS;
+		pub const MaxIntentLookahead: u32 = 1 * time::HOURS;
+		pub const SubmissionReceiptPeriod: u32 = 1 * time::DAYS;
+		pub const BlockLengthChangeDelay: u32 = 1 * time::DAYS;
 	}
+	pub type DataBytePriceChangeDenominator = ConstU32<8>;
 	pub type MaxAppKeyLength = ConstU32<64>;
 	pub type MaxAppDataLength = ConstU32<524_288>; // 512 Kb
+	pub type MaxAppSubmitters = ConstU32<64>;
+	pub type MaxBlockSpaceIntents = ConstU32<64>;
+	pub type MaxAppChildren = ConstU32<16>;
+	pub type MinChargedDataLength = ConstU32<128>;
 }
 
 /// Macro to set a value (e.g. when using the `parameter_types` macro) to either a production value
@@ -3,7 +3,7 @@ use crate::{Runtime, SignedExtra, UncheckedExtrinsic};
 
 use avail_base::HeaderExtensionBuilderData;
 use avail_core::data_proof::{BoundedData, Message, TxDataRoots};
-use da_control::{AppDataFor, Call as DaCall, CheckAppId};
+use da_control::{AppDataFor, Call as DaCall, CheckAppId, PrioritizedTransactionPayment};
 use frame_system::{
 	CheckEra, CheckGenesis, CheckNonZeroSender, CheckNonce, CheckSpecVersion, CheckTxVersion,
 	CheckWeight,
@@ -17,7 +17,6 @@ use binary_merkle_tree::{verify_proof, Leaf, MerkleProof};
 use codec::{Compact, Encode};
 use derive_more::Constructor;
 use hex_literal::hex;
-use pallet_transaction_payment::ChargeTransactionPayment;
 use sp_core::H256;
 use sp_keyring::AccountKeyring::{Alice, Bob};
 use sp_runtime::traits::Keccak256;
@@ -110,7 +109,7 @@ fn extra() -> SignedExtra {
 		CheckEra::<Runtime>::from(Era::Mortal(32, 2)),
 		CheckNonce::<Runtime>::from(0),
 		CheckWeight::<Runtime>::new(),
-		ChargeTransactionPayment::<Runtime>::from(0),
+		PrioritizedTransactionPayment::<Runtime>::from(0),
 		CheckAppId::<Runtime>::from(AppId(1)),
 	)
 }
@@ -294,6 +293,48 @@ mod to_app_extrinsics_tests {
 	}
 }
 
+#[cfg(test)]
+mod batch_tests {
+	use super::*;
+	use frame_system::limits::BlockLength;
+
+	fn submit_data_batch(entries: Vec<(u32, Vec<u8>)>) -> Vec<u8> {
+		let batch = entries
+			.into_iter()
+			.map(|(id, data)| (AppId(id), AppDataFor::<Runtime>::truncate_from(data)))
+			.collect::<Vec<_>>();
+		let function = DaCall::submit_data_batch {
+			batch: batch.try_into().unwrap(),
+		}
+		.into();
+
+		signed_extrinsic(function)
+	}
+
+	#[test]
+	fn batch_entries_are_app_extrinsics_of_their_application() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let extrinsics = vec![
+				submit_data_batch(vec![(2, vec![0xAA; 100]), (1, vec![0xBB; 100])]),
+				submit_data(hex!("abcd").to_vec()),
+			];
+			let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(1, &extrinsics);
+
+			let app_extrinsics = data.to_app_extrinsics();
+			let ids = app_extrinsics.iter().map(|e| e.app_id).collect::<Vec<_>>();
+			assert_eq!(ids, vec![AppId(2), AppId(1), AppId(1)]);
+			assert_eq!(app_extrinsics[0].data, vec![0xAA; 100]);
+
+			let ranges =
+				crate::kate::app_data_ranges::<Runtime>(app_extrinsics, BlockLength::default())
+					.unwrap();
+			let ids = ranges.iter().map(|(id, _, _)| *id).collect::<Vec<_>>();
+			assert_eq!(ids, vec![AppId(1), AppId(2)]);
+			assert!(ranges[0].2 <= ranges[1].1);
+		});
+	}
+}
+
 #[cfg(test)]
 mod bridge_tests {
 
@@ -669,3 +710,73 @@ mod data_root {
 		assert_eq!(expected_hash, keccak_256(encoded_message.as_slice()));
 	}
 }
+
+#[cfg(test)]
+mod multisig_tests {
+	use super::*;
+	use frame_support::weights::Weight;
+	use frame_system::Phase;
+	use pallet_multisig::{Call as MultisigCall, Event as MultisigEvent, Timepoint};
+
+	fn submission() -> RuntimeCall {
+		let data = AppDataFor::<Runtime>::truncate_from(hex!("abcd").to_vec());
+		DaCall::submit_data { data }.into()
+	}
+
+	fn as_multi(maybe_timepoint: Option<Timepoint<BlockNumber>>) -> Vec<u8> {
+		let function = MultisigCall::as_multi {
+			threshold: 2,
+			other_signatories: vec![Bob.to_account_id()],
+			maybe_timepoint,
+			call: Box::new(submission()),
+			max_weight: Weight::zero(),
+		}
+		.into();
+
+		signed_extrinsic(function)
+	}
+
+	fn submitted_data(extrinsics: &[Vec<u8>]) -> Vec<Vec<u8>> {
+		HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(1, extrinsics)
+			.to_app_extrinsics()
+			.into_iter()
+			.map(|app_extrinsic| app_extrinsic.data)
+			.collect()
+	}
+
+	#[test]
+	fn approvals_are_not_data_submissions() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let timepoint = Timepoint {
+				height: 1,
+				index: 0,
+			};
+			assert!(submitted_data(&[as_multi(None)]).is_empty());
+			assert!(submitted_data(&[as_multi(Some(timepoint))]).is_empty());
+		});
+	}
+
+	#[test]
+	fn final_approval_is_a_data_submission() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let timepoint = Timepoint {
+				height: 1,
+				index: 0,
+			};
+			System::set_block_number(1);
+			System::note_finished_initialize();
+			System::deposit_event(MultisigEvent::<Runtime>::MultisigExecuted {
+				approving: Alice.to_account_id(),
+				timepoint,
+				multisig: Bob.to_account_id(),
+				call_hash: sp_io::hashing::blake2_256(&submission().encode()),
+				result: Ok(()),
+			});
+
+			assert_eq!(
+				submitted_data(&[as_multi(Some(timepoint))]),
+				vec![hex!("abcd").to_vec()]
+			);
+		});
+	}
+}
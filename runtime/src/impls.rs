@@ -1,10 +1,10 @@
 use crate::{
 	constants, prod_or_fast, voter_bags, weights, AccountId, AccountIndex, Babe, Balances, Block,
-	BlockNumber, ElectionProviderMultiPhase, Everything, Hash, Header, Historical, ImOnline,
-	ImOnlineId, Index, Indices, Moment, NominationPools, Offences, OriginCaller, PalletInfo,
-	Preimage, ReserveIdentifier, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
-	RuntimeHoldReason, RuntimeOrigin, RuntimeVersion, Session, SessionKeys, Signature,
-	SignedPayload, Staking, System, Timestamp, TransactionPayment, Treasury, TxPause,
+	BlockNumber, DataAvailability, ElectionProviderMultiPhase, Everything, Hash, Header,
+	Historical, ImOnline, ImOnlineId, Index, Indices, Moment, NominationPools, Offences,
+	OriginCaller, PalletInfo, Preimage, ReserveIdentifier, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeVersion, Session, SessionKeys,
+	Signature, SignedPayload, Staking, System, Timestamp, TransactionPayment, Treasury, TxPause,
 	UncheckedExtrinsic, VoterList, MINUTES, SLOT_DURATION, VERSION,
 };
 use avail_core::{
@@ -38,12 +38,13 @@ use pallet_identity::legacy::IdentityInfo;
 use pallet_transaction_payment::{FungibleAdapter, Multiplier, TargetedFeeAdjustment};
 use pallet_treasury::TreasuryAccountId;
 use pallet_tx_pause::RuntimeCallNameOf;
-use sp_core::{ConstU64, RuntimeDebug};
+use sp_core::{sr25519, ConstU64, RuntimeDebug};
 use sp_runtime::{
 	generic::Era,
-	traits::{self, BlakeTwo256, Bounded, Convert, IdentityLookup, OpaqueKeys},
-	FixedPointNumber, FixedU128, Perbill, Permill, Perquintill,
+	traits::{self, BlakeTwo256, Bounded, Convert, IdentityLookup, OpaqueKeys, StaticLookup},
+	FixedPointNumber, FixedU128, Perbill, Permill, Perquintill, SaturatedConversion,
 };
+use sp_std::{vec, vec::Vec};
 
 pub type NegativeImbalance<T> = <pallet_balances::Pallet<T> as Currency<
 	<T as frame_system::Config>::AccountId,
@@ -67,6 +68,10 @@ impl pallet_mandate::Config for Runtime {
 
 parameter_types! {
 	pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+	pub const DaControlPalletId: PalletId = PalletId(*b"avl/dact");
+	pub const ArbitraryMessageByteFee: Balance = 10 * NANO_AVAIL;
+	pub MaxBridgedCallWeight: Weight = Perbill::from_percent(10) *
+		constants::system::RuntimeBlockWeights::get().max_block;
 }
 
 impl pallet_vector::Config for Runtime {
@@ -78,6 +83,38 @@ impl pallet_vector::Config for Runtime {
 	type MessageMappingStorageIndex = ConstU64<1>;
 	type PalletId = BridgePalletId;
 	type AvailDomain = ConstU32<1>;
+	type MessageHandler = ();
+	type Assets = ();
+	type AddressMapping = pallet_vector::TruncatedAddress;
+	type AccountMapping = pallet_vector::TruncatedAddress;
+	type OnNewVerifiedHead = ();
+	type OnMessageExecuted = ();
+	type AddressBindingOrigin = pallet_vector::EnsureBridgedOrigin;
+	type RuntimeBridgedOrigin = RuntimeOrigin;
+	type MaxBridgedCallWeight = MaxBridgedCallWeight;
+	type DomainPauseOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>,
+	>;
+	type WhitelistOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>,
+	>;
+	type EmergencyPauseOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>,
+	>;
+	type MaxEmergencyPauseDuration = ConstU32<{ 7 * DAYS }>;
+	type StateRootRollbackOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+	>;
+	type ProverUnbondingPeriod = ConstU32<{ 28 * DAYS }>;
+	type MessagePreimageRetention = ConstU32<{ 7 * DAYS }>;
+	type VerificationKeyActivationDelay = ConstU32<{ 2 * DAYS }>;
+	type ArbitraryMessageByteFee = ArbitraryMessageByteFee;
+	type MessageVolumeTarget = ConstU32<20>;
+	type MaxFeeMultiplier = ConstU32<100>;
 }
 
 parameter_types! {
@@ -121,6 +158,25 @@ impl da_control::Config for Runtime {
 	type BlockLenProposalId = u32;
 	type MaxAppDataLength = constants::da::MaxAppDataLength;
 	type MaxAppKeyLength = constants::da::MaxAppKeyLength;
+	type MaxAppSubmitters = constants::da::MaxAppSubmitters;
+	type AppQuotaEraLength = constants::da::AppQuotaEraLength;
+	type Currency = Balances;
+	type AppOriginFilter = ProxiedAppOrigin;
+	type MinDataBytePrice = constants::da::MinDataBytePrice;
+	type AppKeyDeposit = constants::da::AppKeyDeposit;
+	type TargetGridFill = constants::da::TargetGridFill;
+	type MaxReservedBlockSpace = constants::da::MaxReservedBlockSpace;
+	type MaxRetentionPeriod = constants::da::MaxRetentionPeriod;
+	type MaxAppUsageHistory = constants::da::MaxAppUsageHistory;
+	type AppKeyRetirementPeriod = constants::da::AppKeyRetirementPeriod;
+	type MaxIntentLookahead = constants::da::MaxIntentLookahead;
+	type MaxBlockSpaceIntents = constants::da::MaxBlockSpaceIntents;
+	type MaxAppChildren = constants::da::MaxAppChildren;
+	type BlockLengthChangeDelay = constants::da::BlockLengthChangeDelay;
+	type MinChargedDataLength = constants::da::MinChargedDataLength;
+	type SubmissionReceiptPeriod = constants::da::SubmissionReceiptPeriod;
+	type DataBytePriceChangeDenominator = constants::da::DataBytePriceChangeDenominator;
+	type PalletId = DaControlPalletId;
 	type MaxBlockCols = constants::da::MaxBlockCols;
 	type MaxBlockRows = constants::da::MaxBlockRows;
 	type MinBlockCols = constants::da::MinBlockCols;
@@ -169,7 +225,8 @@ impl pallet_transaction_payment::Config for Runtime {
 		MaximumMultiplier,
 	>;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
-	type OnChargeTransaction = FungibleAdapter<Balances, DealWithFees<Runtime>>;
+	type OnChargeTransaction =
+		da_control::SponsoredFeeAdapter<Runtime, FungibleAdapter<Balances, DealWithFees<Runtime>>>;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightToFee = ConstantMultiplier<Balance, WeightFee>; // 1 weight = 10 picoAVAIL -> second_price = 10 AVAIL
@@ -702,6 +759,7 @@ pub enum ProxyType {
 	Staking,
 	IdentityJudgement,
 	NominationPools,
+	DataAvailability,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -740,6 +798,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				c,
 				RuntimeCall::NominationPools(..) | RuntimeCall::Utility(..)
 			),
+			ProxyType::DataAvailability => matches!(
+				c,
+				RuntimeCall::DataAvailability(..) | RuntimeCall::Utility(..)
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -753,6 +815,108 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	}
 }
 
+/// Unwraps the proxy, multisig and derivative calls, so the data submissions they wrap are
+/// validated against the account they are dispatched for.
+pub struct ProxiedAppOrigin;
+
+impl ProxiedAppOrigin {
+	/// Returns the multisig account of `who` and `other_signatories` for `threshold`.
+	fn multisig_account(
+		who: &AccountId,
+		other_signatories: &[AccountId],
+		threshold: u16,
+	) -> AccountId {
+		let mut signatories = other_signatories.to_vec();
+		signatories.push(who.clone());
+		signatories.sort();
+		pallet_multisig::Pallet::<Runtime>::multi_account_id(&signatories, threshold)
+	}
+
+	/// Returns whether the approval of `who` is the final one of the multisig operation of
+	/// `call`, which dispatches it once its approvals reach `threshold`.
+	fn is_final_approval(
+		who: &AccountId,
+		threshold: u16,
+		other_signatories: &[AccountId],
+		maybe_timepoint: &Option<pallet_multisig::Timepoint<BlockNumber>>,
+		call: &RuntimeCall,
+	) -> bool {
+		// The fields of `pallet_multisig::Multisig` are private, so the operation is decoded as
+		// its `when`, `deposit`, `depositor` and `approvals` fields.
+		type Operation = (
+			pallet_multisig::Timepoint<BlockNumber>,
+			Balance,
+			AccountId,
+			Vec<AccountId>,
+		);
+
+		let multisig = Self::multisig_account(who, other_signatories, threshold);
+		let call_hash = sp_io::hashing::blake2_256(&call.encode());
+		let key = pallet_multisig::Multisigs::<Runtime>::hashed_key_for(&multisig, call_hash);
+		let Some((when, _, _, approvals)) =
+			frame_support::storage::unhashed::get::<Operation>(&key)
+		else {
+			return false;
+		};
+
+		let approvals = approvals
+			.len()
+			.saturating_add(usize::from(!approvals.contains(who)));
+		*maybe_timepoint == Some(when) && approvals >= usize::from(threshold)
+	}
+}
+
+impl da_control::AppOriginFilter<AccountId, RuntimeCall> for ProxiedAppOrigin {
+	fn inner_call<'a>(who: &AccountId, call: &'a RuntimeCall) -> Option<&'a RuntimeCall> {
+		match call {
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy { call, .. })
+			| RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 { call, .. })
+			| RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. }) => Some(call.as_ref()),
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi {
+				threshold,
+				other_signatories,
+				maybe_timepoint,
+				call,
+				..
+			}) => Self::is_final_approval(who, *threshold, other_signatories, maybe_timepoint, call)
+				.then_some(call.as_ref()),
+			_ => None,
+		}
+	}
+
+	fn inner_origin(who: &AccountId, call: &RuntimeCall) -> Option<AccountId> {
+		match call {
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+				real,
+				force_proxy_type,
+				call,
+			}) => {
+				let real = Indices::lookup(real.clone()).ok()?;
+				let proxy = pallet_proxy::Pallet::<Runtime>::find_proxy(
+					&real,
+					who,
+					force_proxy_type.clone(),
+				)
+				.ok()?;
+				(proxy.delay == 0 && proxy.proxy_type.filter(call)).then_some(real)
+			},
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 {
+				other_signatories,
+				..
+			}) => Some(Self::multisig_account(who, other_signatories, 1)),
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi {
+				threshold,
+				other_signatories,
+				..
+			}) => Some(Self::multisig_account(who, other_signatories, *threshold)),
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { index, .. }) => Some(
+				pallet_utility::Pallet::<Runtime>::derivative_account_id(who.clone(), *index),
+			),
+			_ => None,
+		}
+	}
+}
+
 parameter_types! {
 	// One storage item; key size 32, value size 8; .
 	pub const ProxyDepositBase: Balance = 10 * AVAIL;
@@ -885,8 +1049,6 @@ where
 		RuntimeCall,
 		<UncheckedExtrinsic as traits::Extrinsic>::SignaturePayload,
 	)> {
-		use sp_runtime::{traits::StaticLookup, SaturatedConversion as _};
-
 		let tip = 0;
 		// take the biggest period possible.
 		let period = BlockHashCount::get()
@@ -907,7 +1069,7 @@ where
 			frame_system::CheckEra::<Runtime>::from(era),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			da_control::PrioritizedTransactionPayment::<Runtime>::from(tip),
 			da_control::CheckAppId::<Runtime>::from(AppId(0)),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
@@ -922,6 +1084,36 @@ where
 	}
 }
 
+/// Returns the fee of a `submit_data` extrinsic of `len` bytes of data for the application
+/// `app_id`, signed with `nonce` in a mortal era and without tip: its transaction fee under the
+/// current fee multiplier, plus the price of its data. Returns `None` if `len` exceeds
+/// `MaxAppDataLength`.
+pub fn submit_data_fee(len: u32, app_id: AppId, nonce: Index) -> Option<Balance> {
+	let data = vec![0u8; len as usize].try_into().ok()?;
+	let call = RuntimeCall::DataAvailability(da_control::Call::submit_data { data });
+
+	let extra = (
+		frame_system::CheckNonZeroSender::<Runtime>::new(),
+		frame_system::CheckSpecVersion::<Runtime>::new(),
+		frame_system::CheckTxVersion::<Runtime>::new(),
+		frame_system::CheckGenesis::<Runtime>::new(),
+		frame_system::CheckEra::<Runtime>::from(Era::mortal(BlockHashCount::get().into(), 0)),
+		frame_system::CheckNonce::<Runtime>::from(nonce),
+		frame_system::CheckWeight::<Runtime>::new(),
+		da_control::PrioritizedTransactionPayment::<Runtime>::from(0),
+		da_control::CheckAppId::<Runtime>::from(app_id),
+	);
+	let address = Indices::unlookup(AccountId::new([0; 32]));
+	let signature = Signature::Sr25519(sr25519::Signature::from_raw([0; 64]));
+	let extrinsic = UncheckedExtrinsic::new_signed(call.clone(), address, signature, extra);
+	let extrinsic_len = extrinsic.encoded_size().saturated_into();
+
+	let fee = TransactionPayment::query_call_fee_details(call, extrinsic_len).final_fee();
+	let data_len = DataAvailability::charged_data_len(len as usize);
+	let data_fee = DataAvailability::data_byte_price().saturating_mul(data_len.saturated_into());
+	Some(fee.saturating_add(data_fee))
+}
+
 impl frame_system::offchain::SigningTypes for Runtime {
 	type Public = <Signature as traits::Verify>::Signer;
 	type Signature = Signature;
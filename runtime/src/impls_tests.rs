@@ -623,3 +623,73 @@ mod tests {
 		);
 	}
 }
+
+#[cfg(test)]
+mod app_origin_tests {
+	use crate::impls::ProxiedAppOrigin;
+	use crate::*;
+	use da_control::{AppDataFor, AppOriginFilter};
+	use frame_support::{assert_ok, traits::Currency, weights::Weight};
+	use sp_keyring::AccountKeyring::{Alice, Bob};
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		t.execute_with(|| System::set_block_number(1));
+		t
+	}
+
+	fn submission() -> RuntimeCall {
+		let data = AppDataFor::<Runtime>::truncate_from(vec![1, 2, 3]);
+		da_control::Call::submit_data { data }.into()
+	}
+
+	fn as_multi(
+		other: AccountId,
+		maybe_timepoint: Option<pallet_multisig::Timepoint<BlockNumber>>,
+	) -> RuntimeCall {
+		pallet_multisig::Call::as_multi {
+			threshold: 2,
+			other_signatories: vec![other],
+			maybe_timepoint,
+			call: Box::new(submission()),
+			max_weight: Weight::zero(),
+		}
+		.into()
+	}
+
+	#[test]
+	fn multisig_submission_is_unwrapped_on_final_approval() {
+		new_test_ext().execute_with(|| {
+			let (alice, bob) = (Alice.to_account_id(), Bob.to_account_id());
+			Balances::make_free_balance_be(&alice, 1_000 * AVAIL);
+
+			// The first approval only opens the multisig operation.
+			let first = as_multi(bob.clone(), None);
+			assert_eq!(ProxiedAppOrigin::inner_call(&alice, &first), None);
+
+			let timepoint = Multisig::timepoint();
+			assert_ok!(Multisig::as_multi(
+				RuntimeOrigin::signed(alice.clone()),
+				2,
+				vec![bob.clone()],
+				None,
+				Box::new(submission()),
+				Weight::zero(),
+			));
+
+			// The opener approving again does not dispatch it either.
+			let again = as_multi(bob.clone(), Some(timepoint));
+			assert_eq!(ProxiedAppOrigin::inner_call(&alice, &again), None);
+
+			let last = as_multi(alice, Some(timepoint));
+			assert_eq!(
+				ProxiedAppOrigin::inner_call(&bob, &last),
+				Some(&submission())
+			);
+		});
+	}
+}
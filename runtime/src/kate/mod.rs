@@ -2,7 +2,7 @@ pub mod native;
 pub mod runtime;
 
 // Reexport
-pub use runtime::{grid, proof};
+pub use runtime::{app_data_ranges, data_rows, grid, multiproof, proof, verify_proofs};
 
 use codec::{Decode, Encode};
 use core::num::TryFromIntError;
@@ -20,6 +20,8 @@ use kate::{com::Error as KateError, gridgen::AppRowError as KateAppRowError};
 pub type GRawScalar = U256;
 pub type GRow = Vec<GRawScalar>;
 pub type GDataProof = (GRawScalar, GProof);
+/// Cells of a block of the grid, row by row, and their multiproof.
+pub type GMultiProof = (Vec<GRawScalar>, GProof);
 
 /// # NOTE
 /// `Serde` requires a custom implementation for `GProof` due to the array size (greater than `[T;32]`).
@@ -48,6 +50,38 @@ impl TryFrom<Vec<u8>> for GProof {
 	}
 }
 
+/// Block of cells of the extended grid covered by a multiproof, bounds excluded at the end.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GCellBlock {
+	/// First column of the block.
+	pub start_x: u32,
+	/// First row of the block.
+	pub start_y: u32,
+	/// Column following the last column of the block.
+	pub end_x: u32,
+	/// Row following the last row of the block.
+	pub end_y: u32,
+}
+
+/// Location of the data submitted by an extrinsic, needed to build its data and cell proofs.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct DataLocation {
+	/// Index of the extrinsic in the block.
+	pub tx_index: u32,
+	/// Index of its first leaf in the submitted data sub trie of the data root.
+	pub leaf_index: u32,
+	/// Number of its leaves, more than one for a batch.
+	pub leaf_count: u32,
+	/// First row of the grid, before its extension, holding its first leaf.
+	pub start_row: u32,
+	/// Last row of the grid, before its extension, holding its first leaf.
+	pub end_row: u32,
+}
+
 #[derive(Error, Encode, Decode, TypeInfo, PassByCodec, Debug)]
 pub enum Error {
 	#[error("Invalid integer conversion")]
@@ -68,6 +102,10 @@ pub enum Error {
 	Proof,
 	#[error("Failed to extend columns")]
 	ColumnExtension,
+	#[error("Missing app extrinsic {0}")]
+	MissingExtrinsic(u32),
+	#[error("Missing commitment of row {0}")]
+	MissingCommitment(u32),
 }
 
 impl From<TryFromIntError> for Error {
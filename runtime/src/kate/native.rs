@@ -1,25 +1,121 @@
-use super::{Error, GDataProof, GProof, GRawScalar, GRow};
+use super::{Error, GCellBlock, GDataProof, GMultiProof, GProof, GRawScalar, GRow};
 use avail_core::{AppExtrinsic, AppId, BlockLengthColumns, BlockLengthRows};
+#[cfg(feature = "std")]
+use codec::{Compact, CompactLen as _, Encode as _};
 use core::num::NonZeroU16;
 use frame_system::{limits::BlockLength, native::hosted_header_builder::MIN_WIDTH};
 use kate::Seed;
 #[cfg(feature = "std")]
 use kate::{
 	com::Cell,
+	config::DATA_CHUNK_SIZE,
 	couscous::multiproof_params,
-	gridgen::{AsBytes as _, EvaluationGrid as EGrid},
+	gridgen::{AsBytes as _, EvaluationGrid as EGrid, PolynomialGrid},
 	pmp::m1_blst::M1NoPrecomp,
 };
+#[cfg(feature = "std")]
+use kate_recovery::{
+	data::Cell as DataCell,
+	matrix::{Dimensions, Position},
+	proof::verify,
+};
+#[cfg(feature = "std")]
+use lru::LruCache;
+#[cfg(feature = "std")]
+use sp_core::hashing::blake2_256;
 use sp_runtime::SaturatedConversion as _;
 use sp_runtime_interface::runtime_interface;
 use sp_std::vec::Vec;
+#[cfg(feature = "std")]
+use std::{
+	collections::BTreeSet,
+	sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+#[cfg(feature = "std")]
+static SRS: OnceLock<M1NoPrecomp> = OnceLock::new();
+
+/// Size of a serialized KZG commitment or proof.
+#[cfg(feature = "std")]
+const COMMITMENT_SIZE: usize = 48;
+
+/// Bytes of extended grids and row polynomials kept in the cache.
+#[cfg(feature = "std")]
+const GRID_CACHE_BYTES: usize = 256 * 1024 * 1024;
+
+/// Extended grid of a block and its row polynomials, which are only built for proof queries.
+#[cfg(feature = "std")]
+struct CachedGrid {
+	grid: EGrid,
+	poly: OnceLock<PolynomialGrid>,
+}
+
+#[cfg(feature = "std")]
+impl CachedGrid {
+	/// Bytes of the grid along with its row polynomials, which hold as many scalars.
+	fn bytes(&self) -> usize {
+		let dims = self.grid.dims();
+		dims.width()
+			.saturating_mul(dims.height())
+			.saturating_mul(2 * 32)
+	}
+
+	/// Returns the row polynomials of the grid, building them on the first proof query.
+	fn poly(&self) -> Result<&PolynomialGrid, Error> {
+		if let Some(poly) = self.poly.get() {
+			return Ok(poly);
+		}
+		let poly = self.grid.make_polynomial_grid()?;
+		Ok(self.poly.get_or_init(|| poly))
+	}
+}
+
+/// Cached extended grids, bounded by their bytes.
+#[cfg(feature = "std")]
+struct GridCache {
+	grids: LruCache<[u8; 32], Arc<CachedGrid>>,
+	bytes: usize,
+}
 
 #[cfg(feature = "std")]
-static SRS: std::sync::OnceLock<M1NoPrecomp> = std::sync::OnceLock::new();
+impl GridCache {
+	fn get(&mut self, key: &[u8; 32]) -> Option<Arc<CachedGrid>> {
+		self.grids.get(key).cloned()
+	}
+
+	/// Inserts `cached`, evicting the least recently queried grids beyond `GRID_CACHE_BYTES`.
+	/// A grid larger than the whole cache is not kept.
+	fn put(&mut self, key: [u8; 32], cached: Arc<CachedGrid>) {
+		let bytes = cached.bytes();
+		if bytes > GRID_CACHE_BYTES {
+			return;
+		}
+
+		if let Some(replaced) = self.grids.put(key, cached) {
+			self.bytes = self.bytes.saturating_sub(replaced.bytes());
+		}
+		self.bytes = self.bytes.saturating_add(bytes);
+		while self.bytes > GRID_CACHE_BYTES {
+			let Some((_, evicted)) = self.grids.pop_lru() else {
+				break;
+			};
+			self.bytes = self.bytes.saturating_sub(evicted.bytes());
+		}
+	}
+}
+
+/// Extended grids of the last blocks queried, keyed by the hash of their extrinsics, block
+/// length and seed, so repeated kate queries of a recent block do not rebuild its grid.
+#[cfg(feature = "std")]
+static GRIDS: OnceLock<Mutex<GridCache>> = OnceLock::new();
 
 #[cfg(feature = "std")]
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
+/// Number of rows and columns of blocks the extended grid is divided into by the multiproofs.
+#[cfg(feature = "std")]
+const MULTIPROOF_GRID: (u16, u16) = (16, 64);
+
 /// Hosted function to build the header using `kate` commitments.
 #[runtime_interface]
 pub trait HostedKate {
@@ -29,15 +125,13 @@ pub trait HostedKate {
 		seed: Seed,
 		selected_rows: Vec<u32>,
 	) -> Result<Vec<GRow>, Error> {
-		let (max_width, max_height) = to_width_height(&block_length);
 		let selected_rows = selected_rows
 			.into_par_iter()
 			.map(usize::try_from)
 			.collect::<Result<Vec<_>, _>>()?;
 
-		let grid = EGrid::from_extrinsics(submitted, MIN_WIDTH, max_width, max_height, seed)?
-			.extend_columns(NonZeroU16::new(2).expect("2>0"))
-			.map_err(|_| Error::ColumnExtension)?;
+		let cached = extended_grid(submitted, &block_length, seed)?;
+		let grid = &cached.grid;
 		let rows = selected_rows
 			.into_par_iter()
 			.map(|row_idx| {
@@ -59,12 +153,8 @@ pub trait HostedKate {
 		cells: Vec<(u32, u32)>,
 	) -> Result<Vec<GDataProof>, Error> {
 		let srs = SRS.get_or_init(multiproof_params);
-		let (max_width, max_height) = to_width_height(&block_len);
-		let grid = EGrid::from_extrinsics(extrinsics, MIN_WIDTH, max_width, max_height, seed)?
-			.extend_columns(NonZeroU16::new(2).expect("2>0"))
-			.map_err(|_| Error::ColumnExtension)?;
-
-		let poly = grid.make_polynomial_grid()?;
+		let cached = extended_grid(extrinsics, &block_len, seed)?;
+		let (grid, poly) = (&cached.grid, cached.poly()?);
 
 		let proofs = cells
 			.into_par_iter()
@@ -90,6 +180,95 @@ pub trait HostedKate {
 		Ok(proofs)
 	}
 
+	/// Returns the multiproof of the block of cells of the extended grid holding each of
+	/// `cells`, along with the cells of the block. A single multiproof covers all the cells of
+	/// its block.
+	fn multiproof(
+		extrinsics: Vec<AppExtrinsic>,
+		block_len: BlockLength,
+		seed: Seed,
+		cells: Vec<(u32, u32)>,
+	) -> Result<Vec<(GMultiProof, GCellBlock)>, Error> {
+		let srs = SRS.get_or_init(multiproof_params);
+		let cached = extended_grid(extrinsics, &block_len, seed)?;
+		let (grid, poly) = (&cached.grid, cached.poly()?);
+		let (rows, cols) = MULTIPROOF_GRID;
+		let target_dims = Dimensions::new(rows, cols).ok_or(Error::InvalidDimension)?;
+
+		let proofs = cells
+			.into_par_iter()
+			.map(|(row, col)| -> Result<(GMultiProof, GCellBlock), Error> {
+				grid.get(row as usize, col as usize)
+					.ok_or(Error::MissingCell { row, col })?;
+
+				let cell = Cell::new(BlockLengthRows(row), BlockLengthColumns(col));
+				let multiproof = poly
+					.multiproof(srs, &cell, grid, target_dims)
+					.map_err(|_| Error::Proof)?;
+				let data = multiproof
+					.evals
+					.iter()
+					.flatten()
+					.map(|scalar| scalar.to_bytes().map(GRawScalar::from))
+					.collect::<Result<Vec<_>, _>>()
+					.map_err(|_| Error::InvalidScalarAtRow(row))?;
+				let proof = multiproof
+					.proof
+					.to_bytes()
+					.map(GProof)
+					.map_err(|_| Error::Proof)?;
+				let block = GCellBlock {
+					start_x: multiproof.block.start_x.try_into()?,
+					start_y: multiproof.block.start_y.try_into()?,
+					end_x: multiproof.block.end_x.try_into()?,
+					end_y: multiproof.block.end_y.try_into()?,
+				};
+
+				Ok(((data, proof), block))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(proofs)
+	}
+
+	/// Returns whether the proofs of `cells` of the extended grid are valid against the row
+	/// commitments of a header, for a grid of `rows` by `cols` before its extension. Each row
+	/// has its commitment at its index in `commitment`.
+	fn verify_proofs(
+		commitment: Vec<u8>,
+		rows: u16,
+		cols: u16,
+		cells: Vec<((u32, u32), GDataProof)>,
+	) -> Result<bool, Error> {
+		let pp = kate::couscous::public_params();
+		let dims = Dimensions::new(rows, cols).ok_or(Error::InvalidDimension)?;
+
+		let verified = cells
+			.into_par_iter()
+			.map(|((row, col), (data, proof))| -> Result<bool, Error> {
+				let start = usize::try_from(row)? * COMMITMENT_SIZE;
+				let row_commitment: [u8; COMMITMENT_SIZE] = commitment
+					.get(start..start + COMMITMENT_SIZE)
+					.and_then(|c| c.try_into().ok())
+					.ok_or(Error::MissingCommitment(row))?;
+
+				// The content of a cell is its proof followed by its big endian scalar.
+				let mut content = [0u8; COMMITMENT_SIZE + 32];
+				content[..COMMITMENT_SIZE].copy_from_slice(&proof.0);
+				data.to_big_endian(&mut content[COMMITMENT_SIZE..]);
+				let position = Position {
+					row,
+					col: u16::try_from(col)?,
+				};
+				let cell = DataCell { position, content };
+
+				verify(&pp, dims, &row_commitment, &cell).map_err(|_| Error::Proof)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(verified.into_iter().all(|valid| valid))
+	}
+
 	fn app_data(
 		submitted: Vec<AppExtrinsic>,
 		block_length: BlockLength,
@@ -117,6 +296,106 @@ pub trait HostedKate {
 
 		Ok(all_rows)
 	}
+
+	/// Returns the first and last rows, in the grid before its extension, holding the app
+	/// extrinsic at `index` of `submitted`.
+	fn data_rows(
+		submitted: Vec<AppExtrinsic>,
+		block_length: BlockLength,
+		seed: Seed,
+		index: u32,
+	) -> Result<(u32, u32), Error> {
+		let position = usize::try_from(index)?;
+		let extrinsic = submitted
+			.get(position)
+			.ok_or(Error::MissingExtrinsic(index))?;
+		let app_id = extrinsic.app_id;
+		let len = extrinsic.data.len();
+
+		// The grid holds the SCALE encoded extrinsics of each application, in their block order.
+		let encoded_len = |len: usize| Compact::<u32>::compact_len(&len.saturated_into()) + len;
+		let count = submitted.iter().filter(|e| e.app_id == app_id).count();
+		let start = submitted[..position]
+			.iter()
+			.filter(|e| e.app_id == app_id)
+			.fold(
+				Compact::<u32>::compact_len(&count.saturated_into()),
+				|start, e| start + encoded_len(e.data.len()),
+			);
+		let end = start + encoded_len(len) - 1;
+
+		let (max_width, max_height) = to_width_height(&block_length);
+		let grid = EGrid::from_extrinsics(submitted, MIN_WIDTH, max_width, max_height, seed)?;
+		let width = grid.dims().width();
+		let app_start = grid.lookup().range_of(app_id).ok_or(Error::AppRow)?.start;
+		let row_of = |byte: usize| -> Result<u32, Error> {
+			let scalar = usize::try_from(app_start)? + byte / DATA_CHUNK_SIZE;
+			Ok(u32::try_from(scalar / width)?)
+		};
+
+		Ok((row_of(start)?, row_of(end)?))
+	}
+
+	/// Returns the first and last rows, in the grid before its extension, holding the data of
+	/// each application of `submitted`, in order of their IDs.
+	fn app_data_ranges(
+		submitted: Vec<AppExtrinsic>,
+		block_length: BlockLength,
+		seed: Seed,
+	) -> Result<Vec<(AppId, u32, u32)>, Error> {
+		let app_ids = submitted.iter().map(|e| e.app_id).collect::<BTreeSet<_>>();
+		let (max_width, max_height) = to_width_height(&block_length);
+		let grid = EGrid::from_extrinsics(submitted, MIN_WIDTH, max_width, max_height, seed)?;
+		let width = u32::try_from(grid.dims().width())?;
+
+		let ranges = app_ids
+			.into_iter()
+			.filter_map(|app_id| {
+				let range = grid.lookup().range_of(app_id)?;
+				(!range.is_empty()).then(|| (app_id, range.start / width, (range.end - 1) / width))
+			})
+			.collect();
+		Ok(ranges)
+	}
+}
+
+/// Returns the extended grid of `extrinsics`, from the cache if it was built for a recent
+/// query.
+#[cfg(feature = "std")]
+fn extended_grid(
+	extrinsics: Vec<AppExtrinsic>,
+	block_len: &BlockLength,
+	seed: Seed,
+) -> Result<Arc<CachedGrid>, Error> {
+	let key = blake2_256(&(&extrinsics, block_len, seed).encode());
+	let cache = GRIDS.get_or_init(|| {
+		Mutex::new(GridCache {
+			grids: LruCache::unbounded(),
+			bytes: 0,
+		})
+	});
+	if let Some(cached) = cache
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.get(&key)
+	{
+		return Ok(cached);
+	}
+
+	let (max_width, max_height) = to_width_height(block_len);
+	let grid = EGrid::from_extrinsics(extrinsics, MIN_WIDTH, max_width, max_height, seed)?
+		.extend_columns(NonZeroU16::new(2).expect("2>0"))
+		.map_err(|_| Error::ColumnExtension)?;
+
+	let cached = Arc::new(CachedGrid {
+		grid,
+		poly: OnceLock::new(),
+	});
+	cache
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.put(key, cached.clone());
+	Ok(cached)
 }
 
 fn to_width_height(block_len: &BlockLength) -> (usize, usize) {
@@ -1,7 +1,7 @@
-use super::{native::hosted_kate, Error, GDataProof, GRow};
+use super::{native::hosted_kate, Error, GCellBlock, GDataProof, GMultiProof, GRow};
 use da_control::LOG_TARGET as DALOG_TARGET;
 
-use avail_core::AppExtrinsic;
+use avail_core::{AppExtrinsic, AppId};
 use frame_system::{limits::BlockLength, Config as SystemConfig};
 use kate::Seed;
 
@@ -41,3 +41,40 @@ pub fn proof<T: SystemConfig>(
 	let seed = random_seed::<T>();
 	hosted_kate::proof(app_extrinsics, block_len, seed, cells)
 }
+
+pub fn multiproof<T: SystemConfig>(
+	app_extrinsics: Vec<AppExtrinsic>,
+	block_len: BlockLength,
+	cells: Vec<(u32, u32)>,
+) -> Result<Vec<(GMultiProof, GCellBlock)>, Error> {
+	let seed = random_seed::<T>();
+	hosted_kate::multiproof(app_extrinsics, block_len, seed, cells)
+}
+
+/// Returns whether the proofs of `cells` of the extended grid are valid against the row
+/// commitments of a header, for a grid of `rows` by `cols` before its extension.
+pub fn verify_proofs(
+	commitment: Vec<u8>,
+	rows: u16,
+	cols: u16,
+	cells: Vec<((u32, u32), GDataProof)>,
+) -> Result<bool, Error> {
+	hosted_kate::verify_proofs(commitment, rows, cols, cells)
+}
+
+pub fn data_rows<T: SystemConfig>(
+	app_extrinsics: Vec<AppExtrinsic>,
+	block_length: BlockLength,
+	index: u32,
+) -> Result<(u32, u32), Error> {
+	let seed = random_seed::<T>();
+	hosted_kate::data_rows(app_extrinsics, block_length, seed, index)
+}
+
+pub fn app_data_ranges<T: SystemConfig>(
+	app_extrinsics: Vec<AppExtrinsic>,
+	block_length: BlockLength,
+) -> Result<Vec<(AppId, u32, u32)>, Error> {
+	let seed = random_seed::<T>();
+	hosted_kate::app_data_ranges(app_extrinsics, block_length, seed)
+}
@@ -1,4 +1,5 @@
 use avail_core::{asdr::AppUncheckedExtrinsic, header::Header as DaHeader, OpaqueExtrinsic};
+use frame_support::traits::ConstU32;
 use sp_runtime::{
 	generic, impl_opaque_keys,
 	traits::{BlakeTwo256, IdentifyAccount, Verify},
@@ -57,7 +58,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	da_control::PrioritizedTransactionPayment<Runtime>,
 	da_control::CheckAppId<Runtime>,
 );
 
@@ -72,8 +73,21 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// All migrations executed on runtime upgrade.
+pub type Migrations = (
+	pallet_vector::migrations::v1::MigrateToV1<
+		Runtime,
+		ConstU32<{ pallet_vector::constants::BROADCASTER_DOMAIN }>,
+	>,
+	pallet_vector::migrations::v2::MigrateToV2<Runtime>,
+	pallet_vector::migrations::v3::MigrateToV3<Runtime>,
+	da_control::migrations::v1::MigrateToV1<Runtime>,
+	da_control::migrations::v2::MigrateToV2<Runtime>,
+);
+
 /// ID type for named reserves.
 pub type ReserveIdentifier = [u8; 8];
 
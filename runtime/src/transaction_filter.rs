@@ -1,4 +1,4 @@
-use crate::{AccountId, Runtime, RuntimeCall as Call, UncheckedExtrinsic};
+use crate::{AccountId, Runtime, RuntimeCall as Call, RuntimeEvent, UncheckedExtrinsic};
 use avail_base::header_extension::{
 	BridgedData, ExtractedTxData, HeaderExtensionDataFilter, SubmittedData,
 };
@@ -8,12 +8,20 @@ use avail_core::{
 	AppExtrinsic, AppId, OpaqueExtrinsic,
 };
 
+use codec::Encode;
 use da_control::Call as DACall;
-use pallet_vector::Call as VectorCall;
+use frame_system::Phase;
+use pallet_multisig::{Call as MultisigCall, Event as MultisigEvent};
+use pallet_proxy::Call as ProxyCall;
+use pallet_utility::Call as UtilityCall;
+use pallet_vector::{batch_message_id, Call as VectorCall};
 use sp_core::H256;
-use sp_std::vec::Vec;
+use sp_std::{vec, vec::Vec};
 
-/// Filters and extracts `data` from `call` if it is a `DataAvailability::submit_data` type.
+/// Filters and extracts `data` from `call` if it is a `DataAvailability::submit_data`,
+/// `DataAvailability::submit_compressed_data`, `DataAvailability::submit_sponsored_data`,
+/// `DataAvailability::submit_data_with_retention`, `DataAvailability::submit_reserved_data` or
+/// `DataAvailability::submit_data_batch` type, even if it is wrapped in proxy or multisig calls.
 impl HeaderExtensionDataFilter for Runtime {
 	fn filter(
 		failed_transactions: &[u32],
@@ -32,11 +40,13 @@ impl HeaderExtensionDataFilter for Runtime {
 			Call::Vector(call) => {
 				filter_vector_call(failed_transactions, maybe_caller, call, block, tx_index)
 			},
-			Call::DataAvailability(call) => {
+			function => {
+				let Call::DataAvailability(call) = dispatched_call(function, tx_index) else {
+					return None;
+				};
 				let app_extrinsic = AppExtrinsic::from(unchecked_extrinsic.clone());
 				filter_da_call(app_extrinsic, call, app_id, tx_index)
 			},
-			_ => None,
 		}
 	}
 
@@ -57,31 +67,91 @@ impl HeaderExtensionDataFilter for Runtime {
 	}
 }
 
+/// Returns the call dispatched through the proxy, multisig and derivative calls wrapping
+/// `call`. `CheckAppId` bounds their nesting in the included extrinsics.
+///
+/// `as_multi` only dispatches its call on the final approval. The filter runs once the block is
+/// executed, so the final approval is the one which deposited `MultisigExecuted` for the call.
+fn dispatched_call(mut call: &Call, tx_index: usize) -> &Call {
+	loop {
+		call = match call {
+			Call::Proxy(ProxyCall::proxy { call, .. })
+			| Call::Multisig(MultisigCall::as_multi_threshold_1 { call, .. })
+			| Call::Utility(UtilityCall::as_derivative { call, .. }) => call.as_ref(),
+			Call::Multisig(MultisigCall::as_multi { call, .. })
+				if executed_multisig(call, tx_index) =>
+			{
+				call.as_ref()
+			},
+			_ => return call,
+		};
+	}
+}
+
+/// Returns whether the extrinsic at `tx_index` executed the multisig operation of `call`.
+fn executed_multisig(call: &Call, tx_index: usize) -> bool {
+	let Ok(tx_index) = u32::try_from(tx_index) else {
+		return false;
+	};
+	let call_hash = sp_io::hashing::blake2_256(&call.encode());
+
+	frame_system::Pallet::<Runtime>::read_events_no_consensus().any(|record| {
+		record.phase == Phase::ApplyExtrinsic(tx_index)
+			&& matches!(
+				record.event,
+				RuntimeEvent::Multisig(MultisigEvent::MultisigExecuted { call_hash: hash, .. })
+					if hash == call_hash
+			)
+	})
+}
+
 /// Filters and extracts `data` from `calls` if internal data is not empty.
+///
+/// Each entry of a data batch is laid out in the grid as an app extrinsic of its own
+/// application, so the app extrinsics match the submitted data one to one.
 fn filter_da_call(
 	app_extrinsic: AppExtrinsic,
 	call: &DACall<Runtime>,
 	app_id: AppId,
 	tx_index: usize,
 ) -> Option<ExtractedTxData> {
-	let DACall::submit_data { data } = call else {
-		return None;
-	};
-
-	if data.is_empty() {
-		return None;
-	}
-
 	let tx_index = u32::try_from(tx_index).ok()?;
-	let submitted_data = Some(SubmittedData::new(
-		app_id,
-		tx_index,
-		data.as_slice().to_vec(),
-	));
+	let (submitted_data, app_extrinsics) = match call {
+		DACall::submit_data { data }
+		| DACall::submit_compressed_data { data, .. }
+		| DACall::submit_sponsored_data { data, .. }
+		| DACall::submit_data_with_retention { data, .. }
+		| DACall::submit_reserved_data { data } => {
+			if data.is_empty() {
+				return None;
+			}
+
+			let submitted = SubmittedData::new(app_id, tx_index, data.to_vec());
+			(vec![submitted], vec![app_extrinsic])
+		},
+		DACall::submit_data_batch { batch } => {
+			if batch.is_empty() || batch.iter().any(|(_, data)| data.is_empty()) {
+				return None;
+			}
+
+			batch
+				.iter()
+				.map(|(id, data)| {
+					let submitted = SubmittedData::new(*id, tx_index, data.to_vec());
+					let app_extrinsic = AppExtrinsic {
+						app_id: *id,
+						data: data.to_vec(),
+					};
+					(submitted, app_extrinsic)
+				})
+				.unzip()
+		},
+		_ => return None,
+	};
 
 	Some(ExtractedTxData {
 		submitted_data,
-		app_extrinsic: Some(app_extrinsic),
+		app_extrinsics,
 		..Default::default()
 	})
 }
@@ -99,23 +169,40 @@ fn filter_vector_call(
 		return None;
 	}
 
-	let VectorCall::send_message {
-		message,
-		to,
-		domain,
-	} = call
-	else {
-		return None;
+	let from: [u8; 32] = *caller?.as_ref();
+	let bridge_data = match call {
+		VectorCall::send_message {
+			message,
+			to,
+			domain,
+		} => {
+			if message.is_empty() {
+				return None;
+			}
+
+			let id = tx_uid(block, tx_index);
+			let msg = AddressedMessage::new(message.clone(), H256(from), *to, 1, *domain, id);
+			vec![BridgedData::new(tx_index, msg)]
+		},
+		VectorCall::send_messages { messages, domain } => {
+			if messages.is_empty() || messages.iter().any(|(message, _)| message.is_empty()) {
+				return None;
+			}
+
+			messages
+				.iter()
+				.zip(0u32..)
+				.map(|((message, to), index)| {
+					let id = batch_message_id(block, tx_index, index);
+					let msg =
+						AddressedMessage::new(message.clone(), H256(from), *to, 1, *domain, id);
+					BridgedData::new(tx_index, msg)
+				})
+				.collect()
+		},
+		_ => return None,
 	};
 
-	if message.is_empty() {
-		return None;
-	}
-
-	let from: [u8; 32] = *caller?.as_ref();
-	let id = tx_uid(block, tx_index);
-	let msg = AddressedMessage::new(message.clone(), H256(from), *to, 1, *domain, id);
-	let bridge_data = Some(BridgedData::new(tx_index, msg));
 	Some(ExtractedTxData {
 		bridge_data,
 		..Default::default()
@@ -15,9 +15,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `da_control`
+//! Weights for `da_control`, partly hand-written.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! NOT REGENERATED: this file is not the output of the benchmark run below. The weights marked
+//! PLACEHOLDER are hand-written estimates for the calls added since that run, not measurements.
+//! Those of the calls any signed account can dispatch, and of the hooks run every block, are
+//! doubled as a margin. This file must be regenerated with the command below before release.
+//!
+//! The other weights were measured by the SUBSTRATE BENCHMARK CLI VERSION 32.0.0 run of
 //! DATE: 2024-03-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-172-31-12-189`, CPU: `Intel(R) Xeon(R) Platinum 8175M CPU @ 2.50GHz`
@@ -50,53 +55,65 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 	/// Storage: `DataAvailability::AppKeys` (r:1 w:1)
-	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
 	/// Storage: `DataAvailability::NextAppId` (r:1 w:1)
 	/// Proof: `DataAvailability::NextAppId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn create_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `312`
-		//  Estimated: `3583`
+		//  Estimated: `3698`
 		// Minimum execution time: 23_525_000 picoseconds.
 		Weight::from_parts(24_376_000, 0)
-			.saturating_add(Weight::from_parts(0, 3583))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
-	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PendingBlockLengthProposal` (r:0 w:1)
+	/// Proof: `DataAvailability::PendingBlockLengthProposal` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn submit_block_length_proposal() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `65`
-		//  Estimated: `1509`
-		// Minimum execution time: 16_350_000 picoseconds.
-		Weight::from_parts(17_393_000, 0)
-			.saturating_add(Weight::from_parts(0, 1509))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_905_000 picoseconds.
+		Weight::from_parts(8_233_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `DataAvailability::DataBytePrice` (r:1 w:0)
+	/// Proof: `DataAvailability::DataBytePrice` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::SubmittedDataLeaves` (r:1 w:0)
+	/// Proof: `DataAvailability::SubmittedDataLeaves` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockRetentionPeriod` (r:1 w:1)
+	/// Proof: `DataAvailability::BlockRetentionPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// The range of component `i` is `[1, 524288]`.
 	fn submit_data(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `137`
+		//  Estimated: `3593`
 		// Minimum execution time: 12_741_000 picoseconds.
 		Weight::from_parts(5_340_425, 0)
-			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(0, 3593))
 			// Standard Error: 1
 			.saturating_add(Weight::from_parts(1_690, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `DataAvailability::AppKeys` (r:2 w:2)
-	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PendingAppKeyTransfers` (r:1 w:2)
+	/// Proof: `DataAvailability::PendingAppKeyTransfers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	fn set_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `377`
-		//  Estimated: `6176`
+		//  Estimated: `8930`
 		// Minimum execution time: 33_289_000 picoseconds.
 		Weight::from_parts(33_685_000, 0)
-			.saturating_add(Weight::from_parts(0, 6176))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 8930))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// The range of component `i` is `[0, 524288]`.
 	fn data_root(i: u32, ) -> Weight {
@@ -131,4 +148,283 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	fn set_application_key_metadata() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(42_600_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PendingAppKeyTransfers` (r:0 w:1)
+	/// Proof: `DataAvailability::PendingAppKeyTransfers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn transfer_app_key_ownership() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(39_600_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::PendingAppKeyTransfers` (r:1 w:1)
+	/// Proof: `DataAvailability::PendingAppKeyTransfers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_app_key_ownership() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(55_200_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::SubmitterAllowlists` (r:0 w:1)
+	/// Proof: `DataAvailability::SubmitterAllowlists` (`max_values`: None, `max_size`: Some(2062), added: 4537, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 64]`.
+	fn set_submitter_allowlist(s: u32, ) -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(41_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(Weight::from_parts(82_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppDataQuotas` (r:0 w:1)
+	/// Proof: `DataAvailability::AppDataQuotas` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppDataUsages` (r:0 w:1)
+	/// Proof: `DataAvailability::AppDataUsages` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn set_app_data_quota() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked.
+		Weight::from_parts(12_600_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::BlockLengthGovernor` (r:0 w:1)
+	/// Proof: `DataAvailability::BlockLengthGovernor` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::GridUtilization` (r:0 w:1)
+	/// Proof: `DataAvailability::GridUtilization` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_block_length_governor() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked.
+		Weight::from_parts(10_900_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::BlockLengthGovernor` (r:2 w:0)
+	/// Proof: `DataAvailability::BlockLengthGovernor` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::GridUtilization` (r:1 w:2)
+	/// Proof: `DataAvailability::GridUtilization` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:2 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+	/// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn govern_block_length() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as every block runs it.
+		Weight::from_parts(33_400_000, 0)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DataAvailability::DataBytePrice` (r:1 w:1)
+	/// Proof: `DataAvailability::DataBytePrice` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:1 w:0)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+	/// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_data_byte_price() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as every block runs it.
+		Weight::from_parts(19_600_000, 0)
+			.saturating_add(Weight::from_parts(0, 1509))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fund_sponsorship_pool() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(122_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_sponsorship_pool() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(118_494_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PendingAppKeyTransfers` (r:0 w:1)
+	/// Proof: `DataAvailability::PendingAppKeyTransfers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::SubmitterAllowlists` (r:0 w:1)
+	/// Proof: `DataAvailability::SubmitterAllowlists` (`max_values`: None, `max_size`: Some(2062), added: 4537, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppDataQuotas` (r:0 w:1)
+	/// Proof: `DataAvailability::AppDataQuotas` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppDataUsages` (r:0 w:1)
+	/// Proof: `DataAvailability::AppDataUsages` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppReservedSpaces` (r:1 w:1)
+	/// Proof: `DataAvailability::AppReservedSpaces` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ReservedSpaceUsages` (r:0 w:1)
+	/// Proof: `DataAvailability::ReservedSpaceUsages` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::TotalReservedSpace` (r:1 w:1)
+	/// Proof: `DataAvailability::TotalReservedSpace` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppMaxDataLengths` (r:0 w:1)
+	/// Proof: `DataAvailability::AppMaxDataLengths` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppChildren` (r:2 w:1)
+	/// Proof: `DataAvailability::AppChildren` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppParents` (r:1 w:1)
+	/// Proof: `DataAvailability::AppParents` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PausedApps` (r:0 w:1)
+	/// Proof: `DataAvailability::PausedApps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn remove_application_key() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(82_400_000, 0)
+			.saturating_add(Weight::from_parts(0, 5527))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `DataAvailability::AppReservedSpaces` (r:1 w:1)
+	/// Proof: `DataAvailability::AppReservedSpaces` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ReservedSpaceUsages` (r:0 w:1)
+	/// Proof: `DataAvailability::ReservedSpaceUsages` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::TotalReservedSpace` (r:1 w:1)
+	/// Proof: `DataAvailability::TotalReservedSpace` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_app_reserved_space() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked.
+		Weight::from_parts(14_300_000, 0)
+			.saturating_add(Weight::from_parts(0, 3481))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppMaxDataLengths` (r:0 w:1)
+	/// Proof: `DataAvailability::AppMaxDataLengths` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn set_app_max_data_length() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(36_820_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppSequences` (r:1 w:1)
+	/// Proof: `DataAvailability::AppSequences` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_app_sequencing() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(37_920_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppRetirements` (r:1 w:1)
+	/// Proof: `DataAvailability::AppRetirements` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn retire_application_key() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(35_900_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppRetirements` (r:1 w:0)
+	/// Proof: `DataAvailability::AppRetirements` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn complete_app_key_retirement() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(62_840_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::BlockSpaceIntentOf` (r:1 w:1)
+	/// Proof: `DataAvailability::BlockSpaceIntentOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::IntentSpaces` (r:1 w:1)
+	/// Proof: `DataAvailability::IntentSpaces` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::TotalReservedSpace` (r:1 w:0)
+	/// Proof: `DataAvailability::TotalReservedSpace` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::DataBytePrice` (r:1 w:0)
+	/// Proof: `DataAvailability::DataBytePrice` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reserve_block_space() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(85_740_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:2 w:1)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppParents` (r:1 w:1)
+	/// Proof: `DataAvailability::AppParents` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppChildren` (r:1 w:1)
+	/// Proof: `DataAvailability::AppChildren` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::NextAppId` (r:1 w:1)
+	/// Proof: `DataAvailability::NextAppId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn create_child_application_key() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(61_880_000, 0)
+			.saturating_add(Weight::from_parts(0, 6406))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PausedApps` (r:1 w:1)
+	/// Proof: `DataAvailability::PausedApps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn pause_application() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(32_420_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PausedApps` (r:1 w:1)
+	/// Proof: `DataAvailability::PausedApps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn unpause_application() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked. Doubled as signed accounts can dispatch it.
+		Weight::from_parts(33_740_000, 0)
+			.saturating_add(Weight::from_parts(0, 3698))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::PendingBlockLengthProposal` (r:1 w:1)
+	/// Proof: `DataAvailability::PendingBlockLengthProposal` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:1 w:0)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockWeight` (r:1 w:0)
+	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ScheduledBlockLengthChange` (r:0 w:1)
+	/// Proof: `DataAvailability::ScheduledBlockLengthChange` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn approve_block_length_proposal() -> Weight {
+		// PLACEHOLDER: hand-written, not benchmarked.
+		Weight::from_parts(19_733_000, 0)
+			.saturating_add(Weight::from_parts(0, 1599))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }